@@ -0,0 +1,115 @@
+use async_trait::async_trait;
+use domain_model::{
+    check_ng_words::{err::CheckNgWordsError, model::UnvalidatedCheckText},
+    login::model::LoggedInUser,
+    ng_word_policy::model::NgWordCheck,
+};
+use domain_service::check_ng_words::DomainService as CheckNgWordsDomainService;
+use std::sync::Arc;
+use tracing::error;
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn check_ng_words(
+        &self,
+        text: UnvalidatedCheckText,
+        user: &LoggedInUser,
+    ) -> Result<NgWordCheck, CheckNgWordsError>;
+}
+
+pub struct AppServiceImpl {
+    check_ng_words_domain_service: Arc<dyn CheckNgWordsDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(check_ng_words_domain_service: Arc<dyn CheckNgWordsDomainService>) -> Self {
+        AppServiceImpl {
+            check_ng_words_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn check_ng_words(
+        &self,
+        text: UnvalidatedCheckText,
+        user: &LoggedInUser,
+    ) -> Result<NgWordCheck, CheckNgWordsError> {
+        let result = self
+            .check_ng_words_domain_service
+            .check_ng_words(text)
+            .await;
+
+        if let Err(err) = &result {
+            error!("Failed to check ng words: {:?} user: {:?}", err, &user.name);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{
+            check_ng_words::err::ServiceError, login::model::Role,
+            ng_word_policy::model::ContentVerdict,
+        };
+        use mockall::{mock, predicate::*};
+
+        fn member() -> LoggedInUser {
+            LoggedInUser {
+                id: 1,
+                name: "member".to_string(),
+                role: Role::Member,
+            }
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl CheckNgWordsDomainService for DomainService {
+                async fn check_ng_words(&self, text: UnvalidatedCheckText) -> Result<NgWordCheck, CheckNgWordsError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_check_ng_words() {
+            let check = NgWordCheck {
+                matches: vec![],
+                verdict: ContentVerdict::Accept("content".to_string()),
+            };
+            let expected = check.clone();
+            let mut mock = MockDomainService::new();
+            mock.expect_check_ng_words()
+                .with(eq(UnvalidatedCheckText("content".to_string())))
+                .times(1)
+                .returning(move |_| Ok(check.clone()));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .check_ng_words(UnvalidatedCheckText("content".to_string()), &member())
+                .await;
+            assert_eq!(result, Ok(expected));
+        }
+
+        #[tokio::test]
+        async fn test_check_ng_words_service_error() {
+            let mut mock = MockDomainService::new();
+            mock.expect_check_ng_words().times(1).returning(|_| {
+                Err(CheckNgWordsError::ServiceError(ServiceError(
+                    "service error".to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .check_ng_words(UnvalidatedCheckText("content".to_string()), &member())
+                .await;
+            assert!(matches!(result, Err(CheckNgWordsError::ServiceError(_))));
+        }
+    }
+}
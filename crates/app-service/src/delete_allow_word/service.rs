@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use domain_model::{delete_allow_word::err::DeleteAllowWordError, login::model::LoggedInUser};
+use domain_service::delete_allow_word::DomainService as DeleteAllowWordDomainService;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn delete_allow_word(
+        &self,
+        id: i32,
+        user: &LoggedInUser,
+    ) -> Result<(), DeleteAllowWordError>;
+}
+
+pub struct AppServiceImpl {
+    delete_allow_word_domain_service: Arc<dyn DeleteAllowWordDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(delete_allow_word_domain_service: Arc<dyn DeleteAllowWordDomainService>) -> Self {
+        AppServiceImpl {
+            delete_allow_word_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn delete_allow_word(
+        &self,
+        id: i32,
+        user: &LoggedInUser,
+    ) -> Result<(), DeleteAllowWordError> {
+        let result = self
+            .delete_allow_word_domain_service
+            .delete_allow_word(id, user)
+            .await;
+
+        // 削除した行には記録が残らないため、誰が削除したかはログに残す
+        if let Err(err) = &result {
+            error!(
+                "Failed to delete allow word: {:?} id: {} user: {:?}",
+                err, id, &user.name
+            );
+        } else {
+            info!(
+                "Successfully deleted allow word: {} user: {:?}",
+                id, &user.name
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{delete_allow_word::err::NotFoundError, login::model::Role};
+        use mockall::{mock, predicate::*};
+
+        fn moderator() -> LoggedInUser {
+            LoggedInUser {
+                id: 1,
+                name: "moderator".to_string(),
+                role: Role::Moderator,
+            }
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl DeleteAllowWordDomainService for DomainService {
+                async fn delete_allow_word(&self, id: i32, user: &LoggedInUser) -> Result<(), DeleteAllowWordError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_delete_allow_word() {
+            let mut mock = MockDomainService::new();
+            mock.expect_delete_allow_word()
+                .with(eq(10), eq(moderator()))
+                .times(1)
+                .returning(|_, _| Ok(()));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.delete_allow_word(10, &moderator()).await;
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_delete_allow_word_not_found() {
+            let mut mock = MockDomainService::new();
+            mock.expect_delete_allow_word().times(1).returning(|_, _| {
+                Err(DeleteAllowWordError::NotFoundError(NotFoundError(
+                    "Allow word not found".to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.delete_allow_word(10, &moderator()).await;
+            assert!(matches!(
+                result,
+                Err(DeleteAllowWordError::NotFoundError(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use async_trait::async_trait;
+use domain_model::{delete_mute_word::err::DeleteMuteWordError, login::model::LoggedInUser};
+use domain_service::delete_mute_word::DomainService as DeleteMuteWordDomainService;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn delete_mute_word(
+        &self,
+        id: i32,
+        user: &LoggedInUser,
+    ) -> Result<(), DeleteMuteWordError>;
+}
+
+pub struct AppServiceImpl {
+    delete_mute_word_domain_service: Arc<dyn DeleteMuteWordDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(delete_mute_word_domain_service: Arc<dyn DeleteMuteWordDomainService>) -> Self {
+        AppServiceImpl {
+            delete_mute_word_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn delete_mute_word(
+        &self,
+        id: i32,
+        user: &LoggedInUser,
+    ) -> Result<(), DeleteMuteWordError> {
+        let result = self
+            .delete_mute_word_domain_service
+            .delete_mute_word(id, user)
+            .await;
+
+        if let Err(err) = &result {
+            error!(
+                "Failed to delete mute word: {:?} id: {} user: {:?}",
+                err, id, &user.name
+            );
+        } else {
+            info!(
+                "Successfully deleted mute word: {} user: {:?}",
+                id, &user.name
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{delete_mute_word::err::NotFoundError, login::model::Role};
+        use mockall::{mock, predicate::*};
+
+        fn member() -> LoggedInUser {
+            LoggedInUser {
+                id: 2,
+                name: "member".to_string(),
+                role: Role::Member,
+            }
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl DeleteMuteWordDomainService for DomainService {
+                async fn delete_mute_word(&self, id: i32, user: &LoggedInUser) -> Result<(), DeleteMuteWordError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_delete_mute_word() {
+            let mut mock = MockDomainService::new();
+            mock.expect_delete_mute_word()
+                .with(eq(10), eq(member()))
+                .times(1)
+                .returning(|_, _| Ok(()));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.delete_mute_word(10, &member()).await;
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_delete_mute_word_not_found() {
+            let mut mock = MockDomainService::new();
+            mock.expect_delete_mute_word().times(1).returning(|_, _| {
+                Err(DeleteMuteWordError::NotFoundError(NotFoundError(
+                    "Mute word not found".to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.delete_mute_word(10, &member()).await;
+            assert!(matches!(result, Err(DeleteMuteWordError::NotFoundError(_))));
+        }
+    }
+}
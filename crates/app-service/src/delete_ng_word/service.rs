@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+use domain_model::{delete_ng_word::err::DeleteNgWordError, login::model::LoggedInUser};
+use domain_service::delete_ng_word::DomainService as DeleteNgWordDomainService;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn delete_ng_word(&self, id: i32, user: &LoggedInUser) -> Result<(), DeleteNgWordError>;
+}
+
+pub struct AppServiceImpl {
+    delete_ng_word_domain_service: Arc<dyn DeleteNgWordDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(delete_ng_word_domain_service: Arc<dyn DeleteNgWordDomainService>) -> Self {
+        AppServiceImpl {
+            delete_ng_word_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn delete_ng_word(&self, id: i32, user: &LoggedInUser) -> Result<(), DeleteNgWordError> {
+        let result = self
+            .delete_ng_word_domain_service
+            .delete_ng_word(id, user)
+            .await;
+
+        // 削除した行には記録が残らないため、誰が削除したかはログに残す
+        if let Err(err) = &result {
+            error!(
+                "Failed to delete ng word: {:?} id: {} user: {:?}",
+                err, id, &user.name
+            );
+        } else {
+            info!(
+                "Successfully deleted ng word: {} user: {:?}",
+                id, &user.name
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{delete_ng_word::err::NotFoundError, login::model::Role};
+        use mockall::{mock, predicate::*};
+
+        fn moderator() -> LoggedInUser {
+            LoggedInUser {
+                id: 1,
+                name: "moderator".to_string(),
+                role: Role::Moderator,
+            }
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl DeleteNgWordDomainService for DomainService {
+                async fn delete_ng_word(&self, id: i32, user: &LoggedInUser) -> Result<(), DeleteNgWordError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_delete_ng_word() {
+            let mut mock = MockDomainService::new();
+            mock.expect_delete_ng_word()
+                .with(eq(10), eq(moderator()))
+                .times(1)
+                .returning(|_, _| Ok(()));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.delete_ng_word(10, &moderator()).await;
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_delete_ng_word_not_found() {
+            let mut mock = MockDomainService::new();
+            mock.expect_delete_ng_word().times(1).returning(|_, _| {
+                Err(DeleteNgWordError::NotFoundError(NotFoundError(
+                    "NG word not found".to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.delete_ng_word(10, &moderator()).await;
+            assert!(matches!(result, Err(DeleteNgWordError::NotFoundError(_))));
+        }
+    }
+}
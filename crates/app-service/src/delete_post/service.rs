@@ -0,0 +1,115 @@
+use async_trait::async_trait;
+use domain_model::{
+    delete_post::{err::DeletePostError, model::UnvalidatedDeleteReason},
+    login::model::LoggedInUser,
+};
+use domain_service::delete_post::DomainService as DeletePostDomainService;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn delete_post(
+        &self,
+        id: i32,
+        reason: UnvalidatedDeleteReason,
+        user: &LoggedInUser,
+    ) -> Result<(), DeletePostError>;
+}
+
+pub struct AppServiceImpl {
+    delete_post_domain_service: Arc<dyn DeletePostDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(delete_post_domain_service: Arc<dyn DeletePostDomainService>) -> Self {
+        AppServiceImpl {
+            delete_post_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn delete_post(
+        &self,
+        id: i32,
+        reason: UnvalidatedDeleteReason,
+        user: &LoggedInUser,
+    ) -> Result<(), DeletePostError> {
+        let result = self
+            .delete_post_domain_service
+            .delete_post(id, reason.clone(), user)
+            .await;
+
+        if let Err(err) = &result {
+            error!(
+                "Failed to delete post: {:?} id: {} user: {:?}",
+                err, id, &user.name
+            );
+        } else {
+            info!(
+                "Successfully deleted post: {} reason: {:?} user: {:?}",
+                id, reason.0, &user.name
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{delete_post::err::NotFoundError, login::model::Role};
+        use mockall::{mock, predicate::*};
+
+        fn moderator() -> LoggedInUser {
+            LoggedInUser {
+                id: 1,
+                name: "moderator".to_string(),
+                role: Role::Moderator,
+            }
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl DeletePostDomainService for DomainService {
+                async fn delete_post(&self, id: i32, reason: UnvalidatedDeleteReason, user: &LoggedInUser) -> Result<(), DeletePostError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_delete_post() {
+            let reason = UnvalidatedDeleteReason(Some("spam".to_string()));
+            let mut mock = MockDomainService::new();
+            mock.expect_delete_post()
+                .with(eq(1), eq(reason.clone()), eq(moderator()))
+                .times(1)
+                .returning(|_, _, _| Ok(()));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.delete_post(1, reason, &moderator()).await;
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_delete_post_not_found() {
+            let mut mock = MockDomainService::new();
+            mock.expect_delete_post().times(1).returning(|_, _, _| {
+                Err(DeletePostError::NotFoundError(NotFoundError(
+                    "Post not found".to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .delete_post(1, UnvalidatedDeleteReason::default(), &moderator())
+                .await;
+            assert!(matches!(result, Err(DeletePostError::NotFoundError(_))));
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use async_trait::async_trait;
+use domain_model::{delete_reaction::err::DeleteReactionError, login::model::LoggedInUser};
+use domain_service::delete_reaction::DomainService as DeleteReactionDomainService;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn delete_reaction(
+        &self,
+        post_id: i32,
+        kind: String,
+        user: &LoggedInUser,
+    ) -> Result<(), DeleteReactionError>;
+}
+
+pub struct AppServiceImpl {
+    delete_reaction_domain_service: Arc<dyn DeleteReactionDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(delete_reaction_domain_service: Arc<dyn DeleteReactionDomainService>) -> Self {
+        AppServiceImpl {
+            delete_reaction_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn delete_reaction(
+        &self,
+        post_id: i32,
+        kind: String,
+        user: &LoggedInUser,
+    ) -> Result<(), DeleteReactionError> {
+        let result = self
+            .delete_reaction_domain_service
+            .delete_reaction(post_id, kind.clone(), user)
+            .await;
+
+        if let Err(err) = &result {
+            error!(
+                "Failed to delete reaction: {:?} post_id: {} kind: {} user: {:?}",
+                err, post_id, kind, &user.name
+            );
+        } else {
+            info!(
+                "Successfully deleted reaction: {} post_id: {} user: {:?}",
+                kind, post_id, &user.name
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{delete_reaction::err::ValidationError, login::model::Role};
+        use mockall::{mock, predicate::*};
+
+        fn member() -> LoggedInUser {
+            LoggedInUser {
+                id: 2,
+                name: "member".to_string(),
+                role: Role::Member,
+            }
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl DeleteReactionDomainService for DomainService {
+                async fn delete_reaction(&self, post_id: i32, kind: String, user: &LoggedInUser) -> Result<(), DeleteReactionError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_delete_reaction() {
+            let mut mock = MockDomainService::new();
+            mock.expect_delete_reaction()
+                .with(eq(1), eq("like".to_string()), eq(member()))
+                .times(1)
+                .returning(|_, _, _| Ok(()));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .delete_reaction(1, "like".to_string(), &member())
+                .await;
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_delete_reaction_validation_error() {
+            let mut mock = MockDomainService::new();
+            mock.expect_delete_reaction().times(1).returning(|_, _, _| {
+                Err(DeleteReactionError::ValidationError(ValidationError(
+                    "Reaction kind must consist of lowercase letters, digits and underscores, or be a single emoji"
+                        .to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .delete_reaction(1, "Like".to_string(), &member())
+                .await;
+            assert!(matches!(
+                result,
+                Err(DeleteReactionError::ValidationError(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use domain_model::{
+    export_ng_words::{err::ExportNgWordsError, model::ExportedNgWord},
+    login::model::LoggedInUser,
+};
+use domain_service::export_ng_words::DomainService as ExportNgWordsDomainService;
+use std::sync::Arc;
+use tracing::error;
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn export_ng_words(
+        &self,
+        user: &LoggedInUser,
+    ) -> Result<Vec<ExportedNgWord>, ExportNgWordsError>;
+}
+
+pub struct AppServiceImpl {
+    export_ng_words_domain_service: Arc<dyn ExportNgWordsDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(export_ng_words_domain_service: Arc<dyn ExportNgWordsDomainService>) -> Self {
+        AppServiceImpl {
+            export_ng_words_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn export_ng_words(
+        &self,
+        user: &LoggedInUser,
+    ) -> Result<Vec<ExportedNgWord>, ExportNgWordsError> {
+        let result = self
+            .export_ng_words_domain_service
+            .export_ng_words(user)
+            .await;
+
+        if let Err(err) = &result {
+            error!(
+                "Failed to export ng words: {:?} user: {:?}",
+                err, &user.name
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{export_ng_words::err::ServiceError, login::model::Role};
+        use mockall::{mock, predicate::*};
+
+        fn moderator() -> LoggedInUser {
+            LoggedInUser {
+                id: 1,
+                name: "moderator".to_string(),
+                role: Role::Moderator,
+            }
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl ExportNgWordsDomainService for DomainService {
+                async fn export_ng_words(&self, user: &LoggedInUser) -> Result<Vec<ExportedNgWord>, ExportNgWordsError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_export_ng_words() {
+            let mut mock = MockDomainService::new();
+            mock.expect_export_ng_words()
+                .with(eq(moderator()))
+                .times(1)
+                .returning(|_| Ok(vec![]));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.export_ng_words(&moderator()).await;
+            assert_eq!(result, Ok(vec![]));
+        }
+
+        #[tokio::test]
+        async fn test_export_ng_words_service_error() {
+            let mut mock = MockDomainService::new();
+            mock.expect_export_ng_words().times(1).returning(|_| {
+                Err(ExportNgWordsError::ServiceError(ServiceError(
+                    "service error".to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.export_ng_words(&moderator()).await;
+            assert!(matches!(result, Err(ExportNgWordsError::ServiceError(_))));
+        }
+    }
+}
@@ -1,12 +1,19 @@
 use async_trait::async_trait;
-use domain_model::get_all_post::{err::ServiceError, model::Post};
+use domain_model::get_all_post::{
+    err::GetAllPostError,
+    model::{PostPage, UnvalidatedPageRequest, Viewer},
+};
 use domain_service::get_all_post::DomainService as GetAllPostDomainService;
 use std::sync::Arc;
 use tracing::{error, info};
 
 #[async_trait]
 pub trait AppService: Send + Sync {
-    async fn get_all_post(&self) -> Result<Vec<Post>, ServiceError>;
+    async fn get_all_post(
+        &self,
+        page: UnvalidatedPageRequest,
+        viewer: Option<Viewer>,
+    ) -> Result<PostPage, GetAllPostError>;
 }
 
 pub struct AppServiceImpl {
@@ -21,8 +28,15 @@ impl AppServiceImpl {
 }
 #[async_trait]
 impl AppService for AppServiceImpl {
-    async fn get_all_post(&self) -> Result<Vec<Post>, ServiceError> {
-        let result = self.get_all_post_domain_service.get_all_post().await;
+    async fn get_all_post(
+        &self,
+        page: UnvalidatedPageRequest,
+        viewer: Option<Viewer>,
+    ) -> Result<PostPage, GetAllPostError> {
+        let result = self
+            .get_all_post_domain_service
+            .get_all_post(page, viewer)
+            .await;
 
         if let Err(err) = &result {
             error!("Failed to get all post: {:?}", err);
@@ -48,26 +62,42 @@ mod tests {
             pub DomainService{}
             #[async_trait]
             impl GetAllPostDomainService for DomainService{
-                async fn get_all_post(&self) -> Result<Vec<Post>, ServiceError>;
+                async fn get_all_post(&self, page: UnvalidatedPageRequest, viewer: Option<Viewer>) -> Result<PostPage, GetAllPostError>;
             }
         }
 
         #[tokio::test]
         async fn test_get_all_post() {
             let mut domain_service = MockDomainService::new();
-            domain_service.expect_get_all_post().times(1).returning(|| {
-                Ok(vec![Post {
-                    posted_user_name: PostedUserName("test".to_string()),
-                    posted_datetime: PostedDatetime(
-                        NaiveDateTime::parse_from_str("2021-01-01T00:00:00Z", DATETIME_FORMAT)
-                            .unwrap(),
-                    ),
-                    content: Content("test".to_string()),
-                }])
-            });
+            domain_service
+                .expect_get_all_post()
+                .with(eq(UnvalidatedPageRequest::default()), eq(None))
+                .times(1)
+                .returning(|_, _| {
+                    Ok(PostPage {
+                        posts: vec![Post {
+                            id: 1,
+                            posted_user_name: PostedUserName("test".to_string()),
+                            posted_datetime: PostedDatetime(
+                                NaiveDateTime::parse_from_str(
+                                    "2021-01-01T00:00:00Z",
+                                    DATETIME_FORMAT,
+                                )
+                                .unwrap(),
+                            ),
+                            content: Content("test".to_string()),
+                            muted: false,
+                            reply_count: 0,
+                            reactions: vec![],
+                        }],
+                        next_cursor: None,
+                    })
+                });
 
             let app_service = AppServiceImpl::new(Arc::new(domain_service));
-            let result = app_service.get_all_post().await;
+            let result = app_service
+                .get_all_post(UnvalidatedPageRequest::default(), None)
+                .await;
 
             assert!(result.is_ok());
         }
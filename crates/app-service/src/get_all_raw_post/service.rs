@@ -0,0 +1,106 @@
+use async_trait::async_trait;
+use domain_model::{
+    get_all_raw_post::{err::GetAllRawPostError, model::RawPost},
+    login::model::LoggedInUser,
+};
+use domain_service::get_all_raw_post::DomainService as GetAllRawPostDomainService;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn get_all_raw_post(
+        &self,
+        user: &LoggedInUser,
+    ) -> Result<Vec<RawPost>, GetAllRawPostError>;
+}
+
+pub struct AppServiceImpl {
+    get_all_raw_post_domain_service: Arc<dyn GetAllRawPostDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(get_all_raw_post_domain_service: Arc<dyn GetAllRawPostDomainService>) -> Self {
+        AppServiceImpl {
+            get_all_raw_post_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn get_all_raw_post(
+        &self,
+        user: &LoggedInUser,
+    ) -> Result<Vec<RawPost>, GetAllRawPostError> {
+        let result = self
+            .get_all_raw_post_domain_service
+            .get_all_raw_post(user)
+            .await;
+
+        // 塗りつぶす前の内容を誰が見たか残す
+        if let Err(err) = &result {
+            error!(
+                "Failed to get all raw post: {:?} user: {:?}",
+                err, &user.name
+            );
+        } else {
+            info!("Successfully get all raw post user: {:?}", &user.name);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{get_all_raw_post::err::ServiceError, login::model::Role};
+        use mockall::{mock, predicate::*};
+
+        fn moderator() -> LoggedInUser {
+            LoggedInUser {
+                id: 1,
+                name: "moderator".to_string(),
+                role: Role::Moderator,
+            }
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl GetAllRawPostDomainService for DomainService {
+                async fn get_all_raw_post(&self, user: &LoggedInUser) -> Result<Vec<RawPost>, GetAllRawPostError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_get_all_raw_post() {
+            let mut mock = MockDomainService::new();
+            mock.expect_get_all_raw_post()
+                .with(eq(moderator()))
+                .times(1)
+                .returning(|_| Ok(vec![]));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.get_all_raw_post(&moderator()).await;
+            assert_eq!(result, Ok(vec![]));
+        }
+
+        #[tokio::test]
+        async fn test_get_all_raw_post_service_error() {
+            let mut mock = MockDomainService::new();
+            mock.expect_get_all_raw_post().times(1).returning(|_| {
+                Err(GetAllRawPostError::ServiceError(ServiceError(
+                    "service error".to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.get_all_raw_post(&moderator()).await;
+            assert!(matches!(result, Err(GetAllRawPostError::ServiceError(_))));
+        }
+    }
+}
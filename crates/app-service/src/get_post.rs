@@ -0,0 +1,2 @@
+mod service;
+pub use service::{AppService, AppServiceImpl};
@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use domain_model::get_post::{err::GetPostError, model::PostView};
+use domain_service::get_post::DomainService as GetPostDomainService;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn get_post(&self, id: i32) -> Result<PostView, GetPostError>;
+}
+
+pub struct AppServiceImpl {
+    get_post_domain_service: Arc<dyn GetPostDomainService>,
+}
+impl AppServiceImpl {
+    pub fn new(get_post_domain_service: Arc<dyn GetPostDomainService>) -> Self {
+        Self {
+            get_post_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn get_post(&self, id: i32) -> Result<PostView, GetPostError> {
+        let result = self.get_post_domain_service.get_post(id).await;
+
+        if let Err(err) = &result {
+            error!("Failed to get post: {:?} id: {}", err, id);
+        } else {
+            info!("Successfully get post id: {}", id);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::get_post::err::NotFoundError;
+        use mockall::{mock, predicate::*};
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl GetPostDomainService for DomainService {
+                async fn get_post(&self, id: i32) -> Result<PostView, GetPostError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_get_post_not_found() {
+            let mut mock = MockDomainService::new();
+            mock.expect_get_post().with(eq(1)).times(1).returning(|_| {
+                Err(GetPostError::NotFoundError(NotFoundError(
+                    "Post not found".to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.get_post(1).await;
+            assert!(matches!(result, Err(GetPostError::NotFoundError(_))));
+        }
+    }
+}
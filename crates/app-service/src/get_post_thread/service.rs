@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use domain_model::get_post_thread::{err::GetPostThreadError, model::ThreadNode};
+use domain_service::get_post_thread::DomainService as GetPostThreadDomainService;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn get_post_thread(
+        &self,
+        id: i32,
+        depth: Option<usize>,
+    ) -> Result<ThreadNode, GetPostThreadError>;
+}
+
+pub struct AppServiceImpl {
+    get_post_thread_domain_service: Arc<dyn GetPostThreadDomainService>,
+}
+impl AppServiceImpl {
+    pub fn new(get_post_thread_domain_service: Arc<dyn GetPostThreadDomainService>) -> Self {
+        Self {
+            get_post_thread_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn get_post_thread(
+        &self,
+        id: i32,
+        depth: Option<usize>,
+    ) -> Result<ThreadNode, GetPostThreadError> {
+        let result = self
+            .get_post_thread_domain_service
+            .get_post_thread(id, depth)
+            .await;
+
+        if let Err(err) = &result {
+            error!(
+                "Failed to get post thread: {:?} id: {} depth: {:?}",
+                err, id, depth
+            );
+        } else {
+            info!("Successfully get post thread id: {} depth: {:?}", id, depth);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::get_post_thread::err::NotFoundError;
+        use mockall::{mock, predicate::*};
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl GetPostThreadDomainService for DomainService {
+                async fn get_post_thread(&self, id: i32, depth: Option<usize>) -> Result<ThreadNode, GetPostThreadError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_get_post_thread_not_found() {
+            let mut mock = MockDomainService::new();
+            mock.expect_get_post_thread()
+                .with(eq(1), eq(Some(2)))
+                .times(1)
+                .returning(|_, _| {
+                    Err(GetPostThreadError::NotFoundError(NotFoundError(
+                        "Post not found".to_string(),
+                    )))
+                });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.get_post_thread(1, Some(2)).await;
+            assert!(matches!(result, Err(GetPostThreadError::NotFoundError(_))));
+        }
+    }
+}
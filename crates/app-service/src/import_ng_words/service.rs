@@ -0,0 +1,137 @@
+use async_trait::async_trait;
+use domain_model::{
+    import_ng_words::{
+        err::ImportNgWordsError,
+        model::{ImportReport, ImportRow},
+    },
+    login::model::LoggedInUser,
+};
+use domain_service::import_ng_words::DomainService as ImportNgWordsDomainService;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn import_ng_words(
+        &self,
+        rows: Vec<ImportRow>,
+        dry_run: bool,
+        user: &LoggedInUser,
+    ) -> Result<ImportReport, ImportNgWordsError>;
+}
+
+pub struct AppServiceImpl {
+    import_ng_words_domain_service: Arc<dyn ImportNgWordsDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(import_ng_words_domain_service: Arc<dyn ImportNgWordsDomainService>) -> Self {
+        AppServiceImpl {
+            import_ng_words_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn import_ng_words(
+        &self,
+        rows: Vec<ImportRow>,
+        dry_run: bool,
+        user: &LoggedInUser,
+    ) -> Result<ImportReport, ImportNgWordsError> {
+        let count = rows.len();
+        let result = self
+            .import_ng_words_domain_service
+            .import_ng_words(rows, dry_run, user)
+            .await;
+
+        match &result {
+            Err(err) => error!(
+                "Failed to import ng words: {:?} rows: {} user: {:?}",
+                err, count, &user.name
+            ),
+            Ok(report) if report.imported => info!(
+                "Successfully imported ng words: {} user: {:?}",
+                count, &user.name
+            ),
+            Ok(_) => {}
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{
+            import_ng_words::err::ServiceError, login::model::Role,
+            register_ng_word::model::UnvalidatedNgWord,
+        };
+        use mockall::{mock, predicate::*};
+
+        fn moderator() -> LoggedInUser {
+            LoggedInUser {
+                id: 1,
+                name: "moderator".to_string(),
+                role: Role::Moderator,
+            }
+        }
+
+        fn rows() -> Vec<ImportRow> {
+            vec![ImportRow::Parsed(UnvalidatedNgWord {
+                ng_word: "bad".to_string(),
+                kind: "literal".to_string(),
+                severity: "mask".to_string(),
+            })]
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl ImportNgWordsDomainService for DomainService {
+                async fn import_ng_words(&self, rows: Vec<ImportRow>, dry_run: bool, user: &LoggedInUser) -> Result<ImportReport, ImportNgWordsError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_import_ng_words() {
+            let report = ImportReport {
+                rows: vec![],
+                dry_run: true,
+                imported: false,
+            };
+            let expected = report.clone();
+            let mut mock = MockDomainService::new();
+            mock.expect_import_ng_words()
+                .with(eq(rows()), eq(true), eq(moderator()))
+                .times(1)
+                .returning(move |_, _, _| Ok(report.clone()));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .import_ng_words(rows(), true, &moderator())
+                .await;
+            assert_eq!(result, Ok(expected));
+        }
+
+        #[tokio::test]
+        async fn test_import_ng_words_service_error() {
+            let mut mock = MockDomainService::new();
+            mock.expect_import_ng_words().times(1).returning(|_, _, _| {
+                Err(ImportNgWordsError::ServiceError(ServiceError(
+                    "service error".to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .import_ng_words(rows(), false, &moderator())
+                .await;
+            assert!(matches!(result, Err(ImportNgWordsError::ServiceError(_))));
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use domain_model::{
+    list_allow_words::{err::ListAllowWordsError, model::AllowWord},
+    login::model::LoggedInUser,
+};
+use domain_service::list_allow_words::DomainService as ListAllowWordsDomainService;
+use std::sync::Arc;
+use tracing::error;
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn list_allow_words(
+        &self,
+        user: &LoggedInUser,
+    ) -> Result<Vec<AllowWord>, ListAllowWordsError>;
+}
+
+pub struct AppServiceImpl {
+    list_allow_words_domain_service: Arc<dyn ListAllowWordsDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(list_allow_words_domain_service: Arc<dyn ListAllowWordsDomainService>) -> Self {
+        AppServiceImpl {
+            list_allow_words_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn list_allow_words(
+        &self,
+        user: &LoggedInUser,
+    ) -> Result<Vec<AllowWord>, ListAllowWordsError> {
+        let result = self
+            .list_allow_words_domain_service
+            .list_allow_words(user)
+            .await;
+
+        if let Err(err) = &result {
+            error!(
+                "Failed to list allow words: {:?} user: {:?}",
+                err, &user.name
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{list_allow_words::err::ServiceError, login::model::Role};
+        use mockall::{mock, predicate::*};
+
+        fn moderator() -> LoggedInUser {
+            LoggedInUser {
+                id: 1,
+                name: "moderator".to_string(),
+                role: Role::Moderator,
+            }
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl ListAllowWordsDomainService for DomainService {
+                async fn list_allow_words(&self, user: &LoggedInUser) -> Result<Vec<AllowWord>, ListAllowWordsError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_list_allow_words() {
+            let mut mock = MockDomainService::new();
+            mock.expect_list_allow_words()
+                .with(eq(moderator()))
+                .times(1)
+                .returning(|_| Ok(vec![]));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.list_allow_words(&moderator()).await;
+            assert_eq!(result, Ok(vec![]));
+        }
+
+        #[tokio::test]
+        async fn test_list_allow_words_service_error() {
+            let mut mock = MockDomainService::new();
+            mock.expect_list_allow_words().times(1).returning(|_| {
+                Err(ListAllowWordsError::ServiceError(ServiceError(
+                    "service error".to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.list_allow_words(&moderator()).await;
+            assert!(matches!(result, Err(ListAllowWordsError::ServiceError(_))));
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use domain_model::{
+    list_mute_words::{err::ListMuteWordsError, model::MuteWord},
+    login::model::LoggedInUser,
+};
+use domain_service::list_mute_words::DomainService as ListMuteWordsDomainService;
+use std::sync::Arc;
+use tracing::error;
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn list_mute_words(
+        &self,
+        user: &LoggedInUser,
+    ) -> Result<Vec<MuteWord>, ListMuteWordsError>;
+}
+
+pub struct AppServiceImpl {
+    list_mute_words_domain_service: Arc<dyn ListMuteWordsDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(list_mute_words_domain_service: Arc<dyn ListMuteWordsDomainService>) -> Self {
+        AppServiceImpl {
+            list_mute_words_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn list_mute_words(
+        &self,
+        user: &LoggedInUser,
+    ) -> Result<Vec<MuteWord>, ListMuteWordsError> {
+        let result = self
+            .list_mute_words_domain_service
+            .list_mute_words(user)
+            .await;
+
+        if let Err(err) = &result {
+            error!(
+                "Failed to list mute words: {:?} user: {:?}",
+                err, &user.name
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{list_mute_words::err::ServiceError, login::model::Role};
+        use mockall::{mock, predicate::*};
+
+        fn member() -> LoggedInUser {
+            LoggedInUser {
+                id: 2,
+                name: "member".to_string(),
+                role: Role::Member,
+            }
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl ListMuteWordsDomainService for DomainService {
+                async fn list_mute_words(&self, user: &LoggedInUser) -> Result<Vec<MuteWord>, ListMuteWordsError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_list_mute_words() {
+            let mut mock = MockDomainService::new();
+            mock.expect_list_mute_words()
+                .with(eq(member()))
+                .times(1)
+                .returning(|_| Ok(vec![]));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.list_mute_words(&member()).await;
+            assert_eq!(result, Ok(vec![]));
+        }
+
+        #[tokio::test]
+        async fn test_list_mute_words_service_error() {
+            let mut mock = MockDomainService::new();
+            mock.expect_list_mute_words().times(1).returning(|_| {
+                Err(ListMuteWordsError::ServiceError(ServiceError(
+                    "service error".to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.list_mute_words(&member()).await;
+            assert!(matches!(result, Err(ListMuteWordsError::ServiceError(_))));
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+use domain_model::{
+    list_ng_words::{
+        err::ListNgWordsError,
+        model::{NgWordPage, UnvalidatedNgWordQuery},
+    },
+    login::model::LoggedInUser,
+};
+use domain_service::list_ng_words::DomainService as ListNgWordsDomainService;
+use std::sync::Arc;
+use tracing::error;
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn list_ng_words(
+        &self,
+        query: UnvalidatedNgWordQuery,
+        user: &LoggedInUser,
+    ) -> Result<NgWordPage, ListNgWordsError>;
+}
+
+pub struct AppServiceImpl {
+    list_ng_words_domain_service: Arc<dyn ListNgWordsDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(list_ng_words_domain_service: Arc<dyn ListNgWordsDomainService>) -> Self {
+        AppServiceImpl {
+            list_ng_words_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn list_ng_words(
+        &self,
+        query: UnvalidatedNgWordQuery,
+        user: &LoggedInUser,
+    ) -> Result<NgWordPage, ListNgWordsError> {
+        let result = self
+            .list_ng_words_domain_service
+            .list_ng_words(query.clone(), user)
+            .await;
+
+        if let Err(err) = &result {
+            error!(
+                "Failed to list ng words: {:?} query: {:?} user: {:?}",
+                err, query, &user.name
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{list_ng_words::err::ServiceError, login::model::Role};
+        use mockall::{mock, predicate::*};
+
+        fn moderator() -> LoggedInUser {
+            LoggedInUser {
+                id: 1,
+                name: "moderator".to_string(),
+                role: Role::Moderator,
+            }
+        }
+
+        fn empty_page() -> NgWordPage {
+            NgWordPage {
+                ng_words: vec![],
+                total: 0,
+                page: 1,
+                per_page: 20,
+            }
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl ListNgWordsDomainService for DomainService {
+                async fn list_ng_words(&self, query: UnvalidatedNgWordQuery, user: &LoggedInUser) -> Result<NgWordPage, ListNgWordsError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_list_ng_words() {
+            let mut mock = MockDomainService::new();
+            mock.expect_list_ng_words()
+                .with(eq(UnvalidatedNgWordQuery::default()), eq(moderator()))
+                .times(1)
+                .returning(|_, _| Ok(empty_page()));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .list_ng_words(UnvalidatedNgWordQuery::default(), &moderator())
+                .await;
+            assert_eq!(result, Ok(empty_page()));
+        }
+
+        #[tokio::test]
+        async fn test_list_ng_words_service_error() {
+            let mut mock = MockDomainService::new();
+            mock.expect_list_ng_words().times(1).returning(|_, _| {
+                Err(ListNgWordsError::ServiceError(ServiceError(
+                    "service error".to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .list_ng_words(UnvalidatedNgWordQuery::default(), &moderator())
+                .await;
+            assert!(matches!(result, Err(ListNgWordsError::ServiceError(_))));
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use domain_model::list_post_revisions::{err::ListPostRevisionsError, model::PostRevision};
+use domain_service::list_post_revisions::DomainService as ListPostRevisionsDomainService;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn list_post_revisions(
+        &self,
+        post_id: i32,
+    ) -> Result<Vec<PostRevision>, ListPostRevisionsError>;
+}
+
+pub struct AppServiceImpl {
+    list_post_revisions_domain_service: Arc<dyn ListPostRevisionsDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(
+        list_post_revisions_domain_service: Arc<dyn ListPostRevisionsDomainService>,
+    ) -> Self {
+        AppServiceImpl {
+            list_post_revisions_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn list_post_revisions(
+        &self,
+        post_id: i32,
+    ) -> Result<Vec<PostRevision>, ListPostRevisionsError> {
+        let result = self
+            .list_post_revisions_domain_service
+            .list_post_revisions(post_id)
+            .await;
+
+        if let Err(err) = &result {
+            error!("Failed to list post revisions: {:?} post: {}", err, post_id);
+        } else {
+            info!("Successfully listed post revisions post: {}", post_id);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::list_post_revisions::err::NotFoundError;
+        use mockall::{mock, predicate::*};
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl ListPostRevisionsDomainService for DomainService {
+                async fn list_post_revisions(&self, post_id: i32) -> Result<Vec<PostRevision>, ListPostRevisionsError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_list_post_revisions() {
+            let mut mock = MockDomainService::new();
+            mock.expect_list_post_revisions()
+                .with(eq(1))
+                .times(1)
+                .returning(|_| Ok(vec![]));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.list_post_revisions(1).await;
+            assert_eq!(result, Ok(vec![]));
+        }
+
+        #[tokio::test]
+        async fn test_list_post_revisions_not_found() {
+            let mut mock = MockDomainService::new();
+            mock.expect_list_post_revisions().times(1).returning(|_| {
+                Err(ListPostRevisionsError::NotFoundError(NotFoundError(
+                    "Post not found".to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.list_post_revisions(1).await;
+            assert!(matches!(
+                result,
+                Err(ListPostRevisionsError::NotFoundError(_))
+            ));
+        }
+    }
+}
@@ -1,12 +1,23 @@
 use async_trait::async_trait;
-use domain_model::login::{err::LoginError, model::UncheckedUserName};
+use domain_model::login::{
+    err::LoginError,
+    model::{LoggedInUser, Session, SessionToken, UncheckedPassword, UncheckedUserName},
+};
 use domain_service::login::DomainService as loginDomainService;
 use std::sync::Arc;
 use tracing::{error, info};
 
 #[async_trait]
 pub trait AppService: Send + Sync {
-    async fn login(&self, user_name: UncheckedUserName) -> Result<(), LoginError>;
+    async fn login(
+        &self,
+        user_name: UncheckedUserName,
+        password: UncheckedPassword,
+    ) -> Result<Session, LoginError>;
+    async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+    async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+    async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+    async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
 }
 
 pub struct AppServiceImpl {
@@ -21,8 +32,15 @@ impl AppServiceImpl {
 }
 #[async_trait]
 impl AppService for AppServiceImpl {
-    async fn login(&self, user_name: UncheckedUserName) -> Result<(), LoginError> {
-        let result = self.login_domain_service.login(user_name.clone()).await;
+    async fn login(
+        &self,
+        user_name: UncheckedUserName,
+        password: UncheckedPassword,
+    ) -> Result<Session, LoginError> {
+        let result = self
+            .login_domain_service
+            .login(user_name.clone(), password)
+            .await;
 
         if let Err(err) = &result {
             error!("Failed to login: {:?} name: {:?}", err, &user_name.0);
@@ -31,6 +49,50 @@ impl AppService for AppServiceImpl {
         }
         result
     }
+
+    async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError> {
+        let result = self.login_domain_service.authenticate(token).await;
+
+        if let Err(err) = &result {
+            error!("Failed to authenticate: {:?}", err);
+        }
+        result
+    }
+
+    async fn logout(&self, token: SessionToken) -> Result<(), LoginError> {
+        let result = self.login_domain_service.logout(token).await;
+
+        if let Err(err) = &result {
+            error!("Failed to logout: {:?}", err);
+        } else {
+            info!("Successfully logout");
+        }
+        result
+    }
+
+    async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError> {
+        let result = self.login_domain_service.revoke_all_sessions(user).await;
+
+        if let Err(err) = &result {
+            error!(
+                "Failed to revoke sessions: {:?} name: {:?}",
+                err, &user.name
+            );
+        } else {
+            info!("Successfully revoked all sessions: {:?}", &user.name);
+        }
+        result
+    }
+
+    async fn purge_expired_sessions(&self) -> Result<u64, LoginError> {
+        let result = self.login_domain_service.purge_expired_sessions().await;
+
+        match &result {
+            Ok(count) => info!("Successfully purged expired sessions: {}", count),
+            Err(err) => error!("Failed to purge expired sessions: {:?}", err),
+        }
+        result
+    }
 }
 
 #[cfg(test)]
@@ -39,34 +101,47 @@ mod tests {
 
     mod app_service_impl {
         use super::*;
-        use domain_model::login::err::{ServiceError, ValidationError};
+        use chrono::Utc;
+        use domain_model::login::err::{ServiceError, SessionError, ValidationError};
+        use domain_model::login::model::Role;
         use mockall::{mock, predicate::*};
 
         mock! {
             pub DomainService{}
             #[async_trait]
             impl loginDomainService for DomainService{
-                async fn login(&self, user_name: UncheckedUserName) -> Result<(), LoginError>;
+                async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+                async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+                async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+                async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+                async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
             }
         }
 
         #[tokio::test]
         async fn test_login() {
+            let session = Session::new(Utc::now());
+            let expected = session.clone();
             let mut domain_service = MockDomainService::new();
-            domain_service.expect_login().times(1).returning(|_| Ok(()));
+            domain_service
+                .expect_login()
+                .times(1)
+                .returning(move |_, _| Ok(session.clone()));
 
             let app_service = AppServiceImpl::new(Arc::new(domain_service));
             let user_name = UncheckedUserName("user_name".to_string());
-            let result = app_service.login(user_name).await;
+            let result = app_service
+                .login(user_name, UncheckedPassword("password1".to_string()))
+                .await;
 
             assert!(result.is_ok());
-            assert_eq!(result, Ok(()));
+            assert_eq!(result, Ok(expected));
         }
 
         #[tokio::test]
         async fn test_login_error() {
             let mut domain_service = MockDomainService::new();
-            domain_service.expect_login().times(1).returning(|_| {
+            domain_service.expect_login().times(1).returning(|_, _| {
                 Err(LoginError::ValidationError(ValidationError(
                     "error".to_string(),
                 )))
@@ -74,7 +149,9 @@ mod tests {
 
             let app_service = AppServiceImpl::new(Arc::new(domain_service));
             let user_name = UncheckedUserName("user_name".to_string());
-            let result = app_service.login(user_name).await;
+            let result = app_service
+                .login(user_name, UncheckedPassword("password1".to_string()))
+                .await;
 
             assert!(result.is_err());
             assert_eq!(
@@ -91,11 +168,13 @@ mod tests {
             domain_service
                 .expect_login()
                 .times(1)
-                .returning(|_| Err(LoginError::ServiceError(ServiceError("error".to_string()))));
+                .returning(|_, _| Err(LoginError::ServiceError(ServiceError("error".to_string()))));
 
             let app_service = AppServiceImpl::new(Arc::new(domain_service));
             let user_name = UncheckedUserName("user_name".to_string());
-            let result = app_service.login(user_name).await;
+            let result = app_service
+                .login(user_name, UncheckedPassword("password1".to_string()))
+                .await;
 
             assert!(result.is_err());
             assert_eq!(
@@ -103,5 +182,106 @@ mod tests {
                 Err(LoginError::ServiceError(ServiceError("error".to_string())))
             );
         }
+
+        #[tokio::test]
+        async fn test_authenticate() {
+            let mut domain_service = MockDomainService::new();
+            domain_service
+                .expect_authenticate()
+                .with(eq(SessionToken("token".to_string())))
+                .times(1)
+                .returning(|_| {
+                    Ok(LoggedInUser {
+                        id: 1,
+                        name: "user_name".to_string(),
+                        role: Role::Member,
+                    })
+                });
+
+            let app_service = AppServiceImpl::new(Arc::new(domain_service));
+            let result = app_service
+                .authenticate(SessionToken("token".to_string()))
+                .await;
+
+            assert_eq!(
+                result,
+                Ok(LoggedInUser {
+                    id: 1,
+                    name: "user_name".to_string(),
+                    role: Role::Member,
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_authenticate_session_error() {
+            let mut domain_service = MockDomainService::new();
+            domain_service
+                .expect_authenticate()
+                .times(1)
+                .returning(|_| Err(LoginError::SessionError(SessionError("error".to_string()))));
+
+            let app_service = AppServiceImpl::new(Arc::new(domain_service));
+            let result = app_service
+                .authenticate(SessionToken("token".to_string()))
+                .await;
+
+            assert_eq!(
+                result,
+                Err(LoginError::SessionError(SessionError("error".to_string())))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_logout() {
+            let mut domain_service = MockDomainService::new();
+            domain_service
+                .expect_logout()
+                .with(eq(SessionToken("token".to_string())))
+                .times(1)
+                .returning(|_| Ok(()));
+
+            let app_service = AppServiceImpl::new(Arc::new(domain_service));
+            let result = app_service.logout(SessionToken("token".to_string())).await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_revoke_all_sessions() {
+            let user = LoggedInUser {
+                id: 1,
+                name: "user_name".to_string(),
+                role: Role::Member,
+            };
+            let mut domain_service = MockDomainService::new();
+            domain_service
+                .expect_revoke_all_sessions()
+                .with(eq(user.clone()))
+                .times(1)
+                .returning(|_| Ok(()));
+
+            let app_service = AppServiceImpl::new(Arc::new(domain_service));
+            let result = app_service.revoke_all_sessions(&user).await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_purge_expired_sessions_error() {
+            let mut domain_service = MockDomainService::new();
+            domain_service
+                .expect_purge_expired_sessions()
+                .times(1)
+                .returning(|| Err(LoginError::ServiceError(ServiceError("error".to_string()))));
+
+            let app_service = AppServiceImpl::new(Arc::new(domain_service));
+            let result = app_service.purge_expired_sessions().await;
+
+            assert_eq!(
+                result,
+                Err(LoginError::ServiceError(ServiceError("error".to_string())))
+            );
+        }
     }
 }
@@ -1,12 +1,20 @@
 use async_trait::async_trait;
-use domain_model::post::{err::PostError, model::UnvalidatedPost};
+use domain_model::post::{
+    err::PostError,
+    model::{PostStatus, UnvalidatedPost},
+};
 use domain_service::post::DomainService as PostDomainService;
 use std::sync::Arc;
 use tracing::{error, info};
 
 #[async_trait]
 pub trait AppService: Send + Sync {
-    async fn post(&self, post: UnvalidatedPost) -> Result<(), PostError>;
+    async fn post(&self, post: UnvalidatedPost) -> Result<PostStatus, PostError>;
+    async fn reply(
+        &self,
+        parent_post_id: i32,
+        post: UnvalidatedPost,
+    ) -> Result<PostStatus, PostError>;
 }
 
 pub struct AppServiceImpl {
@@ -21,16 +29,44 @@ impl AppServiceImpl {
 }
 #[async_trait]
 impl AppService for AppServiceImpl {
-    async fn post(&self, post: UnvalidatedPost) -> Result<(), PostError> {
+    async fn post(&self, post: UnvalidatedPost) -> Result<PostStatus, PostError> {
         let result = self.post_domain_service.post(post.clone()).await;
 
         if let Err(err) = &result {
             error!("Failed to post: {:?} ", err);
+        } else if let Ok(PostStatus::Held) = &result {
+            info!("Post held for moderation: {:?}", &post);
         } else {
             info!("Successfully posted: {:?}", &post);
         }
         result
     }
+
+    async fn reply(
+        &self,
+        parent_post_id: i32,
+        post: UnvalidatedPost,
+    ) -> Result<PostStatus, PostError> {
+        let result = self
+            .post_domain_service
+            .reply(parent_post_id, post.clone())
+            .await;
+
+        if let Err(err) = &result {
+            error!("Failed to reply to post {}: {:?} ", parent_post_id, err);
+        } else if let Ok(PostStatus::Held) = &result {
+            info!(
+                "Reply to post {} held for moderation: {:?}",
+                parent_post_id, &post
+            );
+        } else {
+            info!(
+                "Successfully replied to post {}: {:?}",
+                parent_post_id, &post
+            );
+        }
+        result
+    }
 }
 
 #[cfg(test)]
@@ -49,7 +85,8 @@ mod tests {
             pub DomainService {}
             #[async_trait]
             impl PostDomainService for DomainService {
-                async fn post(&self, post: UnvalidatedPost) -> Result<(), PostError>;
+                async fn post(&self, post: UnvalidatedPost) -> Result<PostStatus, PostError>;
+                async fn reply(&self, parent_post_id: i32, post: UnvalidatedPost) -> Result<PostStatus, PostError>;
             }
         }
 
@@ -63,11 +100,11 @@ mod tests {
             mock.expect_post()
                 .with(eq(post.clone()))
                 .times(1)
-                .returning(|_| Ok(()));
+                .returning(|_| Ok(PostStatus::Published));
 
             let app_service = AppServiceImpl::new(Arc::new(mock));
             let result = app_service.post(post).await;
-            assert!(result.is_ok());
+            assert_eq!(result, Ok(PostStatus::Published));
         }
 
         #[tokio::test]
@@ -103,5 +140,22 @@ mod tests {
             let result = app_service.post(post).await;
             assert!(result.is_err());
         }
+
+        #[tokio::test]
+        async fn reply() {
+            let mut mock = MockDomainService::new();
+            let post = UnvalidatedPost {
+                user_name: UnvalidatedUserName("user_name".to_string()),
+                content: "content".to_string(),
+            };
+            mock.expect_reply()
+                .with(eq(10), eq(post.clone()))
+                .times(1)
+                .returning(|_, _| Ok(PostStatus::Held));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.reply(10, post).await;
+            assert_eq!(result, Ok(PostStatus::Held));
+        }
     }
 }
@@ -0,0 +1,155 @@
+use async_trait::async_trait;
+use domain_model::{
+    login::model::LoggedInUser,
+    register_allow_word::{err::RegisterAllowWordError, model::UnvalidatedAllowWord},
+};
+use domain_service::register_allow_word::DomainService as RegisterAllowWordDomainService;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn register_allow_word(
+        &self,
+        allow_word: UnvalidatedAllowWord,
+        user: &LoggedInUser,
+    ) -> Result<(), RegisterAllowWordError>;
+}
+
+pub struct AppServiceImpl {
+    register_allow_word_domain_service: Arc<dyn RegisterAllowWordDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(
+        register_allow_word_domain_service: Arc<dyn RegisterAllowWordDomainService>,
+    ) -> Self {
+        AppServiceImpl {
+            register_allow_word_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn register_allow_word(
+        &self,
+        allow_word: UnvalidatedAllowWord,
+        user: &LoggedInUser,
+    ) -> Result<(), RegisterAllowWordError> {
+        let result = self
+            .register_allow_word_domain_service
+            .register_allow_word(allow_word.clone(), user)
+            .await;
+
+        if let Err(err) = &result {
+            error!(
+                "Failed to register allow word: {:?} user: {:?}",
+                err, &user.name
+            );
+        } else {
+            info!(
+                "Successfully registered allow word: {:?} user: {:?}",
+                allow_word, &user.name
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{
+            login::model::Role,
+            register_allow_word::err::{ServiceError, ValidationError},
+        };
+        use mockall::{mock, predicate::*};
+
+        fn unvalidated(allow_word: &str) -> UnvalidatedAllowWord {
+            UnvalidatedAllowWord {
+                allow_word: allow_word.to_string(),
+            }
+        }
+
+        fn moderator() -> LoggedInUser {
+            LoggedInUser {
+                id: 1,
+                name: "moderator".to_string(),
+                role: Role::Moderator,
+            }
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl RegisterAllowWordDomainService for DomainService {
+                async fn register_allow_word(&self, allow_word: UnvalidatedAllowWord, user: &LoggedInUser) -> Result<(), RegisterAllowWordError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_register_allow_word() {
+            let mut mock = MockDomainService::new();
+            mock.expect_register_allow_word()
+                .with(eq(unvalidated("class")), eq(moderator()))
+                .times(1)
+                .returning(|_, _| Ok(()));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .register_allow_word(unvalidated("class"), &moderator())
+                .await;
+            assert!(result.is_ok());
+        }
+
+        #[tokio::test]
+        async fn test_register_allow_word_validation_error() {
+            let mut mock = MockDomainService::new();
+            mock.expect_register_allow_word()
+                .with(eq(unvalidated("cl")), eq(moderator()))
+                .times(1)
+                .returning(|_, _| {
+                    Err(RegisterAllowWordError::ValidationError(ValidationError(
+                        "validation error".to_string(),
+                    )))
+                });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .register_allow_word(unvalidated("cl"), &moderator())
+                .await;
+            assert!(result.is_err());
+            assert!(matches!(
+                result,
+                Err(RegisterAllowWordError::ValidationError(_))
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_register_allow_word_service_error() {
+            let mut mock = MockDomainService::new();
+            mock.expect_register_allow_word()
+                .with(eq(unvalidated("class")), eq(moderator()))
+                .times(1)
+                .returning(|_, _| {
+                    Err(RegisterAllowWordError::ServiceError(ServiceError(
+                        "service error".to_string(),
+                    )))
+                });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .register_allow_word(unvalidated("class"), &moderator())
+                .await;
+            assert!(result.is_err());
+            assert!(matches!(
+                result,
+                Err(RegisterAllowWordError::ServiceError(_))
+            ));
+        }
+    }
+}
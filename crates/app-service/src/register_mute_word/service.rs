@@ -0,0 +1,123 @@
+use async_trait::async_trait;
+use domain_model::{
+    login::model::LoggedInUser,
+    register_mute_word::{err::RegisterMuteWordError, model::UnvalidatedMuteWord},
+};
+use domain_service::register_mute_word::DomainService as RegisterMuteWordDomainService;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn register_mute_word(
+        &self,
+        mute_word: UnvalidatedMuteWord,
+        user: &LoggedInUser,
+    ) -> Result<(), RegisterMuteWordError>;
+}
+
+pub struct AppServiceImpl {
+    register_mute_word_domain_service: Arc<dyn RegisterMuteWordDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(register_mute_word_domain_service: Arc<dyn RegisterMuteWordDomainService>) -> Self {
+        AppServiceImpl {
+            register_mute_word_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn register_mute_word(
+        &self,
+        mute_word: UnvalidatedMuteWord,
+        user: &LoggedInUser,
+    ) -> Result<(), RegisterMuteWordError> {
+        let result = self
+            .register_mute_word_domain_service
+            .register_mute_word(mute_word.clone(), user)
+            .await;
+
+        if let Err(err) = &result {
+            error!(
+                "Failed to register mute word: {:?} user: {:?}",
+                err, &user.name
+            );
+        } else {
+            info!(
+                "Successfully registered mute word: {:?} user: {:?}",
+                mute_word, &user.name
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{login::model::Role, register_mute_word::err::ValidationError};
+        use mockall::{mock, predicate::*};
+
+        fn unvalidated(mute_word: &str) -> UnvalidatedMuteWord {
+            UnvalidatedMuteWord {
+                mute_word: mute_word.to_string(),
+            }
+        }
+
+        fn member() -> LoggedInUser {
+            LoggedInUser {
+                id: 2,
+                name: "member".to_string(),
+                role: Role::Member,
+            }
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl RegisterMuteWordDomainService for DomainService {
+                async fn register_mute_word(&self, mute_word: UnvalidatedMuteWord, user: &LoggedInUser) -> Result<(), RegisterMuteWordError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_register_mute_word() {
+            let mut mock = MockDomainService::new();
+            mock.expect_register_mute_word()
+                .with(eq(unvalidated("spoiler")), eq(member()))
+                .times(1)
+                .returning(|_, _| Ok(()));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .register_mute_word(unvalidated("spoiler"), &member())
+                .await;
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_register_mute_word_validation_error() {
+            let mut mock = MockDomainService::new();
+            mock.expect_register_mute_word().times(1).returning(|_, _| {
+                Err(RegisterMuteWordError::ValidationError(ValidationError(
+                    "validation error".to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .register_mute_word(unvalidated(""), &member())
+                .await;
+            assert!(matches!(
+                result,
+                Err(RegisterMuteWordError::ValidationError(_))
+            ));
+        }
+    }
+}
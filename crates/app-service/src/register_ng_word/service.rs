@@ -1,5 +1,8 @@
 use async_trait::async_trait;
-use domain_model::register_ng_word::err::RegisterNgWordError;
+use domain_model::{
+    login::model::LoggedInUser,
+    register_ng_word::{err::RegisterNgWordError, model::UnvalidatedNgWord},
+};
 use domain_service::register_ng_word::DomainService as RegisterNgWordDomainService;
 use std::sync::Arc;
 use tracing::{error, info};
@@ -8,8 +11,8 @@ use tracing::{error, info};
 pub trait AppService: Send + Sync {
     async fn register_ng_word(
         &self,
-        ng_word: &str,
-        user_id: i32,
+        ng_word: UnvalidatedNgWord,
+        user: &LoggedInUser,
     ) -> Result<(), RegisterNgWordError>;
 }
 
@@ -29,18 +32,24 @@ impl AppServiceImpl {
 impl AppService for AppServiceImpl {
     async fn register_ng_word(
         &self,
-        ng_word: &str,
-        user_id: i32,
+        ng_word: UnvalidatedNgWord,
+        user: &LoggedInUser,
     ) -> Result<(), RegisterNgWordError> {
         let result = self
             .register_ng_word_domain_service
-            .register_ng_word(ng_word, user_id)
+            .register_ng_word(ng_word.clone(), user)
             .await;
 
         if let Err(err) = &result {
-            error!("Failed to register ng word: {:?}", err);
+            error!(
+                "Failed to register ng word: {:?} user: {:?}",
+                err, &user.name
+            );
         } else {
-            info!("Successfully registered ng word: {}", ng_word);
+            info!(
+                "Successfully registered ng word: {:?} user: {:?}",
+                ng_word, &user.name
+            );
         }
         result
     }
@@ -52,14 +61,33 @@ mod tests {
 
     mod app_service_impl {
         use super::*;
-        use domain_model::register_ng_word::err::{ServiceError, ValidationError};
+        use domain_model::{
+            login::model::Role,
+            register_ng_word::err::{ServiceError, ValidationError},
+        };
         use mockall::{mock, predicate::*};
 
+        fn unvalidated(ng_word: &str) -> UnvalidatedNgWord {
+            UnvalidatedNgWord {
+                ng_word: ng_word.to_string(),
+                kind: "literal".to_string(),
+                severity: "mask".to_string(),
+            }
+        }
+
+        fn moderator() -> LoggedInUser {
+            LoggedInUser {
+                id: 1,
+                name: "moderator".to_string(),
+                role: Role::Moderator,
+            }
+        }
+
         mock! {
             pub DomainService {}
             #[async_trait]
             impl RegisterNgWordDomainService for DomainService {
-                async fn register_ng_word(&self, ng_word: &str, user_id: i32) -> Result<(), RegisterNgWordError>;
+                async fn register_ng_word(&self, ng_word: UnvalidatedNgWord, user: &LoggedInUser) -> Result<(), RegisterNgWordError>;
             }
         }
 
@@ -67,12 +95,14 @@ mod tests {
         async fn test_register_ng_word() {
             let mut mock = MockDomainService::new();
             mock.expect_register_ng_word()
-                .with(eq("test"), eq(1))
+                .with(eq(unvalidated("test")), eq(moderator()))
                 .times(1)
                 .returning(|_, _| Ok(()));
 
             let app_service = AppServiceImpl::new(Arc::new(mock));
-            let result = app_service.register_ng_word("test", 1).await;
+            let result = app_service
+                .register_ng_word(unvalidated("test"), &moderator())
+                .await;
             assert!(result.is_ok());
         }
 
@@ -80,7 +110,7 @@ mod tests {
         async fn test_register_ng_word_validation_error() {
             let mut mock = MockDomainService::new();
             mock.expect_register_ng_word()
-                .with(eq("te"), eq(1))
+                .with(eq(unvalidated("te")), eq(moderator()))
                 .times(1)
                 .returning(|_, _| {
                     Err(RegisterNgWordError::ValidationError(ValidationError(
@@ -89,7 +119,9 @@ mod tests {
                 });
 
             let app_service = AppServiceImpl::new(Arc::new(mock));
-            let result = app_service.register_ng_word("te", 1).await;
+            let result = app_service
+                .register_ng_word(unvalidated("te"), &moderator())
+                .await;
             assert!(result.is_err());
             assert!(matches!(
                 result,
@@ -101,7 +133,7 @@ mod tests {
         async fn test_register_ng_word_service_error() {
             let mut mock = MockDomainService::new();
             mock.expect_register_ng_word()
-                .with(eq("test"), eq(1))
+                .with(eq(unvalidated("test")), eq(moderator()))
                 .times(1)
                 .returning(|_, _| {
                     Err(RegisterNgWordError::ServiceError(ServiceError(
@@ -110,7 +142,9 @@ mod tests {
                 });
 
             let app_service = AppServiceImpl::new(Arc::new(mock));
-            let result = app_service.register_ng_word("test", 1).await;
+            let result = app_service
+                .register_ng_word(unvalidated("test"), &moderator())
+                .await;
             assert!(result.is_err());
             assert!(matches!(result, Err(RegisterNgWordError::ServiceError(_))));
         }
@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use domain_model::{login::model::LoggedInUser, register_reaction::err::RegisterReactionError};
+use domain_service::register_reaction::DomainService as RegisterReactionDomainService;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn register_reaction(
+        &self,
+        post_id: i32,
+        kind: String,
+        user: &LoggedInUser,
+    ) -> Result<(), RegisterReactionError>;
+}
+
+pub struct AppServiceImpl {
+    register_reaction_domain_service: Arc<dyn RegisterReactionDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(register_reaction_domain_service: Arc<dyn RegisterReactionDomainService>) -> Self {
+        AppServiceImpl {
+            register_reaction_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn register_reaction(
+        &self,
+        post_id: i32,
+        kind: String,
+        user: &LoggedInUser,
+    ) -> Result<(), RegisterReactionError> {
+        let result = self
+            .register_reaction_domain_service
+            .register_reaction(post_id, kind.clone(), user)
+            .await;
+
+        if let Err(err) = &result {
+            error!(
+                "Failed to register reaction: {:?} post_id: {} kind: {} user: {:?}",
+                err, post_id, kind, &user.name
+            );
+        } else {
+            info!(
+                "Successfully registered reaction: {} post_id: {} user: {:?}",
+                kind, post_id, &user.name
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{login::model::Role, register_reaction::err::NotFoundError};
+        use mockall::{mock, predicate::*};
+
+        fn member() -> LoggedInUser {
+            LoggedInUser {
+                id: 2,
+                name: "member".to_string(),
+                role: Role::Member,
+            }
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl RegisterReactionDomainService for DomainService {
+                async fn register_reaction(&self, post_id: i32, kind: String, user: &LoggedInUser) -> Result<(), RegisterReactionError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_register_reaction() {
+            let mut mock = MockDomainService::new();
+            mock.expect_register_reaction()
+                .with(eq(1), eq("like".to_string()), eq(member()))
+                .times(1)
+                .returning(|_, _, _| Ok(()));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .register_reaction(1, "like".to_string(), &member())
+                .await;
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_register_reaction_not_found() {
+            let mut mock = MockDomainService::new();
+            mock.expect_register_reaction()
+                .times(1)
+                .returning(|_, _, _| {
+                    Err(RegisterReactionError::NotFoundError(NotFoundError(
+                        "Post not found".to_string(),
+                    )))
+                });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .register_reaction(1, "like".to_string(), &member())
+                .await;
+            assert!(matches!(
+                result,
+                Err(RegisterReactionError::NotFoundError(_))
+            ));
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use async_trait::async_trait;
-use domain_model::register_user_name::{err::RegisterUserNameError, model::UnvalidatedUserName};
+use domain_model::register_user_name::{
+    err::RegisterUserNameError,
+    model::{UnvalidatedPassword, UnvalidatedUserName},
+};
 use domain_service::register_user_name::DomainService as RegisterUserNameDomainService;
 use std::sync::Arc;
 use tracing::{error, info};
@@ -9,6 +12,7 @@ pub trait AppService: Send + Sync {
     async fn register_user_name(
         &self,
         user_name: UnvalidatedUserName,
+        password: UnvalidatedPassword,
     ) -> Result<(), RegisterUserNameError>;
 }
 
@@ -27,10 +31,11 @@ impl AppService for AppServiceImpl {
     async fn register_user_name(
         &self,
         user_name: UnvalidatedUserName,
+        password: UnvalidatedPassword,
     ) -> Result<(), RegisterUserNameError> {
         let result = self
             .register_user_name_domain_service
-            .register_user_name(user_name.clone())
+            .register_user_name(user_name.clone(), password)
             .await;
 
         if let Err(err) = &result {
@@ -61,6 +66,7 @@ mod tests {
                 async fn register_user_name(
                     &self,
                     user_name: UnvalidatedUserName,
+                    password: UnvalidatedPassword,
                 ) -> Result<(), RegisterUserNameError>;
             }
         }
@@ -72,11 +78,16 @@ mod tests {
             let mut domain_service = MockDomainService::new();
             domain_service
                 .expect_register_user_name()
-                .with(eq(unvalidated_user_name))
+                .with(
+                    eq(unvalidated_user_name),
+                    eq(UnvalidatedPassword("password1".to_string())),
+                )
                 .times(1)
-                .returning(|_| Ok(()));
+                .returning(|_, _| Ok(()));
             let app_service = AppServiceImpl::new(Arc::new(domain_service));
-            let result = app_service.register_user_name(user_name).await;
+            let result = app_service
+                .register_user_name(user_name, UnvalidatedPassword("password1".to_string()))
+                .await;
             assert!(result.is_ok());
         }
 
@@ -87,15 +98,20 @@ mod tests {
             let mut domain_service = MockDomainService::new();
             domain_service
                 .expect_register_user_name()
-                .with(eq(unvalidated_user_name))
+                .with(
+                    eq(unvalidated_user_name),
+                    eq(UnvalidatedPassword("password1".to_string())),
+                )
                 .times(1)
-                .returning(|_| {
+                .returning(|_, _| {
                     Err(RegisterUserNameError::from(ValidationError(
                         "Name must not contain NG words".to_string(),
                     )))
                 });
             let app_service = AppServiceImpl::new(Arc::new(domain_service));
-            let result = app_service.register_user_name(user_name).await;
+            let result = app_service
+                .register_user_name(user_name, UnvalidatedPassword("password1".to_string()))
+                .await;
             assert!(result.is_err());
             assert_eq!(
                 result.unwrap_err(),
@@ -111,15 +127,20 @@ mod tests {
             let mut domain_service = MockDomainService::new();
             domain_service
                 .expect_register_user_name()
-                .with(eq(unvalidated_user_name))
+                .with(
+                    eq(unvalidated_user_name),
+                    eq(UnvalidatedPassword("password1".to_string())),
+                )
                 .times(1)
-                .returning(|_| {
+                .returning(|_, _| {
                     Err(RegisterUserNameError::from(ServiceError(
                         "ServiceError".to_string(),
                     )))
                 });
             let app_service = AppServiceImpl::new(Arc::new(domain_service));
-            let result = app_service.register_user_name(user_name).await;
+            let result = app_service
+                .register_user_name(user_name, UnvalidatedPassword("password1".to_string()))
+                .await;
             assert!(result.is_err());
             assert_eq!(
                 result.unwrap_err(),
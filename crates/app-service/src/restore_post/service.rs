@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use domain_model::{login::model::LoggedInUser, restore_post::err::RestorePostError};
+use domain_service::restore_post::DomainService as RestorePostDomainService;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn restore_post(&self, id: i32, user: &LoggedInUser) -> Result<(), RestorePostError>;
+}
+
+pub struct AppServiceImpl {
+    restore_post_domain_service: Arc<dyn RestorePostDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(restore_post_domain_service: Arc<dyn RestorePostDomainService>) -> Self {
+        AppServiceImpl {
+            restore_post_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn restore_post(&self, id: i32, user: &LoggedInUser) -> Result<(), RestorePostError> {
+        let result = self
+            .restore_post_domain_service
+            .restore_post(id, user)
+            .await;
+
+        if let Err(err) = &result {
+            error!(
+                "Failed to restore post: {:?} id: {} user: {:?}",
+                err, id, &user.name
+            );
+        } else {
+            info!("Successfully restored post: {} user: {:?}", id, &user.name);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{login::model::Role, restore_post::err::PermissionError};
+        use mockall::{mock, predicate::*};
+
+        fn moderator() -> LoggedInUser {
+            LoggedInUser {
+                id: 1,
+                name: "moderator".to_string(),
+                role: Role::Moderator,
+            }
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl RestorePostDomainService for DomainService {
+                async fn restore_post(&self, id: i32, user: &LoggedInUser) -> Result<(), RestorePostError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_restore_post() {
+            let mut mock = MockDomainService::new();
+            mock.expect_restore_post()
+                .with(eq(1), eq(moderator()))
+                .times(1)
+                .returning(|_, _| Ok(()));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.restore_post(1, &moderator()).await;
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_restore_post_permission_error() {
+            let mut mock = MockDomainService::new();
+            mock.expect_restore_post().times(1).returning(|_, _| {
+                Err(RestorePostError::PermissionError(PermissionError(
+                    "Only moderators can restore posts".to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.restore_post(1, &moderator()).await;
+            assert!(matches!(result, Err(RestorePostError::PermissionError(_))));
+        }
+    }
+}
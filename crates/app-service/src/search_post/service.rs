@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use domain_model::{
+    get_all_post::model::Viewer,
+    search_post::{
+        err::SearchPostError,
+        model::{SearchHit, UnvalidatedSearchRequest},
+    },
+};
+use domain_service::search_post::DomainService as SearchPostDomainService;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn search_post(
+        &self,
+        request: UnvalidatedSearchRequest,
+        viewer: Option<Viewer>,
+    ) -> Result<Vec<SearchHit>, SearchPostError>;
+}
+
+pub struct AppServiceImpl {
+    search_post_domain_service: Arc<dyn SearchPostDomainService>,
+}
+impl AppServiceImpl {
+    pub fn new(search_post_domain_service: Arc<dyn SearchPostDomainService>) -> Self {
+        Self {
+            search_post_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn search_post(
+        &self,
+        request: UnvalidatedSearchRequest,
+        viewer: Option<Viewer>,
+    ) -> Result<Vec<SearchHit>, SearchPostError> {
+        let result = self
+            .search_post_domain_service
+            .search_post(request.clone(), viewer)
+            .await;
+
+        match &result {
+            Err(err) => error!("Failed to search post: {:?} request: {:?}", err, request),
+            Ok(hits) => info!(
+                "Successfully search post hits: {} request: {:?}",
+                hits.len(),
+                request
+            ),
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::search_post::err::ValidationError;
+        use mockall::{mock, predicate::*};
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl SearchPostDomainService for DomainService {
+                async fn search_post(&self, request: UnvalidatedSearchRequest, viewer: Option<Viewer>) -> Result<Vec<SearchHit>, SearchPostError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_search_post_validation_error() {
+            let request = UnvalidatedSearchRequest {
+                limit: Some(0),
+                ..Default::default()
+            };
+            let mut mock = MockDomainService::new();
+            mock.expect_search_post()
+                .with(eq(request.clone()), eq(None))
+                .times(1)
+                .returning(|_, _| {
+                    Err(SearchPostError::ValidationError(ValidationError(
+                        "Query is required".to_string(),
+                    )))
+                });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.search_post(request, None).await;
+            assert!(matches!(result, Err(SearchPostError::ValidationError(_))));
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use async_trait::async_trait;
+use domain_model::{
+    login::model::LoggedInUser,
+    update_ng_word::{err::UpdateNgWordError, model::UnvalidatedNgWordPatch},
+};
+use domain_service::update_ng_word::DomainService as UpdateNgWordDomainService;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn update_ng_word(
+        &self,
+        id: i32,
+        patch: UnvalidatedNgWordPatch,
+        user: &LoggedInUser,
+    ) -> Result<(), UpdateNgWordError>;
+}
+
+pub struct AppServiceImpl {
+    update_ng_word_domain_service: Arc<dyn UpdateNgWordDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(update_ng_word_domain_service: Arc<dyn UpdateNgWordDomainService>) -> Self {
+        AppServiceImpl {
+            update_ng_word_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn update_ng_word(
+        &self,
+        id: i32,
+        patch: UnvalidatedNgWordPatch,
+        user: &LoggedInUser,
+    ) -> Result<(), UpdateNgWordError> {
+        let result = self
+            .update_ng_word_domain_service
+            .update_ng_word(id, patch.clone(), user)
+            .await;
+
+        if let Err(err) = &result {
+            error!(
+                "Failed to update ng word: {:?} id: {} user: {:?}",
+                err, id, &user.name
+            );
+        } else {
+            info!(
+                "Successfully updated ng word: {} patch: {:?} user: {:?}",
+                id, patch, &user.name
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{login::model::Role, update_ng_word::err::NotFoundError};
+        use mockall::{mock, predicate::*};
+
+        fn moderator() -> LoggedInUser {
+            LoggedInUser {
+                id: 1,
+                name: "moderator".to_string(),
+                role: Role::Moderator,
+            }
+        }
+
+        fn patch() -> UnvalidatedNgWordPatch {
+            UnvalidatedNgWordPatch {
+                severity: Some("reject".to_string()),
+                ..Default::default()
+            }
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl UpdateNgWordDomainService for DomainService {
+                async fn update_ng_word(&self, id: i32, patch: UnvalidatedNgWordPatch, user: &LoggedInUser) -> Result<(), UpdateNgWordError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_update_ng_word() {
+            let mut mock = MockDomainService::new();
+            mock.expect_update_ng_word()
+                .with(eq(10), eq(patch()), eq(moderator()))
+                .times(1)
+                .returning(|_, _, _| Ok(()));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.update_ng_word(10, patch(), &moderator()).await;
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_update_ng_word_not_found() {
+            let mut mock = MockDomainService::new();
+            mock.expect_update_ng_word().times(1).returning(|_, _, _| {
+                Err(UpdateNgWordError::NotFoundError(NotFoundError(
+                    "NG word not found".to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service.update_ng_word(10, patch(), &moderator()).await;
+            assert!(matches!(result, Err(UpdateNgWordError::NotFoundError(_))));
+        }
+    }
+}
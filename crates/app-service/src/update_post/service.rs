@@ -0,0 +1,117 @@
+use async_trait::async_trait;
+use domain_model::{
+    login::model::LoggedInUser, post::model::PostStatus, update_post::err::UpdatePostError,
+};
+use domain_service::update_post::DomainService as UpdatePostDomainService;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait AppService: Send + Sync {
+    async fn update_post(
+        &self,
+        id: i32,
+        content: String,
+        user: &LoggedInUser,
+    ) -> Result<PostStatus, UpdatePostError>;
+}
+
+pub struct AppServiceImpl {
+    update_post_domain_service: Arc<dyn UpdatePostDomainService>,
+}
+
+impl AppServiceImpl {
+    pub fn new(update_post_domain_service: Arc<dyn UpdatePostDomainService>) -> Self {
+        AppServiceImpl {
+            update_post_domain_service,
+        }
+    }
+}
+
+#[async_trait]
+impl AppService for AppServiceImpl {
+    async fn update_post(
+        &self,
+        id: i32,
+        content: String,
+        user: &LoggedInUser,
+    ) -> Result<PostStatus, UpdatePostError> {
+        let result = self
+            .update_post_domain_service
+            .update_post(id, content, user)
+            .await;
+
+        match &result {
+            Err(err) => error!(
+                "Failed to update post: {:?} id: {} user: {:?}",
+                err, id, &user.name
+            ),
+            Ok(PostStatus::Held) => info!(
+                "Updated post held for moderation: {} user: {:?}",
+                id, &user.name
+            ),
+            Ok(PostStatus::Published) => {
+                info!("Successfully updated post: {} user: {:?}", id, &user.name)
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod app_service_impl {
+        use super::*;
+        use domain_model::{login::model::Role, update_post::err::PermissionError};
+        use mockall::{mock, predicate::*};
+
+        fn author() -> LoggedInUser {
+            LoggedInUser {
+                id: 2,
+                name: "user_name".to_string(),
+                role: Role::Member,
+            }
+        }
+
+        mock! {
+            pub DomainService {}
+            #[async_trait]
+            impl UpdatePostDomainService for DomainService {
+                async fn update_post(&self, id: i32, content: String, user: &LoggedInUser) -> Result<PostStatus, UpdatePostError>;
+            }
+        }
+
+        #[tokio::test]
+        async fn test_update_post() {
+            let mut mock = MockDomainService::new();
+            mock.expect_update_post()
+                .with(eq(1), eq("edited".to_string()), eq(author()))
+                .times(1)
+                .returning(|_, _, _| Ok(PostStatus::Published));
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .update_post(1, "edited".to_string(), &author())
+                .await;
+            assert_eq!(result, Ok(PostStatus::Published));
+        }
+
+        #[tokio::test]
+        async fn test_update_post_permission_error() {
+            let mut mock = MockDomainService::new();
+            mock.expect_update_post().times(1).returning(|_, _, _| {
+                Err(UpdatePostError::PermissionError(PermissionError(
+                    "Only the author can edit the post".to_string(),
+                )))
+            });
+
+            let app_service = AppServiceImpl::new(Arc::new(mock));
+            let result = app_service
+                .update_post(1, "edited".to_string(), &author())
+                .await;
+            assert!(matches!(result, Err(UpdatePostError::PermissionError(_))));
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::ng_word_policy;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CheckNgWordsError {
+    ValidationError(ValidationError),
+    ServiceError(ServiceError),
+}
+impl From<ValidationError> for CheckNgWordsError {
+    fn from(e: ValidationError) -> Self {
+        CheckNgWordsError::ValidationError(e)
+    }
+}
+impl From<ServiceError> for CheckNgWordsError {
+    fn from(e: ServiceError) -> Self {
+        CheckNgWordsError::ServiceError(e)
+    }
+}
+impl From<ng_word_policy::err::ServiceError> for CheckNgWordsError {
+    fn from(e: ng_word_policy::err::ServiceError) -> Self {
+        CheckNgWordsError::ServiceError(ServiceError(e.0))
+    }
+}
@@ -0,0 +1,69 @@
+use super::err::ValidationError;
+use crate::post::constants::{CONTENT_LENGTH_UNIT, MAX_CONTENT_LENGTH};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnvalidatedCheckText(pub String);
+
+/// NGワードを確かめる文字列
+///
+/// 投稿前に確かめるため、投稿内容と同じ長さの制約を課す
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckText(String);
+impl CheckText {
+    /// 確かめる文字列の値オブジェクトを生成
+    ///
+    /// 空文字もしくは255文字を超える場合はエラーを返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::check_ng_words::model::CheckText;
+    ///
+    /// let result = CheckText::new("content");
+    /// assert!(result.is_ok());
+    ///
+    /// // 長さはコードポイント単位で数える
+    /// let result = CheckText::new(&"あ".repeat(255));
+    /// assert!(result.is_ok());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::check_ng_words::model::CheckText;
+    ///
+    /// // 空文字の場合
+    /// let result = CheckText::new("");
+    /// assert!(result.is_err());
+    ///
+    /// // 255文字を超える場合
+    /// let result = CheckText::new(&"あ".repeat(256));
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(text: &str) -> Result<Self, ValidationError> {
+        if text.is_empty() {
+            Err(ValidationError("Text must not be empty".to_string()))
+        } else if CONTENT_LENGTH_UNIT.count(text) > MAX_CONTENT_LENGTH {
+            Err(ValidationError(format!(
+                "Text must be at most {} characters long",
+                MAX_CONTENT_LENGTH
+            )))
+        } else {
+            Ok(CheckText(text.to_string()))
+        }
+    }
+
+    /// 確かめる文字列の値オブジェクトの値を取得
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::check_ng_words::model::CheckText;
+    ///
+    /// let text = CheckText::new("content").unwrap();
+    /// assert_eq!(text.value(), "content");
+    /// ```
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
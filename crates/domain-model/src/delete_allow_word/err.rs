@@ -0,0 +1,30 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotFoundError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeleteAllowWordError {
+    ServiceError(ServiceError),
+    PermissionError(PermissionError),
+    NotFoundError(NotFoundError),
+}
+impl From<ServiceError> for DeleteAllowWordError {
+    fn from(e: ServiceError) -> Self {
+        DeleteAllowWordError::ServiceError(e)
+    }
+}
+impl From<PermissionError> for DeleteAllowWordError {
+    fn from(e: PermissionError) -> Self {
+        DeleteAllowWordError::PermissionError(e)
+    }
+}
+impl From<NotFoundError> for DeleteAllowWordError {
+    fn from(e: NotFoundError) -> Self {
+        DeleteAllowWordError::NotFoundError(e)
+    }
+}
@@ -0,0 +1,21 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotFoundError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeleteMuteWordError {
+    ServiceError(ServiceError),
+    NotFoundError(NotFoundError),
+}
+impl From<ServiceError> for DeleteMuteWordError {
+    fn from(e: ServiceError) -> Self {
+        DeleteMuteWordError::ServiceError(e)
+    }
+}
+impl From<NotFoundError> for DeleteMuteWordError {
+    fn from(e: NotFoundError) -> Self {
+        DeleteMuteWordError::NotFoundError(e)
+    }
+}
@@ -0,0 +1,30 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotFoundError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeleteNgWordError {
+    ServiceError(ServiceError),
+    PermissionError(PermissionError),
+    NotFoundError(NotFoundError),
+}
+impl From<ServiceError> for DeleteNgWordError {
+    fn from(e: ServiceError) -> Self {
+        DeleteNgWordError::ServiceError(e)
+    }
+}
+impl From<PermissionError> for DeleteNgWordError {
+    fn from(e: PermissionError) -> Self {
+        DeleteNgWordError::PermissionError(e)
+    }
+}
+impl From<NotFoundError> for DeleteNgWordError {
+    fn from(e: NotFoundError) -> Self {
+        DeleteNgWordError::NotFoundError(e)
+    }
+}
@@ -0,0 +1,5 @@
+use crate::text::LengthUnit;
+
+// DBのVARCHAR(255)はコードポイント単位で数える
+pub const MAX_DELETE_REASON_LENGTH: usize = 255;
+pub const DELETE_REASON_LENGTH_UNIT: LengthUnit = LengthUnit::Char;
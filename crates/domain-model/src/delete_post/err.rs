@@ -0,0 +1,39 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotFoundError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeletePostError {
+    ValidationError(ValidationError),
+    ServiceError(ServiceError),
+    PermissionError(PermissionError),
+    NotFoundError(NotFoundError),
+}
+impl From<ValidationError> for DeletePostError {
+    fn from(e: ValidationError) -> Self {
+        DeletePostError::ValidationError(e)
+    }
+}
+impl From<ServiceError> for DeletePostError {
+    fn from(e: ServiceError) -> Self {
+        DeletePostError::ServiceError(e)
+    }
+}
+impl From<PermissionError> for DeletePostError {
+    fn from(e: PermissionError) -> Self {
+        DeletePostError::PermissionError(e)
+    }
+}
+impl From<NotFoundError> for DeletePostError {
+    fn from(e: NotFoundError) -> Self {
+        DeletePostError::NotFoundError(e)
+    }
+}
@@ -0,0 +1,110 @@
+use super::{
+    constants::{DELETE_REASON_LENGTH_UNIT, MAX_DELETE_REASON_LENGTH},
+    err::{PermissionError, ValidationError},
+};
+use crate::login::model::LoggedInUser;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UnvalidatedDeleteReason(pub Option<String>);
+
+/// 投稿を削除した理由
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeleteReason(Option<String>);
+impl DeleteReason {
+    /// 削除した理由の値オブジェクトを生成
+    ///
+    /// 理由は省略できる。空白だけの場合は省略したものとして扱う
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::delete_post::model::{DeleteReason, UnvalidatedDeleteReason};
+    ///
+    /// let reason = DeleteReason::new(UnvalidatedDeleteReason(Some(" spam ".to_string())));
+    /// assert_eq!(reason.unwrap().value(), Some("spam"));
+    ///
+    /// let reason = DeleteReason::new(UnvalidatedDeleteReason(Some("  ".to_string())));
+    /// assert_eq!(reason.unwrap().value(), None);
+    ///
+    /// let reason = DeleteReason::new(UnvalidatedDeleteReason(None));
+    /// assert_eq!(reason.unwrap().value(), None);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::delete_post::model::{DeleteReason, UnvalidatedDeleteReason};
+    ///
+    /// // 255文字を超える場合
+    /// let reason = DeleteReason::new(UnvalidatedDeleteReason(Some("あ".repeat(256))));
+    /// assert!(reason.is_err());
+    /// ```
+    pub fn new(reason: UnvalidatedDeleteReason) -> Result<Self, ValidationError> {
+        let reason = reason
+            .0
+            .map(|reason| reason.trim().to_string())
+            .filter(|reason| !reason.is_empty());
+        match reason {
+            Some(reason) if DELETE_REASON_LENGTH_UNIT.count(&reason) > MAX_DELETE_REASON_LENGTH => {
+                Err(ValidationError(format!(
+                    "Reason must be at most {} characters long",
+                    MAX_DELETE_REASON_LENGTH
+                )))
+            }
+            reason => Ok(Self(reason)),
+        }
+    }
+
+    pub fn value(&self) -> Option<&str> {
+        self.0.as_deref()
+    }
+}
+
+/// 削除されていない投稿のうち、削除できるかの判断に使う項目
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeletablePost {
+    pub id: i32,
+    pub posted_user_id: i32,
+}
+impl DeletablePost {
+    /// ユーザがこの投稿を削除できるかを確認する
+    ///
+    /// 投稿したユーザとモデレータが削除できる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::{
+    ///     delete_post::model::DeletablePost,
+    ///     login::model::{LoggedInUser, Role},
+    /// };
+    ///
+    /// let post = DeletablePost { id: 1, posted_user_id: 2 };
+    /// let author = LoggedInUser { id: 2, name: "author".to_string(), role: Role::Member };
+    /// let moderator = LoggedInUser { id: 3, name: "moderator".to_string(), role: Role::Moderator };
+    /// assert!(post.check_deletable(&author).is_ok());
+    /// assert!(post.check_deletable(&moderator).is_ok());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::{
+    ///     delete_post::model::DeletablePost,
+    ///     login::model::{LoggedInUser, Role},
+    /// };
+    ///
+    /// let post = DeletablePost { id: 1, posted_user_id: 2 };
+    /// let other = LoggedInUser { id: 3, name: "other".to_string(), role: Role::Member };
+    /// assert!(post.check_deletable(&other).is_err());
+    /// ```
+    pub fn check_deletable(&self, user: &LoggedInUser) -> Result<(), PermissionError> {
+        if self.posted_user_id == user.id || user.role.can_moderate_posts() {
+            Ok(())
+        } else {
+            Err(PermissionError(
+                "Only the author or moderators can delete the post".to_string(),
+            ))
+        }
+    }
+}
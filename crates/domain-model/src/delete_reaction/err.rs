@@ -0,0 +1,33 @@
+use crate::reaction;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError(pub String);
+impl From<reaction::err::ValidationError> for ValidationError {
+    fn from(e: reaction::err::ValidationError) -> Self {
+        ValidationError(e.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeleteReactionError {
+    ValidationError(ValidationError),
+    ServiceError(ServiceError),
+}
+impl From<ValidationError> for DeleteReactionError {
+    fn from(e: ValidationError) -> Self {
+        DeleteReactionError::ValidationError(e)
+    }
+}
+impl From<ServiceError> for DeleteReactionError {
+    fn from(e: ServiceError) -> Self {
+        DeleteReactionError::ServiceError(e)
+    }
+}
+impl From<reaction::err::ValidationError> for DeleteReactionError {
+    fn from(e: reaction::err::ValidationError) -> Self {
+        DeleteReactionError::ValidationError(ValidationError(e.0))
+    }
+}
@@ -0,0 +1,21 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExportNgWordsError {
+    ServiceError(ServiceError),
+    PermissionError(PermissionError),
+}
+impl From<ServiceError> for ExportNgWordsError {
+    fn from(e: ServiceError) -> Self {
+        ExportNgWordsError::ServiceError(e)
+    }
+}
+impl From<PermissionError> for ExportNgWordsError {
+    fn from(e: PermissionError) -> Self {
+        ExportNgWordsError::PermissionError(e)
+    }
+}
@@ -0,0 +1,11 @@
+use crate::ng_word_policy::model::{PatternKind, Severity};
+
+/// 書き出す登録済みのNGワード
+///
+/// 取り込みと同じ項目を持つため、書き出した内容はそのまま取り込める
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportedNgWord {
+    pub word: String,
+    pub kind: PatternKind,
+    pub severity: Severity,
+}
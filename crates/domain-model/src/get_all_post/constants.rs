@@ -1 +1,3 @@
-
+// 投稿取得で1回に返す件数
+pub const DEFAULT_PAGE_SIZE: usize = 20;
+pub const MAX_PAGE_SIZE: usize = 100;
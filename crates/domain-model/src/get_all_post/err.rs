@@ -1,2 +1,33 @@
+use crate::ng_word_policy;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError(pub String);
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ServiceError(pub String);
+impl From<ng_word_policy::err::ServiceError> for ServiceError {
+    fn from(e: ng_word_policy::err::ServiceError) -> Self {
+        ServiceError(e.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GetAllPostError {
+    ValidationError(ValidationError),
+    ServiceError(ServiceError),
+}
+impl From<ValidationError> for GetAllPostError {
+    fn from(e: ValidationError) -> Self {
+        GetAllPostError::ValidationError(e)
+    }
+}
+impl From<ServiceError> for GetAllPostError {
+    fn from(e: ServiceError) -> Self {
+        GetAllPostError::ServiceError(e)
+    }
+}
+impl From<ng_word_policy::err::ServiceError> for GetAllPostError {
+    fn from(e: ng_word_policy::err::ServiceError) -> Self {
+        GetAllPostError::ServiceError(ServiceError(e.0))
+    }
+}
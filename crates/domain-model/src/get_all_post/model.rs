@@ -1,4 +1,7 @@
-use chrono::NaiveDateTime;
+use super::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+use super::err::ValidationError;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, NaiveDateTime};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct PostedUserName(pub String);
@@ -11,7 +14,219 @@ pub struct Content(pub String);
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Post {
+    pub id: i32,
     pub posted_user_name: PostedUserName,
     pub posted_datetime: PostedDatetime,
+    /// リポジトリからは書かれたままの内容を返し、公開する時に塗りつぶす
     pub content: Content,
+    /// 閲覧するユーザがミュートした語を含むため、折りたたんで表示する
+    pub muted: bool,
+    /// 公開中の直接の返信の数
+    pub reply_count: u64,
+    /// 種類ごとのリアクションの数
+    pub reactions: Vec<ReactionCount>,
+}
+
+/// 投稿に付いた1種類のリアクションの集計
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReactionCount {
+    pub kind: String,
+    pub count: u64,
+    /// 閲覧するユーザ自身がこの種類のリアクションをしたか。ログインしていない場合は`false`
+    pub reacted_by_me: bool,
+}
+
+/// ミュートした語を含む投稿の扱い
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MuteMode {
+    /// 一覧から取り除く
+    #[default]
+    Hide,
+    /// 一覧に残し、折りたたむ印を付ける
+    Collapse,
+}
+impl MuteMode {
+    /// 文字列表現から扱いを生成
+    ///
+    /// 不明な値の場合は`None`を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::get_all_post::model::MuteMode;
+    ///
+    /// assert_eq!(MuteMode::parse("hide"), Some(MuteMode::Hide));
+    /// assert_eq!(MuteMode::parse("collapse"), Some(MuteMode::Collapse));
+    /// assert_eq!(MuteMode::parse("unknown"), None);
+    /// ```
+    pub fn parse(mode: &str) -> Option<Self> {
+        match mode {
+            "hide" => Some(MuteMode::Hide),
+            "collapse" => Some(MuteMode::Collapse),
+            _ => None,
+        }
+    }
+}
+
+/// 投稿一覧を閲覧するログイン中のユーザ
+#[derive(Debug, Clone, PartialEq)]
+pub struct Viewer {
+    pub user_id: i32,
+    pub mute_mode: MuteMode,
+}
+
+/// 投稿一覧の続きを取得するための位置
+///
+/// 最後に返した投稿の投稿日時とIDを持つ。投稿日時の新しい順、同じ日時ならIDの大きい順に並べる
+#[derive(Debug, Clone, PartialEq)]
+pub struct PostCursor {
+    pub posted_datetime: NaiveDateTime,
+    pub id: i32,
+}
+impl PostCursor {
+    /// 位置を外部に渡す文字列にする
+    ///
+    /// 中身に依存されないよう、URLで使えるBase64の文字列にする
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDateTime;
+    /// use domain_model::get_all_post::model::PostCursor;
+    ///
+    /// let cursor = PostCursor {
+    ///     posted_datetime: NaiveDateTime::parse_from_str(
+    ///         "2021-01-01T00:00:00Z",
+    ///         "%Y-%m-%dT%H:%M:%SZ",
+    ///     )
+    ///     .unwrap(),
+    ///     id: 10,
+    /// };
+    /// assert_eq!(PostCursor::decode(&cursor.encode()), Ok(cursor));
+    /// ```
+    pub fn encode(&self) -> String {
+        let raw = format!(
+            "{}:{}",
+            self.posted_datetime.and_utc().timestamp_micros(),
+            self.id
+        );
+        URL_SAFE_NO_PAD.encode(raw)
+    }
+
+    /// `encode`した文字列から位置を復元する
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::get_all_post::model::PostCursor;
+    ///
+    /// assert!(PostCursor::decode("").is_err());
+    /// // Base64でない場合
+    /// assert!(PostCursor::decode("!!").is_err());
+    /// // 区切りが無い場合
+    /// assert!(PostCursor::decode("MTA").is_err());
+    /// ```
+    pub fn decode(cursor: &str) -> Result<Self, ValidationError> {
+        let invalid = || ValidationError("Cursor is invalid".to_string());
+        let bytes = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| invalid())?;
+        let decoded = String::from_utf8(bytes).map_err(|_| invalid())?;
+        let (micros, id) = decoded.split_once(':').ok_or_else(invalid)?;
+        let micros = micros.parse::<i64>().map_err(|_| invalid())?;
+        let id = id.parse::<i32>().map_err(|_| invalid())?;
+        let posted_datetime = DateTime::from_timestamp_micros(micros)
+            .ok_or_else(invalid)?
+            .naive_utc();
+        Ok(Self {
+            posted_datetime,
+            id,
+        })
+    }
+}
+
+/// 検証前の投稿一覧の取得範囲
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UnvalidatedPageRequest {
+    pub limit: Option<usize>,
+    pub cursor: Option<String>,
+}
+
+/// 投稿一覧の取得範囲
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageRequest {
+    limit: usize,
+    cursor: Option<PostCursor>,
+}
+impl PageRequest {
+    /// 取得範囲の値オブジェクトを生成
+    ///
+    /// 件数を省略した場合は20件にする。件数が1未満もしくは100を超える場合や、
+    /// 位置が不正な場合はエラーを返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::get_all_post::model::{PageRequest, UnvalidatedPageRequest};
+    ///
+    /// let page = PageRequest::new(UnvalidatedPageRequest::default()).unwrap();
+    /// assert_eq!(page.limit(), 20);
+    /// assert_eq!(page.cursor(), None);
+    ///
+    /// let page = PageRequest::new(UnvalidatedPageRequest {
+    ///     limit: Some(100),
+    ///     cursor: None,
+    /// });
+    /// assert!(page.is_ok());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::get_all_post::model::{PageRequest, UnvalidatedPageRequest};
+    ///
+    /// // 件数が1未満の場合
+    /// let page = PageRequest::new(UnvalidatedPageRequest {
+    ///     limit: Some(0),
+    ///     cursor: None,
+    /// });
+    /// assert!(page.is_err());
+    ///
+    /// // 件数が100を超える場合
+    /// let page = PageRequest::new(UnvalidatedPageRequest {
+    ///     limit: Some(101),
+    ///     cursor: None,
+    /// });
+    /// assert!(page.is_err());
+    ///
+    /// // 位置が不正な場合
+    /// let page = PageRequest::new(UnvalidatedPageRequest {
+    ///     limit: None,
+    ///     cursor: Some("invalid".to_string()),
+    /// });
+    /// assert!(page.is_err());
+    /// ```
+    pub fn new(page: UnvalidatedPageRequest) -> Result<Self, ValidationError> {
+        let limit = page.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        if !(1..=MAX_PAGE_SIZE).contains(&limit) {
+            return Err(ValidationError(format!(
+                "Limit must be between 1 and {}",
+                MAX_PAGE_SIZE
+            )));
+        }
+        let cursor = page.cursor.as_deref().map(PostCursor::decode).transpose()?;
+        Ok(Self { limit, cursor })
+    }
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+    pub fn cursor(&self) -> Option<&PostCursor> {
+        self.cursor.as_ref()
+    }
+}
+
+/// 投稿一覧の1ページ分
+#[derive(Debug, Clone, PartialEq)]
+pub struct PostPage {
+    pub posts: Vec<Post>,
+    /// 続きがある場合、続きを取得するための位置
+    pub next_cursor: Option<PostCursor>,
 }
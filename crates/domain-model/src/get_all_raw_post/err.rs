@@ -0,0 +1,21 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GetAllRawPostError {
+    ServiceError(ServiceError),
+    PermissionError(PermissionError),
+}
+impl From<ServiceError> for GetAllRawPostError {
+    fn from(e: ServiceError) -> Self {
+        GetAllRawPostError::ServiceError(e)
+    }
+}
+impl From<PermissionError> for GetAllRawPostError {
+    fn from(e: PermissionError) -> Self {
+        GetAllRawPostError::PermissionError(e)
+    }
+}
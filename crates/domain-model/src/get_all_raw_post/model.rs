@@ -0,0 +1,29 @@
+use crate::{
+    get_all_post::model::{Content, PostedDatetime, PostedUserName},
+    post::model::PostStatus,
+};
+use chrono::NaiveDateTime;
+
+/// モデレータ向けの、塗りつぶす前の投稿
+///
+/// モデレーション待ちの投稿や削除された投稿も含む
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawPost {
+    pub id: i32,
+    pub posted_user_name: PostedUserName,
+    pub posted_datetime: PostedDatetime,
+    pub status: PostStatus,
+    /// 書かれたままの内容
+    pub content: Content,
+    /// 削除されている場合、その記録
+    pub deletion: Option<PostDeletion>,
+}
+
+/// 投稿を削除した記録
+#[derive(Debug, Clone, PartialEq)]
+pub struct PostDeletion {
+    pub deleted_datetime: NaiveDateTime,
+    /// 削除したユーザのID。ユーザが消えている場合は`None`
+    pub deleted_user_id: Option<i32>,
+    pub reason: Option<String>,
+}
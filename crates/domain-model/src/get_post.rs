@@ -0,0 +1,2 @@
+pub mod err;
+pub mod model;
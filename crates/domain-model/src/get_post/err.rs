@@ -0,0 +1,33 @@
+use crate::ng_word_policy;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+impl From<ng_word_policy::err::ServiceError> for ServiceError {
+    fn from(e: ng_word_policy::err::ServiceError) -> Self {
+        ServiceError(e.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotFoundError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GetPostError {
+    ServiceError(ServiceError),
+    NotFoundError(NotFoundError),
+}
+impl From<ServiceError> for GetPostError {
+    fn from(e: ServiceError) -> Self {
+        GetPostError::ServiceError(e)
+    }
+}
+impl From<NotFoundError> for GetPostError {
+    fn from(e: NotFoundError) -> Self {
+        GetPostError::NotFoundError(e)
+    }
+}
+impl From<ng_word_policy::err::ServiceError> for GetPostError {
+    fn from(e: ng_word_policy::err::ServiceError) -> Self {
+        GetPostError::ServiceError(ServiceError(e.0))
+    }
+}
@@ -0,0 +1,18 @@
+use crate::get_all_post::model::Post;
+use chrono::NaiveDateTime;
+
+/// 削除された投稿の代わりに示す印
+///
+/// 削除した理由や削除したユーザは公開しない
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tombstone {
+    pub id: i32,
+    pub deleted_datetime: NaiveDateTime,
+}
+
+/// IDで取得した投稿
+#[derive(Debug, Clone, PartialEq)]
+pub enum PostView {
+    Published(Post),
+    Deleted(Tombstone),
+}
@@ -0,0 +1,3 @@
+pub(crate) mod constants;
+pub mod err;
+pub mod model;
@@ -0,0 +1,3 @@
+// スレッド取得で辿る返信の深さ
+pub const DEFAULT_THREAD_DEPTH: usize = 3;
+pub const MAX_THREAD_DEPTH: usize = 10;
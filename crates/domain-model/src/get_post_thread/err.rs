@@ -0,0 +1,42 @@
+use crate::ng_word_policy;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+impl From<ng_word_policy::err::ServiceError> for ServiceError {
+    fn from(e: ng_word_policy::err::ServiceError) -> Self {
+        ServiceError(e.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotFoundError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GetPostThreadError {
+    ValidationError(ValidationError),
+    ServiceError(ServiceError),
+    NotFoundError(NotFoundError),
+}
+impl From<ValidationError> for GetPostThreadError {
+    fn from(e: ValidationError) -> Self {
+        GetPostThreadError::ValidationError(e)
+    }
+}
+impl From<ServiceError> for GetPostThreadError {
+    fn from(e: ServiceError) -> Self {
+        GetPostThreadError::ServiceError(e)
+    }
+}
+impl From<NotFoundError> for GetPostThreadError {
+    fn from(e: NotFoundError) -> Self {
+        GetPostThreadError::NotFoundError(e)
+    }
+}
+impl From<ng_word_policy::err::ServiceError> for GetPostThreadError {
+    fn from(e: ng_word_policy::err::ServiceError) -> Self {
+        GetPostThreadError::ServiceError(ServiceError(e.0))
+    }
+}
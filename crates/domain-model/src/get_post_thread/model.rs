@@ -0,0 +1,91 @@
+use super::constants::{DEFAULT_THREAD_DEPTH, MAX_THREAD_DEPTH};
+use super::err::ValidationError;
+use crate::get_all_post::model::Post;
+
+/// スレッドで辿る返信の深さ
+///
+/// 1なら直接の返信まで、2なら返信への返信までを返す
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThreadDepth(usize);
+impl ThreadDepth {
+    /// 深さの値オブジェクトを生成
+    ///
+    /// 省略した場合は3にする。1未満もしくは10を超える場合はエラーを返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::get_post_thread::model::ThreadDepth;
+    ///
+    /// assert_eq!(ThreadDepth::new(None).unwrap().value(), 3);
+    /// assert_eq!(ThreadDepth::new(Some(10)).unwrap().value(), 10);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::get_post_thread::model::ThreadDepth;
+    ///
+    /// // 1未満の場合
+    /// assert!(ThreadDepth::new(Some(0)).is_err());
+    ///
+    /// // 10を超える場合
+    /// assert!(ThreadDepth::new(Some(11)).is_err());
+    /// ```
+    pub fn new(depth: Option<usize>) -> Result<Self, ValidationError> {
+        let depth = depth.unwrap_or(DEFAULT_THREAD_DEPTH);
+        if !(1..=MAX_THREAD_DEPTH).contains(&depth) {
+            return Err(ValidationError(format!(
+                "Depth must be between 1 and {}",
+                MAX_THREAD_DEPTH
+            )));
+        }
+        Ok(Self(depth))
+    }
+    pub fn value(&self) -> usize {
+        self.0
+    }
+}
+
+/// 公開していない投稿の代わりに示す印
+///
+/// 削除された投稿とモデレーション待ちの投稿を区別せず、内容と投稿者も示さない
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThreadTombstone {
+    pub id: i32,
+    /// 公開中の直接の返信の数
+    pub reply_count: u64,
+}
+
+/// スレッドの中の投稿
+///
+/// 公開していない投稿も、その先の返信を辿れるように印として木に残す
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThreadPost {
+    Published(Post),
+    Tombstone(ThreadTombstone),
+}
+impl ThreadPost {
+    pub fn id(&self) -> i32 {
+        match self {
+            ThreadPost::Published(post) => post.id,
+            ThreadPost::Tombstone(tombstone) => tombstone.id,
+        }
+    }
+}
+
+/// 返信先の投稿のIDを付けた返信
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reply {
+    pub parent_post_id: i32,
+    pub post: ThreadPost,
+}
+
+/// スレッドの中の1件の投稿と、その返信
+///
+/// 深さの上限より先の返信は含めない。その場合も`reply_count`で公開中の返信の有無がわかる
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThreadNode {
+    pub post: ThreadPost,
+    pub replies: Vec<ThreadNode>,
+}
@@ -0,0 +1,2 @@
+/// 1回で取り込めるNGワードの最大件数
+pub const MAX_IMPORT_ROWS: usize = 1000;
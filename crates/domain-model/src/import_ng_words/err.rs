@@ -0,0 +1,30 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportNgWordsError {
+    ValidationError(ValidationError),
+    ServiceError(ServiceError),
+    PermissionError(PermissionError),
+}
+impl From<ValidationError> for ImportNgWordsError {
+    fn from(e: ValidationError) -> Self {
+        ImportNgWordsError::ValidationError(e)
+    }
+}
+impl From<ServiceError> for ImportNgWordsError {
+    fn from(e: ServiceError) -> Self {
+        ImportNgWordsError::ServiceError(e)
+    }
+}
+impl From<PermissionError> for ImportNgWordsError {
+    fn from(e: PermissionError) -> Self {
+        ImportNgWordsError::PermissionError(e)
+    }
+}
@@ -0,0 +1,126 @@
+use super::constants::MAX_IMPORT_ROWS;
+use super::err::ValidationError;
+use crate::register_ng_word::model::UnvalidatedNgWord;
+
+/// 取り込む1行
+///
+/// 読み込めなかった行も、行番号を数えて結果に含めるために残す
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportRow {
+    /// 項目を読み込めた行
+    Parsed(UnvalidatedNgWord),
+    /// 読み込めなかった行と、その理由
+    Unreadable(String),
+}
+
+/// 一括で取り込むNGワードの一覧
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportBatch(Vec<ImportRow>);
+impl ImportBatch {
+    /// 取り込む一覧の値オブジェクトを生成
+    ///
+    /// 読み込めなかった行も含めて1件以上1000件以下。各行の内容はここでは検証しない
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::import_ng_words::model::{ImportBatch, ImportRow};
+    /// use domain_model::register_ng_word::model::UnvalidatedNgWord;
+    ///
+    /// let batch = ImportBatch::new(vec![
+    ///     ImportRow::Parsed(UnvalidatedNgWord {
+    ///         ng_word: "bad".to_string(),
+    ///         kind: "literal".to_string(),
+    ///         severity: "mask".to_string(),
+    ///     }),
+    ///     ImportRow::Unreadable("missing field `ng_word`".to_string()),
+    /// ]);
+    /// assert_eq!(batch.unwrap().rows().len(), 2);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::import_ng_words::model::{ImportBatch, ImportRow};
+    /// use domain_model::register_ng_word::model::UnvalidatedNgWord;
+    ///
+    /// // 空の場合
+    /// assert!(ImportBatch::new(vec![]).is_err());
+    ///
+    /// // 1000件を超える場合
+    /// let row = ImportRow::Parsed(UnvalidatedNgWord {
+    ///     ng_word: "bad".to_string(),
+    ///     kind: "literal".to_string(),
+    ///     severity: "mask".to_string(),
+    /// });
+    /// assert!(ImportBatch::new(vec![row; 1001]).is_err());
+    /// ```
+    pub fn new(rows: Vec<ImportRow>) -> Result<Self, ValidationError> {
+        if rows.is_empty() {
+            return Err(ValidationError(
+                "At least one NG word is required".to_string(),
+            ));
+        }
+        if rows.len() > MAX_IMPORT_ROWS {
+            return Err(ValidationError(format!(
+                "At most {} NG words can be imported at once",
+                MAX_IMPORT_ROWS
+            )));
+        }
+        Ok(Self(rows))
+    }
+    pub fn rows(&self) -> &[ImportRow] {
+        &self.0
+    }
+}
+
+/// 取り込む1行の検証結果
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportRowResult {
+    /// 1始まりの行番号(CSVの見出し行は数えない)
+    pub row: usize,
+    /// 読み込めなかった行は空文字
+    pub ng_word: String,
+    /// 検証に失敗した理由。問題無ければ`None`
+    pub error: Option<String>,
+}
+
+/// NGワードの一括取り込みの結果
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportReport {
+    pub rows: Vec<ImportRowResult>,
+    /// 検証だけして登録しなかったか
+    pub dry_run: bool,
+    /// 全ての行を登録したか
+    pub imported: bool,
+}
+impl ImportReport {
+    /// 検証に失敗した行があるかを判定する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::import_ng_words::model::{ImportReport, ImportRowResult};
+    ///
+    /// let mut report = ImportReport {
+    ///     rows: vec![ImportRowResult {
+    ///         row: 1,
+    ///         ng_word: "bad".to_string(),
+    ///         error: None,
+    ///     }],
+    ///     dry_run: true,
+    ///     imported: false,
+    /// };
+    /// assert!(!report.has_errors());
+    ///
+    /// report.rows.push(ImportRowResult {
+    ///     row: 2,
+    ///     ng_word: "b".to_string(),
+    ///     error: Some("NgWord must be at least 3 characters long".to_string()),
+    /// });
+    /// assert!(report.has_errors());
+    /// ```
+    pub fn has_errors(&self) -> bool {
+        self.rows.iter().any(|row| row.error.is_some())
+    }
+}
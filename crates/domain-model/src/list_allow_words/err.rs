@@ -0,0 +1,21 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ListAllowWordsError {
+    ServiceError(ServiceError),
+    PermissionError(PermissionError),
+}
+impl From<ServiceError> for ListAllowWordsError {
+    fn from(e: ServiceError) -> Self {
+        ListAllowWordsError::ServiceError(e)
+    }
+}
+impl From<PermissionError> for ListAllowWordsError {
+    fn from(e: PermissionError) -> Self {
+        ListAllowWordsError::PermissionError(e)
+    }
+}
@@ -0,0 +1,12 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ListMuteWordsError {
+    ServiceError(ServiceError),
+}
+impl From<ServiceError> for ListMuteWordsError {
+    fn from(e: ServiceError) -> Self {
+        ListMuteWordsError::ServiceError(e)
+    }
+}
@@ -0,0 +1,9 @@
+use chrono::NaiveDateTime;
+
+/// 登録済みのミュートする語
+#[derive(Debug, Clone, PartialEq)]
+pub struct MuteWord {
+    pub id: i32,
+    pub word: String,
+    pub created_at: NaiveDateTime,
+}
@@ -0,0 +1,30 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ListNgWordsError {
+    ValidationError(ValidationError),
+    ServiceError(ServiceError),
+    PermissionError(PermissionError),
+}
+impl From<ValidationError> for ListNgWordsError {
+    fn from(e: ValidationError) -> Self {
+        ListNgWordsError::ValidationError(e)
+    }
+}
+impl From<ServiceError> for ListNgWordsError {
+    fn from(e: ServiceError) -> Self {
+        ListNgWordsError::ServiceError(e)
+    }
+}
+impl From<PermissionError> for ListNgWordsError {
+    fn from(e: PermissionError) -> Self {
+        ListNgWordsError::PermissionError(e)
+    }
+}
@@ -0,0 +1,133 @@
+use super::constants::{DEFAULT_PER_PAGE, MAX_PER_PAGE};
+use super::err::ValidationError;
+use crate::ng_word_policy::model::{PatternKind, Severity};
+use chrono::NaiveDateTime;
+
+/// NGワード一覧の検索条件(未検証)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UnvalidatedNgWordQuery {
+    pub keyword: Option<String>,
+    pub page: Option<u64>,
+    pub per_page: Option<u64>,
+}
+
+/// NGワード一覧の検索条件
+#[derive(Debug, Clone, PartialEq)]
+pub struct NgWordQuery {
+    keyword: Option<String>,
+    page: u64,
+    per_page: u64,
+}
+impl NgWordQuery {
+    /// 検索条件の値オブジェクトを生成
+    ///
+    /// ページは1始まりで、省略時は1ページ目。1ページの件数は省略時20件、最大100件。
+    /// キーワードは前後の空白を除き、空なら絞り込まない
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::list_ng_words::model::{NgWordQuery, UnvalidatedNgWordQuery};
+    ///
+    /// let query = NgWordQuery::new(UnvalidatedNgWordQuery::default()).unwrap();
+    /// assert_eq!(query.keyword(), None);
+    /// assert_eq!(query.page(), 1);
+    /// assert_eq!(query.per_page(), 20);
+    ///
+    /// let query = NgWordQuery::new(UnvalidatedNgWordQuery {
+    ///     keyword: Some(" bad ".to_string()),
+    ///     page: Some(3),
+    ///     per_page: Some(10),
+    /// })
+    /// .unwrap();
+    /// assert_eq!(query.keyword(), Some("bad"));
+    /// assert_eq!(query.offset(), 20);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::list_ng_words::model::{NgWordQuery, UnvalidatedNgWordQuery};
+    ///
+    /// // ページが0の場合
+    /// let query = NgWordQuery::new(UnvalidatedNgWordQuery {
+    ///     page: Some(0),
+    ///     ..Default::default()
+    /// });
+    /// assert!(query.is_err());
+    ///
+    /// // 1ページの件数が100件を超える場合
+    /// let query = NgWordQuery::new(UnvalidatedNgWordQuery {
+    ///     per_page: Some(101),
+    ///     ..Default::default()
+    /// });
+    /// assert!(query.is_err());
+    /// ```
+    pub fn new(query: UnvalidatedNgWordQuery) -> Result<Self, ValidationError> {
+        let page = query.page.unwrap_or(1);
+        let per_page = query.per_page.unwrap_or(DEFAULT_PER_PAGE);
+        if page == 0 {
+            return Err(ValidationError("Page must be at least 1".to_string()));
+        }
+        if per_page == 0 || per_page > MAX_PER_PAGE {
+            return Err(ValidationError(format!(
+                "Per page must be between 1 and {}",
+                MAX_PER_PAGE
+            )));
+        }
+        if (page - 1).checked_mul(per_page).is_none() {
+            return Err(ValidationError("Page is too large".to_string()));
+        }
+        let keyword = query
+            .keyword
+            .map(|keyword| keyword.trim().to_string())
+            .filter(|keyword| !keyword.is_empty());
+        Ok(Self {
+            keyword,
+            page,
+            per_page,
+        })
+    }
+    pub fn keyword(&self) -> Option<&str> {
+        self.keyword.as_deref()
+    }
+    pub fn page(&self) -> u64 {
+        self.page
+    }
+    pub fn per_page(&self) -> u64 {
+        self.per_page
+    }
+    /// 読み飛ばす件数
+    pub fn offset(&self) -> u64 {
+        (self.page - 1) * self.per_page
+    }
+}
+
+/// NGワードを作成・変更したユーザ
+#[derive(Debug, Clone, PartialEq)]
+pub struct NgWordEditor {
+    pub id: i32,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NgWord {
+    pub id: i32,
+    pub word: String,
+    pub kind: PatternKind,
+    pub severity: Severity,
+    pub created_by: Option<NgWordEditor>,
+    pub updated_by: Option<NgWordEditor>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+/// NGワード一覧の1ページ分
+#[derive(Debug, Clone, PartialEq)]
+pub struct NgWordPage {
+    pub ng_words: Vec<NgWord>,
+    /// 検索条件に一致する全件数
+    pub total: u64,
+    pub page: u64,
+    pub per_page: u64,
+}
@@ -0,0 +1,28 @@
+use crate::ng_word_policy;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotFoundError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ListPostRevisionsError {
+    ServiceError(ServiceError),
+    NotFoundError(NotFoundError),
+}
+impl From<ServiceError> for ListPostRevisionsError {
+    fn from(e: ServiceError) -> Self {
+        ListPostRevisionsError::ServiceError(e)
+    }
+}
+impl From<NotFoundError> for ListPostRevisionsError {
+    fn from(e: NotFoundError) -> Self {
+        ListPostRevisionsError::NotFoundError(e)
+    }
+}
+impl From<ng_word_policy::err::ServiceError> for ListPostRevisionsError {
+    fn from(e: ng_word_policy::err::ServiceError) -> Self {
+        ListPostRevisionsError::ServiceError(ServiceError(e.0))
+    }
+}
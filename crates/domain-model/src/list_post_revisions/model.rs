@@ -0,0 +1,10 @@
+use crate::get_all_post::model::{Content, PostedDatetime};
+
+/// 編集される前の投稿の版
+#[derive(Debug, Clone, PartialEq)]
+pub struct PostRevision {
+    pub id: i32,
+    /// その版が書かれた日時
+    pub posted_datetime: PostedDatetime,
+    pub content: Content,
+}
@@ -0,0 +1,5 @@
+pub const SESSION_EXPIRATION_HOURS: i64 = 24;
+pub const SESSION_TOKEN_BYTES: usize = 32;
+// ユーザがいない場合の検証に使う、どのパスワードとも一致しないハッシュ(既定のargon2のパラメータ)
+pub const DUMMY_PASSWORD_HASH: &str =
+    "$argon2id$v=19$m=19456,t=2,p=1$ehuyoNr1WJQdfyuh2GwkRQ$9zv3UPjsTOEPDwOkt7s97rldPPTPVjLQdvitLuDrEmg";
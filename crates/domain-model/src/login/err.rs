@@ -4,10 +4,18 @@ pub struct ValidationError(pub String);
 #[derive(Debug, Clone, PartialEq)]
 pub struct ServiceError(pub String);
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CredentialError(pub String);
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum LoginError {
     ValidationError(ValidationError),
     ServiceError(ServiceError),
+    SessionError(SessionError),
+    CredentialError(CredentialError),
 }
 impl From<ValidationError> for LoginError {
     fn from(e: ValidationError) -> Self {
@@ -19,3 +27,13 @@ impl From<ServiceError> for LoginError {
         LoginError::ServiceError(e)
     }
 }
+impl From<SessionError> for LoginError {
+    fn from(e: SessionError) -> Self {
+        LoginError::SessionError(e)
+    }
+}
+impl From<CredentialError> for LoginError {
+    fn from(e: CredentialError) -> Self {
+        LoginError::CredentialError(e)
+    }
+}
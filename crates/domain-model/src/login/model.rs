@@ -1,2 +1,235 @@
+use super::constants::{DUMMY_PASSWORD_HASH, SESSION_EXPIRATION_HOURS, SESSION_TOKEN_BYTES};
+use argon2::{
+    password_hash::{PasswordHash, PasswordVerifier},
+    Argon2,
+};
+use chrono::{DateTime, Duration, Utc};
+use rand::{rngs::OsRng, RngCore};
+use std::fmt::Write;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct UncheckedUserName(pub String);
+
+#[derive(Clone, PartialEq)]
+pub struct UncheckedPassword(pub String);
+// 平文のパスワードをログに出さない
+impl std::fmt::Debug for UncheckedPassword {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("UncheckedPassword(***)")
+    }
+}
+
+/// DBに保存されているPHC文字列形式のパスワードハッシュ
+#[derive(Debug, Clone, PartialEq)]
+pub struct HashedPassword(pub String);
+impl HashedPassword {
+    /// ユーザがいない場合に検証するためのハッシュを取得する
+    ///
+    /// ユーザの有無で応答時間が変わらないよう、本物のハッシュと同じ重さで検証できる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::login::model::{HashedPassword, UncheckedPassword};
+    ///
+    /// assert!(!HashedPassword::dummy().verify(&UncheckedPassword("password1".to_string())));
+    /// ```
+    pub fn dummy() -> Self {
+        Self(DUMMY_PASSWORD_HASH.to_string())
+    }
+
+    /// パスワードがハッシュと一致するか検証する
+    ///
+    /// ハッシュの形式が不正な場合も一致しないものとして扱う
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::login::model::{HashedPassword, UncheckedPassword};
+    /// use domain_model::register_user_name::model::Password;
+    ///
+    /// let hashed = Password::new("password1").unwrap().hash().unwrap();
+    /// let hashed = HashedPassword(hashed.value().to_string());
+    ///
+    /// assert!(hashed.verify(&UncheckedPassword("password1".to_string())));
+    /// assert!(!hashed.verify(&UncheckedPassword("password2".to_string())));
+    /// assert!(!HashedPassword("invalid".to_string()).verify(&UncheckedPassword("password1".to_string())));
+    /// ```
+    pub fn verify(&self, password: &UncheckedPassword) -> bool {
+        match PasswordHash::new(&self.0) {
+            Ok(hash) => Argon2::default()
+                .verify_password(password.0.as_bytes(), &hash)
+                .is_ok(),
+            Err(_) => false,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct SessionToken(pub String);
+// セッショントークンをログに出さない
+impl std::fmt::Debug for SessionToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SessionToken(***)")
+    }
+}
+impl SessionToken {
+    /// セッショントークンを生成
+    ///
+    /// OSの乱数生成器から得た32バイトを16進数文字列にしたもの
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::login::model::SessionToken;
+    ///
+    /// let token = SessionToken::generate();
+    /// assert_eq!(token.0.len(), 64);
+    /// assert_ne!(token, SessionToken::generate());
+    /// assert_eq!(format!("{:?}", token), "SessionToken(***)");
+    /// ```
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; SESSION_TOKEN_BYTES];
+        OsRng.fill_bytes(&mut bytes);
+        let token = bytes.iter().fold(String::new(), |mut token, b| {
+            let _ = write!(token, "{:02x}", b);
+            token
+        });
+        SessionToken(token)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Session {
+    pub token: SessionToken,
+    pub expires_at: DateTime<Utc>,
+}
+impl Session {
+    /// 新しいセッションを生成
+    ///
+    /// 有効期限は`now`から24時間後
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{Duration, Utc};
+    /// use domain_model::login::model::Session;
+    ///
+    /// let now = Utc::now();
+    /// let session = Session::new(now);
+    /// assert_eq!(session.expires_at, now + Duration::hours(24));
+    /// ```
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            token: SessionToken::generate(),
+            expires_at: now + Duration::hours(SESSION_EXPIRATION_HOURS),
+        }
+    }
+}
+
+/// ユーザの権限
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// 一般のユーザ
+    Member,
+    /// 投稿やNGワードを管理するユーザ
+    Moderator,
+    /// 全ての操作ができるユーザ
+    Admin,
+}
+impl Role {
+    /// DBに保存する文字列表現を取得
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::login::model::Role;
+    ///
+    /// assert_eq!(Role::Member.as_str(), "member");
+    /// assert_eq!(Role::Moderator.as_str(), "moderator");
+    /// assert_eq!(Role::Admin.as_str(), "admin");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Member => "member",
+            Role::Moderator => "moderator",
+            Role::Admin => "admin",
+        }
+    }
+
+    /// 文字列表現から権限を生成
+    ///
+    /// 不明な値の場合は`None`を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::login::model::Role;
+    ///
+    /// assert_eq!(Role::parse("admin"), Some(Role::Admin));
+    /// assert_eq!(Role::parse("moderator"), Some(Role::Moderator));
+    /// assert_eq!(Role::parse("member"), Some(Role::Member));
+    /// assert_eq!(Role::parse("unknown"), None);
+    /// ```
+    pub fn parse(role: &str) -> Option<Self> {
+        match role {
+            "admin" => Some(Role::Admin),
+            "moderator" => Some(Role::Moderator),
+            "member" => Some(Role::Member),
+            _ => None,
+        }
+    }
+
+    /// NGワードを管理できるか
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::login::model::Role;
+    ///
+    /// assert!(!Role::Member.can_manage_ng_words());
+    /// assert!(Role::Moderator.can_manage_ng_words());
+    /// assert!(Role::Admin.can_manage_ng_words());
+    /// ```
+    pub fn can_manage_ng_words(&self) -> bool {
+        matches!(self, Role::Moderator | Role::Admin)
+    }
+
+    /// 塗りつぶす前の投稿内容を閲覧できるか
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::login::model::Role;
+    ///
+    /// assert!(!Role::Member.can_view_raw_posts());
+    /// assert!(Role::Moderator.can_view_raw_posts());
+    /// assert!(Role::Admin.can_view_raw_posts());
+    /// ```
+    pub fn can_view_raw_posts(&self) -> bool {
+        matches!(self, Role::Moderator | Role::Admin)
+    }
+
+    /// 他のユーザの投稿を削除したり、削除した投稿を戻したりできるか
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::login::model::Role;
+    ///
+    /// assert!(!Role::Member.can_moderate_posts());
+    /// assert!(Role::Moderator.can_moderate_posts());
+    /// assert!(Role::Admin.can_moderate_posts());
+    /// ```
+    pub fn can_moderate_posts(&self) -> bool {
+        matches!(self, Role::Moderator | Role::Admin)
+    }
+}
+
+/// セッションから解決されたログイン中のユーザ
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoggedInUser {
+    pub id: i32,
+    pub name: String,
+    pub role: Role,
+}
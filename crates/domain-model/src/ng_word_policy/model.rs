@@ -0,0 +1,343 @@
+use crate::text::{MatcherError, NgPatternMatcher, NgWordMatcher, NormalizedText};
+use std::ops::Range;
+
+/// NGワードに一致した時の対応
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// 一致した箇所を塗りつぶして投稿する
+    #[default]
+    Mask,
+    /// 投稿を保存するが、モデレーションが済むまで公開しない
+    Hold,
+    /// 投稿を拒否する
+    Reject,
+}
+impl Severity {
+    /// DBに保存する文字列表現を取得
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::ng_word_policy::model::Severity;
+    ///
+    /// assert_eq!(Severity::Mask.as_str(), "mask");
+    /// assert_eq!(Severity::Hold.as_str(), "hold");
+    /// assert_eq!(Severity::Reject.as_str(), "reject");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Mask => "mask",
+            Severity::Hold => "hold",
+            Severity::Reject => "reject",
+        }
+    }
+
+    /// 文字列表現から対応を生成
+    ///
+    /// 不明な値の場合は`None`を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::ng_word_policy::model::Severity;
+    ///
+    /// assert_eq!(Severity::parse("mask"), Some(Severity::Mask));
+    /// assert_eq!(Severity::parse("hold"), Some(Severity::Hold));
+    /// assert_eq!(Severity::parse("reject"), Some(Severity::Reject));
+    /// assert_eq!(Severity::parse("unknown"), None);
+    /// ```
+    pub fn parse(severity: &str) -> Option<Self> {
+        match severity {
+            "mask" => Some(Severity::Mask),
+            "hold" => Some(Severity::Hold),
+            "reject" => Some(Severity::Reject),
+            _ => None,
+        }
+    }
+}
+
+/// NGワードの書き方
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PatternKind {
+    /// 文字列そのもの
+    #[default]
+    Literal,
+    /// `*`(0文字以上)と`?`(1文字)を使えるグロブ
+    Glob,
+    /// 正規表現
+    Regex,
+}
+impl PatternKind {
+    /// DBに保存する文字列表現を取得
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::ng_word_policy::model::PatternKind;
+    ///
+    /// assert_eq!(PatternKind::Literal.as_str(), "literal");
+    /// assert_eq!(PatternKind::Glob.as_str(), "glob");
+    /// assert_eq!(PatternKind::Regex.as_str(), "regex");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PatternKind::Literal => "literal",
+            PatternKind::Glob => "glob",
+            PatternKind::Regex => "regex",
+        }
+    }
+
+    /// 文字列表現から書き方を生成
+    ///
+    /// 不明な値の場合は`None`を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::ng_word_policy::model::PatternKind;
+    ///
+    /// assert_eq!(PatternKind::parse("literal"), Some(PatternKind::Literal));
+    /// assert_eq!(PatternKind::parse("glob"), Some(PatternKind::Glob));
+    /// assert_eq!(PatternKind::parse("regex"), Some(PatternKind::Regex));
+    /// assert_eq!(PatternKind::parse("unknown"), None);
+    /// ```
+    pub fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "literal" => Some(PatternKind::Literal),
+            "glob" => Some(PatternKind::Glob),
+            "regex" => Some(PatternKind::Regex),
+            _ => None,
+        }
+    }
+
+    /// グロブや正規表現のパターンから照合器を構築する
+    ///
+    /// 文字列そのものの場合は`None`を返す。まとめて`NgWordMatcher`で照合するため
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::ng_word_policy::model::PatternKind;
+    ///
+    /// assert!(PatternKind::Literal.compile("(bad").unwrap().is_none());
+    /// assert!(PatternKind::Glob.compile("b*d").unwrap().is_some());
+    /// assert!(PatternKind::Regex.compile("b.d").unwrap().is_some());
+    /// assert!(PatternKind::Regex.compile("(bad").is_err());
+    /// ```
+    pub fn compile(&self, pattern: &str) -> Result<Option<NgPatternMatcher>, MatcherError> {
+        match self {
+            PatternKind::Literal => Ok(None),
+            PatternKind::Glob => NgPatternMatcher::glob(pattern).map(Some),
+            PatternKind::Regex => NgPatternMatcher::regex(pattern).map(Some),
+        }
+    }
+}
+
+/// 登録済みのNGワード1件分の規則
+#[derive(Debug, Clone, PartialEq)]
+pub struct NgWordRule {
+    pub id: i32,
+    pub word: String,
+    pub kind: PatternKind,
+    pub severity: Severity,
+}
+
+/// 登録済みのNGワード全ての規則と、それらから構築した照合器
+#[derive(Debug, Clone)]
+pub struct NgWordRuleSet {
+    rules: Vec<NgWordRule>,
+    /// 文字列そのものの規則をまとめた照合器
+    matcher: NgWordMatcher,
+    /// グロブや正規表現の規則の番号と、その照合器
+    patterns: Vec<(usize, NgPatternMatcher)>,
+    /// 例外の語をまとめた照合器。この中に収まる一致は無視する
+    allow_words: NgWordMatcher,
+}
+impl NgWordRuleSet {
+    /// 規則の一覧から照合器を構築する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::ng_word_policy::model::{NgWordRule, NgWordRuleSet, PatternKind, Severity};
+    ///
+    /// let rule_set = NgWordRuleSet::new(vec![NgWordRule {
+    ///     id: 1,
+    ///     word: "bad".to_string(),
+    ///     kind: PatternKind::Literal,
+    ///     severity: Severity::Mask,
+    /// }]);
+    /// assert!(rule_set.is_ok());
+    /// ```
+    pub fn new(rules: Vec<NgWordRule>) -> Result<Self, MatcherError> {
+        // 空のNGワードは照合器に含まれないため、パターンの規則を空にしても番号はずれない
+        let matcher = NgWordMatcher::new(rules.iter().map(|rule| match rule.kind {
+            PatternKind::Literal => rule.word.as_str(),
+            PatternKind::Glob | PatternKind::Regex => "",
+        }))?;
+        let mut patterns = Vec::new();
+        for (index, rule) in rules.iter().enumerate() {
+            if let Some(pattern) = rule.kind.compile(&rule.word)? {
+                patterns.push((index, pattern));
+            }
+        }
+        Ok(Self {
+            rules,
+            matcher,
+            patterns,
+            allow_words: NgWordMatcher::empty(),
+        })
+    }
+
+    /// 例外の語を設定する
+    ///
+    /// NGワードに一致しても、その箇所が例外の語の一致箇所に収まっていれば一致しないものとして扱う
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::ng_word_policy::model::{NgWordRule, NgWordRuleSet, PatternKind, Severity};
+    /// use domain_model::text::NormalizedText;
+    ///
+    /// let rule_set = NgWordRuleSet::new(vec![NgWordRule {
+    ///     id: 1,
+    ///     word: "ass".to_string(),
+    ///     kind: PatternKind::Literal,
+    ///     severity: Severity::Mask,
+    /// }])
+    /// .unwrap()
+    /// .with_allow_words(["class", "password"])
+    /// .unwrap();
+    /// assert!(!rule_set.is_match(&NormalizedText::new("CLASS password")));
+    /// assert!(rule_set.is_match(&NormalizedText::new("class ass")));
+    /// ```
+    pub fn with_allow_words<I, S>(self, allow_words: I) -> Result<Self, MatcherError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Ok(Self {
+            allow_words: NgWordMatcher::new(allow_words)?,
+            ..self
+        })
+    }
+
+    /// 規則が1つも無い集合を取得する
+    pub fn empty() -> Self {
+        Self {
+            rules: vec![],
+            matcher: NgWordMatcher::empty(),
+            patterns: vec![],
+            allow_words: NgWordMatcher::empty(),
+        }
+    }
+
+    /// 一致した規則と、元の文字列上のバイト範囲を全て、一致箇所の先頭から順に返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::ng_word_policy::model::{NgWordRule, NgWordRuleSet, PatternKind, Severity};
+    /// use domain_model::text::NormalizedText;
+    ///
+    /// let rule = NgWordRule {
+    ///     id: 1,
+    ///     word: "bad".to_string(),
+    ///     kind: PatternKind::Literal,
+    ///     severity: Severity::Reject,
+    /// };
+    /// let rule_set = NgWordRuleSet::new(vec![rule.clone()]).unwrap();
+    /// let found = rule_set.find_all(&NormalizedText::new("so BAD"));
+    /// assert_eq!(found, vec![(&rule, 3..6)]);
+    /// ```
+    pub fn find_all(&self, text: &NormalizedText) -> Vec<(&NgWordRule, Range<usize>)> {
+        let allowed = self.allow_words.find_all(text);
+        self.find_all_except(text, &allowed)
+    }
+
+    /// 例外の語の一致箇所`allowed`に収まるものを除いて、一致した規則と範囲を返す
+    fn find_all_except(
+        &self,
+        text: &NormalizedText,
+        allowed: &[Range<usize>],
+    ) -> Vec<(&NgWordRule, Range<usize>)> {
+        let mut found = self.matcher.find_matches(text);
+        for (index, pattern) in &self.patterns {
+            found.extend(
+                pattern
+                    .find_all(text)
+                    .into_iter()
+                    .map(|range| (*index, range)),
+            );
+        }
+        found.sort_by_key(|(index, range)| (range.start, *index));
+        found
+            .into_iter()
+            .filter(|(_, range)| {
+                !allowed
+                    .iter()
+                    .any(|allowed| allowed.start <= range.start && range.end <= allowed.end)
+            })
+            .map(|(index, range)| (&self.rules[index], range))
+            .collect()
+    }
+
+    /// いずれかの規則に一致するかを判定する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::ng_word_policy::model::{NgWordRule, NgWordRuleSet, PatternKind, Severity};
+    /// use domain_model::text::NormalizedText;
+    ///
+    /// let rule_set = NgWordRuleSet::new(vec![NgWordRule {
+    ///     id: 1,
+    ///     word: "bad".to_string(),
+    ///     kind: PatternKind::Literal,
+    ///     severity: Severity::Hold,
+    /// }])
+    /// .unwrap();
+    /// assert!(rule_set.is_match(&NormalizedText::new("so bad")));
+    /// assert!(!rule_set.is_match(&NormalizedText::new("good")));
+    /// ```
+    pub fn is_match(&self, text: &NormalizedText) -> bool {
+        let allowed = self.allow_words.find_all(text);
+        if !allowed.is_empty() {
+            return !self.find_all_except(text, &allowed).is_empty();
+        }
+        self.matcher.is_match(text)
+            || self
+                .patterns
+                .iter()
+                .any(|(_, pattern)| pattern.is_match(text))
+    }
+}
+
+/// 投稿内容をNGワードの規則に照らした結果
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContentVerdict {
+    /// 公開してよい。`mask`の規則に一致した箇所は塗りつぶし済み
+    Accept(String),
+    /// 保存するが公開しない。`mask`の規則に一致した箇所は塗りつぶし済み
+    Hold { content: String, rule: NgWordRule },
+    /// 拒否する
+    Reject(NgWordRule),
+}
+
+/// 本文中でNGワードの規則に一致した箇所
+#[derive(Debug, Clone, PartialEq)]
+pub struct NgWordMatch {
+    pub rule: NgWordRule,
+    /// 元の文字列のうち一致した部分
+    pub matched: String,
+    /// 元の文字列上のコードポイント単位の範囲
+    pub range: Range<usize>,
+}
+
+/// 本文をNGワードの規則に照らした結果と、一致した箇所の一覧
+#[derive(Debug, Clone, PartialEq)]
+pub struct NgWordCheck {
+    /// 一致した箇所。先頭から順に並ぶ
+    pub matches: Vec<NgWordMatch>,
+    pub verdict: ContentVerdict,
+}
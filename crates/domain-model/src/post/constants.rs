@@ -1,4 +1,15 @@
+use crate::text::LengthUnit;
+
 pub const MAX_USER_NAME_LENGTH: usize = 16;
 pub const MIN_USER_NAME_LENGTH: usize = 3;
+pub const USER_NAME_LENGTH_UNIT: LengthUnit = LengthUnit::Grapheme;
+// DBのVARCHAR(255)はコードポイント単位で数えるため、結合文字を重ねた書記素でも溢れないよう別に上限を設ける
+pub const MAX_USER_NAME_CHARS: usize = 255;
 pub const MAX_NG_WORD_LENGTH: usize = 10;
 pub const MIN_NG_WORD_LENGTH: usize = 3;
+pub const NG_WORD_LENGTH_UNIT: LengthUnit = LengthUnit::Grapheme;
+// NGワードもVARCHAR(255)に収まるよう、コードポイント単位の上限を設ける
+pub const MAX_NG_WORD_CHARS: usize = 255;
+// DBのVARCHAR(255)はコードポイント単位で数える
+pub const MAX_CONTENT_LENGTH: usize = 255;
+pub const CONTENT_LENGTH_UNIT: LengthUnit = LengthUnit::Char;
@@ -1,13 +1,25 @@
+use crate::ng_word_policy;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ValidationError(pub String);
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ServiceError(pub String);
 
+/// 拒否対象のNGワードを含む
+#[derive(Debug, Clone, PartialEq)]
+pub struct NgWordError(pub String);
+
+/// 返信先の投稿が無い
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotFoundError(pub String);
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum PostError {
     ValidationError(ValidationError),
     ServiceError(ServiceError),
+    NgWordError(NgWordError),
+    NotFoundError(NotFoundError),
 }
 impl From<ValidationError> for PostError {
     fn from(e: ValidationError) -> Self {
@@ -19,3 +31,18 @@ impl From<ServiceError> for PostError {
         PostError::ServiceError(e)
     }
 }
+impl From<NgWordError> for PostError {
+    fn from(e: NgWordError) -> Self {
+        PostError::NgWordError(e)
+    }
+}
+impl From<NotFoundError> for PostError {
+    fn from(e: NotFoundError) -> Self {
+        PostError::NotFoundError(e)
+    }
+}
+impl From<ng_word_policy::err::ServiceError> for PostError {
+    fn from(e: ng_word_policy::err::ServiceError) -> Self {
+        PostError::ServiceError(ServiceError(e.0))
+    }
+}
@@ -1,7 +1,10 @@
 use super::constants::{
-    MAX_NG_WORD_LENGTH, MAX_USER_NAME_LENGTH, MIN_NG_WORD_LENGTH, MIN_USER_NAME_LENGTH,
+    CONTENT_LENGTH_UNIT, MAX_CONTENT_LENGTH, MAX_NG_WORD_CHARS, MAX_NG_WORD_LENGTH,
+    MAX_USER_NAME_CHARS, MAX_USER_NAME_LENGTH, MIN_NG_WORD_LENGTH, MIN_USER_NAME_LENGTH,
+    NG_WORD_LENGTH_UNIT, USER_NAME_LENGTH_UNIT,
 };
 use super::err::ValidationError;
+use crate::text::LengthUnit;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct UnvalidatedUserName(pub String);
@@ -12,6 +15,7 @@ impl UserName {
     /// ユーザ名の値オブジェクトを生成
     ///
     /// ユーザ名が3文字未満もしくは16文字を超える場合はエラーを返す
+    /// 書記素の数が収まっても、コードポイントが255を超える場合はエラーを返す
     ///
     /// # Examples
     ///
@@ -23,6 +27,10 @@ impl UserName {
     ///
     /// let result = UserName::new("name_b");
     /// assert!(result.is_ok());
+    ///
+    /// // 長さは書記素単位で数える
+    /// let result = UserName::new("日本語の名前");
+    /// assert!(result.is_ok());
     /// ```
     ///
     /// # Failures
@@ -37,18 +45,28 @@ impl UserName {
     /// // 16文字を超える場合
     /// let result = UserName::new("thisisaverylongname");
     /// assert!(result.is_err());
+    ///
+    /// // 結合文字を重ねてコードポイントが255を超える場合
+    /// let result = UserName::new(&format!("abc{}", "\u{301}".repeat(300)));
+    /// assert!(result.is_err());
     /// ```
     pub fn new(name: &str) -> Result<Self, ValidationError> {
-        if name.len() < MIN_USER_NAME_LENGTH {
+        let length = USER_NAME_LENGTH_UNIT.count(name);
+        if length < MIN_USER_NAME_LENGTH {
             Err(ValidationError(format!(
                 "Username must be at least {} characters long",
                 MIN_USER_NAME_LENGTH
             )))
-        } else if name.len() > 16 {
+        } else if length > MAX_USER_NAME_LENGTH {
             Err(ValidationError(format!(
                 "Username must be at most {} characters long",
                 MAX_USER_NAME_LENGTH
             )))
+        } else if LengthUnit::Char.count(name) > MAX_USER_NAME_CHARS {
+            Err(ValidationError(format!(
+                "Username must be at most {} code points long",
+                MAX_USER_NAME_CHARS
+            )))
         } else {
             Ok(UserName(name.to_string()))
         }
@@ -78,6 +96,7 @@ impl NgWord {
     /// NGワードの値オブジェクトを生成
     ///
     /// NGワードが3文字未満もしくは10文字を超える場合はエラーを返す
+    /// 書記素の数が収まっても、コードポイントが255を超える場合はエラーを返す
     ///
     /// # Examples
     ///
@@ -89,6 +108,10 @@ impl NgWord {
     ///
     /// let result = NgWord::new("name_b");
     /// assert!(result.is_ok());
+    ///
+    /// // 長さは書記素単位で数える
+    /// let result = NgWord::new("禁止語");
+    /// assert!(result.is_ok());
     /// ```
     ///
     /// # Failures
@@ -100,21 +123,31 @@ impl NgWord {
     /// let result = NgWord::new("sh");
     /// assert!(result.is_err());
     ///
-    /// // 16文字を超える場合
+    /// // 10文字を超える場合
     /// let result = NgWord::new("thisisaverylo");
     /// assert!(result.is_err());
+    ///
+    /// // 結合文字を重ねてコードポイントが255を超える場合
+    /// let result = NgWord::new(&format!("abc{}", "\u{301}".repeat(300)));
+    /// assert!(result.is_err());
     /// ```
     pub fn new(ng_word: &str) -> Result<Self, ValidationError> {
-        if ng_word.len() < MIN_NG_WORD_LENGTH {
+        let length = NG_WORD_LENGTH_UNIT.count(ng_word);
+        if length < MIN_NG_WORD_LENGTH {
             Err(ValidationError(format!(
                 "NgWord must be at least {} characters long",
                 MIN_NG_WORD_LENGTH
             )))
-        } else if ng_word.len() > MAX_NG_WORD_LENGTH {
+        } else if length > MAX_NG_WORD_LENGTH {
             Err(ValidationError(format!(
                 "NgWord must be at most {} characters long",
                 MAX_NG_WORD_LENGTH
             )))
+        } else if LengthUnit::Char.count(ng_word) > MAX_NG_WORD_CHARS {
+            Err(ValidationError(format!(
+                "NgWord must be at most {} code points long",
+                MAX_NG_WORD_CHARS
+            )))
         } else {
             Ok(NgWord(ng_word.to_string()))
         }
@@ -144,16 +177,66 @@ pub struct UnvalidatedPost {
     pub content: String,
 }
 
+/// 投稿の公開状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostStatus {
+    /// 公開済み
+    Published,
+    /// モデレーション待ちで非公開
+    Held,
+}
+impl PostStatus {
+    /// DBに保存する文字列表現を取得
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::post::model::PostStatus;
+    ///
+    /// assert_eq!(PostStatus::Published.as_str(), "published");
+    /// assert_eq!(PostStatus::Held.as_str(), "held");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PostStatus::Published => "published",
+            PostStatus::Held => "held",
+        }
+    }
+
+    /// 文字列表現から公開状態を生成
+    ///
+    /// 不明な値の場合は`None`を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::post::model::PostStatus;
+    ///
+    /// assert_eq!(PostStatus::parse("published"), Some(PostStatus::Published));
+    /// assert_eq!(PostStatus::parse("held"), Some(PostStatus::Held));
+    /// assert_eq!(PostStatus::parse("unknown"), None);
+    /// ```
+    pub fn parse(status: &str) -> Option<Self> {
+        match status {
+            "published" => Some(PostStatus::Published),
+            "held" => Some(PostStatus::Held),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Post {
     user_name: UserName,
+    /// 書かれたままの内容。NGワードは取得時に塗りつぶす
     content: String,
+    status: PostStatus,
 }
 impl Post {
     /// 投稿の値オブジェクトを生成
     ///
     /// ユーザ名が3文字未満もしくは16文字を超える場合はエラーを返す
-    /// コンテンツが空文字の場合はエラーを返す
+    /// コンテンツが空文字もしくは255文字を超える場合はエラーを返す
     ///
     /// # Examples
     /// ```rust
@@ -187,13 +270,47 @@ impl Post {
     /// let content = "".to_string();
     /// let post = Post::new(user_name, content);
     /// assert!(post.is_err());
+    ///
+    /// // コンテンツが255文字を超える場合
+    /// let user_name = "name".to_string();
+    /// let content = "あ".repeat(256);
+    /// let post = Post::new(user_name, content);
+    /// assert!(post.is_err());
     /// ```
     pub fn new(user_name: String, content: String) -> Result<Self, ValidationError> {
         let user_name = UserName::new(&user_name)?;
-        if content.len() == 0 {
+        if content.is_empty() {
             Err(ValidationError("Content must not be empty".to_string()))
+        } else if CONTENT_LENGTH_UNIT.count(&content) > MAX_CONTENT_LENGTH {
+            Err(ValidationError(format!(
+                "Content must be at most {} characters long",
+                MAX_CONTENT_LENGTH
+            )))
         } else {
-            Ok(Self { user_name, content })
+            Ok(Self {
+                user_name,
+                content,
+                status: PostStatus::Published,
+            })
+        }
+    }
+
+    /// モデレーション待ちにする
+    ///
+    /// # Examples
+    /// ```rust
+    /// use domain_model::post::model::{Post, PostStatus};
+    ///
+    /// let post = Post::new("name".to_string(), "content".to_string()).unwrap();
+    /// assert_eq!(post.status(), PostStatus::Published);
+    ///
+    /// let post = post.hold();
+    /// assert_eq!(post.status(), PostStatus::Held);
+    /// ```
+    pub fn hold(self) -> Self {
+        Self {
+            status: PostStatus::Held,
+            ..self
         }
     }
     pub fn user_name(&self) -> &UserName {
@@ -202,4 +319,7 @@ impl Post {
     pub fn content(&self) -> &str {
         &self.content
     }
+    pub fn status(&self) -> PostStatus {
+        self.status
+    }
 }
@@ -0,0 +1,2 @@
+// リアクションの種類の長さ(コードポイント単位)。DBの列の長さに合わせる
+pub const MAX_REACTION_KIND_LENGTH: usize = 32;
@@ -0,0 +1,179 @@
+use super::constants::MAX_REACTION_KIND_LENGTH;
+use super::err::ValidationError;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// リアクションの種類
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReactionKind(String);
+impl ReactionKind {
+    /// リアクションの種類の値オブジェクトを生成
+    ///
+    /// 英小文字・数字・`_`のみの名前か、絵文字などの記号1文字(書記素クラスタ1つ)を使える。
+    /// それ以外の場合や、1文字以上32文字以下でない場合はエラーを返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::reaction::model::ReactionKind;
+    ///
+    /// let kind = ReactionKind::new("thumbs_up").unwrap();
+    /// assert_eq!(kind.value(), "thumbs_up");
+    ///
+    /// // 肌の色や結合子を含む絵文字も1文字として扱う
+    /// assert_eq!(ReactionKind::new("👍").unwrap().value(), "👍");
+    /// assert!(ReactionKind::new("👍🏽").is_ok());
+    /// assert!(ReactionKind::new("👨‍👩‍👧").is_ok());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::reaction::model::ReactionKind;
+    ///
+    /// // 空の場合
+    /// assert!(ReactionKind::new("").is_err());
+    ///
+    /// // 使えない文字を含む場合
+    /// assert!(ReactionKind::new("Like").is_err());
+    /// assert!(ReactionKind::new("あ").is_err());
+    ///
+    /// // 絵文字が2文字以上の場合
+    /// assert!(ReactionKind::new("👍👍").is_err());
+    /// assert!(ReactionKind::new("👍a").is_err());
+    ///
+    /// // 32文字を超える場合
+    /// assert!(ReactionKind::new(&"a".repeat(33)).is_err());
+    /// ```
+    pub fn new(kind: &str) -> Result<Self, ValidationError> {
+        if kind.is_empty() || kind.chars().count() > MAX_REACTION_KIND_LENGTH {
+            return Err(ValidationError(format!(
+                "Reaction kind must be between 1 and {} characters long",
+                MAX_REACTION_KIND_LENGTH
+            )));
+        }
+        if !is_name(kind) && !is_emoji(kind) {
+            return Err(ValidationError(
+                "Reaction kind must consist of lowercase letters, digits and underscores, or be a single emoji"
+                    .to_string(),
+            ));
+        }
+        Ok(Self(kind.to_string()))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+/// 英小文字・数字・`_`のみの名前か
+fn is_name(kind: &str) -> bool {
+    kind.chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// 絵文字などの記号1文字か
+///
+/// 書記素クラスタ1つで、その先頭が英数字・空白・制御文字などでないものを記号とみなす
+fn is_emoji(kind: &str) -> bool {
+    let mut graphemes = kind.graphemes(true);
+    let (Some(grapheme), None) = (graphemes.next(), graphemes.next()) else {
+        return false;
+    };
+    grapheme.chars().next().is_some_and(|c| {
+        !c.is_ascii() && !c.is_alphanumeric() && !c.is_whitespace() && !c.is_control()
+    })
+}
+
+/// 使えるリアクションの種類の一覧
+///
+/// 起動時に設定から読み込み、実行中は変えない
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReactionKindSet(Vec<ReactionKind>);
+impl ReactionKindSet {
+    /// カンマ区切りの文字列から一覧を生成
+    ///
+    /// 前後の空白は除き、重複は1つにまとめる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::reaction::model::ReactionKindSet;
+    ///
+    /// let kinds = ReactionKindSet::parse("like, laugh,like").unwrap();
+    /// assert_eq!(
+    ///     kinds.kinds().iter().map(|kind| kind.value()).collect::<Vec<_>>(),
+    ///     vec!["like", "laugh"]
+    /// );
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::reaction::model::ReactionKindSet;
+    ///
+    /// // 1つも無い場合
+    /// assert!(ReactionKindSet::parse(" , ").is_err());
+    ///
+    /// // 不正な種類を含む場合
+    /// assert!(ReactionKindSet::parse("like,Laugh").is_err());
+    /// ```
+    pub fn parse(kinds: &str) -> Result<Self, ValidationError> {
+        let mut set: Vec<ReactionKind> = Vec::new();
+        for kind in kinds
+            .split(',')
+            .map(str::trim)
+            .filter(|kind| !kind.is_empty())
+        {
+            let kind = ReactionKind::new(kind)?;
+            if !set.contains(&kind) {
+                set.push(kind);
+            }
+        }
+        if set.is_empty() {
+            return Err(ValidationError(
+                "At least one reaction kind is required".to_string(),
+            ));
+        }
+        Ok(Self(set))
+    }
+
+    /// 一覧にある種類を取得する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::reaction::model::ReactionKindSet;
+    ///
+    /// let kinds = ReactionKindSet::parse("like,laugh").unwrap();
+    /// assert_eq!(kinds.get("laugh").unwrap().value(), "laugh");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::reaction::model::ReactionKindSet;
+    ///
+    /// let kinds = ReactionKindSet::parse("like,laugh").unwrap();
+    /// assert!(kinds.get("angry").is_err());
+    /// ```
+    pub fn get(&self, kind: &str) -> Result<ReactionKind, ValidationError> {
+        self.0
+            .iter()
+            .find(|allowed| allowed.value() == kind)
+            .cloned()
+            .ok_or_else(|| {
+                ValidationError(format!(
+                    "Reaction kind must be one of {}",
+                    self.0
+                        .iter()
+                        .map(ReactionKind::value)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))
+            })
+    }
+
+    pub fn kinds(&self) -> &[ReactionKind] {
+        &self.0
+    }
+}
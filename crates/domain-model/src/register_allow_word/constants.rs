@@ -0,0 +1,8 @@
+use crate::text::LengthUnit;
+
+// NGワードを含む語を登録するため、NGワードより長い上限にする
+pub const MAX_ALLOW_WORD_LENGTH: usize = 32;
+pub const MIN_ALLOW_WORD_LENGTH: usize = 3;
+pub const ALLOW_WORD_LENGTH_UNIT: LengthUnit = LengthUnit::Grapheme;
+// 書記素の上限とは別に、VARCHAR(255)に収まるようコードポイントでも制限する
+pub const MAX_ALLOW_WORD_CHARS: usize = 255;
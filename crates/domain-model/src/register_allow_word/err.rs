@@ -0,0 +1,30 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegisterAllowWordError {
+    ValidationError(ValidationError),
+    ServiceError(ServiceError),
+    PermissionError(PermissionError),
+}
+impl From<ValidationError> for RegisterAllowWordError {
+    fn from(e: ValidationError) -> Self {
+        RegisterAllowWordError::ValidationError(e)
+    }
+}
+impl From<ServiceError> for RegisterAllowWordError {
+    fn from(e: ServiceError) -> Self {
+        RegisterAllowWordError::ServiceError(e)
+    }
+}
+impl From<PermissionError> for RegisterAllowWordError {
+    fn from(e: PermissionError) -> Self {
+        RegisterAllowWordError::PermissionError(e)
+    }
+}
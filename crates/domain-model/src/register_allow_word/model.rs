@@ -0,0 +1,73 @@
+use super::constants::{
+    ALLOW_WORD_LENGTH_UNIT, MAX_ALLOW_WORD_CHARS, MAX_ALLOW_WORD_LENGTH, MIN_ALLOW_WORD_LENGTH,
+};
+use super::err::ValidationError;
+use crate::text::LengthUnit;
+
+/// 登録を依頼された例外の語
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnvalidatedAllowWord {
+    pub allow_word: String,
+}
+
+/// NGワードの例外にする語
+///
+/// この語の中に現れたNGワードは一致しないものとして扱う
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllowWord(String);
+impl AllowWord {
+    /// 例外の語の値オブジェクトを生成
+    ///
+    /// 3文字未満もしくは32文字を超える場合、もしくはコードポイントが255を超える場合はエラーを返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::register_allow_word::model::AllowWord;
+    ///
+    /// let allow_word = AllowWord::new("class").unwrap();
+    /// assert_eq!(allow_word.value(), "class");
+    ///
+    /// // 長さは書記素単位で数える
+    /// assert!(AllowWord::new("例外語").is_ok());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::register_allow_word::model::AllowWord;
+    ///
+    /// // 3文字未満の場合
+    /// assert!(AllowWord::new("cl").is_err());
+    ///
+    /// // 32文字を超える場合
+    /// assert!(AllowWord::new(&"a".repeat(33)).is_err());
+    ///
+    /// // 結合文字を重ねてコードポイントが255を超える場合
+    /// assert!(AllowWord::new(&format!("abc{}", "\u{301}".repeat(300))).is_err());
+    /// ```
+    pub fn new(allow_word: &str) -> Result<Self, ValidationError> {
+        let length = ALLOW_WORD_LENGTH_UNIT.count(allow_word);
+        if length < MIN_ALLOW_WORD_LENGTH {
+            Err(ValidationError(format!(
+                "AllowWord must be at least {} characters long",
+                MIN_ALLOW_WORD_LENGTH
+            )))
+        } else if length > MAX_ALLOW_WORD_LENGTH {
+            Err(ValidationError(format!(
+                "AllowWord must be at most {} characters long",
+                MAX_ALLOW_WORD_LENGTH
+            )))
+        } else if LengthUnit::Char.count(allow_word) > MAX_ALLOW_WORD_CHARS {
+            Err(ValidationError(format!(
+                "AllowWord must be at most {} code points long",
+                MAX_ALLOW_WORD_CHARS
+            )))
+        } else {
+            Ok(AllowWord(allow_word.to_string()))
+        }
+    }
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
@@ -0,0 +1,8 @@
+use crate::text::LengthUnit;
+
+// 1文字でも読みたくない語はあるため、下限は1文字にする
+pub const MAX_MUTE_WORD_LENGTH: usize = 32;
+pub const MIN_MUTE_WORD_LENGTH: usize = 1;
+pub const MUTE_WORD_LENGTH_UNIT: LengthUnit = LengthUnit::Grapheme;
+// 結合文字を重ねた語もVARCHAR(255)の列に収まるよう、コードポイントの上限を設ける
+pub const MAX_MUTE_WORD_CHARS: usize = 255;
@@ -0,0 +1,21 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegisterMuteWordError {
+    ValidationError(ValidationError),
+    ServiceError(ServiceError),
+}
+impl From<ValidationError> for RegisterMuteWordError {
+    fn from(e: ValidationError) -> Self {
+        RegisterMuteWordError::ValidationError(e)
+    }
+}
+impl From<ServiceError> for RegisterMuteWordError {
+    fn from(e: ServiceError) -> Self {
+        RegisterMuteWordError::ServiceError(e)
+    }
+}
@@ -0,0 +1,73 @@
+use super::constants::{
+    MAX_MUTE_WORD_CHARS, MAX_MUTE_WORD_LENGTH, MIN_MUTE_WORD_LENGTH, MUTE_WORD_LENGTH_UNIT,
+};
+use super::err::ValidationError;
+use crate::text::LengthUnit;
+
+/// 登録を依頼されたミュートする語
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnvalidatedMuteWord {
+    pub mute_word: String,
+}
+
+/// ユーザごとにミュートする語
+///
+/// この語を含む投稿は、登録したユーザの投稿一覧で隠すか折りたたむ
+#[derive(Debug, Clone, PartialEq)]
+pub struct MuteWord(String);
+impl MuteWord {
+    /// ミュートする語の値オブジェクトを生成
+    ///
+    /// 空文字か32文字を超える場合、もしくはコードポイントが255を超える場合はエラーを返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::register_mute_word::model::MuteWord;
+    ///
+    /// let mute_word = MuteWord::new("spoiler").unwrap();
+    /// assert_eq!(mute_word.value(), "spoiler");
+    ///
+    /// // 長さは書記素単位で数える
+    /// assert!(MuteWord::new("草").is_ok());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::register_mute_word::model::MuteWord;
+    ///
+    /// // 空文字の場合
+    /// assert!(MuteWord::new("").is_err());
+    ///
+    /// // 32文字を超える場合
+    /// assert!(MuteWord::new(&"a".repeat(33)).is_err());
+    ///
+    /// // 結合文字を重ねてコードポイントが255を超える場合
+    /// assert!(MuteWord::new(&format!("abc{}", "\u{301}".repeat(300))).is_err());
+    /// ```
+    pub fn new(mute_word: &str) -> Result<Self, ValidationError> {
+        let length = MUTE_WORD_LENGTH_UNIT.count(mute_word);
+        if length < MIN_MUTE_WORD_LENGTH {
+            Err(ValidationError(format!(
+                "MuteWord must be at least {} characters long",
+                MIN_MUTE_WORD_LENGTH
+            )))
+        } else if length > MAX_MUTE_WORD_LENGTH {
+            Err(ValidationError(format!(
+                "MuteWord must be at most {} characters long",
+                MAX_MUTE_WORD_LENGTH
+            )))
+        } else if LengthUnit::Char.count(mute_word) > MAX_MUTE_WORD_CHARS {
+            Err(ValidationError(format!(
+                "MuteWord must be at most {} code points long",
+                MAX_MUTE_WORD_CHARS
+            )))
+        } else {
+            Ok(MuteWord(mute_word.to_string()))
+        }
+    }
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
@@ -1,2 +1,10 @@
+use crate::text::LengthUnit;
+
 pub const MAX_NG_WORD_LENGTH: usize = 10;
 pub const MIN_NG_WORD_LENGTH: usize = 3;
+pub const NG_WORD_LENGTH_UNIT: LengthUnit = LengthUnit::Grapheme;
+// 結合文字を重ねると書記素の数が少なくてもVARCHAR(255)のコードポイント数を超えるため、別に上限を設ける
+pub const MAX_NG_WORD_CHARS: usize = 255;
+// グロブや正規表現はリテラルより長くなりがちなので別に上限を設ける
+pub const MAX_NG_PATTERN_LENGTH: usize = 64;
+pub const NG_PATTERN_LENGTH_UNIT: LengthUnit = LengthUnit::Char;
@@ -4,10 +4,14 @@ pub struct ValidationError(pub String);
 #[derive(Debug, Clone, PartialEq)]
 pub struct ServiceError(pub String);
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionError(pub String);
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum RegisterNgWordError {
     ValidationError(ValidationError),
     ServiceError(ServiceError),
+    PermissionError(PermissionError),
 }
 impl From<ValidationError> for RegisterNgWordError {
     fn from(e: ValidationError) -> Self {
@@ -19,3 +23,8 @@ impl From<ServiceError> for RegisterNgWordError {
         RegisterNgWordError::ServiceError(e)
     }
 }
+impl From<PermissionError> for RegisterNgWordError {
+    fn from(e: PermissionError) -> Self {
+        RegisterNgWordError::PermissionError(e)
+    }
+}
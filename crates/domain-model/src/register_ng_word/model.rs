@@ -1,12 +1,33 @@
-use super::constants::{MAX_NG_WORD_LENGTH, MIN_NG_WORD_LENGTH};
+use super::constants::{
+    MAX_NG_PATTERN_LENGTH, MAX_NG_WORD_CHARS, MAX_NG_WORD_LENGTH, MIN_NG_WORD_LENGTH,
+    NG_PATTERN_LENGTH_UNIT, NG_WORD_LENGTH_UNIT,
+};
 use super::err::ValidationError;
+use crate::ng_word_policy::model::{PatternKind, Severity};
+use crate::text::LengthUnit;
 
+/// 登録を依頼されたNGワード
 #[derive(Debug, Clone, PartialEq)]
-pub struct NgWord(String);
+pub struct UnvalidatedNgWord {
+    pub ng_word: String,
+    /// literal, glob, regexのいずれか
+    pub kind: String,
+    /// mask, hold, rejectのいずれか
+    pub severity: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NgWord {
+    value: String,
+    kind: PatternKind,
+    severity: Severity,
+}
 impl NgWord {
     /// NGワードの値オブジェクトを生成
     ///
     /// NGワードが3文字未満もしくは10文字を超える場合はエラーを返す
+    /// 書記素の数が収まっても、コードポイントが255を超える場合はエラーを返す
+    /// 一致した時の対応は`Severity::Mask`になる
     ///
     /// # Examples
     ///
@@ -18,6 +39,10 @@ impl NgWord {
     ///
     /// let result = NgWord::new("name_b");
     /// assert!(result.is_ok());
+    ///
+    /// // 長さは書記素単位で数える
+    /// let result = NgWord::new("禁止語");
+    /// assert!(result.is_ok());
     /// ```
     ///
     /// # Failures
@@ -29,26 +54,188 @@ impl NgWord {
     /// let result = NgWord::new("sh");
     /// assert!(result.is_err());
     ///
-    /// // 16文字を超える場合
+    /// // 10文字を超える場合
     /// let result = NgWord::new("thisisaverylo");
     /// assert!(result.is_err());
+    ///
+    /// // 結合文字を重ねてコードポイントが255を超える場合
+    /// let result = NgWord::new(&format!("abc{}", "\u{301}".repeat(300)));
+    /// assert!(result.is_err());
     /// ```
     pub fn new(ng_word: &str) -> Result<Self, ValidationError> {
-        if ng_word.len() < MIN_NG_WORD_LENGTH {
+        let length = NG_WORD_LENGTH_UNIT.count(ng_word);
+        if length < MIN_NG_WORD_LENGTH {
             Err(ValidationError(format!(
                 "NgWord must be at least {} characters long",
                 MIN_NG_WORD_LENGTH
             )))
-        } else if ng_word.len() > MAX_NG_WORD_LENGTH {
+        } else if length > MAX_NG_WORD_LENGTH {
             Err(ValidationError(format!(
                 "NgWord must be at most {} characters long",
                 MAX_NG_WORD_LENGTH
             )))
+        } else if LengthUnit::Char.count(ng_word) > MAX_NG_WORD_CHARS {
+            Err(ValidationError(format!(
+                "NgWord must be at most {} code points long",
+                MAX_NG_WORD_CHARS
+            )))
         } else {
-            Ok(NgWord(ng_word.to_string()))
+            Ok(NgWord {
+                value: ng_word.to_string(),
+                kind: PatternKind::Literal,
+                severity: Severity::default(),
+            })
         }
     }
 
+    /// 書き方を指定してNGワードの値オブジェクトを生成
+    ///
+    /// 文字列そのものの場合は`new`と同じ。
+    /// グロブや正規表現の場合は3文字以上64文字以下で、照合器を構築できることを確かめる。
+    /// 空文字列に一致するパターンはどんな文字列にも一致してしまうためエラーを返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::ng_word_policy::model::PatternKind;
+    /// use domain_model::register_ng_word::model::NgWord;
+    ///
+    /// let ng_word = NgWord::with_kind("b*a*d", PatternKind::Glob).unwrap();
+    /// assert_eq!(ng_word.kind(), PatternKind::Glob);
+    ///
+    /// let ng_word = NgWord::with_kind(r"b[a4@-]+d", PatternKind::Regex).unwrap();
+    /// assert_eq!(ng_word.kind(), PatternKind::Regex);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::ng_word_policy::model::PatternKind;
+    /// use domain_model::register_ng_word::model::NgWord;
+    ///
+    /// // 正規表現の構文エラー
+    /// assert!(NgWord::with_kind("(bad", PatternKind::Regex).is_err());
+    /// // 空文字列に一致する
+    /// assert!(NgWord::with_kind("a*b*", PatternKind::Regex).is_err());
+    /// assert!(NgWord::with_kind("***", PatternKind::Glob).is_err());
+    /// // 64文字を超える
+    /// assert!(NgWord::with_kind(&"a".repeat(65), PatternKind::Glob).is_err());
+    /// ```
+    pub fn with_kind(ng_word: &str, kind: PatternKind) -> Result<Self, ValidationError> {
+        if kind == PatternKind::Literal {
+            return Self::new(ng_word);
+        }
+        let length = NG_PATTERN_LENGTH_UNIT.count(ng_word);
+        if length < MIN_NG_WORD_LENGTH {
+            return Err(ValidationError(format!(
+                "NgWord pattern must be at least {} characters long",
+                MIN_NG_WORD_LENGTH
+            )));
+        } else if length > MAX_NG_PATTERN_LENGTH {
+            return Err(ValidationError(format!(
+                "NgWord pattern must be at most {} characters long",
+                MAX_NG_PATTERN_LENGTH
+            )));
+        }
+        match kind.compile(ng_word) {
+            Ok(Some(matcher)) if matcher.matches_empty() => Err(ValidationError(
+                "NgWord pattern must not match an empty string".to_string(),
+            )),
+            Ok(_) => Ok(NgWord {
+                value: ng_word.to_string(),
+                kind,
+                severity: Severity::default(),
+            }),
+            Err(err) => Err(ValidationError(format!(
+                "Invalid NgWord pattern: {}",
+                err.0
+            ))),
+        }
+    }
+
+    /// 登録を依頼されたNGワードを検証して値オブジェクトを生成
+    ///
+    /// 書き方と対応の文字列を解釈し、`with_kind`の規則で検証する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::ng_word_policy::model::{PatternKind, Severity};
+    /// use domain_model::register_ng_word::model::{NgWord, UnvalidatedNgWord};
+    ///
+    /// let ng_word = NgWord::from_unvalidated(&UnvalidatedNgWord {
+    ///     ng_word: "b*d".to_string(),
+    ///     kind: "glob".to_string(),
+    ///     severity: "reject".to_string(),
+    /// })
+    /// .unwrap();
+    /// assert_eq!(ng_word.kind(), PatternKind::Glob);
+    /// assert_eq!(ng_word.severity(), Severity::Reject);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::register_ng_word::model::{NgWord, UnvalidatedNgWord};
+    ///
+    /// // 不明な書き方
+    /// let result = NgWord::from_unvalidated(&UnvalidatedNgWord {
+    ///     ng_word: "bad".to_string(),
+    ///     kind: "wildcard".to_string(),
+    ///     severity: "mask".to_string(),
+    /// });
+    /// assert!(result.is_err());
+    ///
+    /// // 不明な対応
+    /// let result = NgWord::from_unvalidated(&UnvalidatedNgWord {
+    ///     ng_word: "bad".to_string(),
+    ///     kind: "literal".to_string(),
+    ///     severity: "ban".to_string(),
+    /// });
+    /// assert!(result.is_err());
+    /// ```
+    pub fn from_unvalidated(ng_word: &UnvalidatedNgWord) -> Result<Self, ValidationError> {
+        let Some(kind) = PatternKind::parse(&ng_word.kind) else {
+            return Err(ValidationError(
+                "Kind must be one of literal, glob, regex".to_string(),
+            ));
+        };
+        let Some(severity) = Severity::parse(&ng_word.severity) else {
+            return Err(ValidationError(
+                "Severity must be one of mask, hold, reject".to_string(),
+            ));
+        };
+        Ok(Self::with_kind(&ng_word.ng_word, kind)?.with_severity(severity))
+    }
+
+    /// 一致した時の対応を指定する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::ng_word_policy::model::Severity;
+    /// use domain_model::register_ng_word::model::NgWord;
+    ///
+    /// let ng_word = NgWord::new("name").unwrap();
+    /// assert_eq!(ng_word.severity(), Severity::Mask);
+    ///
+    /// let ng_word = ng_word.with_severity(Severity::Reject);
+    /// assert_eq!(ng_word.severity(), Severity::Reject);
+    /// ```
+    pub fn with_severity(self, severity: Severity) -> Self {
+        Self { severity, ..self }
+    }
+
+    /// 一致した時の対応を取得
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// 書き方を取得
+    pub fn kind(&self) -> PatternKind {
+        self.kind
+    }
+
     /// NGワードの値オブジェクトの値を取得
     ///
     /// # Examples
@@ -63,6 +250,6 @@ impl NgWord {
     /// assert_eq!(ng_word.value(), "name_b");
     /// ```
     pub fn value(&self) -> &str {
-        &self.0
+        &self.value
     }
 }
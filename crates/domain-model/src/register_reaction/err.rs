@@ -0,0 +1,42 @@
+use crate::reaction;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError(pub String);
+impl From<reaction::err::ValidationError> for ValidationError {
+    fn from(e: reaction::err::ValidationError) -> Self {
+        ValidationError(e.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotFoundError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegisterReactionError {
+    ValidationError(ValidationError),
+    ServiceError(ServiceError),
+    NotFoundError(NotFoundError),
+}
+impl From<ValidationError> for RegisterReactionError {
+    fn from(e: ValidationError) -> Self {
+        RegisterReactionError::ValidationError(e)
+    }
+}
+impl From<ServiceError> for RegisterReactionError {
+    fn from(e: ServiceError) -> Self {
+        RegisterReactionError::ServiceError(e)
+    }
+}
+impl From<NotFoundError> for RegisterReactionError {
+    fn from(e: NotFoundError) -> Self {
+        RegisterReactionError::NotFoundError(e)
+    }
+}
+impl From<reaction::err::ValidationError> for RegisterReactionError {
+    fn from(e: reaction::err::ValidationError) -> Self {
+        RegisterReactionError::ValidationError(ValidationError(e.0))
+    }
+}
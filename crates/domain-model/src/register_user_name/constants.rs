@@ -1,2 +1,9 @@
+use crate::text::LengthUnit;
+
 pub const MAX_USER_NAME_LENGTH: usize = 16;
 pub const MIN_USER_NAME_LENGTH: usize = 3;
+pub const USER_NAME_LENGTH_UNIT: LengthUnit = LengthUnit::Grapheme;
+// DBのVARCHAR(255)はコードポイント単位で数えるため、結合文字を重ねた書記素でも溢れないよう別に上限を設ける
+pub const MAX_USER_NAME_CHARS: usize = 255;
+pub const MAX_PASSWORD_LENGTH: usize = 64;
+pub const MIN_PASSWORD_LENGTH: usize = 8;
@@ -1,3 +1,5 @@
+use crate::ng_word_policy;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ValidationError(pub String);
 
@@ -19,3 +21,8 @@ impl From<ServiceError> for RegisterUserNameError {
         RegisterUserNameError::ServiceError(e)
     }
 }
+impl From<ng_word_policy::err::ServiceError> for RegisterUserNameError {
+    fn from(e: ng_word_policy::err::ServiceError) -> Self {
+        RegisterUserNameError::ServiceError(ServiceError(e.0))
+    }
+}
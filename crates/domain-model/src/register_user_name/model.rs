@@ -1,5 +1,14 @@
-use super::constants::{MAX_USER_NAME_LENGTH, MIN_USER_NAME_LENGTH};
-use super::err::ValidationError;
+use super::constants::{
+    MAX_PASSWORD_LENGTH, MAX_USER_NAME_CHARS, MAX_USER_NAME_LENGTH, MIN_PASSWORD_LENGTH,
+    MIN_USER_NAME_LENGTH, USER_NAME_LENGTH_UNIT,
+};
+use super::err::{ServiceError, ValidationError};
+use crate::text::LengthUnit;
+use argon2::{
+    password_hash::{PasswordHasher, SaltString},
+    Argon2,
+};
+use rand::rngs::OsRng;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct UnvalidatedUserName(pub String);
@@ -10,6 +19,7 @@ impl UserName {
     /// ユーザ名の値オブジェクトを生成
     ///
     /// ユーザ名が3文字未満もしくは16文字を超える場合はエラーを返す
+    /// 書記素の数が収まっても、コードポイントが255を超える場合はエラーを返す
     ///
     /// # Examples
     ///
@@ -21,6 +31,10 @@ impl UserName {
     ///
     /// let result = UserName::new("name_b");
     /// assert!(result.is_ok());
+    ///
+    /// // 長さは書記素単位で数える
+    /// let result = UserName::new("日本語の名前");
+    /// assert!(result.is_ok());
     /// ```
     ///
     /// # Failures
@@ -35,18 +49,28 @@ impl UserName {
     /// // 16文字を超える場合
     /// let result = UserName::new("thisisaverylongname");
     /// assert!(result.is_err());
+    ///
+    /// // 結合文字を重ねてコードポイントが255を超える場合
+    /// let result = UserName::new(&format!("abc{}", "\u{301}".repeat(300)));
+    /// assert!(result.is_err());
     /// ```
     pub fn new(name: &str) -> Result<Self, ValidationError> {
-        if name.len() < MIN_USER_NAME_LENGTH {
+        let length = USER_NAME_LENGTH_UNIT.count(name);
+        if length < MIN_USER_NAME_LENGTH {
             Err(ValidationError(format!(
                 "Username must be at least {} characters long",
                 MIN_USER_NAME_LENGTH
             )))
-        } else if name.len() > 16 {
+        } else if length > MAX_USER_NAME_LENGTH {
             Err(ValidationError(format!(
                 "Username must be at most {} characters long",
                 MAX_USER_NAME_LENGTH
             )))
+        } else if LengthUnit::Char.count(name) > MAX_USER_NAME_CHARS {
+            Err(ValidationError(format!(
+                "Username must be at most {} code points long",
+                MAX_USER_NAME_CHARS
+            )))
         } else {
             Ok(UserName(name.to_string()))
         }
@@ -70,6 +94,110 @@ impl UserName {
     }
 }
 
+#[derive(Clone, PartialEq)]
+pub struct UnvalidatedPassword(pub String);
+// 平文のパスワードをログに出さない
+impl std::fmt::Debug for UnvalidatedPassword {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("UnvalidatedPassword(***)")
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Password(String);
+impl Password {
+    /// パスワードの値オブジェクトを生成
+    ///
+    /// パスワードが8文字未満もしくは64文字を超える場合、
+    /// 英字と数字をそれぞれ1文字以上含まない場合はエラーを返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::register_user_name::model::Password;
+    ///
+    /// let result = Password::new("password1");
+    /// assert!(result.is_ok());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::register_user_name::model::Password;
+    ///
+    /// // 8文字未満の場合
+    /// let result = Password::new("pass1");
+    /// assert!(result.is_err());
+    ///
+    /// // 数字を含まない場合
+    /// let result = Password::new("password");
+    /// assert!(result.is_err());
+    ///
+    /// // 英字を含まない場合
+    /// let result = Password::new("12345678");
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(password: &str) -> Result<Self, ValidationError> {
+        let length = password.chars().count();
+        if length < MIN_PASSWORD_LENGTH {
+            Err(ValidationError(format!(
+                "Password must be at least {} characters long",
+                MIN_PASSWORD_LENGTH
+            )))
+        } else if length > MAX_PASSWORD_LENGTH {
+            Err(ValidationError(format!(
+                "Password must be at most {} characters long",
+                MAX_PASSWORD_LENGTH
+            )))
+        } else if !password.chars().any(|c| c.is_ascii_alphabetic())
+            || !password.chars().any(|c| c.is_ascii_digit())
+        {
+            Err(ValidationError(
+                "Password must contain both letters and digits".to_string(),
+            ))
+        } else {
+            Ok(Password(password.to_string()))
+        }
+    }
+
+    /// パスワードをargon2でハッシュ化する
+    ///
+    /// ソルトは毎回ランダムに生成されるため、同じパスワードでも結果は異なる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::register_user_name::model::Password;
+    ///
+    /// let password = Password::new("password1").unwrap();
+    /// let hashed = password.hash().unwrap();
+    /// assert!(hashed.value().starts_with("$argon2id$"));
+    /// assert_ne!(hashed, password.hash().unwrap());
+    /// ```
+    pub fn hash(&self) -> Result<HashedPassword, ServiceError> {
+        let salt = SaltString::generate(&mut OsRng);
+        Argon2::default()
+            .hash_password(self.0.as_bytes(), &salt)
+            .map(|hash| HashedPassword(hash.to_string()))
+            .map_err(|_| ServiceError("Failed to hash password".to_string()))
+    }
+}
+// 平文のパスワードをログに出さない
+impl std::fmt::Debug for Password {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Password(***)")
+    }
+}
+
+/// PHC文字列形式のパスワードハッシュ
+#[derive(Debug, Clone, PartialEq)]
+pub struct HashedPassword(String);
+impl HashedPassword {
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,6 +228,40 @@ mod tests {
             );
         }
         #[test]
+        fn test_username_japanese() {
+            // バイト数では16を超えるが6文字
+            let username = UserName::new("日本語の名前");
+            assert!(username.is_ok());
+        }
+        #[test]
+        fn test_username_japanese_too_long() {
+            let username = UserName::new("じゅげむじゅげむごこうのすりきれかいじゃり");
+            assert_eq!(
+                username.err().unwrap(),
+                ValidationError("Username must be at most 16 characters long".to_string())
+            );
+        }
+        #[test]
+        fn test_username_emoji() {
+            // ZWJで結合された絵文字は1文字として数える
+            let username = UserName::new("👨‍👩‍👧👨‍👩‍👧👨‍👩‍👧");
+            assert!(username.is_ok());
+            let username = UserName::new("👨‍👩‍👧👨‍👩‍👧");
+            assert_eq!(
+                username.err().unwrap(),
+                ValidationError("Username must be at least 3 characters long".to_string())
+            );
+        }
+        #[test]
+        fn test_username_combining_characters() {
+            // 結合文字は基底文字とあわせて1文字として数える
+            let username = UserName::new("か\u{3099}き\u{3099}");
+            assert_eq!(
+                username.err().unwrap(),
+                ValidationError("Username must be at least 3 characters long".to_string())
+            );
+        }
+        #[test]
         fn test_username_edge_case_min_length() {
             let username = UserName::new("abc");
             assert!(username.is_ok());
@@ -112,4 +274,49 @@ mod tests {
             assert_eq!(username.unwrap().value(), "sixteencharacter");
         }
     }
+    mod password {
+        use super::*;
+        #[test]
+        fn test_valid_password() {
+            let password = Password::new("password1");
+            assert!(password.is_ok());
+        }
+        #[test]
+        fn test_password_too_short() {
+            let password = Password::new("pass1");
+            assert_eq!(
+                password.err().unwrap(),
+                ValidationError("Password must be at least 8 characters long".to_string())
+            );
+        }
+        #[test]
+        fn test_password_too_long() {
+            let password = Password::new(&format!("{}1", "a".repeat(64)));
+            assert_eq!(
+                password.err().unwrap(),
+                ValidationError("Password must be at most 64 characters long".to_string())
+            );
+        }
+        #[test]
+        fn test_password_without_digit() {
+            let password = Password::new("password");
+            assert_eq!(
+                password.err().unwrap(),
+                ValidationError("Password must contain both letters and digits".to_string())
+            );
+        }
+        #[test]
+        fn test_password_without_letter() {
+            let password = Password::new("12345678");
+            assert_eq!(
+                password.err().unwrap(),
+                ValidationError("Password must contain both letters and digits".to_string())
+            );
+        }
+        #[test]
+        fn test_password_debug_is_masked() {
+            let password = Password::new("password1").unwrap();
+            assert_eq!(format!("{:?}", password), "Password(***)");
+        }
+    }
 }
@@ -0,0 +1,30 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotFoundError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RestorePostError {
+    ServiceError(ServiceError),
+    PermissionError(PermissionError),
+    NotFoundError(NotFoundError),
+}
+impl From<ServiceError> for RestorePostError {
+    fn from(e: ServiceError) -> Self {
+        RestorePostError::ServiceError(e)
+    }
+}
+impl From<PermissionError> for RestorePostError {
+    fn from(e: PermissionError) -> Self {
+        RestorePostError::PermissionError(e)
+    }
+}
+impl From<NotFoundError> for RestorePostError {
+    fn from(e: NotFoundError) -> Self {
+        RestorePostError::NotFoundError(e)
+    }
+}
@@ -0,0 +1,8 @@
+// 投稿検索で1回に返す件数
+pub const DEFAULT_SEARCH_LIMIT: usize = 20;
+pub const MAX_SEARCH_LIMIT: usize = 100;
+// 検索語の長さ(コードポイント単位)と、空白で区切った語の数の上限
+pub const MAX_SEARCH_QUERY_LENGTH: usize = 100;
+pub const MAX_SEARCH_TERMS: usize = 5;
+// 日時の絞り込みの書式。投稿取得の`posted_datetime`と同じ
+pub const SEARCH_DATETIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
@@ -0,0 +1,33 @@
+use crate::ng_word_policy;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+impl From<ng_word_policy::err::ServiceError> for ServiceError {
+    fn from(e: ng_word_policy::err::ServiceError) -> Self {
+        ServiceError(e.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchPostError {
+    ValidationError(ValidationError),
+    ServiceError(ServiceError),
+}
+impl From<ValidationError> for SearchPostError {
+    fn from(e: ValidationError) -> Self {
+        SearchPostError::ValidationError(e)
+    }
+}
+impl From<ServiceError> for SearchPostError {
+    fn from(e: ServiceError) -> Self {
+        SearchPostError::ServiceError(e)
+    }
+}
+impl From<ng_word_policy::err::ServiceError> for SearchPostError {
+    fn from(e: ng_word_policy::err::ServiceError) -> Self {
+        SearchPostError::ServiceError(ServiceError(e.0))
+    }
+}
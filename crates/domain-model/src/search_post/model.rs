@@ -0,0 +1,236 @@
+use super::constants::{
+    DEFAULT_SEARCH_LIMIT, MAX_SEARCH_LIMIT, MAX_SEARCH_QUERY_LENGTH, MAX_SEARCH_TERMS,
+    SEARCH_DATETIME_FORMAT,
+};
+use super::err::ValidationError;
+use crate::get_all_post::model::Post;
+use chrono::NaiveDateTime;
+use std::ops::Range;
+
+/// 検索語
+///
+/// 空白(全角の空白を含む)で区切った語を全て含む投稿を探す
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchQuery {
+    terms: Vec<String>,
+}
+impl SearchQuery {
+    /// 検索語の値オブジェクトを生成
+    ///
+    /// 前後の空白を除いて100文字まで、区切った語は5つまで
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::search_post::model::SearchQuery;
+    ///
+    /// let query = SearchQuery::new(" 掲示板　rust ").unwrap();
+    /// assert_eq!(query.terms(), ["掲示板", "rust"]);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::search_post::model::SearchQuery;
+    ///
+    /// // 空白だけの場合
+    /// assert!(SearchQuery::new("　").is_err());
+    ///
+    /// // 100文字を超える場合
+    /// assert!(SearchQuery::new(&"あ".repeat(101)).is_err());
+    ///
+    /// // 語が5つを超える場合
+    /// assert!(SearchQuery::new("a b c d e f").is_err());
+    /// ```
+    pub fn new(query: &str) -> Result<Self, ValidationError> {
+        let query = query.trim();
+        if query.chars().count() > MAX_SEARCH_QUERY_LENGTH {
+            return Err(ValidationError(format!(
+                "Query must be at most {} characters",
+                MAX_SEARCH_QUERY_LENGTH
+            )));
+        }
+        let terms: Vec<String> = query.split_whitespace().map(str::to_string).collect();
+        if terms.is_empty() {
+            return Err(ValidationError("Query is required".to_string()));
+        }
+        if terms.len() > MAX_SEARCH_TERMS {
+            return Err(ValidationError(format!(
+                "Query must have at most {} words",
+                MAX_SEARCH_TERMS
+            )));
+        }
+        Ok(Self { terms })
+    }
+    pub fn terms(&self) -> &[String] {
+        &self.terms
+    }
+}
+
+/// 検索結果の絞り込み
+///
+/// 投稿日時の範囲は両端を含む
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchFilter {
+    /// 投稿したユーザの名前
+    pub author: Option<String>,
+    pub since: Option<NaiveDateTime>,
+    pub until: Option<NaiveDateTime>,
+}
+
+/// 検証前の投稿検索の条件
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UnvalidatedSearchRequest {
+    pub query: Option<String>,
+    pub author: Option<String>,
+    pub since: Option<String>,
+    pub until: Option<String>,
+    pub limit: Option<usize>,
+}
+
+/// 投稿検索の条件
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchRequest {
+    query: SearchQuery,
+    filter: SearchFilter,
+    limit: usize,
+}
+impl SearchRequest {
+    /// 検索条件の値オブジェクトを生成
+    ///
+    /// 件数を省略した場合は20件にする。日時は`2021-01-01T00:00:00Z`の形で指定する。
+    /// 空の投稿者名は指定しなかったものとして扱う
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::search_post::model::{SearchRequest, UnvalidatedSearchRequest};
+    ///
+    /// let request = SearchRequest::new(UnvalidatedSearchRequest {
+    ///     query: Some("掲示板".to_string()),
+    ///     author: Some("".to_string()),
+    ///     since: Some("2021-01-01T00:00:00Z".to_string()),
+    ///     ..Default::default()
+    /// })
+    /// .unwrap();
+    /// assert_eq!(request.query().terms(), ["掲示板"]);
+    /// assert_eq!(request.filter().author, None);
+    /// assert!(request.filter().since.is_some());
+    /// assert_eq!(request.limit(), 20);
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::search_post::model::{SearchRequest, UnvalidatedSearchRequest};
+    ///
+    /// // 検索語が無い場合
+    /// assert!(SearchRequest::new(UnvalidatedSearchRequest::default()).is_err());
+    ///
+    /// // 件数が1未満もしくは100を超える場合
+    /// let request = SearchRequest::new(UnvalidatedSearchRequest {
+    ///     query: Some("掲示板".to_string()),
+    ///     limit: Some(101),
+    ///     ..Default::default()
+    /// });
+    /// assert!(request.is_err());
+    ///
+    /// // 日時の形が不正な場合
+    /// let request = SearchRequest::new(UnvalidatedSearchRequest {
+    ///     query: Some("掲示板".to_string()),
+    ///     until: Some("2021-01-01".to_string()),
+    ///     ..Default::default()
+    /// });
+    /// assert!(request.is_err());
+    ///
+    /// // 開始が終了より後の場合
+    /// let request = SearchRequest::new(UnvalidatedSearchRequest {
+    ///     query: Some("掲示板".to_string()),
+    ///     since: Some("2021-01-02T00:00:00Z".to_string()),
+    ///     until: Some("2021-01-01T00:00:00Z".to_string()),
+    ///     ..Default::default()
+    /// });
+    /// assert!(request.is_err());
+    /// ```
+    pub fn new(request: UnvalidatedSearchRequest) -> Result<Self, ValidationError> {
+        let query = SearchQuery::new(request.query.as_deref().unwrap_or_default())?;
+        let limit = request.limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+        if !(1..=MAX_SEARCH_LIMIT).contains(&limit) {
+            return Err(ValidationError(format!(
+                "Limit must be between 1 and {}",
+                MAX_SEARCH_LIMIT
+            )));
+        }
+        let author = request
+            .author
+            .map(|author| author.trim().to_string())
+            .filter(|author| !author.is_empty());
+        let since = parse_datetime("Since", request.since.as_deref())?;
+        let until = parse_datetime("Until", request.until.as_deref())?;
+        if let (Some(since), Some(until)) = (since, until) {
+            if since > until {
+                return Err(ValidationError("Since must not be after until".to_string()));
+            }
+        }
+        Ok(Self {
+            query,
+            filter: SearchFilter {
+                author,
+                since,
+                until,
+            },
+            limit,
+        })
+    }
+    pub fn query(&self) -> &SearchQuery {
+        &self.query
+    }
+    pub fn filter(&self) -> &SearchFilter {
+        &self.filter
+    }
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+fn parse_datetime(
+    name: &str,
+    value: Option<&str>,
+) -> Result<Option<NaiveDateTime>, ValidationError> {
+    value
+        .map(|value| {
+            NaiveDateTime::parse_from_str(value, SEARCH_DATETIME_FORMAT).map_err(|_| {
+                ValidationError(format!("{} must be in the form 2021-01-01T00:00:00Z", name))
+            })
+        })
+        .transpose()
+}
+
+/// 検索語の語を全て含む投稿のIDと、一致した箇所の数
+///
+/// 一致した箇所の数は塗りつぶす前の内容で数えたもの
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RankedPost {
+    pub id: i32,
+    pub score: usize,
+}
+
+/// 検索語で見つかった、返信先の投稿のIDを付けた投稿
+///
+/// 内容は書かれたままで、塗りつぶす前のもの
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchCandidate {
+    pub post: Post,
+    /// 返信の場合、返信先の投稿のID
+    pub parent_post_id: Option<i32>,
+}
+
+/// 検索結果の1件
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    pub post: Post,
+    /// 返信の場合、返信先の投稿のID
+    pub parent_post_id: Option<i32>,
+    /// 塗りつぶした内容の中で検索語に一致した箇所(コードポイント単位の範囲)
+    pub highlights: Vec<Range<usize>>,
+}
@@ -0,0 +1,677 @@
+use aho_corasick::{AhoCorasick, MatchKind};
+use regex::{Regex, RegexBuilder};
+use std::ops::Range;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// 文字列の長さを数える単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthUnit {
+    /// 書記素クラスタ(見た目上の1文字)
+    Grapheme,
+    /// Unicodeのコードポイント
+    Char,
+}
+impl LengthUnit {
+    /// 単位に従って文字列の長さを数える
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::text::LengthUnit;
+    ///
+    /// assert_eq!(LengthUnit::Grapheme.count("名無しさん"), 5);
+    /// assert_eq!(LengthUnit::Char.count("名無しさん"), 5);
+    ///
+    /// // 結合文字は書記素では1文字、コードポイントでは2文字
+    /// assert_eq!(LengthUnit::Grapheme.count("e\u{301}"), 1);
+    /// assert_eq!(LengthUnit::Char.count("e\u{301}"), 2);
+    /// ```
+    pub fn count(&self, value: &str) -> usize {
+        match self {
+            LengthUnit::Grapheme => value.graphemes(true).count(),
+            LengthUnit::Char => value.chars().count(),
+        }
+    }
+}
+
+/// 書記素クラスタ1つにつき`mask_char`を1文字出力して塗りつぶす
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_model::text::mask;
+///
+/// assert_eq!(mask("馬鹿者", '*'), "***");
+/// assert_eq!(mask("👨‍👩‍👧", '*'), "*");
+/// ```
+pub fn mask(value: &str, mask_char: char) -> String {
+    value.graphemes(true).map(|_| mask_char).collect()
+}
+
+/// 指定した範囲(元の文字列上のバイト範囲)にかかる書記素を`mask_char`で塗りつぶす
+///
+/// 書記素1つにつき`mask_char`を1文字出力する
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_model::text::mask_ranges;
+///
+/// assert_eq!(mask_ranges("この馬鹿者め", &[6..15], '*'), "この***め");
+/// assert_eq!(mask_ranges("この馬鹿者め", &[], '*'), "この馬鹿者め");
+/// ```
+pub fn mask_ranges(value: &str, ranges: &[Range<usize>], mask_char: char) -> String {
+    value
+        .grapheme_indices(true)
+        .fold(String::new(), |mut masked, (start, grapheme)| {
+            let end = start + grapheme.len();
+            if ranges
+                .iter()
+                .any(|range| range.start < end && start < range.end)
+            {
+                masked.push(mask_char);
+            } else {
+                masked.push_str(grapheme);
+            }
+            masked
+        })
+}
+
+/// 元の文字列上のバイト範囲を、コードポイント単位の範囲に変換する
+///
+/// # Examples
+///
+/// ```rust
+/// use domain_model::text::char_range;
+///
+/// assert_eq!(char_range("この馬鹿者め", 6..15), 2..5);
+/// assert_eq!(char_range("bad", 0..3), 0..3);
+/// ```
+pub fn char_range(value: &str, range: Range<usize>) -> Range<usize> {
+    let start = value[..range.start].chars().count();
+    start..start + value[range].chars().count()
+}
+
+/// NGワード照合用に正規化した文字列
+///
+/// NFKC(全角・半角の統一を含む)、カタカナのひらがなへの統一、小文字化の順に正規化する。
+/// 正規化後の各文字が元の文字列のどの範囲に由来するかを保持しているため、
+/// 正規化後の文字列で見つけた位置を元の文字列上の位置に戻せる。
+#[derive(Debug, Clone, PartialEq)]
+pub struct NormalizedText {
+    text: String,
+    /// 正規化後の各文字の`text`上の開始バイト位置
+    starts: Vec<usize>,
+    /// 正規化後の各文字の由来となった元の文字列上のバイト範囲
+    origins: Vec<Range<usize>>,
+}
+impl NormalizedText {
+    /// 文字列を正規化する
+    ///
+    /// 書記素ごとに正規化するため、正規化後の文字は必ず元の書記素1つに対応する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::text::NormalizedText;
+    ///
+    /// assert_eq!(NormalizedText::new("ＢＡＤ").as_str(), "bad");
+    /// assert_eq!(NormalizedText::new("Bad").as_str(), "bad");
+    /// assert_eq!(NormalizedText::new("バッド").as_str(), "ばっど");
+    /// assert_eq!(NormalizedText::new("ﾊﾞｯﾄﾞ").as_str(), "ばっど");
+    /// ```
+    pub fn new(value: &str) -> Self {
+        let mut text = String::new();
+        let mut starts = Vec::new();
+        let mut origins = Vec::new();
+        for (start, grapheme) in value.grapheme_indices(true) {
+            let origin = start..start + grapheme.len();
+            for c in grapheme.nfkc().map(fold_kana).flat_map(char::to_lowercase) {
+                starts.push(text.len());
+                origins.push(origin.clone());
+                text.push(c);
+            }
+        }
+        Self {
+            text,
+            starts,
+            origins,
+        }
+    }
+
+    /// 正規化後の文字列を取得
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// `pattern`を正規化して検索し、一致した箇所の元の文字列上のバイト範囲を全て返す
+    ///
+    /// 一致箇所が重なる場合もそれぞれ返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::text::NormalizedText;
+    ///
+    /// let text = NormalizedText::new("それはＢＡＤだ");
+    /// assert_eq!(text.find_all("bad"), vec![9..18]);
+    ///
+    /// let text = NormalizedText::new("ばっどバッド");
+    /// assert_eq!(text.find_all("バッド"), vec![0..9, 9..18]);
+    /// ```
+    pub fn find_all(&self, pattern: &str) -> Vec<Range<usize>> {
+        let pattern = NormalizedText::new(pattern);
+        let pattern = pattern.as_str();
+        if pattern.is_empty() {
+            return vec![];
+        }
+
+        let mut ranges = Vec::new();
+        let mut from = 0;
+        while let Some(found) = self.text[from..].find(pattern) {
+            let start = from + found;
+            let end = start + pattern.len();
+            ranges.push(self.origin_range(start, end));
+            // 重なった一致も拾うため、一致箇所の次の文字から探し直す
+            from = start + self.text[start..].chars().next().map_or(1, char::len_utf8);
+        }
+        ranges
+    }
+
+    /// `pattern`を正規化して含まれているかを判定する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::text::NormalizedText;
+    ///
+    /// assert!(NormalizedText::new("ＢａｄＧｕｙ").contains("bad"));
+    /// assert!(!NormalizedText::new("good").contains("bad"));
+    /// ```
+    pub fn contains(&self, pattern: &str) -> bool {
+        !self.find_all(pattern).is_empty()
+    }
+
+    /// 正規化後の文字列上のバイト範囲を元の文字列上のバイト範囲に変換する
+    fn origin_range(&self, start: usize, end: usize) -> Range<usize> {
+        let first = self.starts.partition_point(|&s| s < start);
+        let last = self.starts.partition_point(|&s| s < end) - 1;
+        self.origins[first].start..self.origins[last].end
+    }
+}
+
+/// 照合器の構築に失敗した場合のエラー
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatcherError(pub String);
+
+/// 複数のNGワードを一度に照合する照合器
+///
+/// NGワードを正規化したうえでAho-Corasickオートマトンにまとめるため、
+/// 照合にかかる時間はNGワードの数によらず本文の長さに比例する。
+/// 構築は重いので、一度作ったものを使い回すこと。
+#[derive(Debug, Clone)]
+pub struct NgWordMatcher {
+    /// NGワードが1つも無い場合は`None`
+    automaton: Option<AhoCorasick>,
+    /// オートマトンのパターン番号に対応する、構築時に渡したNGワードの番号
+    ng_word_indexes: Vec<usize>,
+}
+impl NgWordMatcher {
+    /// NGワードの一覧から照合器を構築する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::text::{NgWordMatcher, NormalizedText};
+    ///
+    /// let matcher = NgWordMatcher::new(["bad", "バッド"]).unwrap();
+    /// assert!(matcher.is_match(&NormalizedText::new("ＢＡＤ")));
+    /// assert!(!matcher.is_match(&NormalizedText::new("good")));
+    /// ```
+    pub fn new<I, S>(ng_words: I) -> Result<Self, MatcherError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let (ng_word_indexes, patterns): (Vec<usize>, Vec<String>) = ng_words
+            .into_iter()
+            .map(|ng_word| NormalizedText::new(ng_word.as_ref()).text)
+            .enumerate()
+            .filter(|(_, pattern)| !pattern.is_empty())
+            .unzip();
+        if patterns.is_empty() {
+            return Ok(Self::empty());
+        }
+
+        // 重なった一致も全て拾うためStandardで構築する
+        let automaton = AhoCorasick::builder()
+            .match_kind(MatchKind::Standard)
+            .build(&patterns)
+            .map_err(|err| MatcherError(err.to_string()))?;
+        Ok(Self {
+            automaton: Some(automaton),
+            ng_word_indexes,
+        })
+    }
+
+    /// NGワードが1つも無い照合器を取得する
+    pub fn empty() -> Self {
+        Self {
+            automaton: None,
+            ng_word_indexes: vec![],
+        }
+    }
+
+    /// NGワードに一致した箇所の元の文字列上のバイト範囲を全て返す
+    ///
+    /// 一致箇所が重なる場合もそれぞれ返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::text::{NgWordMatcher, NormalizedText};
+    ///
+    /// let matcher = NgWordMatcher::new(["bad", "バッド"]).unwrap();
+    /// let text = NormalizedText::new("ＢＡＤとﾊﾞｯﾄﾞ");
+    /// assert_eq!(matcher.find_all(&text), vec![0..9, 12..27]);
+    /// ```
+    pub fn find_all(&self, text: &NormalizedText) -> Vec<Range<usize>> {
+        self.find_matches(text)
+            .into_iter()
+            .map(|(_, range)| range)
+            .collect()
+    }
+
+    /// 一致したNGワードの番号(構築時に渡した順番)と、元の文字列上のバイト範囲を全て返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::text::{NgWordMatcher, NormalizedText};
+    ///
+    /// let matcher = NgWordMatcher::new(["bad", "バッド"]).unwrap();
+    /// let text = NormalizedText::new("ﾊﾞｯﾄﾞとbad");
+    /// assert_eq!(matcher.find_matches(&text), vec![(1, 0..15), (0, 18..21)]);
+    /// ```
+    pub fn find_matches(&self, text: &NormalizedText) -> Vec<(usize, Range<usize>)> {
+        let Some(automaton) = &self.automaton else {
+            return vec![];
+        };
+        automaton
+            .find_overlapping_iter(text.as_str())
+            .map(|found| {
+                (
+                    self.ng_word_indexes[found.pattern().as_usize()],
+                    text.origin_range(found.start(), found.end()),
+                )
+            })
+            .collect()
+    }
+
+    /// NGワードを含むかを判定する
+    pub fn is_match(&self, text: &NormalizedText) -> bool {
+        self.automaton
+            .as_ref()
+            .is_some_and(|automaton| automaton.is_match(text.as_str()))
+    }
+}
+
+/// 正規表現1つあたりのコンパイル後の大きさの上限(バイト)
+const REGEX_SIZE_LIMIT: usize = 1 << 20;
+/// 正規表現の入れ子の深さの上限
+const REGEX_NEST_LIMIT: u32 = 32;
+
+/// グロブや正規表現で書かれたNGワード1つ分の照合器
+///
+/// 正規化後の文字列に対して照合する。`regex`クレートは後方参照や先読みを持たず、
+/// 照合にかかる時間は本文の長さに比例するため、どんなパターンでも破滅的なバックトラックは起きない。
+/// さらにコンパイル後の大きさと入れ子の深さに上限を設け、巨大な繰り返しなどは構築時にエラーにする。
+#[derive(Debug, Clone)]
+pub struct NgPatternMatcher {
+    regex: Regex,
+}
+impl NgPatternMatcher {
+    /// グロブから照合器を構築する
+    ///
+    /// `*`は0文字以上の任意の文字(最短一致)、`?`は任意の1文字に一致する。
+    /// それ以外の文字はNGワードと同じく正規化してから照合する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::text::{NgPatternMatcher, NormalizedText};
+    ///
+    /// let matcher = NgPatternMatcher::glob("b*a*d").unwrap();
+    /// assert!(matcher.is_match(&NormalizedText::new("b a d")));
+    /// assert!(matcher.is_match(&NormalizedText::new("Ｂ-Ａ-Ｄ")));
+    /// assert!(!matcher.is_match(&NormalizedText::new("bed")));
+    ///
+    /// let matcher = NgPatternMatcher::glob("b?d").unwrap();
+    /// assert!(matcher.is_match(&NormalizedText::new("b4d")));
+    /// ```
+    pub fn glob(pattern: &str) -> Result<Self, MatcherError> {
+        let mut regex = String::new();
+        let mut literal = String::new();
+        for c in pattern.chars() {
+            if c != '*' && c != '?' {
+                literal.push(c);
+                continue;
+            }
+            regex.push_str(&regex::escape(NormalizedText::new(&literal).as_str()));
+            literal.clear();
+            regex.push_str(if c == '*' { ".*?" } else { "." });
+        }
+        regex.push_str(&regex::escape(NormalizedText::new(&literal).as_str()));
+        Self::build(&regex)
+    }
+
+    /// 正規表現から照合器を構築する
+    ///
+    /// 英字の大文字・小文字は区別しない。パターン中の全角・半角とカタカナは本文と同じく統一する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::text::{NgPatternMatcher, NormalizedText};
+    ///
+    /// let matcher = NgPatternMatcher::regex(r"b[a4@]+d").unwrap();
+    /// assert!(matcher.is_match(&NormalizedText::new("B4D")));
+    /// assert!(matcher.is_match(&NormalizedText::new("baaad")));
+    /// assert!(!matcher.is_match(&NormalizedText::new("bed")));
+    ///
+    /// let matcher = NgPatternMatcher::regex("バッ+ド").unwrap();
+    /// assert!(matcher.is_match(&NormalizedText::new("ばっっど")));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use domain_model::text::NgPatternMatcher;
+    ///
+    /// // 構文エラー
+    /// assert!(NgPatternMatcher::regex("(bad").is_err());
+    /// // 後方参照は使えない
+    /// assert!(NgPatternMatcher::regex(r"(a)\1").is_err());
+    /// // コンパイル後の大きさが上限を超える
+    /// assert!(NgPatternMatcher::regex("a{1000}{1000}").is_err());
+    /// ```
+    pub fn regex(pattern: &str) -> Result<Self, MatcherError> {
+        let pattern: String = pattern.nfkc().map(fold_kana).collect();
+        Self::build(&pattern)
+    }
+
+    fn build(pattern: &str) -> Result<Self, MatcherError> {
+        let regex = RegexBuilder::new(pattern)
+            .case_insensitive(true)
+            .size_limit(REGEX_SIZE_LIMIT)
+            .dfa_size_limit(REGEX_SIZE_LIMIT)
+            .nest_limit(REGEX_NEST_LIMIT)
+            .build()
+            .map_err(|err| MatcherError(err.to_string()))?;
+        Ok(Self { regex })
+    }
+
+    /// 空文字列に一致するかを判定する
+    ///
+    /// 空文字列に一致するパターンはどんな文字列にも一致してしまうため、登録時に弾くのに使う
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::text::NgPatternMatcher;
+    ///
+    /// assert!(NgPatternMatcher::regex("a*").unwrap().matches_empty());
+    /// assert!(NgPatternMatcher::glob("*").unwrap().matches_empty());
+    /// assert!(!NgPatternMatcher::regex("a+").unwrap().matches_empty());
+    /// ```
+    pub fn matches_empty(&self) -> bool {
+        self.regex.is_match("")
+    }
+
+    /// 一致した箇所の元の文字列上のバイト範囲を全て返す
+    ///
+    /// 空の一致は無視する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::text::{NgPatternMatcher, NormalizedText};
+    ///
+    /// let matcher = NgPatternMatcher::glob("b*d").unwrap();
+    /// let text = NormalizedText::new("ＢＡＤとbed");
+    /// assert_eq!(matcher.find_all(&text), vec![0..9, 12..15]);
+    /// ```
+    pub fn find_all(&self, text: &NormalizedText) -> Vec<Range<usize>> {
+        self.regex
+            .find_iter(text.as_str())
+            .filter(|found| !found.is_empty())
+            .map(|found| text.origin_range(found.start(), found.end()))
+            .collect()
+    }
+
+    /// 一致する箇所があるかを判定する
+    pub fn is_match(&self, text: &NormalizedText) -> bool {
+        !self.find_all(text).is_empty()
+    }
+}
+
+/// カタカナをひらがなに統一する
+///
+/// 対応するひらがなが無い文字(ヷなど)はそのまま返す
+fn fold_kana(c: char) -> char {
+    match c {
+        'ァ'..='ヶ' | 'ヽ' | 'ヾ' => char::from_u32(c as u32 - 0x60).unwrap_or(c),
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod ng_pattern_matcher {
+        use super::*;
+        #[test]
+        fn test_glob_wildcards_are_not_regex() {
+            // グロブの*と?以外の記号は文字そのものとして扱う
+            let matcher = NgPatternMatcher::glob("a.b").unwrap();
+            assert!(matcher.is_match(&NormalizedText::new("a.b")));
+            assert!(!matcher.is_match(&NormalizedText::new("axb")));
+        }
+        #[test]
+        fn test_glob_is_lazy() {
+            let matcher = NgPatternMatcher::glob("b*d").unwrap();
+            let text = NormalizedText::new("bad bed");
+            assert_eq!(matcher.find_all(&text), vec![0..3, 4..7]);
+        }
+        #[test]
+        fn test_regex_masks_original_graphemes() {
+            let matcher = NgPatternMatcher::regex("ば+っど").unwrap();
+            let text = NormalizedText::new("ﾊﾞﾊﾞｯﾄﾞ!");
+            assert_eq!(mask_ranges("ﾊﾞﾊﾞｯﾄﾞ!", &matcher.find_all(&text), '*'), "****!");
+        }
+        #[test]
+        fn test_regex_skips_empty_match() {
+            let matcher = NgPatternMatcher::regex(r"\b").unwrap();
+            assert!(!matcher.is_match(&NormalizedText::new("bad")));
+        }
+        #[test]
+        fn test_regex_is_linear() {
+            // バックトラックする実装なら終わらないパターン
+            let matcher = NgPatternMatcher::regex("(a+)+$").unwrap();
+            let text = NormalizedText::new(&format!("{}!", "a".repeat(10_000)));
+            assert!(!matcher.is_match(&text));
+        }
+    }
+
+    mod length_unit {
+        use super::*;
+        #[test]
+        fn test_count_ascii() {
+            assert_eq!(LengthUnit::Grapheme.count("name"), 4);
+            assert_eq!(LengthUnit::Char.count("name"), 4);
+        }
+        #[test]
+        fn test_count_japanese() {
+            // バイト数では18
+            assert_eq!(LengthUnit::Grapheme.count("日本語の名前"), 6);
+            assert_eq!(LengthUnit::Char.count("日本語の名前"), 6);
+        }
+        #[test]
+        fn test_count_emoji() {
+            // ZWJで結合された家族の絵文字、国旗、肌の色付きの絵文字
+            assert_eq!(LengthUnit::Grapheme.count("👨‍👩‍👧🇯🇵👍🏽"), 3);
+            assert_eq!(LengthUnit::Char.count("👨‍👩‍👧🇯🇵👍🏽"), 9);
+        }
+        #[test]
+        fn test_count_combining_characters() {
+            // 濁点の結合文字
+            assert_eq!(LengthUnit::Grapheme.count("か\u{3099}き\u{3099}"), 2);
+            assert_eq!(LengthUnit::Char.count("か\u{3099}き\u{3099}"), 4);
+        }
+    }
+    mod mask {
+        use super::*;
+        #[test]
+        fn test_mask_ascii() {
+            assert_eq!(mask("word", '*'), "****");
+        }
+        #[test]
+        fn test_mask_japanese() {
+            assert_eq!(mask("禁止語", '*'), "***");
+        }
+        #[test]
+        fn test_mask_emoji() {
+            assert_eq!(mask("👨‍👩‍👧🇯🇵", '*'), "**");
+        }
+        #[test]
+        fn test_mask_combining_characters() {
+            assert_eq!(mask("か\u{3099}き\u{3099}", '*'), "**");
+        }
+        #[test]
+        fn test_mask_empty() {
+            assert_eq!(mask("", '*'), "");
+        }
+    }
+    mod mask_ranges {
+        use super::*;
+        #[test]
+        fn test_mask_ranges_grapheme() {
+            // 範囲にかかる書記素は、複数の範囲にかかっていても1文字の*になる
+            assert_eq!(mask_ranges("a👨‍👩‍👧b", &[1..2, 5..6], '*'), "a*b");
+        }
+        #[test]
+        fn test_mask_ranges_multiple() {
+            assert_eq!(mask_ranges("abcdef", &[0..1, 4..6], '*'), "*bcd**");
+        }
+    }
+    mod normalized_text {
+        use super::*;
+        #[test]
+        fn test_normalize_width() {
+            assert_eq!(NormalizedText::new("ＡＢＣ１２３").as_str(), "abc123");
+            assert_eq!(NormalizedText::new("ｶﾀｶﾅ").as_str(), "かたかな");
+        }
+        #[test]
+        fn test_normalize_kana() {
+            assert_eq!(NormalizedText::new("カタカナ").as_str(), "かたかな");
+            assert_eq!(NormalizedText::new("ヴ").as_str(), "ゔ");
+        }
+        #[test]
+        fn test_normalize_case() {
+            assert_eq!(NormalizedText::new("BaD").as_str(), "bad");
+            assert_eq!(NormalizedText::new("ΑΒΓ").as_str(), "αβγ");
+        }
+        #[test]
+        fn test_normalize_compatibility() {
+            // 合字や組文字もNFKCで展開する
+            assert_eq!(NormalizedText::new("㍻").as_str(), "平成");
+            assert_eq!(NormalizedText::new("ﬁne").as_str(), "fine");
+        }
+        #[test]
+        fn test_normalize_combining_characters() {
+            assert_eq!(NormalizedText::new("か\u{3099}").as_str(), "が");
+            assert_eq!(NormalizedText::new("ﾊﾞｯﾄﾞ").as_str(), "ばっど");
+        }
+        #[test]
+        fn test_find_all_width_and_case() {
+            let text = NormalizedText::new("Ｂａｄ and BAD");
+            assert_eq!(text.find_all("bad"), vec![0..9, 14..17]);
+        }
+        #[test]
+        fn test_find_all_kana() {
+            let text = NormalizedText::new("ばっど、バッド、ﾊﾞｯﾄﾞ");
+            assert_eq!(text.find_all("バッド"), vec![0..9, 12..21, 24..39]);
+        }
+        #[test]
+        fn test_find_all_expanded_grapheme() {
+            // 正規化で1文字が複数文字になっても元の書記素全体を返す
+            let text = NormalizedText::new("㍻元年");
+            assert_eq!(text.find_all("成"), vec![0..3]);
+        }
+        #[test]
+        fn test_find_all_overlapping() {
+            let text = NormalizedText::new("aaaa");
+            assert_eq!(text.find_all("aaa"), vec![0..3, 1..4]);
+        }
+        #[test]
+        fn test_find_all_not_found() {
+            let text = NormalizedText::new("good");
+            assert!(text.find_all("bad").is_empty());
+            assert!(text.find_all("").is_empty());
+        }
+        #[test]
+        fn test_find_all_and_mask() {
+            let content = "それはＢＡＤだ、ﾊﾞｯﾄﾞ";
+            let text = NormalizedText::new(content);
+            let ranges = [text.find_all("bad"), text.find_all("バッド")].concat();
+            assert_eq!(mask_ranges(content, &ranges, '*'), "それは***だ、***");
+        }
+    }
+    mod ng_word_matcher {
+        use super::*;
+        #[test]
+        fn test_find_all_normalized() {
+            let matcher = NgWordMatcher::new(["bad", "バッド"]).unwrap();
+            let content = "Ｂａｄ, BAD, ばっど";
+            let ranges = matcher.find_all(&NormalizedText::new(content));
+            assert_eq!(mask_ranges(content, &ranges, '*'), "***, ***, ***");
+        }
+        #[test]
+        fn test_find_all_overlapping() {
+            let matcher = NgWordMatcher::new(["abc", "bcd"]).unwrap();
+            let text = NormalizedText::new("abcd");
+            assert_eq!(matcher.find_all(&text), vec![0..3, 1..4]);
+        }
+        #[test]
+        fn test_same_as_find_all_of_normalized_text() {
+            let ng_words = ["禁止語", "👨‍👩‍👧", "か\u{3099}き", "ＮＧ"];
+            let text = NormalizedText::new("禁止語👨‍👩‍👧がきng");
+            let matcher = NgWordMatcher::new(ng_words).unwrap();
+            let mut expected: Vec<_> = ng_words
+                .iter()
+                .flat_map(|ng_word| text.find_all(ng_word))
+                .collect();
+            let mut actual = matcher.find_all(&text);
+            expected.sort_by_key(|range| (range.start, range.end));
+            actual.sort_by_key(|range| (range.start, range.end));
+            assert_eq!(actual, expected);
+        }
+        #[test]
+        fn test_find_matches_skips_empty_ng_word() {
+            // 空のNGワードを飛ばしても番号は構築時の順番のまま
+            let matcher = NgWordMatcher::new(["", "bad"]).unwrap();
+            let text = NormalizedText::new("bad");
+            assert_eq!(matcher.find_matches(&text), vec![(1, 0..3)]);
+        }
+        #[test]
+        fn test_empty() {
+            let text = NormalizedText::new("anything");
+            assert!(NgWordMatcher::empty().find_all(&text).is_empty());
+            assert!(!NgWordMatcher::empty().is_match(&text));
+            let matcher = NgWordMatcher::new(Vec::<String>::new()).unwrap();
+            assert!(!matcher.is_match(&text));
+        }
+    }
+}
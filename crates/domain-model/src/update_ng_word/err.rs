@@ -0,0 +1,46 @@
+use crate::register_ng_word;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotFoundError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpdateNgWordError {
+    ValidationError(ValidationError),
+    ServiceError(ServiceError),
+    PermissionError(PermissionError),
+    NotFoundError(NotFoundError),
+}
+impl From<ValidationError> for UpdateNgWordError {
+    fn from(e: ValidationError) -> Self {
+        UpdateNgWordError::ValidationError(e)
+    }
+}
+impl From<ServiceError> for UpdateNgWordError {
+    fn from(e: ServiceError) -> Self {
+        UpdateNgWordError::ServiceError(e)
+    }
+}
+impl From<PermissionError> for UpdateNgWordError {
+    fn from(e: PermissionError) -> Self {
+        UpdateNgWordError::PermissionError(e)
+    }
+}
+impl From<NotFoundError> for UpdateNgWordError {
+    fn from(e: NotFoundError) -> Self {
+        UpdateNgWordError::NotFoundError(e)
+    }
+}
+impl From<register_ng_word::err::ValidationError> for UpdateNgWordError {
+    fn from(e: register_ng_word::err::ValidationError) -> Self {
+        UpdateNgWordError::ValidationError(ValidationError(e.0))
+    }
+}
@@ -0,0 +1,40 @@
+use crate::ng_word_policy::model::{PatternKind, Severity};
+
+/// NGワードの変更内容(未検証)
+///
+/// 省略した項目は変更しない
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UnvalidatedNgWordPatch {
+    pub ng_word: Option<String>,
+    /// literal, glob, regexのいずれか
+    pub kind: Option<String>,
+    /// mask, hold, rejectのいずれか
+    pub severity: Option<String>,
+}
+impl UnvalidatedNgWordPatch {
+    /// 変更する項目が1つも無いかを判定する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use domain_model::update_ng_word::model::UnvalidatedNgWordPatch;
+    ///
+    /// assert!(UnvalidatedNgWordPatch::default().is_empty());
+    /// assert!(!UnvalidatedNgWordPatch {
+    ///     severity: Some("reject".to_string()),
+    ///     ..Default::default()
+    /// }
+    /// .is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.ng_word.is_none() && self.kind.is_none() && self.severity.is_none()
+    }
+}
+
+/// 登録済みのNGワード
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredNgWord {
+    pub word: String,
+    pub kind: PatternKind,
+    pub severity: Severity,
+}
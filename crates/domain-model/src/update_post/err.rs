@@ -0,0 +1,71 @@
+use crate::{ng_word_policy, post};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError(pub String);
+
+/// 拒否対象のNGワードを含む
+#[derive(Debug, Clone, PartialEq)]
+pub struct NgWordError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PermissionError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotFoundError(pub String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpdatePostError {
+    ValidationError(ValidationError),
+    ServiceError(ServiceError),
+    NgWordError(NgWordError),
+    PermissionError(PermissionError),
+    NotFoundError(NotFoundError),
+}
+impl From<ValidationError> for UpdatePostError {
+    fn from(e: ValidationError) -> Self {
+        UpdatePostError::ValidationError(e)
+    }
+}
+impl From<ServiceError> for UpdatePostError {
+    fn from(e: ServiceError) -> Self {
+        UpdatePostError::ServiceError(e)
+    }
+}
+impl From<NgWordError> for UpdatePostError {
+    fn from(e: NgWordError) -> Self {
+        UpdatePostError::NgWordError(e)
+    }
+}
+impl From<PermissionError> for UpdatePostError {
+    fn from(e: PermissionError) -> Self {
+        UpdatePostError::PermissionError(e)
+    }
+}
+impl From<NotFoundError> for UpdatePostError {
+    fn from(e: NotFoundError) -> Self {
+        UpdatePostError::NotFoundError(e)
+    }
+}
+impl From<ng_word_policy::err::ServiceError> for UpdatePostError {
+    fn from(e: ng_word_policy::err::ServiceError) -> Self {
+        UpdatePostError::ServiceError(ServiceError(e.0))
+    }
+}
+impl From<post::err::ValidationError> for UpdatePostError {
+    fn from(e: post::err::ValidationError) -> Self {
+        UpdatePostError::ValidationError(ValidationError(e.0))
+    }
+}
+impl From<post::err::PostError> for UpdatePostError {
+    fn from(e: post::err::PostError) -> Self {
+        match e {
+            post::err::PostError::ValidationError(e) => ValidationError(e.0).into(),
+            post::err::PostError::ServiceError(e) => ServiceError(e.0).into(),
+            post::err::PostError::NgWordError(e) => NgWordError(e.0).into(),
+            post::err::PostError::NotFoundError(e) => NotFoundError(e.0).into(),
+        }
+    }
+}
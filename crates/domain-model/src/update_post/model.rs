@@ -0,0 +1,78 @@
+use super::err::PermissionError;
+use chrono::{DateTime, Duration, Utc};
+
+/// 保存済みの投稿のうち、編集できるかの判断に使う項目
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredPost {
+    pub id: i32,
+    pub posted_user_id: i32,
+    pub created_at: DateTime<Utc>,
+}
+impl StoredPost {
+    /// ユーザがこの投稿を編集できるかを確認する
+    ///
+    /// 投稿したユーザが、投稿してから`window`の間だけ編集できる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{Duration, Utc};
+    /// use domain_model::update_post::model::StoredPost;
+    ///
+    /// let now = Utc::now();
+    /// let post = StoredPost {
+    ///     id: 1,
+    ///     posted_user_id: 2,
+    ///     created_at: now - Duration::minutes(10),
+    /// };
+    /// assert!(post.check_editable(2, now, Duration::minutes(15)).is_ok());
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// ```rust
+    /// use chrono::{Duration, Utc};
+    /// use domain_model::update_post::model::StoredPost;
+    ///
+    /// let now = Utc::now();
+    /// let post = StoredPost {
+    ///     id: 1,
+    ///     posted_user_id: 2,
+    ///     created_at: now - Duration::minutes(20),
+    /// };
+    /// // 投稿したユーザ以外
+    /// assert!(post.check_editable(3, now, Duration::hours(1)).is_err());
+    /// // 編集できる期間を過ぎた
+    /// assert!(post.check_editable(2, now, Duration::minutes(15)).is_err());
+    /// ```
+    pub fn check_editable(
+        &self,
+        user_id: i32,
+        now: DateTime<Utc>,
+        window: Duration,
+    ) -> Result<(), PermissionError> {
+        if self.posted_user_id != user_id {
+            Err(PermissionError(
+                "Only the author can edit the post".to_string(),
+            ))
+        } else if now - self.created_at > window {
+            Err(PermissionError(format!(
+                "Post can only be edited within {} minutes",
+                window.num_minutes()
+            )))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// ロックした投稿を確かめ直して更新した結果
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpdateOutcome {
+    /// 更新した
+    Updated,
+    /// 投稿が無かった
+    NotFound,
+    /// 確かめ直した時点で編集できなくなっていた
+    NotEditable(PermissionError),
+}
@@ -0,0 +1,116 @@
+//! NGワードの数を増やしても投稿1件あたりの処理時間が変わらないことを確認するベンチマーク
+//!
+//! NGワードの規則はキャッシュ済みの前提で、`post::DomainServiceImpl::post`の時間を計測する
+use async_trait::async_trait;
+use domain_model::{
+    ng_word_policy::{
+        self,
+        model::{NgWordRule, NgWordRuleSet, PatternKind, Severity},
+    },
+    post::{
+        err::ServiceError,
+        model::{Post, UnvalidatedPost, UnvalidatedUserName, UserName},
+    },
+};
+use domain_service::{
+    ng_word_policy::{NgWordPolicyImpl, NgWordPolicyRepository},
+    post::{DomainService, DomainServiceImpl, PostRepository},
+};
+use std::{hint::black_box, sync::Arc, time::Instant};
+
+const NG_WORD_COUNTS: [usize; 4] = [0, 100, 1_000, 10_000];
+const ITERATIONS: u32 = 2_000;
+
+/// キャッシュ済みの規則を返すだけのリポジトリ
+struct CachedNgWordPolicyRepository {
+    ng_word_rules: Arc<NgWordRuleSet>,
+}
+#[async_trait]
+impl NgWordPolicyRepository for CachedNgWordPolicyRepository {
+    async fn find_ng_word_rules(
+        &self,
+    ) -> Result<Arc<NgWordRuleSet>, ng_word_policy::err::ServiceError> {
+        Ok(self.ng_word_rules.clone())
+    }
+}
+
+/// DBに書き込まないリポジトリ
+struct NopPostRepository;
+#[async_trait]
+impl PostRepository for NopPostRepository {
+    async fn find_user_id(&self, _user: &UserName) -> Result<Option<i32>, ServiceError> {
+        Ok(Some(1))
+    }
+    async fn exists_reply_target(&self, _parent_post_id: i32) -> Result<bool, ServiceError> {
+        Ok(true)
+    }
+    async fn create_post(
+        &self,
+        _user_id: i32,
+        _parent_post_id: Option<i32>,
+        post: &Post,
+    ) -> Result<(), ServiceError> {
+        black_box(post);
+        Ok(())
+    }
+}
+
+/// ひらがな・カタカナ・英字を混ぜた重複の無いNGワードを生成する
+fn ng_words(count: usize) -> Vec<NgWordRule> {
+    const KANA: [char; 10] = ['あ', 'イ', 'う', 'エ', 'お', 'か', 'キ', 'く', 'ケ', 'こ'];
+    (0..count)
+        .map(|i| {
+            let mut word = String::from("ng");
+            let mut n = i;
+            for _ in 0..4 {
+                word.push(KANA[n % KANA.len()]);
+                n /= KANA.len();
+            }
+            NgWordRule {
+                id: i as i32,
+                word,
+                kind: PatternKind::Literal,
+                severity: Severity::Mask,
+            }
+        })
+        .collect()
+}
+
+fn main() {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let content = "今日はいい天気ですね。ＮＧあいうえおを含む投稿です。".repeat(4);
+
+    println!("{:>10} {:>12} {:>14}", "ng_words", "build", "post (avg)");
+    for count in NG_WORD_COUNTS {
+        let started = Instant::now();
+        let ng_word_rules = Arc::new(NgWordRuleSet::new(ng_words(count)).unwrap());
+        let build = started.elapsed();
+
+        let service = DomainServiceImpl::new(
+            Arc::new(NopPostRepository),
+            Arc::new(NgWordPolicyImpl::new(Arc::new(
+                CachedNgWordPolicyRepository { ng_word_rules },
+            ))),
+        );
+        let post = || UnvalidatedPost {
+            user_name: UnvalidatedUserName("user_name".to_string()),
+            content: content.clone(),
+        };
+
+        let elapsed = runtime.block_on(async {
+            // ウォームアップ
+            for _ in 0..ITERATIONS / 10 {
+                service.post(post()).await.unwrap();
+            }
+            let started = Instant::now();
+            for _ in 0..ITERATIONS {
+                service.post(black_box(post())).await.unwrap();
+            }
+            started.elapsed()
+        });
+
+        println!("{:>10} {:>12?} {:>14?}", count, build, elapsed / ITERATIONS);
+    }
+}
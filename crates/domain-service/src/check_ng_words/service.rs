@@ -0,0 +1,136 @@
+use crate::ng_word_policy::NgWordPolicy;
+use async_trait::async_trait;
+use domain_model::{
+    check_ng_words::{
+        err::CheckNgWordsError,
+        model::{CheckText, UnvalidatedCheckText},
+    },
+    ng_word_policy::model::NgWordCheck,
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    /// 投稿せずに、文字列をNGワードの規則に照らした結果を返す
+    ///
+    /// 投稿と同じ`NgWordPolicy::check_text`で照らすため、結果は投稿した時と一致する
+    async fn check_ng_words(
+        &self,
+        text: UnvalidatedCheckText,
+    ) -> Result<NgWordCheck, CheckNgWordsError>;
+}
+
+pub struct DomainServiceImpl {
+    ng_word_policy: Arc<dyn NgWordPolicy>,
+}
+impl DomainServiceImpl {
+    pub fn new(ng_word_policy: Arc<dyn NgWordPolicy>) -> Self {
+        DomainServiceImpl { ng_word_policy }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn check_ng_words(
+        &self,
+        text: UnvalidatedCheckText,
+    ) -> Result<NgWordCheck, CheckNgWordsError> {
+        let text = CheckText::new(&text.0)?;
+        Ok(self.ng_word_policy.check_text(text.value()).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::ng_word_policy::{ng_word_policy, MockNgWordPolicyRepository, NgWordPolicyImpl};
+        use domain_model::{
+            check_ng_words::err::{ServiceError, ValidationError},
+            ng_word_policy::{
+                self,
+                model::{ContentVerdict, Severity},
+            },
+        };
+
+        #[tokio::test]
+        async fn test_check_ng_words() {
+            let service = DomainServiceImpl::new(ng_word_policy(&[("馬鹿", Severity::Mask)], 1));
+            let result = service
+                .check_ng_words(UnvalidatedCheckText("この馬鹿め".to_string()))
+                .await
+                .unwrap();
+
+            assert_eq!(result.matches.len(), 1);
+            assert_eq!(result.matches[0].rule.word, "馬鹿");
+            assert_eq!(result.matches[0].range, 2..4);
+            assert_eq!(
+                result.verdict,
+                ContentVerdict::Accept("この**め".to_string())
+            );
+        }
+
+        #[tokio::test]
+        async fn test_check_ng_words_reject() {
+            let service = DomainServiceImpl::new(ng_word_policy(
+                &[("bad", Severity::Mask), ("evil", Severity::Reject)],
+                1,
+            ));
+            let result = service
+                .check_ng_words(UnvalidatedCheckText("bad and evil".to_string()))
+                .await
+                .unwrap();
+
+            assert_eq!(
+                result
+                    .matches
+                    .iter()
+                    .map(|found| found.range.clone())
+                    .collect::<Vec<_>>(),
+                vec![0..3, 8..12]
+            );
+            assert!(matches!(
+                result.verdict,
+                ContentVerdict::Reject(rule) if rule.id == 2
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_check_ng_words_validation_error() {
+            let service = DomainServiceImpl::new(ng_word_policy(&[], 0));
+            let result = service
+                .check_ng_words(UnvalidatedCheckText("".to_string()))
+                .await;
+
+            assert_eq!(
+                result,
+                Err(CheckNgWordsError::ValidationError(ValidationError(
+                    "Text must not be empty".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_check_ng_words_service_error() {
+            let mut mock = MockNgWordPolicyRepository::new();
+            mock.expect_find_ng_word_rules().times(1).returning(|| {
+                Err(ng_word_policy::err::ServiceError(
+                    "Database error".to_string(),
+                ))
+            });
+            let service = DomainServiceImpl::new(Arc::new(NgWordPolicyImpl::new(Arc::new(mock))));
+            let result = service
+                .check_ng_words(UnvalidatedCheckText("content".to_string()))
+                .await;
+
+            assert_eq!(
+                result,
+                Err(CheckNgWordsError::ServiceError(ServiceError(
+                    "Database error".to_string()
+                )))
+            );
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use domain_model::delete_allow_word::err::ServiceError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait DeleteAllowWordRepository: Send + Sync {
+    /// 例外の語を削除する
+    ///
+    /// 削除対象が無かった場合は`false`を返す
+    async fn delete_allow_word(&self, id: i32) -> Result<bool, ServiceError>;
+}
@@ -0,0 +1,146 @@
+use super::repository::DeleteAllowWordRepository;
+use async_trait::async_trait;
+use domain_model::{
+    delete_allow_word::err::{DeleteAllowWordError, NotFoundError, PermissionError},
+    login::model::LoggedInUser,
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    async fn delete_allow_word(
+        &self,
+        id: i32,
+        user: &LoggedInUser,
+    ) -> Result<(), DeleteAllowWordError>;
+}
+
+pub struct DomainServiceImpl {
+    delete_allow_word_repository: Arc<dyn DeleteAllowWordRepository>,
+}
+
+impl DomainServiceImpl {
+    pub fn new(delete_allow_word_repository: Arc<dyn DeleteAllowWordRepository>) -> Self {
+        Self {
+            delete_allow_word_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn delete_allow_word(
+        &self,
+        id: i32,
+        user: &LoggedInUser,
+    ) -> Result<(), DeleteAllowWordError> {
+        if !user.role.can_manage_ng_words() {
+            return Err(DeleteAllowWordError::from(PermissionError(
+                "Only moderators can delete allow words".to_string(),
+            )));
+        }
+        if self
+            .delete_allow_word_repository
+            .delete_allow_word(id)
+            .await?
+        {
+            Ok(())
+        } else {
+            Err(DeleteAllowWordError::from(NotFoundError(
+                "Allow word not found".to_string(),
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_model::{delete_allow_word::err::ServiceError, login::model::Role};
+    use mockall::predicate::*;
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "moderator".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::delete_allow_word::repository::MockDeleteAllowWordRepository;
+
+        #[tokio::test]
+        async fn test_delete_allow_word() {
+            let mut mock = MockDeleteAllowWordRepository::new();
+            mock.expect_delete_allow_word()
+                .with(eq(10))
+                .times(1)
+                .returning(|_| Ok(true));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.delete_allow_word(10, &moderator()).await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_delete_allow_word_not_found() {
+            let mut mock = MockDeleteAllowWordRepository::new();
+            mock.expect_delete_allow_word()
+                .with(eq(10))
+                .times(1)
+                .returning(|_| Ok(false));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.delete_allow_word(10, &moderator()).await;
+
+            assert_eq!(
+                result,
+                Err(DeleteAllowWordError::NotFoundError(NotFoundError(
+                    "Allow word not found".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_delete_allow_word_permission_error() {
+            let mut mock = MockDeleteAllowWordRepository::new();
+            mock.expect_delete_allow_word().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let member = LoggedInUser {
+                id: 2,
+                name: "member".to_string(),
+                role: Role::Member,
+            };
+            let result = service.delete_allow_word(10, &member).await;
+
+            assert_eq!(
+                result,
+                Err(DeleteAllowWordError::PermissionError(PermissionError(
+                    "Only moderators can delete allow words".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_delete_allow_word_service_error() {
+            let mut mock = MockDeleteAllowWordRepository::new();
+            mock.expect_delete_allow_word()
+                .times(1)
+                .returning(|_| Err(ServiceError("Database error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.delete_allow_word(10, &moderator()).await;
+
+            assert_eq!(
+                result,
+                Err(DeleteAllowWordError::ServiceError(ServiceError(
+                    "Database error".to_string()
+                )))
+            );
+        }
+    }
+}
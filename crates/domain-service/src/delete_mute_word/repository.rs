@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use domain_model::delete_mute_word::err::ServiceError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait DeleteMuteWordRepository: Send + Sync {
+    /// ユーザがミュートした語を削除する
+    ///
+    /// そのユーザの削除対象が無かった場合は`false`を返す
+    async fn delete_mute_word(&self, id: i32, user_id: i32) -> Result<bool, ServiceError>;
+}
@@ -0,0 +1,123 @@
+use super::repository::DeleteMuteWordRepository;
+use async_trait::async_trait;
+use domain_model::{
+    delete_mute_word::err::{DeleteMuteWordError, NotFoundError},
+    login::model::LoggedInUser,
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    /// ログイン中のユーザ自身がミュートした語を削除する
+    ///
+    /// 他のユーザの語は見つからないものとして扱う
+    async fn delete_mute_word(
+        &self,
+        id: i32,
+        user: &LoggedInUser,
+    ) -> Result<(), DeleteMuteWordError>;
+}
+
+pub struct DomainServiceImpl {
+    delete_mute_word_repository: Arc<dyn DeleteMuteWordRepository>,
+}
+
+impl DomainServiceImpl {
+    pub fn new(delete_mute_word_repository: Arc<dyn DeleteMuteWordRepository>) -> Self {
+        Self {
+            delete_mute_word_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn delete_mute_word(
+        &self,
+        id: i32,
+        user: &LoggedInUser,
+    ) -> Result<(), DeleteMuteWordError> {
+        if self
+            .delete_mute_word_repository
+            .delete_mute_word(id, user.id)
+            .await?
+        {
+            Ok(())
+        } else {
+            Err(DeleteMuteWordError::from(NotFoundError(
+                "Mute word not found".to_string(),
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_model::{delete_mute_word::err::ServiceError, login::model::Role};
+    use mockall::predicate::*;
+
+    fn member() -> LoggedInUser {
+        LoggedInUser {
+            id: 2,
+            name: "member".to_string(),
+            role: Role::Member,
+        }
+    }
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::delete_mute_word::repository::MockDeleteMuteWordRepository;
+
+        #[tokio::test]
+        async fn test_delete_mute_word() {
+            let mut mock = MockDeleteMuteWordRepository::new();
+            mock.expect_delete_mute_word()
+                .with(eq(10), eq(2))
+                .times(1)
+                .returning(|_, _| Ok(true));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.delete_mute_word(10, &member()).await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_delete_mute_word_not_found() {
+            let mut mock = MockDeleteMuteWordRepository::new();
+            mock.expect_delete_mute_word()
+                .with(eq(10), eq(2))
+                .times(1)
+                .returning(|_, _| Ok(false));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.delete_mute_word(10, &member()).await;
+
+            assert_eq!(
+                result,
+                Err(DeleteMuteWordError::NotFoundError(NotFoundError(
+                    "Mute word not found".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_delete_mute_word_service_error() {
+            let mut mock = MockDeleteMuteWordRepository::new();
+            mock.expect_delete_mute_word()
+                .times(1)
+                .returning(|_, _| Err(ServiceError("Database error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.delete_mute_word(10, &member()).await;
+
+            assert_eq!(
+                result,
+                Err(DeleteMuteWordError::ServiceError(ServiceError(
+                    "Database error".to_string()
+                )))
+            );
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use domain_model::delete_ng_word::err::ServiceError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait DeleteNgWordRepository: Send + Sync {
+    /// NGワードを削除する
+    ///
+    /// 削除対象が無かった場合は`false`を返す
+    async fn delete_ng_word(&self, id: i32) -> Result<bool, ServiceError>;
+}
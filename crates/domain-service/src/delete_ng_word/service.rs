@@ -0,0 +1,134 @@
+use super::repository::DeleteNgWordRepository;
+use async_trait::async_trait;
+use domain_model::{
+    delete_ng_word::err::{DeleteNgWordError, NotFoundError, PermissionError},
+    login::model::LoggedInUser,
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    async fn delete_ng_word(&self, id: i32, user: &LoggedInUser) -> Result<(), DeleteNgWordError>;
+}
+
+pub struct DomainServiceImpl {
+    delete_ng_word_repository: Arc<dyn DeleteNgWordRepository>,
+}
+
+impl DomainServiceImpl {
+    pub fn new(delete_ng_word_repository: Arc<dyn DeleteNgWordRepository>) -> Self {
+        Self {
+            delete_ng_word_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn delete_ng_word(&self, id: i32, user: &LoggedInUser) -> Result<(), DeleteNgWordError> {
+        if !user.role.can_manage_ng_words() {
+            return Err(DeleteNgWordError::from(PermissionError(
+                "Only moderators can delete NG words".to_string(),
+            )));
+        }
+        if self.delete_ng_word_repository.delete_ng_word(id).await? {
+            Ok(())
+        } else {
+            Err(DeleteNgWordError::from(NotFoundError(
+                "NG word not found".to_string(),
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_model::{delete_ng_word::err::ServiceError, login::model::Role};
+    use mockall::predicate::*;
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "moderator".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::delete_ng_word::repository::MockDeleteNgWordRepository;
+
+        #[tokio::test]
+        async fn test_delete_ng_word() {
+            let mut mock = MockDeleteNgWordRepository::new();
+            mock.expect_delete_ng_word()
+                .with(eq(10))
+                .times(1)
+                .returning(|_| Ok(true));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.delete_ng_word(10, &moderator()).await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_delete_ng_word_not_found() {
+            let mut mock = MockDeleteNgWordRepository::new();
+            mock.expect_delete_ng_word()
+                .with(eq(10))
+                .times(1)
+                .returning(|_| Ok(false));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.delete_ng_word(10, &moderator()).await;
+
+            assert_eq!(
+                result,
+                Err(DeleteNgWordError::NotFoundError(NotFoundError(
+                    "NG word not found".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_delete_ng_word_permission_error() {
+            let mut mock = MockDeleteNgWordRepository::new();
+            mock.expect_delete_ng_word().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let member = LoggedInUser {
+                id: 2,
+                name: "member".to_string(),
+                role: Role::Member,
+            };
+            let result = service.delete_ng_word(10, &member).await;
+
+            assert_eq!(
+                result,
+                Err(DeleteNgWordError::PermissionError(PermissionError(
+                    "Only moderators can delete NG words".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_delete_ng_word_service_error() {
+            let mut mock = MockDeleteNgWordRepository::new();
+            mock.expect_delete_ng_word()
+                .times(1)
+                .returning(|_| Err(ServiceError("Database error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.delete_ng_word(10, &moderator()).await;
+
+            assert_eq!(
+                result,
+                Err(DeleteNgWordError::ServiceError(ServiceError(
+                    "Database error".to_string()
+                )))
+            );
+        }
+    }
+}
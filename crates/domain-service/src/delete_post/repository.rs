@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use domain_model::delete_post::{
+    err::ServiceError,
+    model::{DeletablePost, DeleteReason},
+};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait DeletePostRepository: Send + Sync {
+    /// 削除されていない投稿をIDで取得する。モデレーション待ちの投稿も含む
+    async fn find_post(&self, id: i32) -> Result<Option<DeletablePost>, ServiceError>;
+    /// 投稿を削除済みにし、削除したユーザと理由を記録する。内容は消さない
+    ///
+    /// 削除対象が無かった場合は`false`を返す
+    async fn delete_post(
+        &self,
+        id: i32,
+        user_id: i32,
+        reason: &DeleteReason,
+    ) -> Result<bool, ServiceError>;
+}
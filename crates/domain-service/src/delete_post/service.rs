@@ -0,0 +1,211 @@
+use super::repository::DeletePostRepository;
+use async_trait::async_trait;
+use domain_model::{
+    delete_post::{
+        err::{DeletePostError, NotFoundError},
+        model::{DeleteReason, UnvalidatedDeleteReason},
+    },
+    login::model::LoggedInUser,
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    /// 投稿を削除する
+    ///
+    /// 内容は残し、モデレータが戻せるようにする
+    async fn delete_post(
+        &self,
+        id: i32,
+        reason: UnvalidatedDeleteReason,
+        user: &LoggedInUser,
+    ) -> Result<(), DeletePostError>;
+}
+
+pub struct DomainServiceImpl {
+    delete_post_repository: Arc<dyn DeletePostRepository>,
+}
+
+impl DomainServiceImpl {
+    pub fn new(delete_post_repository: Arc<dyn DeletePostRepository>) -> Self {
+        Self {
+            delete_post_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn delete_post(
+        &self,
+        id: i32,
+        reason: UnvalidatedDeleteReason,
+        user: &LoggedInUser,
+    ) -> Result<(), DeletePostError> {
+        let reason = DeleteReason::new(reason)?;
+        let not_found = || DeletePostError::from(NotFoundError("Post not found".to_string()));
+        let Some(post) = self.delete_post_repository.find_post(id).await? else {
+            return Err(not_found());
+        };
+        post.check_deletable(user)?;
+
+        if self
+            .delete_post_repository
+            .delete_post(id, user.id, &reason)
+            .await?
+        {
+            Ok(())
+        } else {
+            Err(not_found())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_model::{
+        delete_post::{
+            err::{PermissionError, ServiceError},
+            model::DeletablePost,
+        },
+        login::model::Role,
+    };
+    use mockall::predicate::*;
+
+    fn user(id: i32, role: Role) -> LoggedInUser {
+        LoggedInUser {
+            id,
+            name: "user_name".to_string(),
+            role,
+        }
+    }
+
+    fn reason(reason: &str) -> UnvalidatedDeleteReason {
+        UnvalidatedDeleteReason(Some(reason.to_string()))
+    }
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::delete_post::repository::MockDeletePostRepository;
+
+        fn mock_with_post(post: Option<DeletablePost>) -> MockDeletePostRepository {
+            let mut mock = MockDeletePostRepository::new();
+            mock.expect_find_post()
+                .with(eq(1))
+                .times(1)
+                .returning(move |_| Ok(post.clone()));
+            mock
+        }
+
+        fn post() -> Option<DeletablePost> {
+            Some(DeletablePost {
+                id: 1,
+                posted_user_id: 2,
+            })
+        }
+
+        #[tokio::test]
+        async fn test_delete_post_by_author() {
+            let mut mock = mock_with_post(post());
+            mock.expect_delete_post()
+                .withf(|id, user_id, reason| *id == 1 && *user_id == 2 && reason.value().is_none())
+                .times(1)
+                .returning(|_, _, _| Ok(true));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .delete_post(1, UnvalidatedDeleteReason(None), &user(2, Role::Member))
+                .await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_delete_post_by_moderator() {
+            let mut mock = mock_with_post(post());
+            mock.expect_delete_post()
+                .withf(|id, user_id, reason| {
+                    *id == 1 && *user_id == 3 && reason.value() == Some("spam")
+                })
+                .times(1)
+                .returning(|_, _, _| Ok(true));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .delete_post(1, reason("spam"), &user(3, Role::Moderator))
+                .await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_delete_post_permission_error() {
+            let mut mock = mock_with_post(post());
+            mock.expect_delete_post().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .delete_post(1, reason("spam"), &user(3, Role::Member))
+                .await;
+
+            assert_eq!(
+                result,
+                Err(DeletePostError::PermissionError(PermissionError(
+                    "Only the author or moderators can delete the post".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_delete_post_validation_error() {
+            let mut mock = MockDeletePostRepository::new();
+            mock.expect_find_post().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .delete_post(1, reason(&"a".repeat(256)), &user(2, Role::Member))
+                .await;
+
+            assert!(matches!(result, Err(DeletePostError::ValidationError(_))));
+        }
+
+        #[tokio::test]
+        async fn test_delete_post_not_found() {
+            let mut mock = mock_with_post(None);
+            mock.expect_delete_post().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .delete_post(1, UnvalidatedDeleteReason(None), &user(2, Role::Member))
+                .await;
+
+            assert_eq!(
+                result,
+                Err(DeletePostError::NotFoundError(NotFoundError(
+                    "Post not found".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_delete_post_service_error() {
+            let mut mock = mock_with_post(post());
+            mock.expect_delete_post()
+                .times(1)
+                .returning(|_, _, _| Err(ServiceError("error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .delete_post(1, UnvalidatedDeleteReason(None), &user(2, Role::Member))
+                .await;
+
+            assert_eq!(
+                result,
+                Err(DeletePostError::ServiceError(ServiceError(
+                    "error".to_string()
+                )))
+            );
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use domain_model::{delete_reaction::err::ServiceError, reaction::model::ReactionKind};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait DeleteReactionRepository: Send + Sync {
+    /// リアクションを取り消す。同じリアクションが無い場合は何もしない
+    async fn delete_reaction(
+        &self,
+        post_id: i32,
+        user_id: i32,
+        kind: &ReactionKind,
+    ) -> Result<(), ServiceError>;
+}
@@ -0,0 +1,102 @@
+use super::repository::DeleteReactionRepository;
+use async_trait::async_trait;
+use domain_model::{
+    delete_reaction::err::DeleteReactionError, login::model::LoggedInUser,
+    reaction::model::ReactionKind,
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    /// ログイン中のユーザ自身のリアクションを投稿から取り消す
+    ///
+    /// 付けていないリアクションや無い投稿を指定しても成功する。
+    /// 使えなくなった種類も取り消せるよう、種類は形式だけを検証する
+    async fn delete_reaction(
+        &self,
+        post_id: i32,
+        kind: String,
+        user: &LoggedInUser,
+    ) -> Result<(), DeleteReactionError>;
+}
+
+pub struct DomainServiceImpl {
+    delete_reaction_repository: Arc<dyn DeleteReactionRepository>,
+}
+
+impl DomainServiceImpl {
+    pub fn new(delete_reaction_repository: Arc<dyn DeleteReactionRepository>) -> Self {
+        Self {
+            delete_reaction_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn delete_reaction(
+        &self,
+        post_id: i32,
+        kind: String,
+        user: &LoggedInUser,
+    ) -> Result<(), DeleteReactionError> {
+        let kind = ReactionKind::new(&kind)?;
+        self.delete_reaction_repository
+            .delete_reaction(post_id, user.id, &kind)
+            .await
+            .map_err(|e| e.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_model::{delete_reaction::err::ValidationError, login::model::Role};
+    use mockall::predicate::*;
+
+    fn member() -> LoggedInUser {
+        LoggedInUser {
+            id: 2,
+            name: "member".to_string(),
+            role: Role::Member,
+        }
+    }
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::delete_reaction::repository::MockDeleteReactionRepository;
+
+        #[tokio::test]
+        async fn test_delete_reaction() {
+            let mut mock = MockDeleteReactionRepository::new();
+            mock.expect_delete_reaction()
+                .with(eq(1), eq(2), eq(ReactionKind::new("like").unwrap()))
+                .times(1)
+                .returning(|_, _, _| Ok(()));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .delete_reaction(1, "like".to_string(), &member())
+                .await;
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_delete_reaction_invalid_kind() {
+            let mut mock = MockDeleteReactionRepository::new();
+            mock.expect_delete_reaction().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .delete_reaction(1, "Like".to_string(), &member())
+                .await;
+            assert_eq!(
+                result,
+                Err(DeleteReactionError::ValidationError(ValidationError(
+                    "Reaction kind must consist of lowercase letters, digits and underscores, or be a single emoji"
+                        .to_string()
+                )))
+            );
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use domain_model::export_ng_words::{err::ServiceError, model::ExportedNgWord};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ExportNgWordsRepository: Send + Sync {
+    /// 登録済みのNGワードを全てID順に取得する
+    async fn find_all_ng_words(&self) -> Result<Vec<ExportedNgWord>, ServiceError>;
+}
@@ -0,0 +1,129 @@
+use super::repository::ExportNgWordsRepository;
+use async_trait::async_trait;
+use domain_model::{
+    export_ng_words::{
+        err::{ExportNgWordsError, PermissionError},
+        model::ExportedNgWord,
+    },
+    login::model::LoggedInUser,
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    async fn export_ng_words(
+        &self,
+        user: &LoggedInUser,
+    ) -> Result<Vec<ExportedNgWord>, ExportNgWordsError>;
+}
+
+pub struct DomainServiceImpl {
+    export_ng_words_repository: Arc<dyn ExportNgWordsRepository>,
+}
+
+impl DomainServiceImpl {
+    pub fn new(export_ng_words_repository: Arc<dyn ExportNgWordsRepository>) -> Self {
+        Self {
+            export_ng_words_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn export_ng_words(
+        &self,
+        user: &LoggedInUser,
+    ) -> Result<Vec<ExportedNgWord>, ExportNgWordsError> {
+        if !user.role.can_manage_ng_words() {
+            return Err(ExportNgWordsError::from(PermissionError(
+                "Only moderators can export NG words".to_string(),
+            )));
+        }
+        self.export_ng_words_repository
+            .find_all_ng_words()
+            .await
+            .map_err(|e| e.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_model::{
+        export_ng_words::err::ServiceError,
+        login::model::Role,
+        ng_word_policy::model::{PatternKind, Severity},
+    };
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "moderator".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::export_ng_words::repository::MockExportNgWordsRepository;
+
+        #[tokio::test]
+        async fn test_export_ng_words() {
+            let ng_words = vec![ExportedNgWord {
+                word: "bad".to_string(),
+                kind: PatternKind::Literal,
+                severity: Severity::Reject,
+            }];
+            let expected = ng_words.clone();
+            let mut mock = MockExportNgWordsRepository::new();
+            mock.expect_find_all_ng_words()
+                .times(1)
+                .returning(move || Ok(ng_words.clone()));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.export_ng_words(&moderator()).await;
+
+            assert_eq!(result, Ok(expected));
+        }
+
+        #[tokio::test]
+        async fn test_export_ng_words_permission_error() {
+            let mut mock = MockExportNgWordsRepository::new();
+            mock.expect_find_all_ng_words().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let member = LoggedInUser {
+                id: 2,
+                name: "member".to_string(),
+                role: Role::Member,
+            };
+            let result = service.export_ng_words(&member).await;
+
+            assert_eq!(
+                result,
+                Err(ExportNgWordsError::PermissionError(PermissionError(
+                    "Only moderators can export NG words".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_export_ng_words_service_error() {
+            let mut mock = MockExportNgWordsRepository::new();
+            mock.expect_find_all_ng_words()
+                .times(1)
+                .returning(|| Err(ServiceError("Database error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.export_ng_words(&moderator()).await;
+
+            assert_eq!(
+                result,
+                Err(ExportNgWordsError::ServiceError(ServiceError(
+                    "Database error".to_string()
+                )))
+            );
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use async_trait::async_trait;
-use domain_model::get_all_post::{err::ServiceError, model::Post};
+use domain_model::get_all_post::{
+    err::ServiceError,
+    model::{Post, PostCursor},
+};
 
 #[cfg(test)]
 use mockall::automock;
@@ -7,5 +10,16 @@ use mockall::automock;
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait GetAllPostRepository: Send + Sync {
-    async fn get_all_post(&self) -> Result<Vec<Post>, ServiceError>;
+    /// 公開済みの投稿を、投稿日時の新しい順、同じ日時ならIDの大きい順に最大`limit`件取得する
+    ///
+    /// `cursor`がある場合は、その位置より後の投稿だけを取得する。
+    /// `viewer_user_id`がある場合は、そのユーザが付けたリアクションに印を付ける
+    async fn get_all_post(
+        &self,
+        limit: u64,
+        cursor: Option<PostCursor>,
+        viewer_user_id: Option<i32>,
+    ) -> Result<Vec<Post>, ServiceError>;
+    /// ユーザがミュートした語を全て取得する
+    async fn find_mute_words(&self, user_id: i32) -> Result<Vec<String>, ServiceError>;
 }
@@ -1,28 +1,106 @@
 use super::repository::GetAllPostRepository;
+use crate::ng_word_policy::NgWordPolicy;
 use async_trait::async_trait;
-use domain_model::get_all_post::{err::ServiceError, model::Post};
+use domain_model::{
+    get_all_post::{
+        err::{GetAllPostError, ServiceError},
+        model::{
+            Content, MuteMode, PageRequest, Post, PostCursor, PostPage, UnvalidatedPageRequest,
+            Viewer,
+        },
+    },
+    text::{NgWordMatcher, NormalizedText},
+};
 use std::sync::Arc;
 
 #[async_trait]
 pub trait DomainService: Send + Sync {
-    async fn get_all_post(&self) -> Result<Vec<Post>, ServiceError>;
+    /// 公開済みの投稿を新しい順に1ページ分取得する
+    ///
+    /// NGワードは保存時ではなく、その時点の規則で塗りつぶす。
+    /// 閲覧するユーザがいる場合、そのユーザがミュートした語を含む投稿は隠すか折りたたむ。
+    /// 隠した投稿の分、1ページの件数は少なくなる
+    async fn get_all_post(
+        &self,
+        page: UnvalidatedPageRequest,
+        viewer: Option<Viewer>,
+    ) -> Result<PostPage, GetAllPostError>;
 }
 
 pub struct DomainServiceImpl {
     get_all_post_repository: Arc<dyn GetAllPostRepository>,
+    ng_word_policy: Arc<dyn NgWordPolicy>,
 }
 impl DomainServiceImpl {
-    pub fn new(get_all_post_repository: Arc<dyn GetAllPostRepository>) -> Self {
+    pub fn new(
+        get_all_post_repository: Arc<dyn GetAllPostRepository>,
+        ng_word_policy: Arc<dyn NgWordPolicy>,
+    ) -> Self {
         Self {
             get_all_post_repository,
+            ng_word_policy,
         }
     }
 }
 
 #[async_trait]
 impl DomainService for DomainServiceImpl {
-    async fn get_all_post(&self) -> Result<Vec<Post>, ServiceError> {
-        self.get_all_post_repository.get_all_post().await
+    async fn get_all_post(
+        &self,
+        page: UnvalidatedPageRequest,
+        viewer: Option<Viewer>,
+    ) -> Result<PostPage, GetAllPostError> {
+        let page = PageRequest::new(page)?;
+        // 続きがあるかを知るために1件多く取得する
+        let mut posts = self
+            .get_all_post_repository
+            .get_all_post(
+                page.limit() as u64 + 1,
+                page.cursor().cloned(),
+                viewer.as_ref().map(|viewer| viewer.user_id),
+            )
+            .await?;
+        let next_cursor = if posts.len() > page.limit() {
+            posts.truncate(page.limit());
+            posts.last().map(|post| PostCursor {
+                posted_datetime: post.posted_datetime.0,
+                id: post.id,
+            })
+        } else {
+            None
+        };
+
+        let mute = match viewer {
+            Some(viewer) => {
+                let mute_words = self
+                    .get_all_post_repository
+                    .find_mute_words(viewer.user_id)
+                    .await?;
+                let matcher = NgWordMatcher::new(mute_words).map_err(|e| ServiceError(e.0))?;
+                Some((matcher, viewer.mute_mode))
+            }
+            None => None,
+        };
+        let mut masked_posts = Vec::with_capacity(posts.len());
+        for post in posts {
+            // ミュートはNGワードと同じく、書かれたままの内容を正規化して照合する
+            let muted = mute.as_ref().is_some_and(|(matcher, _)| {
+                matcher.is_match(&NormalizedText::new(&post.content.0))
+            });
+            if muted && matches!(mute, Some((_, MuteMode::Hide))) {
+                continue;
+            }
+            let content = self.ng_word_policy.mask_content(&post.content.0).await?;
+            masked_posts.push(Post {
+                content: Content(content),
+                muted,
+                ..post
+            });
+        }
+        Ok(PostPage {
+            posts: masked_posts,
+            next_cursor,
+        })
     }
 }
 
@@ -33,52 +111,180 @@ mod tests {
 
     mod domain_service_impl {
         use super::*;
-        use crate::get_all_post::repository::MockGetAllPostRepository;
+        use crate::{
+            get_all_post::repository::MockGetAllPostRepository, ng_word_policy::ng_word_policy,
+        };
         use chrono::NaiveDateTime;
-        use domain_model::get_all_post::model::{Content, Post, PostedDatetime, PostedUserName};
+        use domain_model::{
+            get_all_post::model::{PostedDatetime, PostedUserName},
+            ng_word_policy::model::Severity,
+        };
         const DATETIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
 
+        fn post(id: i32, content: &str) -> Post {
+            Post {
+                id,
+                posted_user_name: PostedUserName("test".to_string()),
+                posted_datetime: PostedDatetime(
+                    NaiveDateTime::parse_from_str("2021-01-01T00:00:00Z", DATETIME_FORMAT).unwrap(),
+                ),
+                content: Content(content.to_string()),
+                muted: false,
+                reply_count: 0,
+                reactions: vec![],
+            }
+        }
+
         #[tokio::test]
         async fn test_get_all_post() {
             let mut mock = MockGetAllPostRepository::new();
-            mock.expect_get_all_post().times(1).returning(|| {
-                Ok(vec![Post {
-                    posted_user_name: PostedUserName("test".to_string()),
-                    posted_datetime: PostedDatetime(
-                        NaiveDateTime::parse_from_str("2021-01-01T00:00:00Z", DATETIME_FORMAT)
-                            .unwrap(),
-                    ),
-                    content: Content("test".to_string()),
-                }])
-            });
+            mock.expect_get_all_post()
+                .with(eq(21), eq(None), eq(None))
+                .times(1)
+                .returning(|_, _, _| Ok(vec![post(1, "test")]));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 1));
+            let result = service
+                .get_all_post(UnvalidatedPageRequest::default(), None)
+                .await;
+
+            assert_eq!(
+                result,
+                Ok(PostPage {
+                    posts: vec![post(1, "test")],
+                    next_cursor: None,
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_all_post_next_cursor() {
+            let mut mock = MockGetAllPostRepository::new();
+            mock.expect_get_all_post()
+                .with(eq(3), eq(None), eq(None))
+                .times(1)
+                .returning(|_, _, _| Ok(vec![post(3, "a"), post(2, "b"), post(1, "c")]));
 
-            let service = DomainServiceImpl::new(Arc::new(mock));
-            let result = service.get_all_post().await;
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 2));
+            let result = service
+                .get_all_post(
+                    UnvalidatedPageRequest {
+                        limit: Some(2),
+                        cursor: None,
+                    },
+                    None,
+                )
+                .await;
 
-            assert!(result.is_ok());
+            // 余分に取得した1件は返さず、最後に返した投稿の位置を続きとする
             assert_eq!(
                 result,
-                Ok(vec![Post {
-                    posted_user_name: PostedUserName("test".to_string()),
-                    posted_datetime: PostedDatetime(
-                        NaiveDateTime::parse_from_str("2021-01-01T00:00:00Z", DATETIME_FORMAT)
-                            .unwrap(),
-                    ),
-                    content: Content("test".to_string()),
-                }])
+                Ok(PostPage {
+                    posts: vec![post(3, "a"), post(2, "b")],
+                    next_cursor: Some(PostCursor {
+                        posted_datetime: post(2, "b").posted_datetime.0,
+                        id: 2,
+                    }),
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_all_post_with_cursor() {
+            let cursor = PostCursor {
+                posted_datetime: post(2, "b").posted_datetime.0,
+                id: 2,
+            };
+            let mut mock = MockGetAllPostRepository::new();
+            mock.expect_get_all_post()
+                .with(eq(3), eq(Some(cursor.clone())), eq(None))
+                .times(1)
+                .returning(|_, _, _| Ok(vec![post(1, "c")]));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 1));
+            let result = service
+                .get_all_post(
+                    UnvalidatedPageRequest {
+                        limit: Some(2),
+                        cursor: Some(cursor.encode()),
+                    },
+                    None,
+                )
+                .await;
+
+            assert_eq!(
+                result,
+                Ok(PostPage {
+                    posts: vec![post(1, "c")],
+                    next_cursor: None,
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_all_post_validation_error() {
+            let mut mock = MockGetAllPostRepository::new();
+            mock.expect_get_all_post().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 0));
+            let result = service
+                .get_all_post(
+                    UnvalidatedPageRequest {
+                        limit: Some(101),
+                        cursor: None,
+                    },
+                    None,
+                )
+                .await;
+
+            assert!(matches!(result, Err(GetAllPostError::ValidationError(_))));
+        }
+
+        #[tokio::test]
+        async fn test_get_all_post_masked() {
+            let mut mock = MockGetAllPostRepository::new();
+            mock.expect_get_all_post()
+                .times(1)
+                .returning(|_, _, _| Ok(vec![post(1, "so bad")]));
+
+            // 保存後に登録されたNGワードも、対応に関わらず塗りつぶす
+            let service = DomainServiceImpl::new(
+                Arc::new(mock),
+                ng_word_policy(&[("bad", Severity::Reject)], 1),
+            );
+            let result = service
+                .get_all_post(UnvalidatedPageRequest::default(), None)
+                .await;
+
+            assert_eq!(
+                result.map(|page| page
+                    .posts
+                    .into_iter()
+                    .map(|post| post.content)
+                    .collect::<Vec<_>>()),
+                Ok(vec![Content("so ***".to_string())])
             );
         }
 
         #[tokio::test]
         async fn test_get_all_post_empty() {
             let mut mock = MockGetAllPostRepository::new();
-            mock.expect_get_all_post().times(1).returning(|| Ok(vec![]));
+            mock.expect_get_all_post()
+                .times(1)
+                .returning(|_, _, _| Ok(vec![]));
 
-            let service = DomainServiceImpl::new(Arc::new(mock));
-            let result = service.get_all_post().await;
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 0));
+            let result = service
+                .get_all_post(UnvalidatedPageRequest::default(), None)
+                .await;
 
-            assert!(result.is_ok());
-            assert_eq!(result, Ok(vec![]));
+            assert_eq!(
+                result,
+                Ok(PostPage {
+                    posts: vec![],
+                    next_cursor: None,
+                })
+            );
         }
 
         #[tokio::test]
@@ -86,13 +292,142 @@ mod tests {
             let mut mock = MockGetAllPostRepository::new();
             mock.expect_get_all_post()
                 .times(1)
-                .returning(|| Err(ServiceError("error".to_string())));
+                .returning(|_, _, _| Err(ServiceError("error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 0));
+            let result = service
+                .get_all_post(UnvalidatedPageRequest::default(), None)
+                .await;
+
+            assert_eq!(
+                result,
+                Err(GetAllPostError::ServiceError(ServiceError(
+                    "error".to_string()
+                )))
+            );
+        }
+
+        fn mock_with_mute_words(mute_words: &[&str]) -> MockGetAllPostRepository {
+            let mute_words: Vec<String> = mute_words.iter().map(|word| word.to_string()).collect();
+            let mut mock = MockGetAllPostRepository::new();
+            mock.expect_get_all_post()
+                .with(always(), always(), eq(Some(2)))
+                .times(1)
+                .returning(|_, _, _| Ok(vec![post(2, "ネタバレ注意"), post(1, "hello")]));
+            mock.expect_find_mute_words()
+                .with(eq(2))
+                .times(1)
+                .returning(move |_| Ok(mute_words.clone()));
+            mock
+        }
 
-            let service = DomainServiceImpl::new(Arc::new(mock));
-            let result = service.get_all_post().await;
+        #[tokio::test]
+        async fn test_get_all_post_mute_hide() {
+            let service = DomainServiceImpl::new(
+                Arc::new(mock_with_mute_words(&["ねたばれ"])),
+                ng_word_policy(&[], 1),
+            );
+            let result = service
+                .get_all_post(
+                    UnvalidatedPageRequest::default(),
+                    Some(Viewer {
+                        user_id: 2,
+                        mute_mode: MuteMode::Hide,
+                    }),
+                )
+                .await;
+
+            // ミュートした語は正規化して照合する
+            assert_eq!(result.map(|page| page.posts), Ok(vec![post(1, "hello")]));
+        }
 
-            assert!(result.is_err());
-            assert_eq!(result, Err(ServiceError("error".to_string())));
+        #[tokio::test]
+        async fn test_get_all_post_mute_hide_keeps_cursor() {
+            let service = DomainServiceImpl::new(
+                Arc::new(mock_with_mute_words(&["hello"])),
+                ng_word_policy(&[], 1),
+            );
+            let result = service
+                .get_all_post(
+                    UnvalidatedPageRequest {
+                        limit: Some(1),
+                        cursor: None,
+                    },
+                    Some(Viewer {
+                        user_id: 2,
+                        mute_mode: MuteMode::Hide,
+                    }),
+                )
+                .await;
+
+            // 隠した投稿があっても、続きの位置は取得した投稿から決める
+            assert_eq!(
+                result,
+                Ok(PostPage {
+                    posts: vec![post(2, "ネタバレ注意")],
+                    next_cursor: Some(PostCursor {
+                        posted_datetime: post(2, "").posted_datetime.0,
+                        id: 2,
+                    }),
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_all_post_mute_collapse() {
+            let service = DomainServiceImpl::new(
+                Arc::new(mock_with_mute_words(&["ネタバレ"])),
+                ng_word_policy(&[], 2),
+            );
+            let result = service
+                .get_all_post(
+                    UnvalidatedPageRequest::default(),
+                    Some(Viewer {
+                        user_id: 2,
+                        mute_mode: MuteMode::Collapse,
+                    }),
+                )
+                .await;
+
+            assert_eq!(
+                result.map(|page| page.posts),
+                Ok(vec![
+                    Post {
+                        muted: true,
+                        ..post(2, "ネタバレ注意")
+                    },
+                    post(1, "hello")
+                ])
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_all_post_mute_words_error() {
+            let mut mock = MockGetAllPostRepository::new();
+            mock.expect_get_all_post()
+                .times(1)
+                .returning(|_, _, _| Ok(vec![]));
+            mock.expect_find_mute_words()
+                .times(1)
+                .returning(|_| Err(ServiceError("error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 0));
+            let result = service
+                .get_all_post(
+                    UnvalidatedPageRequest::default(),
+                    Some(Viewer {
+                        user_id: 2,
+                        mute_mode: MuteMode::Hide,
+                    }),
+                )
+                .await;
+
+            assert_eq!(
+                result,
+                Err(GetAllPostError::ServiceError(ServiceError(
+                    "error".to_string()
+                )))
+            );
         }
     }
 }
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use domain_model::get_all_raw_post::{err::ServiceError, model::RawPost};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait GetAllRawPostRepository: Send + Sync {
+    /// モデレーション待ちも含めた投稿を全てID順に取得する
+    async fn get_all_raw_post(&self) -> Result<Vec<RawPost>, ServiceError>;
+}
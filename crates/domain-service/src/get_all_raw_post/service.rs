@@ -0,0 +1,137 @@
+use super::repository::GetAllRawPostRepository;
+use async_trait::async_trait;
+use domain_model::{
+    get_all_raw_post::{
+        err::{GetAllRawPostError, PermissionError},
+        model::RawPost,
+    },
+    login::model::LoggedInUser,
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    async fn get_all_raw_post(
+        &self,
+        user: &LoggedInUser,
+    ) -> Result<Vec<RawPost>, GetAllRawPostError>;
+}
+
+pub struct DomainServiceImpl {
+    get_all_raw_post_repository: Arc<dyn GetAllRawPostRepository>,
+}
+
+impl DomainServiceImpl {
+    pub fn new(get_all_raw_post_repository: Arc<dyn GetAllRawPostRepository>) -> Self {
+        Self {
+            get_all_raw_post_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn get_all_raw_post(
+        &self,
+        user: &LoggedInUser,
+    ) -> Result<Vec<RawPost>, GetAllRawPostError> {
+        if !user.role.can_view_raw_posts() {
+            return Err(GetAllRawPostError::from(PermissionError(
+                "Only moderators can view raw posts".to_string(),
+            )));
+        }
+        self.get_all_raw_post_repository
+            .get_all_raw_post()
+            .await
+            .map_err(|e| e.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDateTime;
+    use domain_model::{
+        get_all_post::model::{Content, PostedDatetime, PostedUserName},
+        get_all_raw_post::err::ServiceError,
+        login::model::Role,
+        post::model::PostStatus,
+    };
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "moderator".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::get_all_raw_post::repository::MockGetAllRawPostRepository;
+
+        #[tokio::test]
+        async fn test_get_all_raw_post() {
+            let posts = vec![RawPost {
+                id: 1,
+                posted_user_name: PostedUserName("test".to_string()),
+                posted_datetime: PostedDatetime(
+                    NaiveDateTime::parse_from_str("2021-01-01T00:00:00Z", "%Y-%m-%dT%H:%M:%SZ")
+                        .unwrap(),
+                ),
+                status: PostStatus::Held,
+                content: Content("so bad".to_string()),
+                deletion: None,
+            }];
+            let expected = posts.clone();
+            let mut mock = MockGetAllRawPostRepository::new();
+            mock.expect_get_all_raw_post()
+                .times(1)
+                .returning(move || Ok(posts.clone()));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.get_all_raw_post(&moderator()).await;
+
+            assert_eq!(result, Ok(expected));
+        }
+
+        #[tokio::test]
+        async fn test_get_all_raw_post_permission_error() {
+            let mut mock = MockGetAllRawPostRepository::new();
+            mock.expect_get_all_raw_post().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let member = LoggedInUser {
+                id: 2,
+                name: "member".to_string(),
+                role: Role::Member,
+            };
+            let result = service.get_all_raw_post(&member).await;
+
+            assert_eq!(
+                result,
+                Err(GetAllRawPostError::PermissionError(PermissionError(
+                    "Only moderators can view raw posts".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_all_raw_post_service_error() {
+            let mut mock = MockGetAllRawPostRepository::new();
+            mock.expect_get_all_raw_post()
+                .times(1)
+                .returning(|| Err(ServiceError("Database error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.get_all_raw_post(&moderator()).await;
+
+            assert_eq!(
+                result,
+                Err(GetAllRawPostError::ServiceError(ServiceError(
+                    "Database error".to_string()
+                )))
+            );
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use domain_model::get_post::{err::ServiceError, model::PostView};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait GetPostRepository: Send + Sync {
+    /// 公開済みの投稿をIDで取得する。塗りつぶす前の内容を返す
+    ///
+    /// 削除された投稿は削除の印を返す。無いかモデレーション待ちの場合は`None`を返す
+    async fn find_post(&self, id: i32) -> Result<Option<PostView>, ServiceError>;
+}
@@ -0,0 +1,167 @@
+use super::repository::GetPostRepository;
+use crate::ng_word_policy::NgWordPolicy;
+use async_trait::async_trait;
+use domain_model::{
+    get_all_post::model::{Content, Post},
+    get_post::{
+        err::{GetPostError, NotFoundError},
+        model::PostView,
+    },
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    /// 公開済みの投稿を1件取得する
+    ///
+    /// 投稿取得と同じく、その時点のNGワードで塗りつぶす。ミュートは扱わない。
+    /// 削除された投稿は削除の印を返す
+    async fn get_post(&self, id: i32) -> Result<PostView, GetPostError>;
+}
+
+pub struct DomainServiceImpl {
+    get_post_repository: Arc<dyn GetPostRepository>,
+    ng_word_policy: Arc<dyn NgWordPolicy>,
+}
+impl DomainServiceImpl {
+    pub fn new(
+        get_post_repository: Arc<dyn GetPostRepository>,
+        ng_word_policy: Arc<dyn NgWordPolicy>,
+    ) -> Self {
+        Self {
+            get_post_repository,
+            ng_word_policy,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn get_post(&self, id: i32) -> Result<PostView, GetPostError> {
+        // モデレーション待ちの投稿は無いものとして扱い、存在を明かさない
+        let post = self
+            .get_post_repository
+            .find_post(id)
+            .await?
+            .ok_or_else(|| NotFoundError("Post not found".to_string()))?;
+        match post {
+            PostView::Published(post) => {
+                let content = self.ng_word_policy.mask_content(&post.content.0).await?;
+                Ok(PostView::Published(Post {
+                    content: Content(content),
+                    ..post
+                }))
+            }
+            PostView::Deleted(tombstone) => Ok(PostView::Deleted(tombstone)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::*;
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::{get_post::repository::MockGetPostRepository, ng_word_policy::ng_word_policy};
+        use chrono::NaiveDateTime;
+        use domain_model::{
+            get_all_post::model::{PostedDatetime, PostedUserName},
+            get_post::{err::ServiceError, model::Tombstone},
+            ng_word_policy::model::Severity,
+        };
+
+        fn post(content: &str) -> Post {
+            Post {
+                id: 1,
+                posted_user_name: PostedUserName("test".to_string()),
+                posted_datetime: PostedDatetime(
+                    NaiveDateTime::parse_from_str("2021-01-01T00:00:00Z", "%Y-%m-%dT%H:%M:%SZ")
+                        .unwrap(),
+                ),
+                content: Content(content.to_string()),
+                muted: false,
+                reply_count: 0,
+                reactions: vec![],
+            }
+        }
+
+        #[tokio::test]
+        async fn test_get_post() {
+            let mut mock = MockGetPostRepository::new();
+            mock.expect_find_post()
+                .with(eq(1))
+                .times(1)
+                .returning(|_| Ok(Some(PostView::Published(post("so bad")))));
+
+            let service = DomainServiceImpl::new(
+                Arc::new(mock),
+                ng_word_policy(&[("bad", Severity::Reject)], 1),
+            );
+            let result = service.get_post(1).await;
+
+            assert_eq!(result, Ok(PostView::Published(post("so ***"))));
+        }
+
+        #[tokio::test]
+        async fn test_get_post_deleted() {
+            let tombstone = Tombstone {
+                id: 1,
+                deleted_datetime: post("").posted_datetime.0,
+            };
+            let expected = tombstone.clone();
+            let mut mock = MockGetPostRepository::new();
+            mock.expect_find_post()
+                .with(eq(1))
+                .times(1)
+                .returning(move |_| Ok(Some(PostView::Deleted(tombstone.clone()))));
+
+            // 削除された投稿は塗りつぶす内容が無いので規則も読まない
+            let service = DomainServiceImpl::new(
+                Arc::new(mock),
+                ng_word_policy(&[("bad", Severity::Reject)], 0),
+            );
+            let result = service.get_post(1).await;
+
+            assert_eq!(result, Ok(PostView::Deleted(expected)));
+        }
+
+        #[tokio::test]
+        async fn test_get_post_not_found() {
+            let mut mock = MockGetPostRepository::new();
+            mock.expect_find_post()
+                .with(eq(2))
+                .times(1)
+                .returning(|_| Ok(None));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 0));
+            let result = service.get_post(2).await;
+
+            assert_eq!(
+                result,
+                Err(GetPostError::NotFoundError(NotFoundError(
+                    "Post not found".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_post_service_error() {
+            let mut mock = MockGetPostRepository::new();
+            mock.expect_find_post()
+                .times(1)
+                .returning(|_| Err(ServiceError("error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 0));
+            let result = service.get_post(1).await;
+
+            assert_eq!(
+                result,
+                Err(GetPostError::ServiceError(ServiceError(
+                    "error".to_string()
+                )))
+            );
+        }
+    }
+}
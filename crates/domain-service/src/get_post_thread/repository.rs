@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use domain_model::get_post_thread::{
+    err::ServiceError,
+    model::{Reply, ThreadPost},
+};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait GetPostThreadRepository: Send + Sync {
+    /// 投稿をIDで取得する。塗りつぶす前の内容を返す
+    ///
+    /// モデレーション待ちの投稿は含めない。削除された投稿は印にして返す
+    async fn find_post(&self, id: i32) -> Result<Option<ThreadPost>, ServiceError>;
+    /// 投稿への直接の返信を、古い順に取得する
+    ///
+    /// 公開済みの返信は塗りつぶす前の内容を返す。削除された返信とモデレーション待ちの返信は印にして返す
+    async fn find_replies(&self, parent_post_ids: &[i32]) -> Result<Vec<Reply>, ServiceError>;
+}
@@ -0,0 +1,341 @@
+use super::repository::GetPostThreadRepository;
+use crate::ng_word_policy::NgWordPolicy;
+use async_trait::async_trait;
+use domain_model::{
+    get_all_post::model::{Content, Post},
+    get_post_thread::{
+        err::{GetPostThreadError, NotFoundError},
+        model::{ThreadDepth, ThreadNode, ThreadPost},
+    },
+};
+use std::{collections::HashMap, sync::Arc};
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    /// 投稿と、その返信を木にして取得する
+    ///
+    /// 返信は`depth`の深さまで辿る。削除された投稿やモデレーション待ちの返信は、
+    /// その先の返信を辿れるように内容と投稿者を除いた印にする。
+    /// 投稿取得と同じく、その時点のNGワードで塗りつぶす
+    async fn get_post_thread(
+        &self,
+        id: i32,
+        depth: Option<usize>,
+    ) -> Result<ThreadNode, GetPostThreadError>;
+}
+
+pub struct DomainServiceImpl {
+    get_post_thread_repository: Arc<dyn GetPostThreadRepository>,
+    ng_word_policy: Arc<dyn NgWordPolicy>,
+}
+impl DomainServiceImpl {
+    pub fn new(
+        get_post_thread_repository: Arc<dyn GetPostThreadRepository>,
+        ng_word_policy: Arc<dyn NgWordPolicy>,
+    ) -> Self {
+        Self {
+            get_post_thread_repository,
+            ng_word_policy,
+        }
+    }
+
+    async fn mask(&self, post: ThreadPost) -> Result<ThreadPost, GetPostThreadError> {
+        let ThreadPost::Published(post) = post else {
+            return Ok(post);
+        };
+        let content = self.ng_word_policy.mask_content(&post.content.0).await?;
+        Ok(ThreadPost::Published(Post {
+            content: Content(content),
+            ..post
+        }))
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn get_post_thread(
+        &self,
+        id: i32,
+        depth: Option<usize>,
+    ) -> Result<ThreadNode, GetPostThreadError> {
+        let depth = ThreadDepth::new(depth)?;
+        let root = self
+            .get_post_thread_repository
+            .find_post(id)
+            .await?
+            .ok_or_else(|| NotFoundError("Post not found".to_string()))?;
+
+        // 1段ずつ返信を取得する。公開中の返信の数には印の返信が含まれないため、数に関わらず取得する
+        let mut levels = Vec::new();
+        let mut parent_post_ids = vec![root.id()];
+        for _ in 0..depth.value() {
+            if parent_post_ids.is_empty() {
+                break;
+            }
+            let replies = self
+                .get_post_thread_repository
+                .find_replies(&parent_post_ids)
+                .await?;
+            parent_post_ids = replies.iter().map(|reply| reply.post.id()).collect();
+            levels.push(replies);
+        }
+
+        // 深い段から組み立てる。同じ投稿への返信は取得した順に並ぶ
+        let mut children: HashMap<i32, Vec<ThreadNode>> = HashMap::new();
+        for replies in levels.into_iter().rev() {
+            let mut nodes: HashMap<i32, Vec<ThreadNode>> = HashMap::new();
+            for reply in replies {
+                let node = ThreadNode {
+                    replies: children.remove(&reply.post.id()).unwrap_or_default(),
+                    post: self.mask(reply.post).await?,
+                };
+                nodes.entry(reply.parent_post_id).or_default().push(node);
+            }
+            children = nodes;
+        }
+        Ok(ThreadNode {
+            replies: children.remove(&root.id()).unwrap_or_default(),
+            post: self.mask(root).await?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::*;
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::{
+            get_post_thread::repository::MockGetPostThreadRepository,
+            ng_word_policy::ng_word_policy,
+        };
+        use chrono::NaiveDateTime;
+        use domain_model::{
+            get_all_post::model::{PostedDatetime, PostedUserName},
+            get_post_thread::{
+                err::ValidationError,
+                model::{Reply, ThreadTombstone},
+            },
+            ng_word_policy::model::Severity,
+        };
+
+        fn post(id: i32, content: &str, reply_count: u64) -> ThreadPost {
+            ThreadPost::Published(Post {
+                id,
+                posted_user_name: PostedUserName("test".to_string()),
+                posted_datetime: PostedDatetime(
+                    NaiveDateTime::parse_from_str("2021-01-01T00:00:00Z", "%Y-%m-%dT%H:%M:%SZ")
+                        .unwrap(),
+                ),
+                content: Content(content.to_string()),
+                muted: false,
+                reply_count,
+                reactions: vec![],
+            })
+        }
+
+        fn tombstone(id: i32, reply_count: u64) -> ThreadPost {
+            ThreadPost::Tombstone(ThreadTombstone { id, reply_count })
+        }
+
+        fn reply(parent_post_id: i32, post: ThreadPost) -> Reply {
+            Reply {
+                parent_post_id,
+                post,
+            }
+        }
+
+        fn leaf(post: ThreadPost) -> ThreadNode {
+            ThreadNode {
+                post,
+                replies: vec![],
+            }
+        }
+
+        #[tokio::test]
+        async fn test_get_post_thread() {
+            let mut mock = MockGetPostThreadRepository::new();
+            mock.expect_find_post()
+                .with(eq(1))
+                .times(1)
+                .returning(|_| Ok(Some(post(1, "root", 2))));
+            mock.expect_find_replies()
+                .withf(|ids| ids == [1])
+                .times(1)
+                .returning(|_| {
+                    Ok(vec![
+                        reply(1, post(2, "so bad", 1)),
+                        reply(1, post(3, "b", 0)),
+                    ])
+                });
+            mock.expect_find_replies()
+                .withf(|ids| ids == [2, 3])
+                .times(1)
+                .returning(|_| Ok(vec![reply(2, post(4, "c", 0))]));
+            mock.expect_find_replies()
+                .withf(|ids| ids == [4])
+                .times(1)
+                .returning(|_| Ok(vec![]));
+
+            let service = DomainServiceImpl::new(
+                Arc::new(mock),
+                ng_word_policy(&[("bad", Severity::Reject)], 4),
+            );
+            let result = service.get_post_thread(1, None).await;
+
+            assert_eq!(
+                result,
+                Ok(ThreadNode {
+                    post: post(1, "root", 2),
+                    replies: vec![
+                        ThreadNode {
+                            post: post(2, "so ***", 1),
+                            replies: vec![leaf(post(4, "c", 0))],
+                        },
+                        leaf(post(3, "b", 0)),
+                    ],
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_post_thread_depth_limit() {
+            let mut mock = MockGetPostThreadRepository::new();
+            mock.expect_find_post()
+                .times(1)
+                .returning(|_| Ok(Some(post(1, "root", 1))));
+            mock.expect_find_replies()
+                .withf(|ids| ids == [1])
+                .times(1)
+                .returning(|_| Ok(vec![reply(1, post(2, "a", 3))]));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 2));
+            let result = service.get_post_thread(1, Some(1)).await;
+
+            // 深さの上限より先は含めないが、返信の数は残る
+            assert_eq!(
+                result,
+                Ok(ThreadNode {
+                    post: post(1, "root", 1),
+                    replies: vec![leaf(post(2, "a", 3))],
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_post_thread_without_replies() {
+            let mut mock = MockGetPostThreadRepository::new();
+            mock.expect_find_post()
+                .times(1)
+                .returning(|_| Ok(Some(post(1, "root", 0))));
+            mock.expect_find_replies()
+                .withf(|ids| ids == [1])
+                .times(1)
+                .returning(|_| Ok(vec![]));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 1));
+            let result = service.get_post_thread(1, None).await;
+
+            assert_eq!(result, Ok(leaf(post(1, "root", 0))));
+        }
+
+        #[tokio::test]
+        async fn test_get_post_thread_with_tombstone() {
+            let mut mock = MockGetPostThreadRepository::new();
+            mock.expect_find_post()
+                .times(1)
+                .returning(|_| Ok(Some(post(1, "root", 0))));
+            mock.expect_find_replies()
+                .withf(|ids| ids == [1])
+                .times(1)
+                .returning(|_| Ok(vec![reply(1, tombstone(2, 1))]));
+            mock.expect_find_replies()
+                .withf(|ids| ids == [2])
+                .times(1)
+                .returning(|_| Ok(vec![reply(2, post(3, "so bad", 0))]));
+            mock.expect_find_replies()
+                .withf(|ids| ids == [3])
+                .times(1)
+                .returning(|_| Ok(vec![]));
+
+            let service = DomainServiceImpl::new(
+                Arc::new(mock),
+                ng_word_policy(&[("bad", Severity::Reject)], 2),
+            );
+            let result = service.get_post_thread(1, None).await;
+
+            // 削除された返信の先の公開中の返信も辿る
+            assert_eq!(
+                result,
+                Ok(ThreadNode {
+                    post: post(1, "root", 0),
+                    replies: vec![ThreadNode {
+                        post: tombstone(2, 1),
+                        replies: vec![leaf(post(3, "so ***", 0))],
+                    }],
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_post_thread_deleted_root() {
+            let mut mock = MockGetPostThreadRepository::new();
+            mock.expect_find_post()
+                .times(1)
+                .returning(|_| Ok(Some(tombstone(1, 1))));
+            mock.expect_find_replies()
+                .withf(|ids| ids == [1])
+                .times(1)
+                .returning(|_| Ok(vec![reply(1, post(2, "a", 0))]));
+            mock.expect_find_replies()
+                .withf(|ids| ids == [2])
+                .times(1)
+                .returning(|_| Ok(vec![]));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 1));
+            let result = service.get_post_thread(1, None).await;
+
+            assert_eq!(
+                result,
+                Ok(ThreadNode {
+                    post: tombstone(1, 1),
+                    replies: vec![leaf(post(2, "a", 0))],
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_post_thread_not_found() {
+            let mut mock = MockGetPostThreadRepository::new();
+            mock.expect_find_post().times(1).returning(|_| Ok(None));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 0));
+            let result = service.get_post_thread(1, None).await;
+
+            assert_eq!(
+                result,
+                Err(GetPostThreadError::NotFoundError(NotFoundError(
+                    "Post not found".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_post_thread_invalid_depth() {
+            let mut mock = MockGetPostThreadRepository::new();
+            mock.expect_find_post().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 0));
+            let result = service.get_post_thread(1, Some(11)).await;
+
+            assert_eq!(
+                result,
+                Err(GetPostThreadError::ValidationError(ValidationError(
+                    "Depth must be between 1 and 10".to_string()
+                )))
+            );
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use domain_model::{import_ng_words::err::ServiceError, register_ng_word::model::NgWord};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ImportNgWordsRepository: Send + Sync {
+    /// 指定したNGワードのうち、登録済みのものを取得する
+    async fn find_existing_ng_words(&self, words: &[String]) -> Result<Vec<String>, ServiceError>;
+    /// NGワードを1つのトランザクションでまとめて登録する
+    ///
+    /// 1件でも登録に失敗した場合は1件も登録しない
+    async fn import_ng_words(&self, ng_words: &[NgWord], user_id: i32) -> Result<(), ServiceError>;
+}
@@ -0,0 +1,404 @@
+use super::repository::ImportNgWordsRepository;
+use async_trait::async_trait;
+use domain_model::{
+    import_ng_words::{
+        err::{ImportNgWordsError, PermissionError},
+        model::{ImportBatch, ImportReport, ImportRow, ImportRowResult},
+    },
+    login::model::LoggedInUser,
+    register_ng_word::model::NgWord,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    /// NGワードをまとめて検証し、全ての行が正しければまとめて登録する
+    ///
+    /// 読み込めなかった行も、その理由を行ごとの結果に含めて残りの行を検証する。
+    /// `dry_run`の場合は検証だけして登録しない
+    async fn import_ng_words(
+        &self,
+        rows: Vec<ImportRow>,
+        dry_run: bool,
+        user: &LoggedInUser,
+    ) -> Result<ImportReport, ImportNgWordsError>;
+}
+
+pub struct DomainServiceImpl {
+    import_ng_words_repository: Arc<dyn ImportNgWordsRepository>,
+}
+
+impl DomainServiceImpl {
+    pub fn new(import_ng_words_repository: Arc<dyn ImportNgWordsRepository>) -> Self {
+        Self {
+            import_ng_words_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn import_ng_words(
+        &self,
+        rows: Vec<ImportRow>,
+        dry_run: bool,
+        user: &LoggedInUser,
+    ) -> Result<ImportReport, ImportNgWordsError> {
+        if !user.role.can_manage_ng_words() {
+            return Err(ImportNgWordsError::from(PermissionError(
+                "Only moderators can import NG words".to_string(),
+            )));
+        }
+        let batch = ImportBatch::new(rows)?;
+        let validated: Vec<Result<NgWord, String>> = batch
+            .rows()
+            .iter()
+            .map(|row| match row {
+                ImportRow::Parsed(unvalidated) => {
+                    NgWord::from_unvalidated(unvalidated).map_err(|err| err.0)
+                }
+                ImportRow::Unreadable(reason) => Err(reason.clone()),
+            })
+            .collect();
+
+        // DBの一意制約と同じく、大文字小文字の違いだけなら重複として扱う
+        let words: Vec<String> = validated
+            .iter()
+            .flatten()
+            .map(|ng_word| ng_word.value().to_string())
+            .collect();
+        let existing: HashSet<String> = self
+            .import_ng_words_repository
+            .find_existing_ng_words(&words)
+            .await?
+            .into_iter()
+            .map(|word| word.to_lowercase())
+            .collect();
+
+        let mut first_rows: HashMap<String, usize> = HashMap::new();
+        let rows = batch
+            .rows()
+            .iter()
+            .zip(&validated)
+            .enumerate()
+            .map(|(index, (import_row, result))| {
+                let row = index + 1;
+                let error = match result {
+                    Err(err) => Some(err.clone()),
+                    Ok(ng_word) => {
+                        let key = ng_word.value().to_lowercase();
+                        if let Some(first_row) = first_rows.get(&key) {
+                            Some(format!("NgWord is duplicated with row {}", first_row))
+                        } else if existing.contains(&key) {
+                            first_rows.insert(key, row);
+                            Some("NgWord is already registered".to_string())
+                        } else {
+                            first_rows.insert(key, row);
+                            None
+                        }
+                    }
+                };
+                let ng_word = match import_row {
+                    ImportRow::Parsed(unvalidated) => unvalidated.ng_word.clone(),
+                    ImportRow::Unreadable(_) => String::new(),
+                };
+                ImportRowResult {
+                    row,
+                    ng_word,
+                    error,
+                }
+            })
+            .collect();
+
+        let mut report = ImportReport {
+            rows,
+            dry_run,
+            imported: false,
+        };
+        if dry_run || report.has_errors() {
+            return Ok(report);
+        }
+
+        let ng_words: Vec<NgWord> = validated.into_iter().flatten().collect();
+        self.import_ng_words_repository
+            .import_ng_words(&ng_words, user.id)
+            .await?;
+        report.imported = true;
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_model::{
+        import_ng_words::err::{ServiceError, ValidationError},
+        login::model::Role,
+        ng_word_policy::model::{PatternKind, Severity},
+        register_ng_word::model::UnvalidatedNgWord,
+    };
+    use mockall::predicate::*;
+
+    fn parsed(ng_word: &str, kind: &str, severity: &str) -> ImportRow {
+        ImportRow::Parsed(UnvalidatedNgWord {
+            ng_word: ng_word.to_string(),
+            kind: kind.to_string(),
+            severity: severity.to_string(),
+        })
+    }
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "moderator".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    fn valid_row(row: usize, ng_word: &str) -> ImportRowResult {
+        ImportRowResult {
+            row,
+            ng_word: ng_word.to_string(),
+            error: None,
+        }
+    }
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::import_ng_words::repository::MockImportNgWordsRepository;
+
+        #[tokio::test]
+        async fn test_import_ng_words() {
+            let mut mock = MockImportNgWordsRepository::new();
+            mock.expect_find_existing_ng_words()
+                .with(eq(vec!["bad".to_string(), "b*d".to_string()]))
+                .times(1)
+                .returning(|_| Ok(vec![]));
+            mock.expect_import_ng_words()
+                .with(
+                    eq(vec![
+                        NgWord::new("bad").unwrap(),
+                        NgWord::with_kind("b*d", PatternKind::Glob)
+                            .unwrap()
+                            .with_severity(Severity::Reject),
+                    ]),
+                    eq(1),
+                )
+                .times(1)
+                .returning(|_, _| Ok(()));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .import_ng_words(
+                    vec![
+                        parsed("bad", "literal", "mask"),
+                        parsed("b*d", "glob", "reject"),
+                    ],
+                    false,
+                    &moderator(),
+                )
+                .await;
+
+            assert_eq!(
+                result,
+                Ok(ImportReport {
+                    rows: vec![valid_row(1, "bad"), valid_row(2, "b*d")],
+                    dry_run: false,
+                    imported: true,
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_import_ng_words_dry_run() {
+            let mut mock = MockImportNgWordsRepository::new();
+            mock.expect_find_existing_ng_words()
+                .times(1)
+                .returning(|_| Ok(vec![]));
+            mock.expect_import_ng_words().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .import_ng_words(vec![parsed("bad", "literal", "mask")], true, &moderator())
+                .await;
+
+            assert_eq!(
+                result,
+                Ok(ImportReport {
+                    rows: vec![valid_row(1, "bad")],
+                    dry_run: true,
+                    imported: false,
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_import_ng_words_invalid_rows() {
+            let mut mock = MockImportNgWordsRepository::new();
+            mock.expect_find_existing_ng_words()
+                .with(eq(vec![
+                    "bad".to_string(),
+                    "BAD".to_string(),
+                    "evil".to_string(),
+                ]))
+                .times(1)
+                .returning(|_| Ok(vec!["Evil".to_string()]));
+            // 1行でも誤りがあれば1件も登録しない
+            mock.expect_import_ng_words().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .import_ng_words(
+                    vec![
+                        parsed("bad", "literal", "mask"),
+                        parsed("ba", "literal", "mask"),
+                        parsed("BAD", "literal", "mask"),
+                        parsed("evil", "literal", "mask"),
+                        parsed("worse", "literal", "ban"),
+                    ],
+                    false,
+                    &moderator(),
+                )
+                .await;
+
+            assert_eq!(
+                result,
+                Ok(ImportReport {
+                    rows: vec![
+                        valid_row(1, "bad"),
+                        ImportRowResult {
+                            row: 2,
+                            ng_word: "ba".to_string(),
+                            error: Some("NgWord must be at least 3 characters long".to_string()),
+                        },
+                        ImportRowResult {
+                            row: 3,
+                            ng_word: "BAD".to_string(),
+                            error: Some("NgWord is duplicated with row 1".to_string()),
+                        },
+                        ImportRowResult {
+                            row: 4,
+                            ng_word: "evil".to_string(),
+                            error: Some("NgWord is already registered".to_string()),
+                        },
+                        ImportRowResult {
+                            row: 5,
+                            ng_word: "worse".to_string(),
+                            error: Some("Severity must be one of mask, hold, reject".to_string()),
+                        },
+                    ],
+                    dry_run: false,
+                    imported: false,
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_import_ng_words_unreadable_rows() {
+            let mut mock = MockImportNgWordsRepository::new();
+            mock.expect_find_existing_ng_words()
+                .with(eq(vec!["bad".to_string(), "evil".to_string()]))
+                .times(1)
+                .returning(|_| Ok(vec![]));
+            mock.expect_import_ng_words().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .import_ng_words(
+                    vec![
+                        parsed("bad", "literal", "mask"),
+                        ImportRow::Unreadable("Invalid CSV: missing field `ng_word`".to_string()),
+                        parsed("evil", "literal", "mask"),
+                    ],
+                    false,
+                    &moderator(),
+                )
+                .await;
+
+            // 読み込めなかった行があっても残りの行を検証する
+            assert_eq!(
+                result,
+                Ok(ImportReport {
+                    rows: vec![
+                        valid_row(1, "bad"),
+                        ImportRowResult {
+                            row: 2,
+                            ng_word: "".to_string(),
+                            error: Some("Invalid CSV: missing field `ng_word`".to_string()),
+                        },
+                        valid_row(3, "evil"),
+                    ],
+                    dry_run: false,
+                    imported: false,
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_import_ng_words_empty() {
+            let mut mock = MockImportNgWordsRepository::new();
+            mock.expect_find_existing_ng_words().times(0);
+            mock.expect_import_ng_words().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.import_ng_words(vec![], false, &moderator()).await;
+
+            assert_eq!(
+                result,
+                Err(ImportNgWordsError::ValidationError(ValidationError(
+                    "At least one NG word is required".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_import_ng_words_permission_error() {
+            let mut mock = MockImportNgWordsRepository::new();
+            mock.expect_find_existing_ng_words().times(0);
+            mock.expect_import_ng_words().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let member = LoggedInUser {
+                id: 2,
+                name: "member".to_string(),
+                role: Role::Member,
+            };
+            let result = service
+                .import_ng_words(vec![parsed("bad", "literal", "mask")], false, &member)
+                .await;
+
+            assert_eq!(
+                result,
+                Err(ImportNgWordsError::PermissionError(PermissionError(
+                    "Only moderators can import NG words".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_import_ng_words_service_error() {
+            let mut mock = MockImportNgWordsRepository::new();
+            mock.expect_find_existing_ng_words()
+                .times(1)
+                .returning(|_| Ok(vec![]));
+            mock.expect_import_ng_words()
+                .times(1)
+                .returning(|_, _| Err(ServiceError("Database error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .import_ng_words(vec![parsed("bad", "literal", "mask")], false, &moderator())
+                .await;
+
+            assert_eq!(
+                result,
+                Err(ImportNgWordsError::ServiceError(ServiceError(
+                    "Database error".to_string()
+                )))
+            );
+        }
+    }
+}
@@ -1,5 +1,28 @@
+pub mod check_ng_words;
+pub mod delete_allow_word;
+pub mod delete_mute_word;
+pub mod delete_ng_word;
+pub mod delete_post;
+pub mod delete_reaction;
+pub mod export_ng_words;
 pub mod get_all_post;
+pub mod get_all_raw_post;
+pub mod get_post;
+pub mod get_post_thread;
+pub mod import_ng_words;
+pub mod list_allow_words;
+pub mod list_mute_words;
+pub mod list_ng_words;
+pub mod list_post_revisions;
 pub mod login;
+pub mod ng_word_policy;
 pub mod post;
+pub mod register_allow_word;
+pub mod register_mute_word;
 pub mod register_ng_word;
+pub mod register_reaction;
 pub mod register_user_name;
+pub mod restore_post;
+pub mod search_post;
+pub mod update_ng_word;
+pub mod update_post;
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use domain_model::list_allow_words::{err::ServiceError, model::AllowWord};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ListAllowWordsRepository: Send + Sync {
+    /// 登録済みの例外の語を全てID順に取得する
+    async fn find_all_allow_words(&self) -> Result<Vec<AllowWord>, ServiceError>;
+}
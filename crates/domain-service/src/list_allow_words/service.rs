@@ -0,0 +1,130 @@
+use super::repository::ListAllowWordsRepository;
+use async_trait::async_trait;
+use domain_model::{
+    list_allow_words::{
+        err::{ListAllowWordsError, PermissionError},
+        model::AllowWord,
+    },
+    login::model::LoggedInUser,
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    async fn list_allow_words(
+        &self,
+        user: &LoggedInUser,
+    ) -> Result<Vec<AllowWord>, ListAllowWordsError>;
+}
+
+pub struct DomainServiceImpl {
+    list_allow_words_repository: Arc<dyn ListAllowWordsRepository>,
+}
+
+impl DomainServiceImpl {
+    pub fn new(list_allow_words_repository: Arc<dyn ListAllowWordsRepository>) -> Self {
+        Self {
+            list_allow_words_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn list_allow_words(
+        &self,
+        user: &LoggedInUser,
+    ) -> Result<Vec<AllowWord>, ListAllowWordsError> {
+        if !user.role.can_manage_ng_words() {
+            return Err(ListAllowWordsError::from(PermissionError(
+                "Only moderators can list allow words".to_string(),
+            )));
+        }
+        self.list_allow_words_repository
+            .find_all_allow_words()
+            .await
+            .map_err(|e| e.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDateTime;
+    use domain_model::{list_allow_words::err::ServiceError, login::model::Role};
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "moderator".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::list_allow_words::repository::MockListAllowWordsRepository;
+
+        #[tokio::test]
+        async fn test_list_allow_words() {
+            let allow_words = vec![AllowWord {
+                id: 1,
+                word: "class".to_string(),
+                created_at: NaiveDateTime::parse_from_str(
+                    "2021-01-01T00:00:00Z",
+                    "%Y-%m-%dT%H:%M:%SZ",
+                )
+                .unwrap(),
+            }];
+            let expected = allow_words.clone();
+            let mut mock = MockListAllowWordsRepository::new();
+            mock.expect_find_all_allow_words()
+                .times(1)
+                .returning(move || Ok(allow_words.clone()));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.list_allow_words(&moderator()).await;
+
+            assert_eq!(result, Ok(expected));
+        }
+
+        #[tokio::test]
+        async fn test_list_allow_words_permission_error() {
+            let mut mock = MockListAllowWordsRepository::new();
+            mock.expect_find_all_allow_words().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let member = LoggedInUser {
+                id: 2,
+                name: "member".to_string(),
+                role: Role::Member,
+            };
+            let result = service.list_allow_words(&member).await;
+
+            assert_eq!(
+                result,
+                Err(ListAllowWordsError::PermissionError(PermissionError(
+                    "Only moderators can list allow words".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_list_allow_words_service_error() {
+            let mut mock = MockListAllowWordsRepository::new();
+            mock.expect_find_all_allow_words()
+                .times(1)
+                .returning(|| Err(ServiceError("Database error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.list_allow_words(&moderator()).await;
+
+            assert_eq!(
+                result,
+                Err(ListAllowWordsError::ServiceError(ServiceError(
+                    "Database error".to_string()
+                )))
+            );
+        }
+    }
+}
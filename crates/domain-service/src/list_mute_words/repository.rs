@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use domain_model::list_mute_words::{err::ServiceError, model::MuteWord};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ListMuteWordsRepository: Send + Sync {
+    /// ユーザがミュートした語を全てID順に取得する
+    async fn find_mute_words(&self, user_id: i32) -> Result<Vec<MuteWord>, ServiceError>;
+}
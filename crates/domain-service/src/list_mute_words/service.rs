@@ -0,0 +1,104 @@
+use super::repository::ListMuteWordsRepository;
+use async_trait::async_trait;
+use domain_model::{
+    list_mute_words::{err::ListMuteWordsError, model::MuteWord},
+    login::model::LoggedInUser,
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    /// ログイン中のユーザ自身がミュートした語を取得する
+    async fn list_mute_words(
+        &self,
+        user: &LoggedInUser,
+    ) -> Result<Vec<MuteWord>, ListMuteWordsError>;
+}
+
+pub struct DomainServiceImpl {
+    list_mute_words_repository: Arc<dyn ListMuteWordsRepository>,
+}
+
+impl DomainServiceImpl {
+    pub fn new(list_mute_words_repository: Arc<dyn ListMuteWordsRepository>) -> Self {
+        Self {
+            list_mute_words_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn list_mute_words(
+        &self,
+        user: &LoggedInUser,
+    ) -> Result<Vec<MuteWord>, ListMuteWordsError> {
+        self.list_mute_words_repository
+            .find_mute_words(user.id)
+            .await
+            .map_err(|e| e.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDateTime;
+    use domain_model::{list_mute_words::err::ServiceError, login::model::Role};
+    use mockall::predicate::*;
+
+    fn member() -> LoggedInUser {
+        LoggedInUser {
+            id: 2,
+            name: "member".to_string(),
+            role: Role::Member,
+        }
+    }
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::list_mute_words::repository::MockListMuteWordsRepository;
+
+        #[tokio::test]
+        async fn test_list_mute_words() {
+            let mute_words = vec![MuteWord {
+                id: 1,
+                word: "spoiler".to_string(),
+                created_at: NaiveDateTime::parse_from_str(
+                    "2021-01-01T00:00:00Z",
+                    "%Y-%m-%dT%H:%M:%SZ",
+                )
+                .unwrap(),
+            }];
+            let expected = mute_words.clone();
+            let mut mock = MockListMuteWordsRepository::new();
+            mock.expect_find_mute_words()
+                .with(eq(2))
+                .times(1)
+                .returning(move |_| Ok(mute_words.clone()));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.list_mute_words(&member()).await;
+
+            assert_eq!(result, Ok(expected));
+        }
+
+        #[tokio::test]
+        async fn test_list_mute_words_service_error() {
+            let mut mock = MockListMuteWordsRepository::new();
+            mock.expect_find_mute_words()
+                .times(1)
+                .returning(|_| Err(ServiceError("Database error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.list_mute_words(&member()).await;
+
+            assert_eq!(
+                result,
+                Err(ListMuteWordsError::ServiceError(ServiceError(
+                    "Database error".to_string()
+                )))
+            );
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use domain_model::list_ng_words::{
+    err::ServiceError,
+    model::{NgWordPage, NgWordQuery},
+};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ListNgWordsRepository: Send + Sync {
+    /// 検索条件に一致するNGワードをID順に1ページ分取得する
+    ///
+    /// キーワードはNGワードの部分一致で絞り込む
+    async fn find_ng_words(&self, query: &NgWordQuery) -> Result<NgWordPage, ServiceError>;
+}
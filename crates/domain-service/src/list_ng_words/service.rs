@@ -0,0 +1,184 @@
+use super::repository::ListNgWordsRepository;
+use async_trait::async_trait;
+use domain_model::{
+    list_ng_words::{
+        err::{ListNgWordsError, PermissionError},
+        model::{NgWordPage, NgWordQuery, UnvalidatedNgWordQuery},
+    },
+    login::model::LoggedInUser,
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    async fn list_ng_words(
+        &self,
+        query: UnvalidatedNgWordQuery,
+        user: &LoggedInUser,
+    ) -> Result<NgWordPage, ListNgWordsError>;
+}
+
+pub struct DomainServiceImpl {
+    list_ng_words_repository: Arc<dyn ListNgWordsRepository>,
+}
+
+impl DomainServiceImpl {
+    pub fn new(list_ng_words_repository: Arc<dyn ListNgWordsRepository>) -> Self {
+        Self {
+            list_ng_words_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn list_ng_words(
+        &self,
+        query: UnvalidatedNgWordQuery,
+        user: &LoggedInUser,
+    ) -> Result<NgWordPage, ListNgWordsError> {
+        if !user.role.can_manage_ng_words() {
+            return Err(ListNgWordsError::from(PermissionError(
+                "Only moderators can list NG words".to_string(),
+            )));
+        }
+        let query = NgWordQuery::new(query)?;
+        self.list_ng_words_repository
+            .find_ng_words(&query)
+            .await
+            .map_err(|e| e.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_model::{
+        list_ng_words::{
+            err::{ServiceError, ValidationError},
+            model::{NgWord, NgWordEditor},
+        },
+        login::model::Role,
+        ng_word_policy::model::{PatternKind, Severity},
+    };
+    use mockall::predicate::*;
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "moderator".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    fn page() -> NgWordPage {
+        let datetime =
+            chrono::NaiveDateTime::parse_from_str("2021-01-01T00:00:00Z", "%Y-%m-%dT%H:%M:%SZ")
+                .unwrap();
+        NgWordPage {
+            ng_words: vec![NgWord {
+                id: 1,
+                word: "bad".to_string(),
+                kind: PatternKind::Literal,
+                severity: Severity::Mask,
+                created_by: Some(NgWordEditor {
+                    id: 1,
+                    name: "moderator".to_string(),
+                }),
+                updated_by: None,
+                created_at: datetime,
+                updated_at: datetime,
+            }],
+            total: 21,
+            page: 2,
+            per_page: 20,
+        }
+    }
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::list_ng_words::repository::MockListNgWordsRepository;
+
+        #[tokio::test]
+        async fn test_list_ng_words() {
+            let query = UnvalidatedNgWordQuery {
+                keyword: Some("bad".to_string()),
+                page: Some(2),
+                per_page: None,
+            };
+            let mut mock = MockListNgWordsRepository::new();
+            mock.expect_find_ng_words()
+                .with(eq(NgWordQuery::new(query.clone()).unwrap()))
+                .times(1)
+                .returning(|_| Ok(page()));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.list_ng_words(query, &moderator()).await;
+
+            assert_eq!(result, Ok(page()));
+        }
+
+        #[tokio::test]
+        async fn test_list_ng_words_validation_error() {
+            let mut mock = MockListNgWordsRepository::new();
+            mock.expect_find_ng_words().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let query = UnvalidatedNgWordQuery {
+                page: Some(0),
+                ..Default::default()
+            };
+            let result = service.list_ng_words(query, &moderator()).await;
+
+            assert_eq!(
+                result,
+                Err(ListNgWordsError::ValidationError(ValidationError(
+                    "Page must be at least 1".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_list_ng_words_permission_error() {
+            let mut mock = MockListNgWordsRepository::new();
+            mock.expect_find_ng_words().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let member = LoggedInUser {
+                id: 2,
+                name: "member".to_string(),
+                role: Role::Member,
+            };
+            let result = service
+                .list_ng_words(UnvalidatedNgWordQuery::default(), &member)
+                .await;
+
+            assert_eq!(
+                result,
+                Err(ListNgWordsError::PermissionError(PermissionError(
+                    "Only moderators can list NG words".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_list_ng_words_service_error() {
+            let mut mock = MockListNgWordsRepository::new();
+            mock.expect_find_ng_words()
+                .times(1)
+                .returning(|_| Err(ServiceError("Database error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .list_ng_words(UnvalidatedNgWordQuery::default(), &moderator())
+                .await;
+
+            assert_eq!(
+                result,
+                Err(ListNgWordsError::ServiceError(ServiceError(
+                    "Database error".to_string()
+                )))
+            );
+        }
+    }
+}
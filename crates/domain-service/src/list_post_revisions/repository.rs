@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use domain_model::list_post_revisions::{err::ServiceError, model::PostRevision};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ListPostRevisionsRepository: Send + Sync {
+    /// 公開済みの投稿があるかを確認する
+    async fn exists_post(&self, post_id: i32) -> Result<bool, ServiceError>;
+    /// 投稿の編集前の版を新しい順に取得する。塗りつぶす前の内容を返す
+    async fn find_revisions(&self, post_id: i32) -> Result<Vec<PostRevision>, ServiceError>;
+}
@@ -0,0 +1,166 @@
+use super::repository::ListPostRevisionsRepository;
+use crate::ng_word_policy::NgWordPolicy;
+use async_trait::async_trait;
+use domain_model::{
+    get_all_post::model::Content,
+    list_post_revisions::{
+        err::{ListPostRevisionsError, NotFoundError},
+        model::PostRevision,
+    },
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    /// 公開済みの投稿の編集履歴を新しい順に取得する
+    ///
+    /// 投稿取得と同じく、その時点のNGワードで塗りつぶす
+    async fn list_post_revisions(
+        &self,
+        post_id: i32,
+    ) -> Result<Vec<PostRevision>, ListPostRevisionsError>;
+}
+
+pub struct DomainServiceImpl {
+    list_post_revisions_repository: Arc<dyn ListPostRevisionsRepository>,
+    ng_word_policy: Arc<dyn NgWordPolicy>,
+}
+
+impl DomainServiceImpl {
+    pub fn new(
+        list_post_revisions_repository: Arc<dyn ListPostRevisionsRepository>,
+        ng_word_policy: Arc<dyn NgWordPolicy>,
+    ) -> Self {
+        Self {
+            list_post_revisions_repository,
+            ng_word_policy,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn list_post_revisions(
+        &self,
+        post_id: i32,
+    ) -> Result<Vec<PostRevision>, ListPostRevisionsError> {
+        // モデレーション待ちの投稿は、投稿1件取得と同じく無いものとして扱う
+        if !self
+            .list_post_revisions_repository
+            .exists_post(post_id)
+            .await?
+        {
+            return Err(ListPostRevisionsError::from(NotFoundError(
+                "Post not found".to_string(),
+            )));
+        }
+        let revisions = self
+            .list_post_revisions_repository
+            .find_revisions(post_id)
+            .await?;
+        let mut masked_revisions = Vec::with_capacity(revisions.len());
+        for revision in revisions {
+            let content = self
+                .ng_word_policy
+                .mask_content(&revision.content.0)
+                .await?;
+            masked_revisions.push(PostRevision {
+                content: Content(content),
+                ..revision
+            });
+        }
+        Ok(masked_revisions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::*;
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::{
+            list_post_revisions::repository::MockListPostRevisionsRepository,
+            ng_word_policy::ng_word_policy,
+        };
+        use chrono::NaiveDateTime;
+        use domain_model::{
+            get_all_post::model::PostedDatetime, list_post_revisions::err::ServiceError,
+            ng_word_policy::model::Severity,
+        };
+
+        fn revision(id: i32, content: &str) -> PostRevision {
+            PostRevision {
+                id,
+                posted_datetime: PostedDatetime(
+                    NaiveDateTime::parse_from_str("2021-01-01T00:00:00Z", "%Y-%m-%dT%H:%M:%SZ")
+                        .unwrap(),
+                ),
+                content: Content(content.to_string()),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_list_post_revisions() {
+            let mut mock = MockListPostRevisionsRepository::new();
+            mock.expect_exists_post()
+                .with(eq(1))
+                .times(1)
+                .returning(|_| Ok(true));
+            mock.expect_find_revisions()
+                .with(eq(1))
+                .times(1)
+                .returning(|_| Ok(vec![revision(2, "so bad"), revision(1, "first")]));
+
+            let service = DomainServiceImpl::new(
+                Arc::new(mock),
+                ng_word_policy(&[("bad", Severity::Reject)], 2),
+            );
+            let result = service.list_post_revisions(1).await;
+
+            assert_eq!(
+                result,
+                Ok(vec![revision(2, "so ***"), revision(1, "first")])
+            );
+        }
+
+        #[tokio::test]
+        async fn test_list_post_revisions_not_found() {
+            let mut mock = MockListPostRevisionsRepository::new();
+            mock.expect_exists_post()
+                .with(eq(1))
+                .times(1)
+                .returning(|_| Ok(false));
+            mock.expect_find_revisions().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 0));
+            let result = service.list_post_revisions(1).await;
+
+            assert_eq!(
+                result,
+                Err(ListPostRevisionsError::NotFoundError(NotFoundError(
+                    "Post not found".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_list_post_revisions_service_error() {
+            let mut mock = MockListPostRevisionsRepository::new();
+            mock.expect_exists_post()
+                .times(1)
+                .returning(|_| Err(ServiceError("error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 0));
+            let result = service.list_post_revisions(1).await;
+
+            assert_eq!(
+                result,
+                Err(ListPostRevisionsError::ServiceError(ServiceError(
+                    "error".to_string()
+                )))
+            );
+        }
+    }
+}
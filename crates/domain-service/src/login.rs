@@ -1,5 +1,5 @@
 mod repository;
 mod service;
 
-pub use repository::LoginRepository;
+pub use repository::{LoginRepository, SessionRepository};
 pub use service::{DomainService, DomainServiceImpl};
@@ -1,5 +1,9 @@
 use async_trait::async_trait;
-use domain_model::login::{err::ServiceError, model::UncheckedUserName};
+use chrono::{DateTime, Utc};
+use domain_model::login::{
+    err::ServiceError,
+    model::{HashedPassword, LoggedInUser, Session, SessionToken, UncheckedUserName},
+};
 
 #[cfg(test)]
 use mockall::automock;
@@ -7,5 +11,34 @@ use mockall::automock;
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait LoginRepository: Send + Sync {
-    async fn user_exists(&self, user: &UncheckedUserName) -> Result<bool, ServiceError>;
+    /// ユーザ名に完全一致するユーザのIDを取得する
+    ///
+    /// 比較はDBの照合順序に従い、前方一致や部分一致はしない
+    async fn find_user_id(&self, user: &UncheckedUserName) -> Result<Option<i32>, ServiceError>;
+    /// ユーザのパスワードハッシュを取得する
+    ///
+    /// パスワードが未設定の場合は`None`を返す
+    async fn find_password_hash(
+        &self,
+        user_id: i32,
+    ) -> Result<Option<HashedPassword>, ServiceError>;
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait SessionRepository: Send + Sync {
+    /// ユーザに紐づくセッションを保存する
+    async fn create_session(&self, user_id: i32, session: &Session) -> Result<(), ServiceError>;
+    /// `now`時点で有効なセッションのユーザを取得する
+    async fn find_session_user(
+        &self,
+        token: &SessionToken,
+        now: DateTime<Utc>,
+    ) -> Result<Option<LoggedInUser>, ServiceError>;
+    /// セッションを失効させる
+    async fn revoke_session(&self, token: &SessionToken) -> Result<(), ServiceError>;
+    /// ユーザの全セッションを失効させる
+    async fn revoke_all_sessions(&self, user_id: i32) -> Result<(), ServiceError>;
+    /// `now`時点で期限切れのセッションを削除し、削除件数を返す
+    async fn delete_expired_sessions(&self, now: DateTime<Utc>) -> Result<u64, ServiceError>;
 }
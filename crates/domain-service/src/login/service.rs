@@ -1,35 +1,101 @@
-use super::repository::LoginRepository;
+use super::repository::{LoginRepository, SessionRepository};
 use async_trait::async_trait;
+use chrono::Utc;
 use domain_model::login::{
-    err::{LoginError, ValidationError},
-    model::UncheckedUserName,
+    err::{CredentialError, LoginError, ServiceError, SessionError},
+    model::{
+        HashedPassword, LoggedInUser, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+    },
 };
 use std::sync::Arc;
 
 #[async_trait]
 pub trait DomainService: Send + Sync {
-    async fn login(&self, user: UncheckedUserName) -> Result<(), LoginError>;
+    async fn login(
+        &self,
+        user: UncheckedUserName,
+        password: UncheckedPassword,
+    ) -> Result<Session, LoginError>;
+    async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+    async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+    async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+    async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
 }
 
 pub struct DomainServiceImpl {
     login_repository: Arc<dyn LoginRepository>,
+    session_repository: Arc<dyn SessionRepository>,
 }
 impl DomainServiceImpl {
-    pub fn new(login_repository: Arc<dyn LoginRepository>) -> Self {
-        Self { login_repository }
+    pub fn new(
+        login_repository: Arc<dyn LoginRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+    ) -> Self {
+        Self {
+            login_repository,
+            session_repository,
+        }
     }
 }
 
 #[async_trait]
 impl DomainService for DomainServiceImpl {
-    async fn login(&self, user: UncheckedUserName) -> Result<(), LoginError> {
-        if !self.login_repository.user_exists(&user).await? {
-            return Err(LoginError::from(ValidationError(
-                "User not found".to_string(),
-            )));
-        }
+    async fn login(
+        &self,
+        user: UncheckedUserName,
+        password: UncheckedPassword,
+    ) -> Result<Session, LoginError> {
+        // ユーザの存在有無が分からないよう、どちらの場合も同じエラーを返す
+        let invalid_credential =
+            || LoginError::from(CredentialError("Invalid user name or password".to_string()));
+
+        let user_id = self.login_repository.find_user_id(&user).await?;
+        let hashed_password = match user_id {
+            Some(user_id) => self.login_repository.find_password_hash(user_id).await?,
+            None => None,
+        };
+        // 応答時間からも存在有無が分からないよう、ユーザやハッシュが無い場合もダミーのハッシュで検証する
+        let (user_id, hashed_password) = match (user_id, hashed_password) {
+            (Some(user_id), Some(hashed_password)) => (Some(user_id), hashed_password),
+            _ => (None, HashedPassword::dummy()),
+        };
+
+        // ハッシュ計算は重いのでブロッキング用のスレッドで行う
+        let verified = tokio::task::spawn_blocking(move || hashed_password.verify(&password))
+            .await
+            .map_err(|_| ServiceError("Failed to verify password".to_string()))?;
+        let Some(user_id) = user_id.filter(|_| verified) else {
+            return Err(invalid_credential());
+        };
+
+        let session = Session::new(Utc::now());
+        self.session_repository
+            .create_session(user_id, &session)
+            .await?;
+
+        Ok(session)
+    }
+
+    async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError> {
+        self.session_repository
+            .find_session_user(&token, Utc::now())
+            .await?
+            .ok_or_else(|| LoginError::from(SessionError("Invalid or expired session".to_string())))
+    }
+
+    async fn logout(&self, token: SessionToken) -> Result<(), LoginError> {
+        Ok(self.session_repository.revoke_session(&token).await?)
+    }
+
+    async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError> {
+        Ok(self.session_repository.revoke_all_sessions(user.id).await?)
+    }
 
-        Ok(())
+    async fn purge_expired_sessions(&self) -> Result<u64, LoginError> {
+        Ok(self
+            .session_repository
+            .delete_expired_sessions(Utc::now())
+            .await?)
     }
 }
 
@@ -39,41 +105,159 @@ mod tests {
     use mockall::predicate::*;
 
     mod domain_service_impl {
-        use domain_model::login::err::ServiceError;
-
         use super::*;
-        use crate::login::repository::MockLoginRepository;
+        use crate::login::repository::{MockLoginRepository, MockSessionRepository};
+        use domain_model::login::model::Role;
+        use domain_model::{login::model::HashedPassword, register_user_name::model::Password};
+
+        fn hashed_password() -> HashedPassword {
+            let hashed = Password::new("password1").unwrap().hash().unwrap();
+            HashedPassword(hashed.value().to_string())
+        }
 
         #[tokio::test]
         async fn test_login() {
+            let hashed = hashed_password();
             let mut mock = MockLoginRepository::new();
-            mock.expect_user_exists()
+            let mut session_mock = MockSessionRepository::new();
+            mock.expect_find_user_id()
                 .with(eq(UncheckedUserName("test".to_string())))
                 .times(1)
-                .returning(|_| Ok(true));
+                .returning(|_| Ok(Some(1)));
+            mock.expect_find_password_hash()
+                .with(eq(1))
+                .times(1)
+                .returning(move |_| Ok(Some(hashed.clone())));
+            session_mock
+                .expect_create_session()
+                .with(eq(1), always())
+                .times(1)
+                .returning(|_, _| Ok(()));
 
-            let service = DomainServiceImpl::new(Arc::new(mock));
-            let result = service.login(UncheckedUserName("test".to_string())).await;
+            let service = DomainServiceImpl::new(Arc::new(mock), Arc::new(session_mock));
+            let result = service
+                .login(
+                    UncheckedUserName("test".to_string()),
+                    UncheckedPassword("password1".to_string()),
+                )
+                .await;
 
             assert!(result.is_ok());
-            assert_eq!(result, Ok(()));
+            assert!(result.unwrap().expires_at > Utc::now());
         }
         #[tokio::test]
         async fn test_login_user_not_found() {
             let mut mock = MockLoginRepository::new();
-            mock.expect_user_exists()
+            let mut session_mock = MockSessionRepository::new();
+            mock.expect_find_user_id()
                 .with(eq(UncheckedUserName("test".to_string())))
                 .times(1)
-                .returning(|_| Ok(false));
+                .returning(|_| Ok(None));
+            mock.expect_find_password_hash().times(0);
+            session_mock.expect_create_session().times(0);
 
-            let service = DomainServiceImpl::new(Arc::new(mock));
-            let result = service.login(UncheckedUserName("test".to_string())).await;
+            let service = DomainServiceImpl::new(Arc::new(mock), Arc::new(session_mock));
+            let result = service
+                .login(
+                    UncheckedUserName("test".to_string()),
+                    UncheckedPassword("password1".to_string()),
+                )
+                .await;
 
             assert!(result.is_err());
             assert_eq!(
                 result,
-                Err(LoginError::from(ValidationError(
-                    "User not found".to_string()
+                Err(LoginError::from(CredentialError(
+                    "Invalid user name or password".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_login_password_hash_not_found() {
+            let mut mock = MockLoginRepository::new();
+            let mut session_mock = MockSessionRepository::new();
+            mock.expect_find_user_id()
+                .times(1)
+                .returning(|_| Ok(Some(1)));
+            mock.expect_find_password_hash()
+                .with(eq(1))
+                .times(1)
+                .returning(|_| Ok(None));
+            session_mock.expect_create_session().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock), Arc::new(session_mock));
+            let result = service
+                .login(
+                    UncheckedUserName("test".to_string()),
+                    UncheckedPassword("password1".to_string()),
+                )
+                .await;
+
+            // ユーザがいない場合と同じエラーにする
+            assert_eq!(
+                result,
+                Err(LoginError::from(CredentialError(
+                    "Invalid user name or password".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_login_prefix_user_name() {
+            // "bobby"が存在しても"bob"ではログインできない
+            let mut mock = MockLoginRepository::new();
+            let mut session_mock = MockSessionRepository::new();
+            mock.expect_find_user_id()
+                .with(eq(UncheckedUserName("bob".to_string())))
+                .times(1)
+                .returning(|_| Ok(None));
+            mock.expect_find_password_hash().times(0);
+            session_mock.expect_create_session().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock), Arc::new(session_mock));
+            let result = service
+                .login(
+                    UncheckedUserName("bob".to_string()),
+                    UncheckedPassword("password1".to_string()),
+                )
+                .await;
+
+            assert_eq!(
+                result,
+                Err(LoginError::from(CredentialError(
+                    "Invalid user name or password".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_login_wrong_password() {
+            let hashed = hashed_password();
+            let mut mock = MockLoginRepository::new();
+            let mut session_mock = MockSessionRepository::new();
+            mock.expect_find_user_id()
+                .with(eq(UncheckedUserName("test".to_string())))
+                .times(1)
+                .returning(|_| Ok(Some(1)));
+            mock.expect_find_password_hash()
+                .with(eq(1))
+                .times(1)
+                .returning(move |_| Ok(Some(hashed.clone())));
+            session_mock.expect_create_session().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock), Arc::new(session_mock));
+            let result = service
+                .login(
+                    UncheckedUserName("test".to_string()),
+                    UncheckedPassword("password2".to_string()),
+                )
+                .await;
+
+            assert_eq!(
+                result,
+                Err(LoginError::from(CredentialError(
+                    "Invalid user name or password".to_string()
                 )))
             );
         }
@@ -81,13 +265,19 @@ mod tests {
         #[tokio::test]
         async fn test_login_error() {
             let mut mock = MockLoginRepository::new();
-            mock.expect_user_exists()
+            let session_mock = MockSessionRepository::new();
+            mock.expect_find_user_id()
                 .with(eq(UncheckedUserName("test".to_string())))
                 .times(1)
                 .returning(|_| Err(ServiceError("error".to_string())));
 
-            let service = DomainServiceImpl::new(Arc::new(mock));
-            let result = service.login(UncheckedUserName("test".to_string())).await;
+            let service = DomainServiceImpl::new(Arc::new(mock), Arc::new(session_mock));
+            let result = service
+                .login(
+                    UncheckedUserName("test".to_string()),
+                    UncheckedPassword("password1".to_string()),
+                )
+                .await;
 
             assert!(result.is_err());
             assert_eq!(
@@ -95,5 +285,180 @@ mod tests {
                 Err(LoginError::from(ServiceError("error".to_string())))
             );
         }
+
+        #[tokio::test]
+        async fn test_login_create_session_error() {
+            let hashed = hashed_password();
+            let mut mock = MockLoginRepository::new();
+            let mut session_mock = MockSessionRepository::new();
+            mock.expect_find_user_id()
+                .times(1)
+                .returning(|_| Ok(Some(1)));
+            mock.expect_find_password_hash()
+                .times(1)
+                .returning(move |_| Ok(Some(hashed.clone())));
+            session_mock
+                .expect_create_session()
+                .times(1)
+                .returning(|_, _| Err(ServiceError("error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), Arc::new(session_mock));
+            let result = service
+                .login(
+                    UncheckedUserName("test".to_string()),
+                    UncheckedPassword("password1".to_string()),
+                )
+                .await;
+
+            assert_eq!(
+                result,
+                Err(LoginError::from(ServiceError("error".to_string())))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_authenticate() {
+            let mock = MockLoginRepository::new();
+            let mut session_mock = MockSessionRepository::new();
+            session_mock
+                .expect_find_session_user()
+                .with(eq(SessionToken("token".to_string())), always())
+                .times(1)
+                .returning(|_, _| {
+                    Ok(Some(LoggedInUser {
+                        id: 1,
+                        name: "test".to_string(),
+                        role: Role::Member,
+                    }))
+                });
+
+            let service = DomainServiceImpl::new(Arc::new(mock), Arc::new(session_mock));
+            let result = service
+                .authenticate(SessionToken("token".to_string()))
+                .await;
+
+            assert_eq!(
+                result,
+                Ok(LoggedInUser {
+                    id: 1,
+                    name: "test".to_string(),
+                    role: Role::Member,
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_authenticate_invalid_session() {
+            let mock = MockLoginRepository::new();
+            let mut session_mock = MockSessionRepository::new();
+            session_mock
+                .expect_find_session_user()
+                .with(eq(SessionToken("token".to_string())), always())
+                .times(1)
+                .returning(|_, _| Ok(None));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), Arc::new(session_mock));
+            let result = service
+                .authenticate(SessionToken("token".to_string()))
+                .await;
+
+            assert_eq!(
+                result,
+                Err(LoginError::from(SessionError(
+                    "Invalid or expired session".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_authenticate_error() {
+            let mock = MockLoginRepository::new();
+            let mut session_mock = MockSessionRepository::new();
+            session_mock
+                .expect_find_session_user()
+                .times(1)
+                .returning(|_, _| Err(ServiceError("error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), Arc::new(session_mock));
+            let result = service
+                .authenticate(SessionToken("token".to_string()))
+                .await;
+
+            assert_eq!(
+                result,
+                Err(LoginError::from(ServiceError("error".to_string())))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_logout() {
+            let mock = MockLoginRepository::new();
+            let mut session_mock = MockSessionRepository::new();
+            session_mock
+                .expect_revoke_session()
+                .with(eq(SessionToken("token".to_string())))
+                .times(1)
+                .returning(|_| Ok(()));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), Arc::new(session_mock));
+            let result = service.logout(SessionToken("token".to_string())).await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_logout_error() {
+            let mock = MockLoginRepository::new();
+            let mut session_mock = MockSessionRepository::new();
+            session_mock
+                .expect_revoke_session()
+                .times(1)
+                .returning(|_| Err(ServiceError("error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), Arc::new(session_mock));
+            let result = service.logout(SessionToken("token".to_string())).await;
+
+            assert_eq!(
+                result,
+                Err(LoginError::from(ServiceError("error".to_string())))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_revoke_all_sessions() {
+            let mock = MockLoginRepository::new();
+            let mut session_mock = MockSessionRepository::new();
+            session_mock
+                .expect_revoke_all_sessions()
+                .with(eq(1))
+                .times(1)
+                .returning(|_| Ok(()));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), Arc::new(session_mock));
+            let result = service
+                .revoke_all_sessions(&LoggedInUser {
+                    id: 1,
+                    name: "test".to_string(),
+                    role: Role::Member,
+                })
+                .await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_purge_expired_sessions() {
+            let mock = MockLoginRepository::new();
+            let mut session_mock = MockSessionRepository::new();
+            session_mock
+                .expect_delete_expired_sessions()
+                .times(1)
+                .returning(|_| Ok(3));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), Arc::new(session_mock));
+            let result = service.purge_expired_sessions().await;
+
+            assert_eq!(result, Ok(3));
+        }
     }
 }
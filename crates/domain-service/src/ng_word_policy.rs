@@ -0,0 +1,9 @@
+mod repository;
+mod service;
+
+#[cfg(test)]
+pub(crate) use repository::MockNgWordPolicyRepository;
+pub use repository::NgWordPolicyRepository;
+#[cfg(test)]
+pub(crate) use service::ng_word_policy;
+pub use service::{NgWordPolicy, NgWordPolicyImpl};
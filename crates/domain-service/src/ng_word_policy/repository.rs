@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use domain_model::ng_word_policy::{err::ServiceError, model::NgWordRuleSet};
+use std::sync::Arc;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait NgWordPolicyRepository: Send + Sync {
+    /// 登録済みのNGワード全ての規則を、例外の語を設定したうえで取得する
+    async fn find_ng_word_rules(&self) -> Result<Arc<NgWordRuleSet>, ServiceError>;
+}
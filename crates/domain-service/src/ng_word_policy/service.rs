@@ -0,0 +1,403 @@
+use super::repository::NgWordPolicyRepository;
+use async_trait::async_trait;
+use domain_model::{
+    ng_word_policy::{
+        err::ServiceError,
+        model::{ContentVerdict, NgWordCheck, NgWordMatch, NgWordRule, Severity},
+    },
+    text::{self, NormalizedText},
+};
+use std::{ops::Range, sync::Arc};
+
+/// NGワードの塗りつぶしに使う文字
+const MASK_CHAR: char = '*';
+
+/// NGワードの判定方針
+///
+/// ユーザ名でも投稿でも同じ照合をするため、NGワードの判定は全てここを通す
+#[async_trait]
+pub trait NgWordPolicy: Send + Sync {
+    /// NGワードを含むかを判定する
+    ///
+    /// 一致した時の対応に関わらず、いずれかのNGワードを含めば`true`を返す
+    async fn contains_ng_word(&self, text: &str) -> Result<bool, ServiceError>;
+    /// 投稿内容をNGワードの規則に照らす
+    ///
+    /// `reject`、`hold`、`mask`の順に優先する。
+    /// `mask`の規則に一致した箇所は、書記素1つにつき`*`1文字で塗りつぶす
+    async fn check_content(&self, text: &str) -> Result<ContentVerdict, ServiceError>;
+    /// 投稿内容をNGワードの規則に照らし、一致した箇所も合わせて返す
+    ///
+    /// 照らした結果は`check_content`と同じ。一致した箇所はコードポイント単位の範囲で返す
+    async fn check_text(&self, text: &str) -> Result<NgWordCheck, ServiceError>;
+    /// 保存済みの内容のNGワードを塗りつぶす
+    ///
+    /// 保存済みの内容は拒否も保留もできないため、一致した時の対応に関わらず
+    /// 一致した箇所を全て塗りつぶす
+    async fn mask_content(&self, text: &str) -> Result<String, ServiceError>;
+}
+
+pub struct NgWordPolicyImpl {
+    ng_word_policy_repository: Arc<dyn NgWordPolicyRepository>,
+}
+impl NgWordPolicyImpl {
+    pub fn new(ng_word_policy_repository: Arc<dyn NgWordPolicyRepository>) -> Self {
+        Self {
+            ng_word_policy_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl NgWordPolicy for NgWordPolicyImpl {
+    async fn contains_ng_word(&self, text: &str) -> Result<bool, ServiceError> {
+        let ng_word_rules = self.ng_word_policy_repository.find_ng_word_rules().await?;
+        Ok(ng_word_rules.is_match(&NormalizedText::new(text)))
+    }
+
+    async fn check_content(&self, text: &str) -> Result<ContentVerdict, ServiceError> {
+        self.check_text(text).await.map(|check| check.verdict)
+    }
+
+    async fn check_text(&self, text: &str) -> Result<NgWordCheck, ServiceError> {
+        let ng_word_rules = self.ng_word_policy_repository.find_ng_word_rules().await?;
+        let found = ng_word_rules.find_all(&NormalizedText::new(text));
+        let matches = found
+            .iter()
+            .map(|(rule, range)| NgWordMatch {
+                rule: (*rule).clone(),
+                matched: text[range.clone()].to_string(),
+                range: text::char_range(text, range.clone()),
+            })
+            .collect();
+        Ok(NgWordCheck {
+            matches,
+            verdict: verdict(text, &found),
+        })
+    }
+
+    async fn mask_content(&self, text: &str) -> Result<String, ServiceError> {
+        let ng_word_rules = self.ng_word_policy_repository.find_ng_word_rules().await?;
+        let ranges: Vec<_> = ng_word_rules
+            .find_all(&NormalizedText::new(text))
+            .into_iter()
+            .map(|(_, range)| range)
+            .collect();
+        Ok(text::mask_ranges(text, &ranges, MASK_CHAR))
+    }
+}
+
+/// 一致した規則から投稿内容の扱いを決める
+fn verdict(text: &str, found: &[(&NgWordRule, Range<usize>)]) -> ContentVerdict {
+    if let Some((rule, _)) = found
+        .iter()
+        .find(|(rule, _)| rule.severity == Severity::Reject)
+    {
+        return ContentVerdict::Reject((*rule).clone());
+    }
+    let mask_ranges: Vec<_> = found
+        .iter()
+        .filter(|(rule, _)| rule.severity == Severity::Mask)
+        .map(|(_, range)| range.clone())
+        .collect();
+    let content = text::mask_ranges(text, &mask_ranges, MASK_CHAR);
+    match found
+        .iter()
+        .find(|(rule, _)| rule.severity == Severity::Hold)
+    {
+        Some((rule, _)) => ContentVerdict::Hold {
+            content,
+            rule: (*rule).clone(),
+        },
+        None => ContentVerdict::Accept(content),
+    }
+}
+
+/// 他のドメインサービスのテストで使う、字面どおりのNGワードを返す判定方針
+///
+/// NGワードの規則は`times`回取得されることを期待する
+#[cfg(test)]
+pub(crate) fn ng_word_policy(ng_words: &[(&str, Severity)], times: usize) -> Arc<NgWordPolicyImpl> {
+    use super::repository::MockNgWordPolicyRepository;
+    use domain_model::ng_word_policy::model::{NgWordRuleSet, PatternKind};
+
+    let rules = ng_words
+        .iter()
+        .zip(1..)
+        .map(|((word, severity), id)| NgWordRule {
+            id,
+            word: word.to_string(),
+            kind: PatternKind::Literal,
+            severity: *severity,
+        })
+        .collect();
+    let rule_set = Arc::new(NgWordRuleSet::new(rules).unwrap());
+    let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
+    ng_word_policy_repository
+        .expect_find_ng_word_rules()
+        .times(times)
+        .returning(move || Ok(rule_set.clone()));
+    Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod ng_word_policy_impl {
+        use super::*;
+        use crate::ng_word_policy::repository::MockNgWordPolicyRepository;
+        use domain_model::ng_word_policy::model::{NgWordRule, NgWordRuleSet, PatternKind};
+
+        fn rules(ng_words: &[(&str, PatternKind, Severity)]) -> Vec<NgWordRule> {
+            ng_words
+                .iter()
+                .zip(1..)
+                .map(|((word, kind, severity), id)| NgWordRule {
+                    id,
+                    word: word.to_string(),
+                    kind: *kind,
+                    severity: *severity,
+                })
+                .collect()
+        }
+
+        fn policy_with_kinds(ng_words: &[(&str, PatternKind, Severity)]) -> NgWordPolicyImpl {
+            let rule_set = Arc::new(NgWordRuleSet::new(rules(ng_words)).unwrap());
+            let mut mock = MockNgWordPolicyRepository::new();
+            mock.expect_find_ng_word_rules()
+                .times(1)
+                .returning(move || Ok(rule_set.clone()));
+            NgWordPolicyImpl::new(Arc::new(mock))
+        }
+
+        fn policy_with(ng_words: &[(&str, Severity)]) -> NgWordPolicyImpl {
+            let ng_words: Vec<_> = ng_words
+                .iter()
+                .map(|(word, severity)| (*word, PatternKind::Literal, *severity))
+                .collect();
+            policy_with_kinds(&ng_words)
+        }
+
+        fn policy(ng_words: &[&str]) -> NgWordPolicyImpl {
+            let ng_words: Vec<_> = ng_words
+                .iter()
+                .map(|word| (*word, Severity::Mask))
+                .collect();
+            policy_with(&ng_words)
+        }
+
+        #[tokio::test]
+        async fn test_contains_ng_word() {
+            let result = policy(&["bad"]).contains_ng_word("so bad").await;
+            assert_eq!(result, Ok(true));
+        }
+
+        #[tokio::test]
+        async fn test_contains_ng_word_normalized() {
+            for text in ["ＢＡＤ", "Bad", "ばっど", "ﾊﾞｯﾄﾞ"] {
+                let result = policy(&["bad", "バッド"]).contains_ng_word(text).await;
+                assert_eq!(result, Ok(true), "{}", text);
+            }
+        }
+
+        #[tokio::test]
+        async fn test_contains_ng_word_not_found() {
+            let result = policy(&["bad"]).contains_ng_word("good").await;
+            assert_eq!(result, Ok(false));
+        }
+
+        #[tokio::test]
+        async fn test_contains_ng_word_any_severity() {
+            // ユーザ名は対応に関わらず拒否する
+            for severity in [Severity::Mask, Severity::Hold, Severity::Reject] {
+                let result = policy_with(&[("bad", severity)])
+                    .contains_ng_word("bad")
+                    .await;
+                assert_eq!(result, Ok(true), "{:?}", severity);
+            }
+        }
+
+        #[tokio::test]
+        async fn test_wildcard_characters_are_literal() {
+            // SQLのLIKEのように%や_をワイルドカードとして扱わない
+            let policy = || policy(&["a%b", "c_d"]);
+            assert_eq!(policy().contains_ng_word("aXXb cXd").await, Ok(false));
+            assert_eq!(policy().contains_ng_word("a%b").await, Ok(true));
+            assert_eq!(
+                policy().check_content("a%b cXd c_d").await,
+                Ok(ContentVerdict::Accept("*** cXd ***".to_string()))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_check_content_mask() {
+            let result = policy(&["bad", "禁止語"])
+                .check_content("bad な禁止語👍")
+                .await;
+            assert_eq!(
+                result,
+                Ok(ContentVerdict::Accept("*** な***👍".to_string()))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_check_content_mask_normalized() {
+            let result = policy(&["bad", "バッド"])
+                .check_content("ＢＡＤ Bad ばっど ﾊﾞｯﾄﾞ")
+                .await;
+            assert_eq!(
+                result,
+                Ok(ContentVerdict::Accept("*** *** *** ***".to_string()))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_check_content_reject() {
+            let result = policy_with(&[
+                ("bad", Severity::Mask),
+                ("evil", Severity::Reject),
+                ("spam", Severity::Hold),
+            ])
+            .check_content("bad spam EVIL")
+            .await;
+            assert_eq!(
+                result,
+                Ok(ContentVerdict::Reject(NgWordRule {
+                    id: 2,
+                    word: "evil".to_string(),
+                    kind: PatternKind::Literal,
+                    severity: Severity::Reject,
+                }))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_check_content_hold() {
+            // 保留の規則に一致した箇所はモデレータが確認できるよう塗りつぶさない
+            let result = policy_with(&[("bad", Severity::Mask), ("spam", Severity::Hold)])
+                .check_content("bad spam")
+                .await;
+            assert_eq!(
+                result,
+                Ok(ContentVerdict::Hold {
+                    content: "*** spam".to_string(),
+                    rule: NgWordRule {
+                        id: 2,
+                        word: "spam".to_string(),
+                        kind: PatternKind::Literal,
+                        severity: Severity::Hold,
+                    },
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_check_content_patterns() {
+            let policy = || {
+                policy_with_kinds(&[
+                    ("b*a*d", PatternKind::Glob, Severity::Mask),
+                    (r"e[v\W_]*[i1!]l", PatternKind::Regex, Severity::Mask),
+                ])
+            };
+            for text in ["b a d", "b-a-d", "Ｂ＿Ａ＿Ｄ", "e-v-1l", "EV!L"] {
+                assert_eq!(policy().contains_ng_word(text).await, Ok(true), "{}", text);
+            }
+            assert_eq!(
+                policy().check_content("so b-a-d and ev!l").await,
+                Ok(ContentVerdict::Accept("so ***** and ****".to_string()))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_check_content_pattern_reject() {
+            let result = policy_with_kinds(&[
+                ("bad", PatternKind::Literal, Severity::Mask),
+                ("b?d", PatternKind::Glob, Severity::Reject),
+            ])
+            .check_content("bad b4d")
+            .await;
+            assert_eq!(
+                result,
+                Ok(ContentVerdict::Reject(NgWordRule {
+                    id: 2,
+                    word: "b?d".to_string(),
+                    kind: PatternKind::Glob,
+                    severity: Severity::Reject,
+                }))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_check_content_same_as_contains_ng_word() {
+            // 判定と塗りつぶしで結果が食い違わないこと
+            for text in ["ｂａｄｇｕｙ", "ばっどマン", "good", "a_b"] {
+                let contains = policy(&["bad", "バッド", "a%b"])
+                    .contains_ng_word(text)
+                    .await
+                    .unwrap();
+                let verdict = policy(&["bad", "バッド", "a%b"])
+                    .check_content(text)
+                    .await
+                    .unwrap();
+                assert_eq!(
+                    contains,
+                    verdict != ContentVerdict::Accept(text.to_string()),
+                    "{}",
+                    text
+                );
+            }
+        }
+
+        #[tokio::test]
+        async fn test_mask_content_any_severity() {
+            let policy = policy_with_kinds(&[
+                ("bad", PatternKind::Literal, Severity::Mask),
+                ("evil", PatternKind::Literal, Severity::Hold),
+                ("w*e", PatternKind::Glob, Severity::Reject),
+            ]);
+            let result = policy.mask_content("bad evil worse good").await;
+            assert_eq!(result, Ok("*** **** ***** good".to_string()));
+        }
+
+        #[tokio::test]
+        async fn test_check_text() {
+            let policy = policy_with(&[("馬鹿", Severity::Mask), ("evil", Severity::Hold)]);
+            let result = policy.check_text("この馬鹿はEVIL").await.unwrap();
+            assert_eq!(
+                result
+                    .matches
+                    .iter()
+                    .map(|found| (found.rule.id, found.range.clone()))
+                    .collect::<Vec<_>>(),
+                vec![(1, 2..4), (2, 5..9)]
+            );
+            assert!(matches!(
+                result.verdict,
+                ContentVerdict::Hold { content, rule } if content == "この**はEVIL" && rule.id == 2
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_error() {
+            let mut mock = MockNgWordPolicyRepository::new();
+            mock.expect_find_ng_word_rules()
+                .times(3)
+                .returning(|| Err(ServiceError("error".to_string())));
+            let policy = NgWordPolicyImpl::new(Arc::new(mock));
+
+            assert_eq!(
+                policy.contains_ng_word("text").await,
+                Err(ServiceError("error".to_string()))
+            );
+            assert_eq!(
+                policy.check_content("text").await,
+                Err(ServiceError("error".to_string()))
+            );
+            assert_eq!(
+                policy.mask_content("text").await,
+                Err(ServiceError("error".to_string()))
+            );
+        }
+    }
+}
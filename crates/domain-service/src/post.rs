@@ -2,4 +2,5 @@ mod repository;
 mod service;
 
 pub use repository::PostRepository;
+pub(crate) use service::judge_post;
 pub use service::{DomainService, DomainServiceImpl};
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use domain_model::post::{
     err::ServiceError,
-    model::{NgWord, Post, UserName},
+    model::{Post, UserName},
 };
 
 #[cfg(test)]
@@ -10,7 +10,17 @@ use mockall::automock;
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait PostRepository: Send + Sync {
-    async fn user_exists(&self, user: &UserName) -> Result<bool, ServiceError>;
-    async fn find_all_ng_word(&self) -> Result<Vec<NgWord>, ServiceError>;
-    async fn create_post(&self, post: &Post) -> Result<(), ServiceError>;
+    /// ユーザ名に完全一致するユーザのIDを取得する
+    ///
+    /// 比較はDBの照合順序に従い、前方一致や部分一致はしない
+    async fn find_user_id(&self, user: &UserName) -> Result<Option<i32>, ServiceError>;
+    /// 返信先にできる投稿があるか。公開済みで削除されていない投稿のみ返信できる
+    async fn exists_reply_target(&self, parent_post_id: i32) -> Result<bool, ServiceError>;
+    /// 投稿を保存する。返信の場合は`parent_post_id`に返信先の投稿のIDを渡す
+    async fn create_post(
+        &self,
+        user_id: i32,
+        parent_post_id: Option<i32>,
+        post: &Post,
+    ) -> Result<(), ServiceError>;
 }
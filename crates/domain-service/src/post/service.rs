@@ -1,51 +1,112 @@
 use super::repository::PostRepository;
+use crate::ng_word_policy::NgWordPolicy;
 use async_trait::async_trait;
-use domain_model::post::{
-    err::{PostError, ValidationError},
-    model::{Post, UnvalidatedPost, UserName},
+use domain_model::{
+    ng_word_policy::model::ContentVerdict,
+    post::{
+        err::{NgWordError, NotFoundError, PostError, ValidationError},
+        model::{Post, PostStatus, UnvalidatedPost, UserName},
+    },
 };
 use std::sync::Arc;
 
 #[async_trait]
 pub trait DomainService: Send + Sync {
-    async fn post(&self, post: UnvalidatedPost) -> Result<(), PostError>;
+    /// 投稿を保存し、保存した投稿の公開状態を返す
+    ///
+    /// `reject`のNGワードを含む場合は保存せずにエラーを返す。
+    /// `hold`のNGワードを含む場合はモデレーション待ちとして保存する
+    async fn post(&self, post: UnvalidatedPost) -> Result<PostStatus, PostError>;
+    /// 投稿への返信を保存し、保存した返信の公開状態を返す
+    ///
+    /// 投稿と同じ規則で検証する。返信先の投稿が無い場合はエラーを返す
+    async fn reply(
+        &self,
+        parent_post_id: i32,
+        post: UnvalidatedPost,
+    ) -> Result<PostStatus, PostError>;
 }
 
 pub struct DomainServiceImpl {
     post_repository: Arc<dyn PostRepository>,
+    ng_word_policy: Arc<dyn NgWordPolicy>,
 }
 impl DomainServiceImpl {
-    pub fn new(post_repository: Arc<dyn PostRepository>) -> Self {
-        DomainServiceImpl { post_repository }
+    pub fn new(
+        post_repository: Arc<dyn PostRepository>,
+        ng_word_policy: Arc<dyn NgWordPolicy>,
+    ) -> Self {
+        DomainServiceImpl {
+            post_repository,
+            ng_word_policy,
+        }
     }
-}
-#[async_trait]
-impl DomainService for DomainServiceImpl {
-    async fn post(&self, post: UnvalidatedPost) -> Result<(), PostError> {
+
+    async fn save(
+        &self,
+        parent_post_id: Option<i32>,
+        post: UnvalidatedPost,
+    ) -> Result<PostStatus, PostError> {
         let user_name = UserName::new(&post.user_name.0)?;
-        let user_exists = self.post_repository.user_exists(&user_name).await?;
-        if !user_exists {
+        let Some(user_id) = self.post_repository.find_user_id(&user_name).await? else {
             return Err(PostError::from(ValidationError(
                 "User does not exist".to_string(),
             )));
-        }
-        let ng_words = self.post_repository.find_all_ng_word().await?;
-
-        // NGワードを全て、同じ文字数の*に置き換える
-        let content = ng_words
-            .iter()
-            .fold(post.content.clone(), |content, ng_word| {
-                content.replace(ng_word.value(), "*".repeat(ng_word.value().len()).as_str())
-            });
-
-        let post = Post::new(user_name.value().to_string(), content)?;
+        };
+        // 書かれたままの内容で検証して保存する。塗りつぶしは取得時に行う
+        let verdict = self.ng_word_policy.check_content(&post.content).await?;
+        let post = judge_post(&user_name, &post.content, verdict)?;
 
-        let result = self.post_repository.create_post(&post).await;
+        let result = self
+            .post_repository
+            .create_post(user_id, parent_post_id, &post)
+            .await;
         if let Err(err) = result {
-            return Err(PostError::from(err));
+            Err(PostError::from(err))
         } else {
-            Ok(())
+            Ok(post.status())
+        }
+    }
+}
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn post(&self, post: UnvalidatedPost) -> Result<PostStatus, PostError> {
+        self.save(None, post).await
+    }
+
+    async fn reply(
+        &self,
+        parent_post_id: i32,
+        post: UnvalidatedPost,
+    ) -> Result<PostStatus, PostError> {
+        // モデレーション待ちや削除された投稿は無いものとして扱う
+        if !self
+            .post_repository
+            .exists_reply_target(parent_post_id)
+            .await?
+        {
+            return Err(PostError::from(NotFoundError("Post not found".to_string())));
         }
+        self.save(Some(parent_post_id), post).await
+    }
+}
+
+/// NGワードの規則に照らした結果から、保存する投稿を組み立てる
+///
+/// 投稿の編集でも同じ規則で判定するため、共通にしている
+pub(crate) fn judge_post(
+    user_name: &UserName,
+    content: &str,
+    verdict: ContentVerdict,
+) -> Result<Post, PostError> {
+    let new_post = || Post::new(user_name.value().to_string(), content.to_string());
+    match verdict {
+        ContentVerdict::Accept(_) => Ok(new_post()?),
+        ContentVerdict::Hold { .. } => Ok(new_post()?.hold()),
+        ContentVerdict::Reject(rule) => Err(PostError::from(NgWordError(format!(
+            "Content contains a rejected NG word (rule {}): {}",
+            rule.id, rule.word
+        )))),
     }
 }
 
@@ -57,7 +118,28 @@ mod tests {
     mod domain_service_impl {
         use domain_model::post::{self, model::UnvalidatedUserName};
 
-        use crate::post::repository::MockPostRepository;
+        use crate::{
+            ng_word_policy::{MockNgWordPolicyRepository, NgWordPolicyImpl},
+            post::repository::MockPostRepository,
+        };
+        use domain_model::ng_word_policy::{
+            self,
+            model::{NgWordRule, NgWordRuleSet, PatternKind, Severity},
+        };
+
+        fn ng_word_rules(ng_words: &[(&str, Severity)]) -> Arc<NgWordRuleSet> {
+            let rules = ng_words
+                .iter()
+                .zip(1..)
+                .map(|((word, severity), id)| NgWordRule {
+                    id,
+                    word: word.to_string(),
+                    kind: PatternKind::Literal,
+                    severity: *severity,
+                })
+                .collect();
+            Arc::new(NgWordRuleSet::new(rules).unwrap())
+        }
 
         use super::*;
 
@@ -71,26 +153,30 @@ mod tests {
             };
             let post = post::model::Post::new(uv_user_name.0, "content".to_string()).unwrap();
             let mut post_repository = MockPostRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
             post_repository
-                .expect_user_exists()
+                .expect_find_user_id()
                 .with(eq(user_name))
                 .times(1)
-                .returning(|_| Ok(true));
+                .returning(|_| Ok(Some(1)));
 
-            post_repository
-                .expect_find_all_ng_word()
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
                 .times(1)
-                .returning(|| Ok(vec![]));
+                .returning(|| Ok(Arc::new(NgWordRuleSet::empty())));
 
             post_repository
                 .expect_create_post()
-                .with(eq(post.clone()))
+                .with(eq(1), eq(None), eq(post.clone()))
                 .times(1)
-                .returning(|_| Ok(()));
+                .returning(|_, _, _| Ok(()));
 
-            let domain_service_impl = DomainServiceImpl::new(Arc::new(post_repository));
+            let domain_service_impl = DomainServiceImpl::new(
+                Arc::new(post_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
             let result = domain_service_impl.post(uv_post).await;
-            assert!(result.is_ok());
+            assert_eq!(result, Ok(PostStatus::Published));
         }
 
         #[tokio::test]
@@ -103,7 +189,12 @@ mod tests {
 
             let post_repository = MockPostRepository::new();
 
-            let domain_service_impl = DomainServiceImpl::new(Arc::new(post_repository));
+            let ng_word_policy_repository = MockNgWordPolicyRepository::new();
+
+            let domain_service_impl = DomainServiceImpl::new(
+                Arc::new(post_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
             let result = domain_service_impl.post(post).await;
             assert!(result.is_err());
             assert_eq!(
@@ -123,13 +214,17 @@ mod tests {
                 content: "content".to_string(),
             };
             let mut post_repository = MockPostRepository::new();
+            let ng_word_policy_repository = MockNgWordPolicyRepository::new();
             post_repository
-                .expect_user_exists()
+                .expect_find_user_id()
                 .with(eq(user_name))
                 .times(1)
-                .returning(|_| Ok(false));
+                .returning(|_| Ok(None));
 
-            let domain_service_impl = DomainServiceImpl::new(Arc::new(post_repository));
+            let domain_service_impl = DomainServiceImpl::new(
+                Arc::new(post_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
             let result = domain_service_impl.post(post).await;
             assert!(result.is_err());
             assert_eq!(
@@ -138,6 +233,37 @@ mod tests {
             );
         }
 
+        #[tokio::test]
+        async fn test_post_prefix_user_name() {
+            // "bobby"が存在しても"bob"では投稿できない
+            let uv_user_name = UnvalidatedUserName("bob".to_string());
+            let user_name = post::model::UserName::new(&uv_user_name.0).unwrap();
+            let post = UnvalidatedPost {
+                user_name: uv_user_name.clone(),
+                content: "content".to_string(),
+            };
+            let mut post_repository = MockPostRepository::new();
+            let ng_word_policy_repository = MockNgWordPolicyRepository::new();
+            post_repository
+                .expect_find_user_id()
+                .with(eq(user_name))
+                .times(1)
+                .returning(|_| Ok(None));
+            post_repository.expect_create_post().times(0);
+
+            let domain_service_impl = DomainServiceImpl::new(
+                Arc::new(post_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
+            let result = domain_service_impl.post(post).await;
+            assert_eq!(
+                result,
+                Err(PostError::from(ValidationError(
+                    "User does not exist".to_string()
+                )))
+            );
+        }
+
         #[tokio::test]
         async fn test_post_ng_word() {
             let uv_user_name = UnvalidatedUserName("user_name".to_string());
@@ -146,28 +272,286 @@ mod tests {
                 user_name: uv_user_name.clone(),
                 content: "content".to_string(),
             };
-            let post = post::model::Post::new(uv_user_name.0, "*******".to_string()).unwrap();
+            let post = post::model::Post::new(uv_user_name.0, uv_post.content.clone()).unwrap();
             let mut post_repository = MockPostRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
             post_repository
-                .expect_user_exists()
+                .expect_find_user_id()
                 .with(eq(user_name))
                 .times(1)
-                .returning(|_| Ok(true));
+                .returning(|_| Ok(Some(1)));
+
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(1)
+                .returning(|| Ok(ng_word_rules(&[("content", Severity::Mask)])));
 
             post_repository
-                .expect_find_all_ng_word()
+                .expect_create_post()
+                .with(eq(1), eq(None), eq(post.clone()))
                 .times(1)
-                .returning(|| Ok(vec![post::model::NgWord::new("content").unwrap()]));
+                .returning(|_, _, _| Ok(()));
+
+            let domain_service_impl = DomainServiceImpl::new(
+                Arc::new(post_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
+            let result = domain_service_impl.post(uv_post).await;
+            assert_eq!(result, Ok(PostStatus::Published));
+        }
+
+        #[tokio::test]
+        async fn test_post_ng_word_allow_word() {
+            let uv_user_name = UnvalidatedUserName("user_name".to_string());
+            let user_name = post::model::UserName::new(&uv_user_name.0).unwrap();
+            let uv_post = UnvalidatedPost {
+                user_name: uv_user_name.clone(),
+                content: "my Password: ass".to_string(),
+            };
+            let post = post::model::Post::new(uv_user_name.0, uv_post.content.clone()).unwrap();
+            let mut post_repository = MockPostRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
+            post_repository
+                .expect_find_user_id()
+                .with(eq(user_name))
+                .times(1)
+                .returning(|_| Ok(Some(1)));
+
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(1)
+                .returning(|| {
+                    let rule_set = Arc::unwrap_or_clone(ng_word_rules(&[("ass", Severity::Mask)]));
+                    Ok(Arc::new(
+                        rule_set.with_allow_words(["class", "password"]).unwrap(),
+                    ))
+                });
+
+            post_repository
+                .expect_create_post()
+                .with(eq(1), eq(None), eq(post.clone()))
+                .times(1)
+                .returning(|_, _, _| Ok(()));
+
+            let domain_service_impl = DomainServiceImpl::new(
+                Arc::new(post_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
+            let result = domain_service_impl.post(uv_post).await;
+            assert_eq!(result, Ok(PostStatus::Published));
+        }
+
+        #[tokio::test]
+        async fn test_post_ng_word_multibyte() {
+            let uv_user_name = UnvalidatedUserName("名無しさん".to_string());
+            let user_name = post::model::UserName::new(&uv_user_name.0).unwrap();
+            let uv_post = UnvalidatedPost {
+                user_name: uv_user_name.clone(),
+                content: "この禁止語と👨‍👩‍👧‍👦👨‍👩‍👧‍👦👨‍👩‍👧‍👦とか\u{3099}き\u{3099}く\u{3099}"
+                    .to_string(),
+            };
+            let post = post::model::Post::new(uv_user_name.0, uv_post.content.clone()).unwrap();
+            let mut post_repository = MockPostRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
+            post_repository
+                .expect_find_user_id()
+                .with(eq(user_name))
+                .times(1)
+                .returning(|_| Ok(Some(1)));
+
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(1)
+                .returning(|| {
+                    Ok(ng_word_rules(&[
+                        ("禁止語", Severity::Mask),
+                        ("👨‍👩‍👧‍👦👨‍👩‍👧‍👦👨‍👩‍👧‍👦", Severity::Mask),
+                        ("か\u{3099}き\u{3099}く\u{3099}", Severity::Mask),
+                    ]))
+                });
 
             post_repository
                 .expect_create_post()
-                .with(eq(post.clone()))
+                .with(eq(1), eq(None), eq(post.clone()))
                 .times(1)
-                .returning(|_| Ok(()));
+                .returning(|_, _, _| Ok(()));
 
-            let domain_service_impl = DomainServiceImpl::new(Arc::new(post_repository));
+            let domain_service_impl = DomainServiceImpl::new(
+                Arc::new(post_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
             let result = domain_service_impl.post(uv_post).await;
-            assert!(result.is_ok());
+            assert_eq!(result, Ok(PostStatus::Published));
+        }
+
+        #[tokio::test]
+        async fn test_post_ng_word_normalized() {
+            let uv_user_name = UnvalidatedUserName("user_name".to_string());
+            let user_name = post::model::UserName::new(&uv_user_name.0).unwrap();
+            let uv_post = UnvalidatedPost {
+                user_name: uv_user_name.clone(),
+                content: "ＢＡＤ Bad ばっど ﾊﾞｯﾄﾞ".to_string(),
+            };
+            let post = post::model::Post::new(uv_user_name.0, uv_post.content.clone()).unwrap();
+            let mut post_repository = MockPostRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
+            post_repository
+                .expect_find_user_id()
+                .with(eq(user_name))
+                .times(1)
+                .returning(|_| Ok(Some(1)));
+
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(1)
+                .returning(|| {
+                    Ok(ng_word_rules(&[
+                        ("bad", Severity::Mask),
+                        ("バッド", Severity::Mask),
+                    ]))
+                });
+
+            post_repository
+                .expect_create_post()
+                .with(eq(1), eq(None), eq(post.clone()))
+                .times(1)
+                .returning(|_, _, _| Ok(()));
+
+            let domain_service_impl = DomainServiceImpl::new(
+                Arc::new(post_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
+            let result = domain_service_impl.post(uv_post).await;
+            assert_eq!(result, Ok(PostStatus::Published));
+        }
+
+        #[tokio::test]
+        async fn test_post_ng_word_pattern() {
+            let uv_user_name = UnvalidatedUserName("user_name".to_string());
+            let user_name = post::model::UserName::new(&uv_user_name.0).unwrap();
+            let uv_post = UnvalidatedPost {
+                user_name: uv_user_name.clone(),
+                content: "so b a d".to_string(),
+            };
+            let post = post::model::Post::new(uv_user_name.0, uv_post.content.clone()).unwrap();
+            let mut post_repository = MockPostRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
+            post_repository
+                .expect_find_user_id()
+                .with(eq(user_name))
+                .times(1)
+                .returning(|_| Ok(Some(1)));
+
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(1)
+                .returning(|| {
+                    let rules = vec![NgWordRule {
+                        id: 1,
+                        word: "b*a*d".to_string(),
+                        kind: PatternKind::Glob,
+                        severity: Severity::Mask,
+                    }];
+                    Ok(Arc::new(NgWordRuleSet::new(rules).unwrap()))
+                });
+
+            post_repository
+                .expect_create_post()
+                .with(eq(1), eq(None), eq(post.clone()))
+                .times(1)
+                .returning(|_, _, _| Ok(()));
+
+            let domain_service_impl = DomainServiceImpl::new(
+                Arc::new(post_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
+            let result = domain_service_impl.post(uv_post).await;
+            assert_eq!(result, Ok(PostStatus::Published));
+        }
+
+        #[tokio::test]
+        async fn test_post_ng_word_hold() {
+            let uv_user_name = UnvalidatedUserName("user_name".to_string());
+            let user_name = post::model::UserName::new(&uv_user_name.0).unwrap();
+            let uv_post = UnvalidatedPost {
+                user_name: uv_user_name.clone(),
+                content: "bad spam".to_string(),
+            };
+            let post = post::model::Post::new(uv_user_name.0, uv_post.content.clone())
+                .unwrap()
+                .hold();
+            let mut post_repository = MockPostRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
+            post_repository
+                .expect_find_user_id()
+                .with(eq(user_name))
+                .times(1)
+                .returning(|_| Ok(Some(1)));
+
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(1)
+                .returning(|| {
+                    Ok(ng_word_rules(&[
+                        ("bad", Severity::Mask),
+                        ("spam", Severity::Hold),
+                    ]))
+                });
+
+            post_repository
+                .expect_create_post()
+                .with(eq(1), eq(None), eq(post.clone()))
+                .times(1)
+                .returning(|_, _, _| Ok(()));
+
+            let domain_service_impl = DomainServiceImpl::new(
+                Arc::new(post_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
+            let result = domain_service_impl.post(uv_post).await;
+            assert_eq!(result, Ok(PostStatus::Held));
+        }
+
+        #[tokio::test]
+        async fn test_post_ng_word_reject() {
+            let uv_user_name = UnvalidatedUserName("user_name".to_string());
+            let user_name = post::model::UserName::new(&uv_user_name.0).unwrap();
+            let uv_post = UnvalidatedPost {
+                user_name: uv_user_name.clone(),
+                content: "bad spam evil".to_string(),
+            };
+            let mut post_repository = MockPostRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
+            post_repository
+                .expect_find_user_id()
+                .with(eq(user_name))
+                .times(1)
+                .returning(|_| Ok(Some(1)));
+
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(1)
+                .returning(|| {
+                    Ok(ng_word_rules(&[
+                        ("bad", Severity::Mask),
+                        ("spam", Severity::Hold),
+                        ("evil", Severity::Reject),
+                    ]))
+                });
+
+            post_repository.expect_create_post().times(0);
+
+            let domain_service_impl = DomainServiceImpl::new(
+                Arc::new(post_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
+            let result = domain_service_impl.post(uv_post).await;
+            assert_eq!(
+                result,
+                Err(PostError::from(post::err::NgWordError(
+                    "Content contains a rejected NG word (rule 3): evil".to_string()
+                )))
+            );
         }
 
         #[tokio::test]
@@ -179,13 +563,17 @@ mod tests {
                 content: "content".to_string(),
             };
             let mut post_repository = MockPostRepository::new();
+            let ng_word_policy_repository = MockNgWordPolicyRepository::new();
             post_repository
-                .expect_user_exists()
+                .expect_find_user_id()
                 .with(eq(user_name))
                 .times(1)
                 .returning(|_| Err(post::err::ServiceError("ServiceError".to_string())));
 
-            let domain_service_impl = DomainServiceImpl::new(Arc::new(post_repository));
+            let domain_service_impl = DomainServiceImpl::new(
+                Arc::new(post_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
             let result = domain_service_impl.post(uv_post).await;
             assert!(result.is_err());
             assert_eq!(
@@ -203,22 +591,26 @@ mod tests {
                 content: "content".to_string(),
             };
             let mut post_repository = MockPostRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
             post_repository
-                .expect_user_exists()
+                .expect_find_user_id()
                 .with(eq(user_name))
                 .times(1)
-                .returning(|_| Ok(true));
+                .returning(|_| Ok(Some(1)));
 
-            post_repository
-                .expect_find_all_ng_word()
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
                 .times(1)
                 .returning(|| {
-                    Err(post::err::ServiceError(
+                    Err(ng_word_policy::err::ServiceError(
                         "DomainServiceImpl error".to_string(),
                     ))
                 });
 
-            let domain_service_impl = DomainServiceImpl::new(Arc::new(post_repository));
+            let domain_service_impl = DomainServiceImpl::new(
+                Arc::new(post_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
             let result = domain_service_impl.post(uv_post).await;
             assert!(result.is_err());
             assert_eq!(
@@ -239,24 +631,28 @@ mod tests {
             };
             let post = post::model::Post::new(uv_user_name.0, "content".to_string()).unwrap();
             let mut post_repository = MockPostRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
             post_repository
-                .expect_user_exists()
+                .expect_find_user_id()
                 .with(eq(user_name))
                 .times(1)
-                .returning(|_| Ok(true));
+                .returning(|_| Ok(Some(1)));
 
-            post_repository
-                .expect_find_all_ng_word()
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
                 .times(1)
-                .returning(|| Ok(vec![]));
+                .returning(|| Ok(Arc::new(NgWordRuleSet::empty())));
 
             post_repository
                 .expect_create_post()
-                .with(eq(post.clone()))
+                .with(eq(1), eq(None), eq(post.clone()))
                 .times(1)
-                .returning(|_| Err(post::err::ServiceError("ServiceError".to_string())));
+                .returning(|_, _, _| Err(post::err::ServiceError("ServiceError".to_string())));
 
-            let domain_service_impl = DomainServiceImpl::new(Arc::new(post_repository));
+            let domain_service_impl = DomainServiceImpl::new(
+                Arc::new(post_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
             let result = domain_service_impl.post(uv_post).await;
             assert!(result.is_err());
             assert_eq!(
@@ -264,5 +660,69 @@ mod tests {
                 PostError::from(post::err::ServiceError("ServiceError".to_string()))
             );
         }
+
+        #[tokio::test]
+        async fn test_reply() {
+            let uv_user_name = UnvalidatedUserName("user_name".to_string());
+            let uv_post = UnvalidatedPost {
+                user_name: uv_user_name.clone(),
+                content: "content".to_string(),
+            };
+            let post = post::model::Post::new(uv_user_name.0, "content".to_string()).unwrap();
+            let mut post_repository = MockPostRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
+            post_repository
+                .expect_exists_reply_target()
+                .with(eq(10))
+                .times(1)
+                .returning(|_| Ok(true));
+            post_repository
+                .expect_find_user_id()
+                .times(1)
+                .returning(|_| Ok(Some(1)));
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(1)
+                .returning(|| Ok(Arc::new(NgWordRuleSet::empty())));
+            post_repository
+                .expect_create_post()
+                .with(eq(1), eq(Some(10)), eq(post))
+                .times(1)
+                .returning(|_, _, _| Ok(()));
+
+            let domain_service_impl = DomainServiceImpl::new(
+                Arc::new(post_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
+            let result = domain_service_impl.reply(10, uv_post).await;
+            assert_eq!(result, Ok(PostStatus::Published));
+        }
+
+        #[tokio::test]
+        async fn test_reply_parent_not_found() {
+            let uv_post = UnvalidatedPost {
+                user_name: UnvalidatedUserName("user_name".to_string()),
+                content: "content".to_string(),
+            };
+            let mut post_repository = MockPostRepository::new();
+            post_repository
+                .expect_exists_reply_target()
+                .with(eq(10))
+                .times(1)
+                .returning(|_| Ok(false));
+            post_repository.expect_create_post().times(0);
+
+            let domain_service_impl = DomainServiceImpl::new(
+                Arc::new(post_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(
+                    MockNgWordPolicyRepository::new(),
+                ))),
+            );
+            let result = domain_service_impl.reply(10, uv_post).await;
+            assert_eq!(
+                result,
+                Err(PostError::from(NotFoundError("Post not found".to_string())))
+            );
+        }
     }
 }
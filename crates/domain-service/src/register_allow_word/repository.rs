@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use domain_model::register_allow_word::{err::ServiceError, model::AllowWord};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait RegisterAllowWordRepository: Send + Sync {
+    /// 例外の語を登録する
+    async fn register_allow_word(
+        &self,
+        allow_word: &AllowWord,
+        user_id: i32,
+    ) -> Result<(), ServiceError>;
+}
@@ -0,0 +1,152 @@
+use super::repository::RegisterAllowWordRepository;
+use async_trait::async_trait;
+use domain_model::{
+    login::model::LoggedInUser,
+    register_allow_word::{
+        err::{PermissionError, RegisterAllowWordError},
+        model::{AllowWord, UnvalidatedAllowWord},
+    },
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    async fn register_allow_word(
+        &self,
+        allow_word: UnvalidatedAllowWord,
+        user: &LoggedInUser,
+    ) -> Result<(), RegisterAllowWordError>;
+}
+
+pub struct DomainServiceImpl {
+    register_allow_word_repository: Arc<dyn RegisterAllowWordRepository>,
+}
+
+impl DomainServiceImpl {
+    pub fn new(register_allow_word_repository: Arc<dyn RegisterAllowWordRepository>) -> Self {
+        Self {
+            register_allow_word_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn register_allow_word(
+        &self,
+        allow_word: UnvalidatedAllowWord,
+        user: &LoggedInUser,
+    ) -> Result<(), RegisterAllowWordError> {
+        if !user.role.can_manage_ng_words() {
+            return Err(RegisterAllowWordError::from(PermissionError(
+                "Only moderators can register allow words".to_string(),
+            )));
+        }
+        let allow_word = AllowWord::new(&allow_word.allow_word)?;
+        self.register_allow_word_repository
+            .register_allow_word(&allow_word, user.id)
+            .await
+            .map_err(|e| e.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_model::{login::model::Role, register_allow_word::err::ServiceError};
+    use mockall::predicate::*;
+
+    fn unvalidated(allow_word: &str) -> UnvalidatedAllowWord {
+        UnvalidatedAllowWord {
+            allow_word: allow_word.to_string(),
+        }
+    }
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "moderator".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::register_allow_word::repository::MockRegisterAllowWordRepository;
+
+        #[tokio::test]
+        async fn test_register_allow_word() {
+            let mut mock = MockRegisterAllowWordRepository::new();
+            mock.expect_register_allow_word()
+                .with(eq(AllowWord::new("class").unwrap()), eq(1))
+                .times(1)
+                .returning(|_, _| Ok(()));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .register_allow_word(unvalidated("class"), &moderator())
+                .await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_register_allow_word_validation_error() {
+            let mut mock = MockRegisterAllowWordRepository::new();
+            mock.expect_register_allow_word().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .register_allow_word(unvalidated("cl"), &moderator())
+                .await;
+
+            assert!(matches!(
+                result,
+                Err(RegisterAllowWordError::ValidationError(_))
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_register_allow_word_permission_error() {
+            let mut mock = MockRegisterAllowWordRepository::new();
+            mock.expect_register_allow_word().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let member = LoggedInUser {
+                id: 2,
+                name: "member".to_string(),
+                role: Role::Member,
+            };
+            let result = service
+                .register_allow_word(unvalidated("class"), &member)
+                .await;
+
+            assert_eq!(
+                result,
+                Err(RegisterAllowWordError::PermissionError(PermissionError(
+                    "Only moderators can register allow words".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_register_allow_word_service_error() {
+            let mut mock = MockRegisterAllowWordRepository::new();
+            mock.expect_register_allow_word()
+                .times(1)
+                .returning(|_, _| Err(ServiceError("Database error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .register_allow_word(unvalidated("class"), &moderator())
+                .await;
+
+            assert_eq!(
+                result,
+                Err(RegisterAllowWordError::ServiceError(ServiceError(
+                    "Database error".to_string()
+                )))
+            );
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod repository;
+pub mod service;
+
+pub use repository::*;
+pub use service::*;
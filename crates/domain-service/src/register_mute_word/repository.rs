@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use domain_model::register_mute_word::{err::ServiceError, model::MuteWord};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait RegisterMuteWordRepository: Send + Sync {
+    /// ユーザが同じ語をミュート済みかを判定する
+    async fn exists_mute_word(
+        &self,
+        mute_word: &MuteWord,
+        user_id: i32,
+    ) -> Result<bool, ServiceError>;
+    /// ユーザがミュートする語を登録する
+    async fn register_mute_word(
+        &self,
+        mute_word: &MuteWord,
+        user_id: i32,
+    ) -> Result<(), ServiceError>;
+}
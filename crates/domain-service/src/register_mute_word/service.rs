@@ -0,0 +1,161 @@
+use super::repository::RegisterMuteWordRepository;
+use async_trait::async_trait;
+use domain_model::{
+    login::model::LoggedInUser,
+    register_mute_word::{
+        err::{RegisterMuteWordError, ValidationError},
+        model::{MuteWord, UnvalidatedMuteWord},
+    },
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    /// ログイン中のユーザ自身がミュートする語を登録する
+    async fn register_mute_word(
+        &self,
+        mute_word: UnvalidatedMuteWord,
+        user: &LoggedInUser,
+    ) -> Result<(), RegisterMuteWordError>;
+}
+
+pub struct DomainServiceImpl {
+    register_mute_word_repository: Arc<dyn RegisterMuteWordRepository>,
+}
+
+impl DomainServiceImpl {
+    pub fn new(register_mute_word_repository: Arc<dyn RegisterMuteWordRepository>) -> Self {
+        Self {
+            register_mute_word_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn register_mute_word(
+        &self,
+        mute_word: UnvalidatedMuteWord,
+        user: &LoggedInUser,
+    ) -> Result<(), RegisterMuteWordError> {
+        let mute_word = MuteWord::new(&mute_word.mute_word)?;
+        if self
+            .register_mute_word_repository
+            .exists_mute_word(&mute_word, user.id)
+            .await?
+        {
+            return Err(RegisterMuteWordError::from(ValidationError(
+                "MuteWord is already registered".to_string(),
+            )));
+        }
+        self.register_mute_word_repository
+            .register_mute_word(&mute_word, user.id)
+            .await
+            .map_err(|e| e.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_model::{login::model::Role, register_mute_word::err::ServiceError};
+    use mockall::predicate::*;
+
+    fn unvalidated(mute_word: &str) -> UnvalidatedMuteWord {
+        UnvalidatedMuteWord {
+            mute_word: mute_word.to_string(),
+        }
+    }
+
+    fn member() -> LoggedInUser {
+        LoggedInUser {
+            id: 2,
+            name: "member".to_string(),
+            role: Role::Member,
+        }
+    }
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::register_mute_word::repository::MockRegisterMuteWordRepository;
+
+        #[tokio::test]
+        async fn test_register_mute_word() {
+            let mut mock = MockRegisterMuteWordRepository::new();
+            mock.expect_exists_mute_word()
+                .with(eq(MuteWord::new("spoiler").unwrap()), eq(2))
+                .times(1)
+                .returning(|_, _| Ok(false));
+            mock.expect_register_mute_word()
+                .with(eq(MuteWord::new("spoiler").unwrap()), eq(2))
+                .times(1)
+                .returning(|_, _| Ok(()));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .register_mute_word(unvalidated("spoiler"), &member())
+                .await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_register_mute_word_validation_error() {
+            let mut mock = MockRegisterMuteWordRepository::new();
+            mock.expect_exists_mute_word().times(0);
+            mock.expect_register_mute_word().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.register_mute_word(unvalidated(""), &member()).await;
+
+            assert!(matches!(
+                result,
+                Err(RegisterMuteWordError::ValidationError(_))
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_register_mute_word_already_registered() {
+            let mut mock = MockRegisterMuteWordRepository::new();
+            mock.expect_exists_mute_word()
+                .times(1)
+                .returning(|_, _| Ok(true));
+            mock.expect_register_mute_word().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .register_mute_word(unvalidated("spoiler"), &member())
+                .await;
+
+            assert_eq!(
+                result,
+                Err(RegisterMuteWordError::ValidationError(ValidationError(
+                    "MuteWord is already registered".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_register_mute_word_service_error() {
+            let mut mock = MockRegisterMuteWordRepository::new();
+            mock.expect_exists_mute_word()
+                .times(1)
+                .returning(|_, _| Ok(false));
+            mock.expect_register_mute_word()
+                .times(1)
+                .returning(|_, _| Err(ServiceError("Database error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .register_mute_word(unvalidated("spoiler"), &member())
+                .await;
+
+            assert_eq!(
+                result,
+                Err(RegisterMuteWordError::ServiceError(ServiceError(
+                    "Database error".to_string()
+                )))
+            );
+        }
+    }
+}
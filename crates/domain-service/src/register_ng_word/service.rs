@@ -1,14 +1,20 @@
 use super::repository::RegisterNgWordRepository;
 use async_trait::async_trait;
-use domain_model::register_ng_word::{err::RegisterNgWordError, model::NgWord};
+use domain_model::{
+    login::model::LoggedInUser,
+    register_ng_word::{
+        err::{PermissionError, RegisterNgWordError},
+        model::{NgWord, UnvalidatedNgWord},
+    },
+};
 use std::sync::Arc;
 
 #[async_trait]
 pub trait DomainService: Send + Sync {
     async fn register_ng_word(
         &self,
-        ng_word: &str,
-        user_id: i32,
+        ng_word: UnvalidatedNgWord,
+        user: &LoggedInUser,
     ) -> Result<(), RegisterNgWordError>;
 }
 
@@ -28,12 +34,17 @@ impl DomainServiceImpl {
 impl DomainService for DomainServiceImpl {
     async fn register_ng_word(
         &self,
-        ng_word: &str,
-        user_id: i32,
+        ng_word: UnvalidatedNgWord,
+        user: &LoggedInUser,
     ) -> Result<(), RegisterNgWordError> {
-        let ng_word = NgWord::new(ng_word)?;
+        if !user.role.can_manage_ng_words() {
+            return Err(RegisterNgWordError::from(PermissionError(
+                "Only moderators can register NG words".to_string(),
+            )));
+        }
+        let ng_word = NgWord::from_unvalidated(&ng_word)?;
         self.register_ng_word_repository
-            .register_ng_word(&ng_word, user_id)
+            .register_ng_word(&ng_word, user.id)
             .await
             .map_err(|e| e.into())
     }
@@ -42,9 +53,29 @@ impl DomainService for DomainServiceImpl {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use domain_model::register_ng_word::err::{ServiceError, ValidationError};
+    use domain_model::{
+        login::model::Role,
+        ng_word_policy::model::{PatternKind, Severity},
+        register_ng_word::err::{ServiceError, ValidationError},
+    };
     use mockall::predicate::*;
 
+    fn unvalidated(ng_word: &str, kind: &str, severity: &str) -> UnvalidatedNgWord {
+        UnvalidatedNgWord {
+            ng_word: ng_word.to_string(),
+            kind: kind.to_string(),
+            severity: severity.to_string(),
+        }
+    }
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "moderator".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
     mod domain_service_impl {
         use super::*;
         use crate::register_ng_word::repository::MockRegisterNgWordRepository;
@@ -58,7 +89,9 @@ mod tests {
                 .returning(|_, _| Ok(()));
 
             let service = DomainServiceImpl::new(Arc::new(mock));
-            let result = service.register_ng_word("test", 1).await;
+            let result = service
+                .register_ng_word(unvalidated("test", "literal", "mask"), &moderator())
+                .await;
 
             assert!(result.is_ok());
             assert_eq!(result, Ok(()));
@@ -68,7 +101,9 @@ mod tests {
         async fn test_register_ng_word_validation_error() {
             let mock = MockRegisterNgWordRepository::new();
             let service = DomainServiceImpl::new(Arc::new(mock));
-            let result = service.register_ng_word("te", 1).await;
+            let result = service
+                .register_ng_word(unvalidated("te", "literal", "mask"), &moderator())
+                .await;
 
             assert!(result.is_err());
             assert!(matches!(
@@ -77,6 +112,96 @@ mod tests {
             ));
         }
 
+        #[tokio::test]
+        async fn test_register_ng_word_severity() {
+            let mut mock = MockRegisterNgWordRepository::new();
+            mock.expect_register_ng_word()
+                .with(
+                    eq(NgWord::new("test").unwrap().with_severity(Severity::Reject)),
+                    eq(1),
+                )
+                .times(1)
+                .returning(|_, _| Ok(()));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .register_ng_word(unvalidated("test", "literal", "reject"), &moderator())
+                .await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_register_ng_word_invalid_severity() {
+            let mut mock = MockRegisterNgWordRepository::new();
+            mock.expect_register_ng_word().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .register_ng_word(unvalidated("test", "literal", "delete"), &moderator())
+                .await;
+
+            assert_eq!(
+                result,
+                Err(RegisterNgWordError::ValidationError(ValidationError(
+                    "Severity must be one of mask, hold, reject".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_register_ng_word_pattern() {
+            let mut mock = MockRegisterNgWordRepository::new();
+            mock.expect_register_ng_word()
+                .with(
+                    eq(NgWord::with_kind("b*a*d", PatternKind::Glob).unwrap()),
+                    eq(1),
+                )
+                .times(1)
+                .returning(|_, _| Ok(()));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .register_ng_word(unvalidated("b*a*d", "glob", "mask"), &moderator())
+                .await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_register_ng_word_invalid_pattern() {
+            let mut mock = MockRegisterNgWordRepository::new();
+            mock.expect_register_ng_word().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .register_ng_word(unvalidated("(bad", "regex", "mask"), &moderator())
+                .await;
+
+            assert!(matches!(
+                result,
+                Err(RegisterNgWordError::ValidationError(_))
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_register_ng_word_invalid_kind() {
+            let mut mock = MockRegisterNgWordRepository::new();
+            mock.expect_register_ng_word().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .register_ng_word(unvalidated("test", "wildcard", "mask"), &moderator())
+                .await;
+
+            assert_eq!(
+                result,
+                Err(RegisterNgWordError::ValidationError(ValidationError(
+                    "Kind must be one of literal, glob, regex".to_string()
+                )))
+            );
+        }
+
         #[tokio::test]
         async fn test_register_ng_word_service_error() {
             let mut mock = MockRegisterNgWordRepository::new();
@@ -86,7 +211,9 @@ mod tests {
                 .returning(|_, _| Err(ServiceError("Database error".to_string())));
 
             let service = DomainServiceImpl::new(Arc::new(mock));
-            let result = service.register_ng_word("test", 1).await;
+            let result = service
+                .register_ng_word(unvalidated("test", "literal", "mask"), &moderator())
+                .await;
 
             assert!(result.is_err());
             assert_eq!(
@@ -96,5 +223,49 @@ mod tests {
                 )))
             );
         }
+
+        #[tokio::test]
+        async fn test_register_ng_word_admin() {
+            let mut mock = MockRegisterNgWordRepository::new();
+            mock.expect_register_ng_word()
+                .with(eq(NgWord::new("test").unwrap()), eq(2))
+                .times(1)
+                .returning(|_, _| Ok(()));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let admin = LoggedInUser {
+                id: 2,
+                name: "admin".to_string(),
+                role: Role::Admin,
+            };
+            let result = service
+                .register_ng_word(unvalidated("test", "literal", "mask"), &admin)
+                .await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_register_ng_word_permission_error() {
+            let mut mock = MockRegisterNgWordRepository::new();
+            mock.expect_register_ng_word().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let member = LoggedInUser {
+                id: 3,
+                name: "member".to_string(),
+                role: Role::Member,
+            };
+            let result = service
+                .register_ng_word(unvalidated("test", "literal", "mask"), &member)
+                .await;
+
+            assert_eq!(
+                result,
+                Err(RegisterNgWordError::PermissionError(PermissionError(
+                    "Only moderators can register NG words".to_string()
+                )))
+            );
+        }
     }
 }
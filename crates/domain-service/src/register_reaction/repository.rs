@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use domain_model::{reaction::model::ReactionKind, register_reaction::err::ServiceError};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait RegisterReactionRepository: Send + Sync {
+    /// リアクションできる投稿があるか。公開済みで削除されていない投稿のみリアクションできる
+    async fn exists_post(&self, post_id: i32) -> Result<bool, ServiceError>;
+    /// リアクションを保存する。既に同じリアクションがある場合は何もしない
+    async fn register_reaction(
+        &self,
+        post_id: i32,
+        user_id: i32,
+        kind: &ReactionKind,
+    ) -> Result<(), ServiceError>;
+}
@@ -0,0 +1,168 @@
+use super::repository::RegisterReactionRepository;
+use async_trait::async_trait;
+use domain_model::{
+    login::model::LoggedInUser,
+    reaction::model::ReactionKindSet,
+    register_reaction::err::{NotFoundError, RegisterReactionError},
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    /// ログイン中のユーザ自身のリアクションを投稿に付ける
+    ///
+    /// 既に同じリアクションを付けている場合も成功する
+    async fn register_reaction(
+        &self,
+        post_id: i32,
+        kind: String,
+        user: &LoggedInUser,
+    ) -> Result<(), RegisterReactionError>;
+}
+
+pub struct DomainServiceImpl {
+    register_reaction_repository: Arc<dyn RegisterReactionRepository>,
+    reaction_kinds: ReactionKindSet,
+}
+
+impl DomainServiceImpl {
+    pub fn new(
+        register_reaction_repository: Arc<dyn RegisterReactionRepository>,
+        reaction_kinds: ReactionKindSet,
+    ) -> Self {
+        Self {
+            register_reaction_repository,
+            reaction_kinds,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn register_reaction(
+        &self,
+        post_id: i32,
+        kind: String,
+        user: &LoggedInUser,
+    ) -> Result<(), RegisterReactionError> {
+        let kind = self.reaction_kinds.get(&kind)?;
+        // モデレーション待ちや削除された投稿は無いものとして扱う
+        if !self
+            .register_reaction_repository
+            .exists_post(post_id)
+            .await?
+        {
+            return Err(RegisterReactionError::from(NotFoundError(
+                "Post not found".to_string(),
+            )));
+        }
+        self.register_reaction_repository
+            .register_reaction(post_id, user.id, &kind)
+            .await
+            .map_err(|e| e.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_model::{
+        login::model::Role,
+        reaction::model::ReactionKind,
+        register_reaction::err::{ServiceError, ValidationError},
+    };
+    use mockall::predicate::*;
+
+    fn member() -> LoggedInUser {
+        LoggedInUser {
+            id: 2,
+            name: "member".to_string(),
+            role: Role::Member,
+        }
+    }
+
+    fn reaction_kinds() -> ReactionKindSet {
+        ReactionKindSet::parse("like,laugh").unwrap()
+    }
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::register_reaction::repository::MockRegisterReactionRepository;
+
+        #[tokio::test]
+        async fn test_register_reaction() {
+            let mut mock = MockRegisterReactionRepository::new();
+            mock.expect_exists_post()
+                .with(eq(1))
+                .times(1)
+                .returning(|_| Ok(true));
+            mock.expect_register_reaction()
+                .with(eq(1), eq(2), eq(ReactionKind::new("laugh").unwrap()))
+                .times(1)
+                .returning(|_, _, _| Ok(()));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), reaction_kinds());
+            let result = service
+                .register_reaction(1, "laugh".to_string(), &member())
+                .await;
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_register_reaction_unknown_kind() {
+            let mut mock = MockRegisterReactionRepository::new();
+            mock.expect_exists_post().times(0);
+            mock.expect_register_reaction().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock), reaction_kinds());
+            let result = service
+                .register_reaction(1, "angry".to_string(), &member())
+                .await;
+            assert_eq!(
+                result,
+                Err(RegisterReactionError::ValidationError(ValidationError(
+                    "Reaction kind must be one of like, laugh".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_register_reaction_post_not_found() {
+            let mut mock = MockRegisterReactionRepository::new();
+            mock.expect_exists_post()
+                .with(eq(1))
+                .times(1)
+                .returning(|_| Ok(false));
+            mock.expect_register_reaction().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock), reaction_kinds());
+            let result = service
+                .register_reaction(1, "like".to_string(), &member())
+                .await;
+            assert_eq!(
+                result,
+                Err(RegisterReactionError::NotFoundError(NotFoundError(
+                    "Post not found".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_register_reaction_service_error() {
+            let mut mock = MockRegisterReactionRepository::new();
+            mock.expect_exists_post().times(1).returning(|_| Ok(true));
+            mock.expect_register_reaction()
+                .times(1)
+                .returning(|_, _, _| Err(ServiceError("error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), reaction_kinds());
+            let result = service
+                .register_reaction(1, "like".to_string(), &member())
+                .await;
+            assert!(matches!(
+                result,
+                Err(RegisterReactionError::ServiceError(_))
+            ));
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use domain_model::register_user_name::{
-    err::{RegisterUserNameError, ServiceError},
-    model::UserName,
+    err::ServiceError,
+    model::{HashedPassword, UserName},
 };
 
 #[cfg(test)]
@@ -10,6 +10,13 @@ use mockall::automock;
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait RegisterUserNameRepository: Send + Sync {
-    async fn find_ng_word(&self, user_name: &UserName) -> Result<bool, RegisterUserNameError>;
-    async fn create_user_name(&self, user_name: &UserName) -> Result<(), ServiceError>;
+    /// ユーザ名に完全一致するユーザのIDを取得する
+    ///
+    /// 比較はDBの照合順序に従い、前方一致や部分一致はしない
+    async fn find_user_id(&self, user_name: &UserName) -> Result<Option<i32>, ServiceError>;
+    async fn create_user_name(
+        &self,
+        user_name: &UserName,
+        password: &HashedPassword,
+    ) -> Result<(), ServiceError>;
 }
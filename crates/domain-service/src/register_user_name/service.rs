@@ -1,8 +1,9 @@
 use super::repository::RegisterUserNameRepository;
+use crate::ng_word_policy::NgWordPolicy;
 use async_trait::async_trait;
 use domain_model::register_user_name::{
-    err::{RegisterUserNameError, ValidationError},
-    model::{UnvalidatedUserName, UserName},
+    err::{RegisterUserNameError, ServiceError, ValidationError},
+    model::{Password, UnvalidatedPassword, UnvalidatedUserName, UserName},
 };
 use std::sync::Arc;
 
@@ -11,16 +12,22 @@ pub trait DomainService: Send + Sync {
     async fn register_user_name(
         &self,
         user_name: UnvalidatedUserName,
+        password: UnvalidatedPassword,
     ) -> Result<(), RegisterUserNameError>;
 }
 
 pub struct DomainServiceImpl {
     register_user_name_repository: Arc<dyn RegisterUserNameRepository>,
+    ng_word_policy: Arc<dyn NgWordPolicy>,
 }
 impl DomainServiceImpl {
-    pub fn new(register_user_name_repository: Arc<dyn RegisterUserNameRepository>) -> Self {
+    pub fn new(
+        register_user_name_repository: Arc<dyn RegisterUserNameRepository>,
+        ng_word_policy: Arc<dyn NgWordPolicy>,
+    ) -> Self {
         Self {
             register_user_name_repository,
+            ng_word_policy,
         }
     }
 }
@@ -29,12 +36,14 @@ impl DomainService for DomainServiceImpl {
     async fn register_user_name(
         &self,
         user_name: UnvalidatedUserName,
+        password: UnvalidatedPassword,
     ) -> Result<(), RegisterUserNameError> {
         let user_name = UserName::new(&user_name.0)?;
+        let password = Password::new(&password.0)?;
 
         if self
-            .register_user_name_repository
-            .find_ng_word(&user_name)
+            .ng_word_policy
+            .contains_ng_word(user_name.value())
             .await?
         {
             return Err(RegisterUserNameError::from(ValidationError(
@@ -42,9 +51,25 @@ impl DomainService for DomainServiceImpl {
             )));
         }
 
+        if self
+            .register_user_name_repository
+            .find_user_id(&user_name)
+            .await?
+            .is_some()
+        {
+            return Err(RegisterUserNameError::from(ValidationError(
+                "User name already exists".to_string(),
+            )));
+        }
+
+        // ハッシュ計算は重いのでブロッキング用のスレッドで行う
+        let hashed_password = tokio::task::spawn_blocking(move || password.hash())
+            .await
+            .map_err(|_| ServiceError("Failed to hash password".to_string()))??;
+
         let register_result = self
             .register_user_name_repository
-            .create_user_name(&user_name)
+            .create_user_name(&user_name, &hashed_password)
             .await;
 
         if let Err(err) = register_result {
@@ -61,31 +86,139 @@ mod tests {
     use mockall::predicate::*;
 
     mod domain_service_impl {
-        use domain_model::register_user_name::err::ServiceError;
-
         use super::*;
-        use crate::register_user_name::repository::MockRegisterUserNameRepository;
+        use crate::{
+            ng_word_policy::{MockNgWordPolicyRepository, NgWordPolicyImpl},
+            register_user_name::repository::MockRegisterUserNameRepository,
+        };
+        use domain_model::ng_word_policy::{
+            self,
+            model::{NgWordRule, NgWordRuleSet, PatternKind, Severity},
+        };
+
+        fn ng_word_rules(ng_words: &[&str]) -> Arc<NgWordRuleSet> {
+            let rules = ng_words
+                .iter()
+                .zip(1..)
+                .map(|(word, id)| NgWordRule {
+                    id,
+                    word: word.to_string(),
+                    kind: PatternKind::Literal,
+                    severity: Severity::Mask,
+                })
+                .collect();
+            Arc::new(NgWordRuleSet::new(rules).unwrap())
+        }
 
         #[tokio::test]
         async fn test_register_user_name() {
             let mut register_user_name_repository = MockRegisterUserNameRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
+
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(1)
+                .returning(|| Ok(Arc::new(NgWordRuleSet::empty())));
 
             register_user_name_repository
-                .expect_find_ng_word()
+                .expect_find_user_id()
                 .times(1)
-                .with(eq(UserName::new("word").unwrap()))
-                .returning(|_| Ok(false));
+                .returning(|_| Ok(None));
 
             register_user_name_repository
                 .expect_create_user_name()
                 .times(1)
+                .with(eq(UserName::new("word").unwrap()), always())
+                .returning(|_, _| Ok(()));
+
+            let service = DomainServiceImpl::new(
+                Arc::new(register_user_name_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
+
+            let result = service
+                .register_user_name(
+                    UnvalidatedUserName("word".to_string()),
+                    UnvalidatedPassword("password1".to_string()),
+                )
+                .await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_register_user_name_already_exists() {
+            let mut register_user_name_repository = MockRegisterUserNameRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
+
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(1)
+                .returning(|| Ok(Arc::new(NgWordRuleSet::empty())));
+
+            register_user_name_repository
+                .expect_find_user_id()
+                .times(1)
                 .with(eq(UserName::new("word").unwrap()))
-                .returning(|_| Ok(()));
+                .returning(|_| Ok(Some(1)));
+
+            register_user_name_repository
+                .expect_create_user_name()
+                .times(0);
+
+            let service = DomainServiceImpl::new(
+                Arc::new(register_user_name_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
 
-            let service = DomainServiceImpl::new(Arc::new(register_user_name_repository));
+            let result = service
+                .register_user_name(
+                    UnvalidatedUserName("word".to_string()),
+                    UnvalidatedPassword("password1".to_string()),
+                )
+                .await;
+
+            assert_eq!(
+                result,
+                Err(RegisterUserNameError::ValidationError(ValidationError(
+                    "User name already exists".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_register_user_name_prefix_of_existing() {
+            // "bobby"が存在しても"bob"は登録できる
+            let mut register_user_name_repository = MockRegisterUserNameRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
+
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(1)
+                .returning(|| Ok(Arc::new(NgWordRuleSet::empty())));
+
+            register_user_name_repository
+                .expect_find_user_id()
+                .times(1)
+                .with(eq(UserName::new("bob").unwrap()))
+                .returning(|_| Ok(None));
+
+            register_user_name_repository
+                .expect_create_user_name()
+                .times(1)
+                .with(eq(UserName::new("bob").unwrap()), always())
+                .returning(|_, _| Ok(()));
+
+            let service = DomainServiceImpl::new(
+                Arc::new(register_user_name_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
 
             let result = service
-                .register_user_name(UnvalidatedUserName("word".to_string()))
+                .register_user_name(
+                    UnvalidatedUserName("bob".to_string()),
+                    UnvalidatedPassword("password1".to_string()),
+                )
                 .await;
 
             assert_eq!(result, Ok(()));
@@ -94,17 +227,26 @@ mod tests {
         #[tokio::test]
         async fn test_register_user_name_too_long() {
             let mut register_user_name_repository = MockRegisterUserNameRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
 
-            register_user_name_repository.expect_find_ng_word().times(0);
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(0);
 
             register_user_name_repository
                 .expect_create_user_name()
                 .times(0);
 
-            let service = DomainServiceImpl::new(Arc::new(register_user_name_repository));
+            let service = DomainServiceImpl::new(
+                Arc::new(register_user_name_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
 
             let result = service
-                .register_user_name(UnvalidatedUserName("veryverylongusern".to_string()))
+                .register_user_name(
+                    UnvalidatedUserName("veryverylongusern".to_string()),
+                    UnvalidatedPassword("password1".to_string()),
+                )
                 .await;
 
             assert_eq!(
@@ -118,17 +260,26 @@ mod tests {
         #[tokio::test]
         async fn test_register_user_name_too_short() {
             let mut register_user_name_repository = MockRegisterUserNameRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
 
-            register_user_name_repository.expect_find_ng_word().times(0);
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(0);
 
             register_user_name_repository
                 .expect_create_user_name()
                 .times(0);
 
-            let service = DomainServiceImpl::new(Arc::new(register_user_name_repository));
+            let service = DomainServiceImpl::new(
+                Arc::new(register_user_name_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
 
             let result = service
-                .register_user_name(UnvalidatedUserName("sh".to_string()))
+                .register_user_name(
+                    UnvalidatedUserName("sh".to_string()),
+                    UnvalidatedPassword("password1".to_string()),
+                )
                 .await;
 
             assert_eq!(
@@ -142,23 +293,109 @@ mod tests {
         #[tokio::test]
         async fn test_register_user_name_ng_word() {
             let mut register_user_name_repository = MockRegisterUserNameRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
+
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(1)
+                .returning(|| Ok(ng_word_rules(&["word"])));
+
+            register_user_name_repository
+                .expect_create_user_name()
+                .times(0);
+
+            let service = DomainServiceImpl::new(
+                Arc::new(register_user_name_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
+
+            let result = service
+                .register_user_name(
+                    UnvalidatedUserName("word".to_string()),
+                    UnvalidatedPassword("password1".to_string()),
+                )
+                .await;
+
+            assert_eq!(
+                result,
+                Err(RegisterUserNameError::ValidationError(ValidationError(
+                    "Name must not contain NG words".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_register_user_name_allow_word() {
+            // 例外の語の中に現れたNGワードは一致しない
+            let mut register_user_name_repository = MockRegisterUserNameRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
+
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(1)
+                .returning(|| {
+                    let rule_set = Arc::unwrap_or_clone(ng_word_rules(&["ass"]));
+                    Ok(Arc::new(rule_set.with_allow_words(["class"]).unwrap()))
+                });
 
             register_user_name_repository
-                .expect_find_ng_word()
+                .expect_find_user_id()
                 .times(1)
-                .with(eq(UserName::new("word").unwrap()))
-                .returning(|_| Ok(true));
+                .returning(|_| Ok(None));
 
             register_user_name_repository
                 .expect_create_user_name()
-                .times(0)
-                .with(eq(UserName::new("word").unwrap()))
-                .returning(|_| Ok(()));
+                .times(1)
+                .with(eq(UserName::new("classmate").unwrap()), always())
+                .returning(|_, _| Ok(()));
 
-            let service = DomainServiceImpl::new(Arc::new(register_user_name_repository));
+            let service = DomainServiceImpl::new(
+                Arc::new(register_user_name_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
+
+            let result = service
+                .register_user_name(
+                    UnvalidatedUserName("classmate".to_string()),
+                    UnvalidatedPassword("password1".to_string()),
+                )
+                .await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_register_user_name_ng_word_pattern() {
+            let mut register_user_name_repository = MockRegisterUserNameRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
+
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(1)
+                .returning(|| {
+                    let rules = vec![NgWordRule {
+                        id: 1,
+                        word: r"b[a4@]d".to_string(),
+                        kind: PatternKind::Regex,
+                        severity: Severity::Mask,
+                    }];
+                    Ok(Arc::new(NgWordRuleSet::new(rules).unwrap()))
+                });
+
+            register_user_name_repository
+                .expect_create_user_name()
+                .times(0);
+
+            let service = DomainServiceImpl::new(
+                Arc::new(register_user_name_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
 
             let result = service
-                .register_user_name(UnvalidatedUserName("word".to_string()))
+                .register_user_name(
+                    UnvalidatedUserName("xb4dx".to_string()),
+                    UnvalidatedPassword("password1".to_string()),
+                )
                 .await;
 
             assert_eq!(
@@ -172,23 +409,34 @@ mod tests {
         #[tokio::test]
         async fn test_register_user_name_too_register_user_name_error() {
             let mut register_user_name_repository = MockRegisterUserNameRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
+
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(1)
+                .returning(|| Ok(Arc::new(NgWordRuleSet::empty())));
 
             register_user_name_repository
-                .expect_find_ng_word()
+                .expect_find_user_id()
                 .times(1)
-                .with(eq(UserName::new("word").unwrap()))
-                .returning(|_| Ok(false));
+                .returning(|_| Ok(None));
 
             register_user_name_repository
                 .expect_create_user_name()
                 .times(1)
-                .with(eq(UserName::new("word").unwrap()))
-                .returning(|_| Err(ServiceError("Already exists".to_string())));
+                .with(eq(UserName::new("word").unwrap()), always())
+                .returning(|_, _| Err(ServiceError("Already exists".to_string())));
 
-            let service = DomainServiceImpl::new(Arc::new(register_user_name_repository));
+            let service = DomainServiceImpl::new(
+                Arc::new(register_user_name_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
 
             let result = service
-                .register_user_name(UnvalidatedUserName("word".to_string()))
+                .register_user_name(
+                    UnvalidatedUserName("word".to_string()),
+                    UnvalidatedPassword("password1".to_string()),
+                )
                 .await;
 
             assert_eq!(
@@ -202,25 +450,31 @@ mod tests {
         #[tokio::test]
         async fn test_register_user_name_check_ng_word_service_error() {
             let mut register_user_name_repository = MockRegisterUserNameRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
 
-            register_user_name_repository
-                .expect_find_ng_word()
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
                 .times(1)
-                .with(eq(UserName::new("word").unwrap()))
-                .returning(|_| {
-                    Err(RegisterUserNameError::from(ServiceError(
+                .returning(|| {
+                    Err(ng_word_policy::err::ServiceError(
                         "DomainServiceImpl error".to_string(),
-                    )))
+                    ))
                 });
 
             register_user_name_repository
                 .expect_create_user_name()
                 .times(0);
 
-            let service = DomainServiceImpl::new(Arc::new(register_user_name_repository));
+            let service = DomainServiceImpl::new(
+                Arc::new(register_user_name_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
 
             let result = service
-                .register_user_name(UnvalidatedUserName("word".to_string()))
+                .register_user_name(
+                    UnvalidatedUserName("word".to_string()),
+                    UnvalidatedPassword("password1".to_string()),
+                )
                 .await;
 
             assert_eq!(
@@ -231,35 +485,113 @@ mod tests {
             );
         }
         #[tokio::test]
-        async fn test_register_user_name_check_ng_word_validation_error() {
+        async fn test_register_user_name_ng_word_normalized() {
+            for name in ["ＢＡＤ_guy", "Bad_guy", "ばっどマン", "ﾊﾞｯﾄﾞマン"] {
+                let mut register_user_name_repository = MockRegisterUserNameRepository::new();
+                let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
+
+                ng_word_policy_repository
+                    .expect_find_ng_word_rules()
+                    .times(1)
+                    .returning(|| Ok(ng_word_rules(&["bad", "バッド"])));
+
+                register_user_name_repository
+                    .expect_create_user_name()
+                    .times(0);
+
+                let service = DomainServiceImpl::new(
+                    Arc::new(register_user_name_repository),
+                    Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+                );
+
+                let result = service
+                    .register_user_name(
+                        UnvalidatedUserName(name.to_string()),
+                        UnvalidatedPassword("password1".to_string()),
+                    )
+                    .await;
+
+                assert_eq!(
+                    result,
+                    Err(RegisterUserNameError::ValidationError(ValidationError(
+                        "Name must not contain NG words".to_string()
+                    ))),
+                    "{}",
+                    name
+                );
+            }
+        }
+
+        #[tokio::test]
+        async fn test_register_user_name_weak_password() {
             let mut register_user_name_repository = MockRegisterUserNameRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
 
-            register_user_name_repository
-                .expect_find_ng_word()
-                .times(1)
-                .with(eq(UserName::new("word").unwrap()))
-                .returning(|_| {
-                    Err(RegisterUserNameError::from(ValidationError(
-                        "Contains NG Word".to_string(),
-                    )))
-                });
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(0);
 
             register_user_name_repository
                 .expect_create_user_name()
                 .times(0);
 
-            let service = DomainServiceImpl::new(Arc::new(register_user_name_repository));
+            let service = DomainServiceImpl::new(
+                Arc::new(register_user_name_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
 
             let result = service
-                .register_user_name(UnvalidatedUserName("word".to_string()))
+                .register_user_name(
+                    UnvalidatedUserName("word".to_string()),
+                    UnvalidatedPassword("password".to_string()),
+                )
                 .await;
 
             assert_eq!(
                 result,
-                Err(RegisterUserNameError::from(ValidationError(
-                    "Contains NG Word".to_string(),
+                Err(RegisterUserNameError::ValidationError(ValidationError(
+                    "Password must contain both letters and digits".to_string()
                 )))
             );
         }
+
+        #[tokio::test]
+        async fn test_register_user_name_password_is_hashed() {
+            let mut register_user_name_repository = MockRegisterUserNameRepository::new();
+            let mut ng_word_policy_repository = MockNgWordPolicyRepository::new();
+
+            ng_word_policy_repository
+                .expect_find_ng_word_rules()
+                .times(1)
+                .returning(|| Ok(Arc::new(NgWordRuleSet::empty())));
+
+            register_user_name_repository
+                .expect_find_user_id()
+                .times(1)
+                .returning(|_| Ok(None));
+
+            register_user_name_repository
+                .expect_create_user_name()
+                .times(1)
+                .withf(|_, password| {
+                    password.value().starts_with("$argon2id$")
+                        && !password.value().contains("password1")
+                })
+                .returning(|_, _| Ok(()));
+
+            let service = DomainServiceImpl::new(
+                Arc::new(register_user_name_repository),
+                Arc::new(NgWordPolicyImpl::new(Arc::new(ng_word_policy_repository))),
+            );
+
+            let result = service
+                .register_user_name(
+                    UnvalidatedUserName("word".to_string()),
+                    UnvalidatedPassword("password1".to_string()),
+                )
+                .await;
+
+            assert_eq!(result, Ok(()));
+        }
     }
 }
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use domain_model::restore_post::err::ServiceError;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait RestorePostRepository: Send + Sync {
+    /// 削除された投稿を元に戻し、元に戻したユーザと日時を記録する
+    ///
+    /// 削除の記録は残す。削除された投稿が無かった場合は`false`を返す
+    async fn restore_post(&self, id: i32, user_id: i32) -> Result<bool, ServiceError>;
+}
@@ -0,0 +1,131 @@
+use super::repository::RestorePostRepository;
+use async_trait::async_trait;
+use domain_model::{
+    login::model::LoggedInUser,
+    restore_post::err::{NotFoundError, PermissionError, RestorePostError},
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    /// 削除された投稿を元に戻す
+    ///
+    /// 削除する前の公開状態に戻る。削除の記録は残し、元に戻したユーザを記録する
+    async fn restore_post(&self, id: i32, user: &LoggedInUser) -> Result<(), RestorePostError>;
+}
+
+pub struct DomainServiceImpl {
+    restore_post_repository: Arc<dyn RestorePostRepository>,
+}
+
+impl DomainServiceImpl {
+    pub fn new(restore_post_repository: Arc<dyn RestorePostRepository>) -> Self {
+        Self {
+            restore_post_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn restore_post(&self, id: i32, user: &LoggedInUser) -> Result<(), RestorePostError> {
+        if !user.role.can_moderate_posts() {
+            return Err(RestorePostError::from(PermissionError(
+                "Only moderators can restore posts".to_string(),
+            )));
+        }
+        if self
+            .restore_post_repository
+            .restore_post(id, user.id)
+            .await?
+        {
+            Ok(())
+        } else {
+            Err(RestorePostError::from(NotFoundError(
+                "Deleted post not found".to_string(),
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_model::{login::model::Role, restore_post::err::ServiceError};
+    use mockall::predicate::*;
+
+    fn user(role: Role) -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role,
+        }
+    }
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::restore_post::repository::MockRestorePostRepository;
+
+        #[tokio::test]
+        async fn test_restore_post() {
+            let mut mock = MockRestorePostRepository::new();
+            mock.expect_restore_post()
+                .with(eq(10), eq(1))
+                .times(1)
+                .returning(|_, _| Ok(true));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.restore_post(10, &user(Role::Moderator)).await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_restore_post_permission_error() {
+            let mut mock = MockRestorePostRepository::new();
+            mock.expect_restore_post().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.restore_post(1, &user(Role::Member)).await;
+
+            assert_eq!(
+                result,
+                Err(RestorePostError::PermissionError(PermissionError(
+                    "Only moderators can restore posts".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_restore_post_not_found() {
+            let mut mock = MockRestorePostRepository::new();
+            mock.expect_restore_post()
+                .with(eq(10), eq(1))
+                .times(1)
+                .returning(|_, _| Ok(false));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.restore_post(10, &user(Role::Admin)).await;
+
+            assert_eq!(
+                result,
+                Err(RestorePostError::NotFoundError(NotFoundError(
+                    "Deleted post not found".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_restore_post_service_error() {
+            let mut mock = MockRestorePostRepository::new();
+            mock.expect_restore_post()
+                .times(1)
+                .returning(|_, _| Err(ServiceError("error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service.restore_post(1, &user(Role::Moderator)).await;
+
+            assert!(matches!(result, Err(RestorePostError::ServiceError(_))));
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use domain_model::search_post::{
+    err::ServiceError,
+    model::{RankedPost, SearchCandidate, SearchFilter, SearchQuery},
+};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait SearchPostRepository: Send + Sync {
+    /// 検索語の語を全て含む投稿のIDを、一致した箇所の多い順、同じならIDの大きい順に全て取得する
+    ///
+    /// 塗りつぶす前の内容で数える。モデレーション待ちや削除された投稿、絞り込みを満たさない投稿も含む
+    async fn rank_posts(&self, query: &SearchQuery) -> Result<Vec<RankedPost>, ServiceError>;
+    /// IDを指定した投稿のうち、公開済みで削除されていない、絞り込みを満たすものを`ids`の順に取得する
+    ///
+    /// 塗りつぶす前の内容を返す。`ids`は1度に取得する分だけに分けて渡すこと
+    async fn find_posts(
+        &self,
+        ids: &[i32],
+        filter: &SearchFilter,
+    ) -> Result<Vec<SearchCandidate>, ServiceError>;
+    /// ユーザがミュートした語を全て取得する
+    async fn find_mute_words(&self, user_id: i32) -> Result<Vec<String>, ServiceError>;
+}
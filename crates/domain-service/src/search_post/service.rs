@@ -0,0 +1,491 @@
+use super::repository::SearchPostRepository;
+use crate::ng_word_policy::NgWordPolicy;
+use async_trait::async_trait;
+use domain_model::{
+    get_all_post::model::{Content, MuteMode, Post, Viewer},
+    search_post::{
+        err::{SearchPostError, ServiceError},
+        model::{RankedPost, SearchHit, SearchRequest, UnvalidatedSearchRequest},
+    },
+    text::{char_range, NgWordMatcher, NormalizedText},
+};
+use std::{ops::Range, sync::Arc};
+
+// 順位の高い方から1度に取得して確かめる候補の件数
+const SEARCH_PAGE_SIZE: usize = 100;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    /// 検索語の語を全て含む公開済みの投稿を、一致した箇所の多い順に取得する
+    ///
+    /// 一致した箇所の数が同じ場合はIDの大きい(新しい)順に並べる。
+    /// 投稿取得と同じくその時点の規則で塗りつぶし、塗りつぶした後の内容で照合する。
+    /// 閲覧するユーザがいる場合、そのユーザがミュートした語を含む投稿は隠すか折りたたむ
+    async fn search_post(
+        &self,
+        request: UnvalidatedSearchRequest,
+        viewer: Option<Viewer>,
+    ) -> Result<Vec<SearchHit>, SearchPostError>;
+}
+
+pub struct DomainServiceImpl {
+    search_post_repository: Arc<dyn SearchPostRepository>,
+    ng_word_policy: Arc<dyn NgWordPolicy>,
+}
+impl DomainServiceImpl {
+    pub fn new(
+        search_post_repository: Arc<dyn SearchPostRepository>,
+        ng_word_policy: Arc<dyn NgWordPolicy>,
+    ) -> Self {
+        Self {
+            search_post_repository,
+            ng_word_policy,
+        }
+    }
+}
+
+/// 内容の中で語が一致した箇所を探す
+///
+/// 1つでも見つからない語がある場合は`None`を返す。
+/// 見つかった場合は一致した箇所の数と、重なりをまとめたコードポイント単位の範囲を返す
+fn find_terms(content: &str, terms: &[String]) -> Option<(usize, Vec<Range<usize>>)> {
+    let text = NormalizedText::new(content);
+    let mut found = Vec::new();
+    for term in terms {
+        let ranges = text.find_all(term);
+        if ranges.is_empty() {
+            return None;
+        }
+        found.extend(ranges);
+    }
+    let score = found.len();
+    found.sort_by_key(|range| range.start);
+    let mut merged: Vec<Range<usize>> = Vec::new();
+    for range in found {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    let highlights = merged
+        .into_iter()
+        .map(|range| char_range(content, range))
+        .collect();
+    Some((score, highlights))
+}
+
+/// 集めた結果の上位`limit`件が、まだ確かめていない投稿に抜かれることが無いか
+///
+/// 塗りつぶすと一致した箇所は減るだけなので、まだ確かめていない投稿の数は`next`の数を超えない
+fn is_settled(hits: &[(usize, SearchHit)], limit: usize, next: &RankedPost) -> bool {
+    hits.get(limit - 1)
+        .is_some_and(|(score, hit)| (*score, hit.post.id) > (next.score, next.id))
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn search_post(
+        &self,
+        request: UnvalidatedSearchRequest,
+        viewer: Option<Viewer>,
+    ) -> Result<Vec<SearchHit>, SearchPostError> {
+        let request = SearchRequest::new(request)?;
+        let ranked = self
+            .search_post_repository
+            .rank_posts(request.query())
+            .await?;
+        if ranked.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mute = match viewer {
+            Some(viewer) => {
+                let mute_words = self
+                    .search_post_repository
+                    .find_mute_words(viewer.user_id)
+                    .await?;
+                let matcher = NgWordMatcher::new(mute_words).map_err(|e| ServiceError(e.0))?;
+                Some((matcher, viewer.mute_mode))
+            }
+            None => None,
+        };
+        let mut hits = Vec::new();
+        // 順位の高い方から少しずつ取得し、上位が決まったら残りは確かめない
+        for page in ranked.chunks(SEARCH_PAGE_SIZE) {
+            if is_settled(&hits, request.limit(), &page[0]) {
+                break;
+            }
+            let ids: Vec<i32> = page.iter().map(|ranked| ranked.id).collect();
+            let candidates = self
+                .search_post_repository
+                .find_posts(&ids, request.filter())
+                .await?;
+            for candidate in candidates {
+                // 投稿取得と同じく、書かれたままの内容を正規化して照合する
+                let muted = mute.as_ref().is_some_and(|(matcher, _)| {
+                    matcher.is_match(&NormalizedText::new(&candidate.post.content.0))
+                });
+                if muted && matches!(mute, Some((_, MuteMode::Hide))) {
+                    continue;
+                }
+                let content = self
+                    .ng_word_policy
+                    .mask_content(&candidate.post.content.0)
+                    .await?;
+                // 塗りつぶした語で投稿を探せないよう、塗りつぶした後の内容で照合し直す
+                let Some((score, highlights)) = find_terms(&content, request.query().terms())
+                else {
+                    continue;
+                };
+                hits.push((
+                    score,
+                    SearchHit {
+                        post: Post {
+                            content: Content(content),
+                            muted,
+                            ..candidate.post
+                        },
+                        parent_post_id: candidate.parent_post_id,
+                        highlights,
+                    },
+                ));
+            }
+            // 塗りつぶして数が減った投稿もあるため、取得するたびに並べ直す
+            hits.sort_by(|(a, a_hit), (b, b_hit)| b.cmp(a).then(b_hit.post.id.cmp(&a_hit.post.id)));
+        }
+        Ok(hits
+            .into_iter()
+            .take(request.limit())
+            .map(|(_, hit)| hit)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::*;
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::{
+            ng_word_policy::ng_word_policy, search_post::repository::MockSearchPostRepository,
+        };
+        use chrono::NaiveDateTime;
+        use domain_model::{
+            get_all_post::model::{PostedDatetime, PostedUserName},
+            ng_word_policy::model::Severity,
+            search_post::{
+                err::ValidationError,
+                model::{SearchCandidate, SearchFilter, SearchQuery},
+            },
+        };
+        const DATETIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+        fn post(id: i32, content: &str) -> Post {
+            Post {
+                id,
+                posted_user_name: PostedUserName("test".to_string()),
+                posted_datetime: PostedDatetime(
+                    NaiveDateTime::parse_from_str("2021-01-01T00:00:00Z", DATETIME_FORMAT).unwrap(),
+                ),
+                content: Content(content.to_string()),
+                muted: false,
+                reply_count: 0,
+                reactions: vec![],
+            }
+        }
+
+        fn candidate(id: i32, content: &str) -> SearchCandidate {
+            SearchCandidate {
+                post: post(id, content),
+                parent_post_id: None,
+            }
+        }
+
+        fn request(query: &str) -> UnvalidatedSearchRequest {
+            UnvalidatedSearchRequest {
+                query: Some(query.to_string()),
+                ..Default::default()
+            }
+        }
+
+        fn ranked(posts: &[(i32, usize)]) -> Vec<RankedPost> {
+            posts
+                .iter()
+                .map(|(id, score)| RankedPost {
+                    id: *id,
+                    score: *score,
+                })
+                .collect()
+        }
+
+        #[tokio::test]
+        async fn test_search_post() {
+            let mut mock = MockSearchPostRepository::new();
+            mock.expect_rank_posts()
+                .with(eq(SearchQuery::new("掲示板").unwrap()))
+                .times(1)
+                .returning(|_| Ok(ranked(&[(2, 2)])));
+            mock.expect_find_posts()
+                .withf(|ids, filter| ids == [2] && *filter == SearchFilter::default())
+                .times(1)
+                .returning(|_, _| {
+                    Ok(vec![SearchCandidate {
+                        post: post(2, "Rustの掲示板と掲示板"),
+                        parent_post_id: Some(1),
+                    }])
+                });
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 1));
+            let result = service.search_post(request("掲示板"), None).await;
+
+            assert_eq!(
+                result,
+                Ok(vec![SearchHit {
+                    post: post(2, "Rustの掲示板と掲示板"),
+                    parent_post_id: Some(1),
+                    highlights: vec![5..8, 9..12],
+                }])
+            );
+        }
+
+        #[tokio::test]
+        async fn test_search_post_not_found() {
+            let mut mock = MockSearchPostRepository::new();
+            mock.expect_rank_posts().times(1).returning(|_| Ok(vec![]));
+            mock.expect_find_posts().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 0));
+            let result = service.search_post(request("掲示板"), None).await;
+
+            assert_eq!(result, Ok(vec![]));
+        }
+
+        #[tokio::test]
+        async fn test_search_post_normalized() {
+            let mut mock = MockSearchPostRepository::new();
+            mock.expect_rank_posts()
+                .times(1)
+                .returning(|_| Ok(ranked(&[(1, 2)])));
+            mock.expect_find_posts()
+                .times(1)
+                .returning(|_, _| Ok(vec![candidate(1, "ＲＵＳＴとラスト")]));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 1));
+            let result = service.search_post(request("rust らすと"), None).await;
+
+            // 大文字小文字や全角半角、カタカナとひらがなの違いは区別しない
+            let hits = result.unwrap();
+            assert_eq!(hits.len(), 1);
+            assert_eq!(hits[0].highlights, vec![0..4, 5..8]);
+        }
+
+        #[tokio::test]
+        async fn test_search_post_ranking() {
+            let mut mock = MockSearchPostRepository::new();
+            mock.expect_rank_posts()
+                .times(1)
+                .returning(|_| Ok(ranked(&[(3, 2), (4, 1), (1, 1)])));
+            mock.expect_find_posts()
+                .withf(|ids, _| ids == [3, 4, 1])
+                .times(1)
+                .returning(|_, _| {
+                    Ok(vec![
+                        candidate(3, "rust rust"),
+                        candidate(4, "rust"),
+                        candidate(1, "rust"),
+                    ])
+                });
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 3));
+            let result = service
+                .search_post(
+                    UnvalidatedSearchRequest {
+                        limit: Some(2),
+                        ..request("rust")
+                    },
+                    None,
+                )
+                .await;
+
+            // 一致した箇所の多い順、同じなら新しい順に、件数までを返す
+            let ids: Vec<i32> = result.unwrap().iter().map(|hit| hit.post.id).collect();
+            assert_eq!(ids, vec![3, 4]);
+        }
+
+        #[tokio::test]
+        async fn test_search_post_ranking_after_mask() {
+            let mut mock = MockSearchPostRepository::new();
+            mock.expect_rank_posts()
+                .times(1)
+                .returning(|_| Ok(ranked(&[(1, 2), (2, 1)])));
+            mock.expect_find_posts()
+                .times(1)
+                .returning(|_, _| Ok(vec![candidate(1, "rust rustbelt"), candidate(2, "rust")]));
+
+            let service = DomainServiceImpl::new(
+                Arc::new(mock),
+                ng_word_policy(&[("rustbelt", Severity::Mask)], 2),
+            );
+            let result = service.search_post(request("rust"), None).await;
+
+            // 塗りつぶした後の内容で数え直して並べる
+            let ids: Vec<i32> = result.unwrap().iter().map(|hit| hit.post.id).collect();
+            assert_eq!(ids, vec![2, 1]);
+        }
+
+        #[tokio::test]
+        async fn test_search_post_pages() {
+            let mut mock = MockSearchPostRepository::new();
+            mock.expect_rank_posts().times(1).returning(|_| {
+                Ok((1..=150)
+                    .rev()
+                    .map(|id| RankedPost { id, score: 1 })
+                    .collect())
+            });
+            // 絞り込みで候補が減っても、順位の低い方を続けて取得する
+            mock.expect_find_posts()
+                .withf(|ids, _| ids.len() == SEARCH_PAGE_SIZE && ids[0] == 150)
+                .times(1)
+                .returning(|_, _| Ok(vec![candidate(150, "rust")]));
+            mock.expect_find_posts()
+                .withf(|ids, _| ids.len() == 50 && ids[0] == 50)
+                .times(1)
+                .returning(|_, _| Ok(vec![candidate(10, "rust")]));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 2));
+            let result = service
+                .search_post(
+                    UnvalidatedSearchRequest {
+                        limit: Some(2),
+                        ..request("rust")
+                    },
+                    None,
+                )
+                .await;
+
+            let ids: Vec<i32> = result.unwrap().iter().map(|hit| hit.post.id).collect();
+            assert_eq!(ids, vec![150, 10]);
+        }
+
+        #[tokio::test]
+        async fn test_search_post_pages_settled() {
+            let mut mock = MockSearchPostRepository::new();
+            mock.expect_rank_posts().times(1).returning(|_| {
+                Ok((1..=150)
+                    .rev()
+                    .map(|id| RankedPost { id, score: 1 })
+                    .collect())
+            });
+            // 上位が決まったら、順位の低い方は取得しない
+            mock.expect_find_posts()
+                .times(1)
+                .returning(|_, _| Ok(vec![candidate(150, "rust"), candidate(149, "rust")]));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 2));
+            let result = service
+                .search_post(
+                    UnvalidatedSearchRequest {
+                        limit: Some(2),
+                        ..request("rust")
+                    },
+                    None,
+                )
+                .await;
+
+            let ids: Vec<i32> = result.unwrap().iter().map(|hit| hit.post.id).collect();
+            assert_eq!(ids, vec![150, 149]);
+        }
+
+        #[tokio::test]
+        async fn test_search_post_masked_word() {
+            let mut mock = MockSearchPostRepository::new();
+            mock.expect_rank_posts()
+                .times(1)
+                .returning(|_| Ok(ranked(&[(1, 1)])));
+            mock.expect_find_posts()
+                .times(1)
+                .returning(|_, _| Ok(vec![candidate(1, "bad words")]));
+
+            let service = DomainServiceImpl::new(
+                Arc::new(mock),
+                ng_word_policy(&[("bad", Severity::Mask)], 1),
+            );
+            let result = service.search_post(request("bad"), None).await;
+
+            // 塗りつぶした語では見つからない
+            assert_eq!(result, Ok(vec![]));
+        }
+
+        #[tokio::test]
+        async fn test_search_post_muted_hide() {
+            let mut mock = MockSearchPostRepository::new();
+            mock.expect_rank_posts()
+                .times(1)
+                .returning(|_| Ok(ranked(&[(2, 1), (1, 1)])));
+            mock.expect_find_posts()
+                .times(1)
+                .returning(|_, _| Ok(vec![candidate(2, "rust spoiler"), candidate(1, "rust")]));
+            mock.expect_find_mute_words()
+                .with(eq(2))
+                .times(1)
+                .returning(|_| Ok(vec!["SPOILER".to_string()]));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 1));
+            let viewer = Viewer {
+                user_id: 2,
+                mute_mode: MuteMode::Hide,
+            };
+            let result = service.search_post(request("rust"), Some(viewer)).await;
+
+            let ids: Vec<i32> = result.unwrap().iter().map(|hit| hit.post.id).collect();
+            assert_eq!(ids, vec![1]);
+        }
+
+        #[tokio::test]
+        async fn test_search_post_muted_collapse() {
+            let mut mock = MockSearchPostRepository::new();
+            mock.expect_rank_posts()
+                .times(1)
+                .returning(|_| Ok(ranked(&[(2, 1), (1, 1)])));
+            mock.expect_find_posts()
+                .times(1)
+                .returning(|_, _| Ok(vec![candidate(2, "rust spoiler"), candidate(1, "rust")]));
+            mock.expect_find_mute_words()
+                .with(eq(2))
+                .times(1)
+                .returning(|_| Ok(vec!["spoiler".to_string()]));
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 2));
+            let viewer = Viewer {
+                user_id: 2,
+                mute_mode: MuteMode::Collapse,
+            };
+            let result = service.search_post(request("rust"), Some(viewer)).await;
+
+            let muted: Vec<(i32, bool)> = result
+                .unwrap()
+                .iter()
+                .map(|hit| (hit.post.id, hit.post.muted))
+                .collect();
+            assert_eq!(muted, vec![(2, true), (1, false)]);
+        }
+
+        #[tokio::test]
+        async fn test_search_post_validation_error() {
+            let mut mock = MockSearchPostRepository::new();
+            mock.expect_rank_posts().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock), ng_word_policy(&[], 0));
+            let result = service.search_post(request(" "), None).await;
+
+            assert_eq!(
+                result,
+                Err(SearchPostError::ValidationError(ValidationError(
+                    "Query is required".to_string()
+                )))
+            );
+        }
+    }
+}
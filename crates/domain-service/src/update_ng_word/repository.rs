@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use domain_model::{
+    register_ng_word::model::NgWord,
+    update_ng_word::{err::ServiceError, model::StoredNgWord},
+};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait UpdateNgWordRepository: Send + Sync {
+    /// IDに一致するNGワードを取得する
+    async fn find_ng_word(&self, id: i32) -> Result<Option<StoredNgWord>, ServiceError>;
+    /// NGワードを変更し、変更したユーザを記録する
+    ///
+    /// 変更対象が無かった場合は`false`を返す
+    async fn update_ng_word(
+        &self,
+        id: i32,
+        ng_word: &NgWord,
+        user_id: i32,
+    ) -> Result<bool, ServiceError>;
+}
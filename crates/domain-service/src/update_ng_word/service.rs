@@ -0,0 +1,330 @@
+use super::repository::UpdateNgWordRepository;
+use async_trait::async_trait;
+use domain_model::{
+    login::model::LoggedInUser,
+    ng_word_policy::model::{PatternKind, Severity},
+    register_ng_word::model::NgWord,
+    update_ng_word::{
+        err::{NotFoundError, PermissionError, UpdateNgWordError, ValidationError},
+        model::UnvalidatedNgWordPatch,
+    },
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    /// NGワードを変更する
+    ///
+    /// 省略した項目は登録済みの値のまま、変更後のNGワードを登録時と同じ規則で検証する
+    async fn update_ng_word(
+        &self,
+        id: i32,
+        patch: UnvalidatedNgWordPatch,
+        user: &LoggedInUser,
+    ) -> Result<(), UpdateNgWordError>;
+}
+
+pub struct DomainServiceImpl {
+    update_ng_word_repository: Arc<dyn UpdateNgWordRepository>,
+}
+
+impl DomainServiceImpl {
+    pub fn new(update_ng_word_repository: Arc<dyn UpdateNgWordRepository>) -> Self {
+        Self {
+            update_ng_word_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn update_ng_word(
+        &self,
+        id: i32,
+        patch: UnvalidatedNgWordPatch,
+        user: &LoggedInUser,
+    ) -> Result<(), UpdateNgWordError> {
+        if !user.role.can_manage_ng_words() {
+            return Err(UpdateNgWordError::from(PermissionError(
+                "Only moderators can update NG words".to_string(),
+            )));
+        }
+        if patch.is_empty() {
+            return Err(UpdateNgWordError::from(ValidationError(
+                "At least one of ng_word, kind or severity is required".to_string(),
+            )));
+        }
+        let kind = patch
+            .kind
+            .as_deref()
+            .map(|kind| {
+                PatternKind::parse(kind).ok_or_else(|| {
+                    ValidationError("Kind must be one of literal, glob, regex".to_string())
+                })
+            })
+            .transpose()?;
+        let severity = patch
+            .severity
+            .as_deref()
+            .map(|severity| {
+                Severity::parse(severity).ok_or_else(|| {
+                    ValidationError("Severity must be one of mask, hold, reject".to_string())
+                })
+            })
+            .transpose()?;
+
+        let not_found = || UpdateNgWordError::from(NotFoundError("NG word not found".to_string()));
+        let Some(stored) = self.update_ng_word_repository.find_ng_word(id).await? else {
+            return Err(not_found());
+        };
+        let ng_word = NgWord::with_kind(
+            patch.ng_word.as_deref().unwrap_or(&stored.word),
+            kind.unwrap_or(stored.kind),
+        )?
+        .with_severity(severity.unwrap_or(stored.severity));
+
+        if self
+            .update_ng_word_repository
+            .update_ng_word(id, &ng_word, user.id)
+            .await?
+        {
+            Ok(())
+        } else {
+            Err(not_found())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_model::{
+        login::model::Role,
+        update_ng_word::{err::ServiceError, model::StoredNgWord},
+    };
+    use mockall::predicate::*;
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "moderator".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    fn stored() -> StoredNgWord {
+        StoredNgWord {
+            word: "bad".to_string(),
+            kind: PatternKind::Literal,
+            severity: Severity::Mask,
+        }
+    }
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::update_ng_word::repository::MockUpdateNgWordRepository;
+
+        #[tokio::test]
+        async fn test_update_ng_word_severity() {
+            let mut mock = MockUpdateNgWordRepository::new();
+            mock.expect_find_ng_word()
+                .with(eq(10))
+                .times(1)
+                .returning(|_| Ok(Some(stored())));
+            mock.expect_update_ng_word()
+                .with(
+                    eq(10),
+                    eq(NgWord::new("bad").unwrap().with_severity(Severity::Reject)),
+                    eq(1),
+                )
+                .times(1)
+                .returning(|_, _, _| Ok(true));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let patch = UnvalidatedNgWordPatch {
+                severity: Some("reject".to_string()),
+                ..Default::default()
+            };
+            let result = service.update_ng_word(10, patch, &moderator()).await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_update_ng_word_word_and_kind() {
+            let mut mock = MockUpdateNgWordRepository::new();
+            mock.expect_find_ng_word()
+                .with(eq(10))
+                .times(1)
+                .returning(|_| Ok(Some(stored())));
+            mock.expect_update_ng_word()
+                .with(
+                    eq(10),
+                    eq(NgWord::with_kind("b*a*d", PatternKind::Glob).unwrap()),
+                    eq(1),
+                )
+                .times(1)
+                .returning(|_, _, _| Ok(true));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let patch = UnvalidatedNgWordPatch {
+                ng_word: Some("b*a*d".to_string()),
+                kind: Some("glob".to_string()),
+                severity: None,
+            };
+            let result = service.update_ng_word(10, patch, &moderator()).await;
+
+            assert_eq!(result, Ok(()));
+        }
+
+        #[tokio::test]
+        async fn test_update_ng_word_validated_with_stored_kind() {
+            // 書き方を省略した場合は登録済みの書き方で検証する
+            let mut mock = MockUpdateNgWordRepository::new();
+            mock.expect_find_ng_word()
+                .with(eq(10))
+                .times(1)
+                .returning(|_| Ok(Some(stored())));
+            mock.expect_update_ng_word().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let patch = UnvalidatedNgWordPatch {
+                ng_word: Some("b*a*d*with*long".to_string()),
+                ..Default::default()
+            };
+            let result = service.update_ng_word(10, patch, &moderator()).await;
+
+            assert_eq!(
+                result,
+                Err(UpdateNgWordError::ValidationError(ValidationError(
+                    "NgWord must be at most 10 characters long".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_update_ng_word_empty_patch() {
+            let mut mock = MockUpdateNgWordRepository::new();
+            mock.expect_find_ng_word().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let result = service
+                .update_ng_word(10, UnvalidatedNgWordPatch::default(), &moderator())
+                .await;
+
+            assert!(matches!(result, Err(UpdateNgWordError::ValidationError(_))));
+        }
+
+        #[tokio::test]
+        async fn test_update_ng_word_invalid_severity() {
+            let mut mock = MockUpdateNgWordRepository::new();
+            mock.expect_find_ng_word().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let patch = UnvalidatedNgWordPatch {
+                severity: Some("delete".to_string()),
+                ..Default::default()
+            };
+            let result = service.update_ng_word(10, patch, &moderator()).await;
+
+            assert_eq!(
+                result,
+                Err(UpdateNgWordError::ValidationError(ValidationError(
+                    "Severity must be one of mask, hold, reject".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_update_ng_word_not_found() {
+            let mut mock = MockUpdateNgWordRepository::new();
+            mock.expect_find_ng_word()
+                .with(eq(10))
+                .times(1)
+                .returning(|_| Ok(None));
+            mock.expect_update_ng_word().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let patch = UnvalidatedNgWordPatch {
+                severity: Some("reject".to_string()),
+                ..Default::default()
+            };
+            let result = service.update_ng_word(10, patch, &moderator()).await;
+
+            assert_eq!(
+                result,
+                Err(UpdateNgWordError::NotFoundError(NotFoundError(
+                    "NG word not found".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_update_ng_word_deleted_while_updating() {
+            let mut mock = MockUpdateNgWordRepository::new();
+            mock.expect_find_ng_word()
+                .with(eq(10))
+                .times(1)
+                .returning(|_| Ok(Some(stored())));
+            mock.expect_update_ng_word()
+                .times(1)
+                .returning(|_, _, _| Ok(false));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let patch = UnvalidatedNgWordPatch {
+                severity: Some("reject".to_string()),
+                ..Default::default()
+            };
+            let result = service.update_ng_word(10, patch, &moderator()).await;
+
+            assert!(matches!(result, Err(UpdateNgWordError::NotFoundError(_))));
+        }
+
+        #[tokio::test]
+        async fn test_update_ng_word_permission_error() {
+            let mut mock = MockUpdateNgWordRepository::new();
+            mock.expect_find_ng_word().times(0);
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let member = LoggedInUser {
+                id: 2,
+                name: "member".to_string(),
+                role: Role::Member,
+            };
+            let patch = UnvalidatedNgWordPatch {
+                severity: Some("reject".to_string()),
+                ..Default::default()
+            };
+            let result = service.update_ng_word(10, patch, &member).await;
+
+            assert_eq!(
+                result,
+                Err(UpdateNgWordError::PermissionError(PermissionError(
+                    "Only moderators can update NG words".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_update_ng_word_service_error() {
+            let mut mock = MockUpdateNgWordRepository::new();
+            mock.expect_find_ng_word()
+                .times(1)
+                .returning(|_| Err(ServiceError("Database error".to_string())));
+
+            let service = DomainServiceImpl::new(Arc::new(mock));
+            let patch = UnvalidatedNgWordPatch {
+                severity: Some("reject".to_string()),
+                ..Default::default()
+            };
+            let result = service.update_ng_word(10, patch, &moderator()).await;
+
+            assert_eq!(
+                result,
+                Err(UpdateNgWordError::ServiceError(ServiceError(
+                    "Database error".to_string()
+                )))
+            );
+        }
+    }
+}
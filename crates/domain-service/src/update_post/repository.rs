@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use chrono::Duration;
+use domain_model::{
+    post::model::Post,
+    update_post::{
+        err::ServiceError,
+        model::{StoredPost, UpdateOutcome},
+    },
+};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait UpdatePostRepository: Send + Sync {
+    /// IDに一致する投稿を取得する。モデレーション待ちの投稿も含む
+    async fn find_post(&self, id: i32) -> Result<Option<StoredPost>, ServiceError>;
+    /// 更新前の版を履歴に残してから、投稿の内容と公開状態を更新する
+    ///
+    /// 同時に編集されないよう投稿をロックし、ロックした投稿を`user_id`のユーザが
+    /// 投稿してから`window`の間に編集しているかを確かめ直してから更新する
+    async fn update_post(
+        &self,
+        id: i32,
+        post: &Post,
+        user_id: i32,
+        window: Duration,
+    ) -> Result<UpdateOutcome, ServiceError>;
+}
@@ -0,0 +1,320 @@
+use super::repository::UpdatePostRepository;
+use crate::{ng_word_policy::NgWordPolicy, post::judge_post};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use domain_model::{
+    login::model::LoggedInUser,
+    post::model::{PostStatus, UserName},
+    update_post::{
+        err::{NotFoundError, UpdatePostError},
+        model::UpdateOutcome,
+    },
+};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait DomainService: Send + Sync {
+    /// 自分の投稿の内容を変更し、変更後の公開状態を返す
+    ///
+    /// 投稿時と同じ規則でNGワードを検証し直す。
+    /// `hold`のNGワードを含む場合はモデレーション待ちに、含まない場合は公開に戻す
+    async fn update_post(
+        &self,
+        id: i32,
+        content: String,
+        user: &LoggedInUser,
+    ) -> Result<PostStatus, UpdatePostError>;
+}
+
+pub struct DomainServiceImpl {
+    update_post_repository: Arc<dyn UpdatePostRepository>,
+    ng_word_policy: Arc<dyn NgWordPolicy>,
+    /// 投稿してから編集できる期間
+    edit_window: Duration,
+}
+
+impl DomainServiceImpl {
+    pub fn new(
+        update_post_repository: Arc<dyn UpdatePostRepository>,
+        ng_word_policy: Arc<dyn NgWordPolicy>,
+        edit_window: std::time::Duration,
+    ) -> Self {
+        Self {
+            update_post_repository,
+            ng_word_policy,
+            // 表せないほど長い期間は無期限として扱う
+            edit_window: Duration::from_std(edit_window).unwrap_or(Duration::MAX),
+        }
+    }
+}
+
+#[async_trait]
+impl DomainService for DomainServiceImpl {
+    async fn update_post(
+        &self,
+        id: i32,
+        content: String,
+        user: &LoggedInUser,
+    ) -> Result<PostStatus, UpdatePostError> {
+        let not_found = || UpdatePostError::from(NotFoundError("Post not found".to_string()));
+        // NGワードの検証の前に確かめて、編集できない投稿では規則を読み込まない
+        let Some(stored) = self.update_post_repository.find_post(id).await? else {
+            return Err(not_found());
+        };
+        stored.check_editable(user.id, Utc::now(), self.edit_window)?;
+
+        let user_name = UserName::new(&user.name)?;
+        let verdict = self.ng_word_policy.check_content(&content).await?;
+        let post = judge_post(&user_name, &content, verdict)?;
+
+        match self
+            .update_post_repository
+            .update_post(id, &post, user.id, self.edit_window)
+            .await?
+        {
+            UpdateOutcome::Updated => Ok(post.status()),
+            UpdateOutcome::NotFound => Err(not_found()),
+            UpdateOutcome::NotEditable(err) => Err(err.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockall::predicate::*;
+
+    mod domain_service_impl {
+        use super::*;
+        use crate::{
+            ng_word_policy::{ng_word_policy, NgWordPolicyImpl},
+            update_post::repository::MockUpdatePostRepository,
+        };
+        use domain_model::{
+            login::model::Role,
+            ng_word_policy::model::Severity,
+            post::model::Post,
+            update_post::{
+                err::{NgWordError, PermissionError, ServiceError},
+                model::StoredPost,
+            },
+        };
+
+        fn author() -> LoggedInUser {
+            LoggedInUser {
+                id: 2,
+                name: "user_name".to_string(),
+                role: Role::Member,
+            }
+        }
+
+        fn stored(minutes_ago: i64) -> StoredPost {
+            StoredPost {
+                id: 1,
+                posted_user_id: 2,
+                created_at: Utc::now() - Duration::minutes(minutes_ago),
+            }
+        }
+
+        fn mock_with_stored(stored: Option<StoredPost>) -> MockUpdatePostRepository {
+            let mut mock = MockUpdatePostRepository::new();
+            mock.expect_find_post()
+                .with(eq(1))
+                .times(1)
+                .returning(move |_| Ok(stored.clone()));
+            mock
+        }
+
+        fn service(
+            mock: MockUpdatePostRepository,
+            ng_word_policy: Arc<NgWordPolicyImpl>,
+        ) -> DomainServiceImpl {
+            DomainServiceImpl::new(
+                Arc::new(mock),
+                ng_word_policy,
+                std::time::Duration::from_secs(15 * 60),
+            )
+        }
+
+        #[tokio::test]
+        async fn test_update_post() {
+            let mut mock = mock_with_stored(Some(stored(5)));
+            let expected = Post::new("user_name".to_string(), "so bad".to_string()).unwrap();
+            mock.expect_update_post()
+                .with(eq(1), eq(expected), eq(2), eq(Duration::minutes(15)))
+                .times(1)
+                .returning(|_, _, _, _| Ok(UpdateOutcome::Updated));
+
+            let service = service(mock, ng_word_policy(&[("bad", Severity::Mask)], 1));
+            let result = service
+                .update_post(1, "so bad".to_string(), &author())
+                .await;
+
+            assert_eq!(result, Ok(PostStatus::Published));
+        }
+
+        #[tokio::test]
+        async fn test_update_post_hold() {
+            let mut mock = mock_with_stored(Some(stored(5)));
+            mock.expect_update_post()
+                .withf(|_, post, _, _| post.status() == PostStatus::Held)
+                .times(1)
+                .returning(|_, _, _, _| Ok(UpdateOutcome::Updated));
+
+            let service = service(mock, ng_word_policy(&[("bad", Severity::Hold)], 1));
+            let result = service
+                .update_post(1, "so bad".to_string(), &author())
+                .await;
+
+            assert_eq!(result, Ok(PostStatus::Held));
+        }
+
+        #[tokio::test]
+        async fn test_update_post_ng_word_reject() {
+            let mut mock = mock_with_stored(Some(stored(5)));
+            mock.expect_update_post().times(0);
+
+            let service = service(mock, ng_word_policy(&[("bad", Severity::Reject)], 1));
+            let result = service
+                .update_post(1, "so bad".to_string(), &author())
+                .await;
+
+            assert_eq!(
+                result,
+                Err(UpdatePostError::NgWordError(NgWordError(
+                    "Content contains a rejected NG word (rule 1): bad".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_update_post_validation_error() {
+            let mut mock = mock_with_stored(Some(stored(5)));
+            mock.expect_update_post().times(0);
+
+            let service = service(mock, ng_word_policy(&[], 1));
+            let result = service.update_post(1, "".to_string(), &author()).await;
+
+            assert!(matches!(result, Err(UpdatePostError::ValidationError(_))));
+        }
+
+        #[tokio::test]
+        async fn test_update_post_not_author() {
+            let mut mock = mock_with_stored(Some(stored(5)));
+            mock.expect_update_post().times(0);
+
+            let service = service(mock, ng_word_policy(&[], 0));
+            let other = LoggedInUser {
+                id: 3,
+                name: "other".to_string(),
+                role: Role::Moderator,
+            };
+            let result = service.update_post(1, "edited".to_string(), &other).await;
+
+            assert_eq!(
+                result,
+                Err(UpdatePostError::PermissionError(PermissionError(
+                    "Only the author can edit the post".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_update_post_window_elapsed() {
+            let mut mock = mock_with_stored(Some(stored(20)));
+            mock.expect_update_post().times(0);
+
+            let service = service(mock, ng_word_policy(&[], 0));
+            let result = service
+                .update_post(1, "edited".to_string(), &author())
+                .await;
+
+            assert_eq!(
+                result,
+                Err(UpdatePostError::PermissionError(PermissionError(
+                    "Post can only be edited within 15 minutes".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_update_post_window_elapsed_while_locking() {
+            let mut mock = mock_with_stored(Some(stored(5)));
+            mock.expect_update_post().times(1).returning(|_, _, _, _| {
+                Ok(UpdateOutcome::NotEditable(PermissionError(
+                    "Post can only be edited within 15 minutes".to_string(),
+                )))
+            });
+
+            let service = service(mock, ng_word_policy(&[], 1));
+            let result = service
+                .update_post(1, "edited".to_string(), &author())
+                .await;
+
+            assert_eq!(
+                result,
+                Err(UpdatePostError::PermissionError(PermissionError(
+                    "Post can only be edited within 15 minutes".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_update_post_deleted_while_locking() {
+            let mut mock = mock_with_stored(Some(stored(5)));
+            mock.expect_update_post()
+                .times(1)
+                .returning(|_, _, _, _| Ok(UpdateOutcome::NotFound));
+
+            let service = service(mock, ng_word_policy(&[], 1));
+            let result = service
+                .update_post(1, "edited".to_string(), &author())
+                .await;
+
+            assert_eq!(
+                result,
+                Err(UpdatePostError::NotFoundError(NotFoundError(
+                    "Post not found".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_update_post_not_found() {
+            let mut mock = mock_with_stored(None);
+            mock.expect_update_post().times(0);
+
+            let service = service(mock, ng_word_policy(&[], 0));
+            let result = service
+                .update_post(1, "edited".to_string(), &author())
+                .await;
+
+            assert_eq!(
+                result,
+                Err(UpdatePostError::NotFoundError(NotFoundError(
+                    "Post not found".to_string()
+                )))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_update_post_service_error() {
+            let mut mock = mock_with_stored(Some(stored(5)));
+            mock.expect_update_post()
+                .times(1)
+                .returning(|_, _, _, _| Err(ServiceError("error".to_string())));
+
+            let service = service(mock, ng_word_policy(&[], 1));
+            let result = service
+                .update_post(1, "edited".to_string(), &author())
+                .await;
+
+            assert_eq!(
+                result,
+                Err(UpdatePostError::ServiceError(ServiceError(
+                    "error".to_string()
+                )))
+            );
+        }
+    }
+}
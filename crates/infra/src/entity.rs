@@ -1,3 +1,8 @@
+pub mod m_allow_word;
 pub mod m_ng_word;
 pub mod m_user_name;
+pub mod t_mute_word;
 pub mod t_post;
+pub mod t_post_reaction;
+pub mod t_post_revision;
+pub mod t_session;
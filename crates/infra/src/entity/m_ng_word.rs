@@ -1,4 +1,3 @@
-use domain_model::post::model::NgWord;
 use sea_orm::entity::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
@@ -7,7 +6,10 @@ pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
     pub word: String,
+    pub kind: String,
+    pub severity: String,
     pub created_user_id: Option<i32>,
+    pub updated_user_id: Option<i32>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
@@ -26,8 +28,3 @@ impl RelationTrait for Relation {
     }
 }
 impl ActiveModelBehavior for ActiveModel {}
-impl Into<NgWord> for Model {
-    fn into(self) -> NgWord {
-        NgWord::new(&self.word).unwrap()
-    }
-}
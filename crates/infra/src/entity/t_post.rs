@@ -6,9 +6,18 @@ pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
     pub posted_user_id: i32,
-    pub content: String,
+    pub parent_post_id: Option<i32>,
+    /// 書かれたままの内容。NGワードは取得時に塗りつぶす
+    pub original_content: String,
+    pub status: String,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
+    pub deleted_at: Option<DateTimeWithTimeZone>,
+    pub deleted_user_id: Option<i32>,
+    pub delete_reason: Option<String>,
+    /// 削除された投稿を最後に元に戻した日時とユーザ
+    pub restored_at: Option<DateTimeWithTimeZone>,
+    pub restored_user_id: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter)]
@@ -0,0 +1,43 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "t_post_reaction")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub post_id: i32,
+    pub user_id: i32,
+    pub kind: String,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {
+    TPost,
+    MUserName,
+}
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        match self {
+            Self::TPost => Entity::belongs_to(super::t_post::Entity)
+                .from(Column::PostId)
+                .to(super::t_post::Column::Id)
+                .into(),
+            Self::MUserName => Entity::belongs_to(super::m_user_name::Entity)
+                .from(Column::UserId)
+                .to(super::m_user_name::Column::Id)
+                .into(),
+        }
+    }
+}
+impl Related<super::t_post::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TPost.def()
+    }
+}
+impl Related<super::m_user_name::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MUserName.def()
+    }
+}
+impl ActiveModelBehavior for ActiveModel {}
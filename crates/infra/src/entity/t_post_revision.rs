@@ -0,0 +1,36 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "t_post_revision")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub post_id: i32,
+    /// 編集される前の、書かれたままの内容
+    pub original_content: String,
+    /// 編集される前の内容が書かれた日時
+    pub written_at: DateTimeWithTimeZone,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter)]
+pub enum Relation {
+    TPost,
+}
+impl RelationTrait for Relation {
+    fn def(&self) -> RelationDef {
+        match self {
+            Self::TPost => Entity::belongs_to(super::t_post::Entity)
+                .from(Column::PostId)
+                .to(super::t_post::Column::Id)
+                .into(),
+        }
+    }
+}
+impl Related<super::t_post::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TPost.def()
+    }
+}
+impl ActiveModelBehavior for ActiveModel {}
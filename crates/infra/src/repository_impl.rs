@@ -1,11 +1,64 @@
+mod delete_allow_word_repository_impl;
+mod delete_mute_word_repository_impl;
+mod delete_ng_word_repository_impl;
+mod delete_post_repository_impl;
+mod delete_reaction_repository_impl;
+mod export_ng_words_repository_impl;
 mod get_all_post_repository_impl;
+mod get_all_raw_post_repository_impl;
+mod get_post_repository_impl;
+mod get_post_thread_repository_impl;
+mod import_ng_words_repository_impl;
+mod list_allow_words_repository_impl;
+mod list_mute_words_repository_impl;
+mod list_ng_words_repository_impl;
+mod list_post_revisions_repository_impl;
 mod login_repository_impl;
+mod ng_word_policy_repository_impl;
+mod ng_word_rule_cache;
 mod post_repository_impl;
+mod post_search_index;
+mod reaction_count;
+mod register_allow_word_repository_impl;
+mod register_mute_word_repository_impl;
 mod register_ng_word_repository_impl;
+mod register_reaction_repository_impl;
 mod register_user_name_repository_impl;
+mod reply_count;
+mod restore_post_repository_impl;
+mod search_post_repository_impl;
+mod session_repository_impl;
+mod update_ng_word_repository_impl;
+mod update_post_repository_impl;
+mod user_lookup;
 
+pub use delete_allow_word_repository_impl::DeleteAllowWordRepositoryImpl;
+pub use delete_mute_word_repository_impl::DeleteMuteWordRepositoryImpl;
+pub use delete_ng_word_repository_impl::DeleteNgWordRepositoryImpl;
+pub use delete_post_repository_impl::DeletePostRepositoryImpl;
+pub use delete_reaction_repository_impl::DeleteReactionRepositoryImpl;
+pub use export_ng_words_repository_impl::ExportNgWordsRepositoryImpl;
 pub use get_all_post_repository_impl::GetAllPostRepositoryImpl;
+pub use get_all_raw_post_repository_impl::GetAllRawPostRepositoryImpl;
+pub use get_post_repository_impl::GetPostRepositoryImpl;
+pub use get_post_thread_repository_impl::GetPostThreadRepositoryImpl;
+pub use import_ng_words_repository_impl::ImportNgWordsRepositoryImpl;
+pub use list_allow_words_repository_impl::ListAllowWordsRepositoryImpl;
+pub use list_mute_words_repository_impl::ListMuteWordsRepositoryImpl;
+pub use list_ng_words_repository_impl::ListNgWordsRepositoryImpl;
+pub use list_post_revisions_repository_impl::ListPostRevisionsRepositoryImpl;
 pub use login_repository_impl::LoginRepositoryImpl;
+pub use ng_word_policy_repository_impl::NgWordPolicyRepositoryImpl;
+pub use ng_word_rule_cache::NgWordRuleCache;
 pub use post_repository_impl::PostRepositoryImpl;
+pub use post_search_index::PostSearchIndex;
+pub use register_allow_word_repository_impl::RegisterAllowWordRepositoryImpl;
+pub use register_mute_word_repository_impl::RegisterMuteWordRepositoryImpl;
 pub use register_ng_word_repository_impl::RegisterNgWordRepositoryImpl;
+pub use register_reaction_repository_impl::RegisterReactionRepositoryImpl;
 pub use register_user_name_repository_impl::RegisterUserNameRepositoryImpl;
+pub use restore_post_repository_impl::RestorePostRepositoryImpl;
+pub use search_post_repository_impl::SearchPostRepositoryImpl;
+pub use session_repository_impl::SessionRepositoryImpl;
+pub use update_ng_word_repository_impl::UpdateNgWordRepositoryImpl;
+pub use update_post_repository_impl::UpdatePostRepositoryImpl;
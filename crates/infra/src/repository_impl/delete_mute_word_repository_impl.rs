@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+use domain_model::delete_mute_word::err::ServiceError;
+use domain_service::delete_mute_word::repository::DeleteMuteWordRepository;
+use sea_orm::{entity::prelude::*, DatabaseConnection};
+use tracing::error;
+
+use crate::entity::t_mute_word;
+
+pub struct DeleteMuteWordRepositoryImpl {
+    db_conn: DatabaseConnection,
+}
+
+impl DeleteMuteWordRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection) -> Self {
+        Self { db_conn }
+    }
+}
+
+#[async_trait]
+impl DeleteMuteWordRepository for DeleteMuteWordRepositoryImpl {
+    async fn delete_mute_word(&self, id: i32, user_id: i32) -> Result<bool, ServiceError> {
+        // 他のユーザの語は消さない
+        let result = t_mute_word::Entity::delete_many()
+            .filter(t_mute_word::Column::Id.eq(id))
+            .filter(t_mute_word::Column::UserId.eq(user_id))
+            .exec(&self.db_conn)
+            .await
+            .map_err(|err| {
+                error!("Database query failed: {:?}", err);
+                ServiceError("Internal service error".to_string())
+            })?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
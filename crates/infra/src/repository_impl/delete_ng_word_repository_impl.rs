@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use domain_model::delete_ng_word::err::ServiceError;
+use domain_service::delete_ng_word::repository::DeleteNgWordRepository;
+use sea_orm::{entity::prelude::*, DatabaseConnection};
+use std::sync::Arc;
+use tracing::error;
+
+use super::NgWordRuleCache;
+use crate::entity::m_ng_word;
+
+pub struct DeleteNgWordRepositoryImpl {
+    db_conn: DatabaseConnection,
+    ng_word_rule_cache: Arc<NgWordRuleCache>,
+}
+
+impl DeleteNgWordRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection, ng_word_rule_cache: Arc<NgWordRuleCache>) -> Self {
+        Self {
+            db_conn,
+            ng_word_rule_cache,
+        }
+    }
+}
+
+#[async_trait]
+impl DeleteNgWordRepository for DeleteNgWordRepositoryImpl {
+    async fn delete_ng_word(&self, id: i32) -> Result<bool, ServiceError> {
+        let result = m_ng_word::Entity::delete_by_id(id)
+            .exec(&self.db_conn)
+            .await
+            .map_err(|err| {
+                error!("Database query failed: {:?}", err);
+                ServiceError("Internal service error".to_string())
+            })?;
+        if result.rows_affected == 0 {
+            return Ok(false);
+        }
+        self.ng_word_rule_cache.invalidate();
+
+        Ok(true)
+    }
+}
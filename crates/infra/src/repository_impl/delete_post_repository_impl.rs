@@ -0,0 +1,67 @@
+use crate::entity::t_post;
+use async_trait::async_trait;
+use domain_model::delete_post::{
+    err::ServiceError,
+    model::{DeletablePost, DeleteReason},
+};
+use domain_service::delete_post::DeletePostRepository;
+use sea_orm::{entity::prelude::*, sea_query::Expr, DatabaseConnection};
+use tracing::error;
+
+pub struct DeletePostRepositoryImpl {
+    db_conn: DatabaseConnection,
+}
+
+impl DeletePostRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection) -> Self {
+        Self { db_conn }
+    }
+}
+
+fn service_error(err: DbErr) -> ServiceError {
+    error!("Database query failed: {:?}", err);
+    ServiceError("Internal service error".to_string())
+}
+
+#[async_trait]
+impl DeletePostRepository for DeletePostRepositoryImpl {
+    async fn find_post(&self, id: i32) -> Result<Option<DeletablePost>, ServiceError> {
+        let model = t_post::Entity::find_by_id(id)
+            .filter(t_post::Column::DeletedAt.is_null())
+            .one(&self.db_conn)
+            .await
+            .map_err(service_error)?;
+        Ok(model.map(|model| DeletablePost {
+            id: model.id,
+            posted_user_id: model.posted_user_id,
+        }))
+    }
+
+    async fn delete_post(
+        &self,
+        id: i32,
+        user_id: i32,
+        reason: &DeleteReason,
+    ) -> Result<bool, ServiceError> {
+        // 同時に削除された場合に記録を上書きしないよう、削除されていない行だけを更新する
+        let result = t_post::Entity::update_many()
+            .col_expr(t_post::Column::DeletedAt, Expr::current_timestamp().into())
+            .col_expr(t_post::Column::DeletedUserId, Expr::value(user_id))
+            .col_expr(
+                t_post::Column::DeleteReason,
+                Expr::value(reason.value().map(str::to_string)),
+            )
+            // 削除は内容の変更ではないため、更新日時は変えない
+            .col_expr(
+                t_post::Column::UpdatedAt,
+                Expr::col(t_post::Column::UpdatedAt).into(),
+            )
+            .filter(t_post::Column::Id.eq(id))
+            .filter(t_post::Column::DeletedAt.is_null())
+            .exec(&self.db_conn)
+            .await
+            .map_err(service_error)?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use domain_model::{delete_reaction::err::ServiceError, reaction::model::ReactionKind};
+use domain_service::delete_reaction::repository::DeleteReactionRepository;
+use sea_orm::{entity::prelude::*, DatabaseConnection};
+use tracing::error;
+
+use crate::entity::t_post_reaction;
+
+pub struct DeleteReactionRepositoryImpl {
+    db_conn: DatabaseConnection,
+}
+
+impl DeleteReactionRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection) -> Self {
+        Self { db_conn }
+    }
+}
+
+#[async_trait]
+impl DeleteReactionRepository for DeleteReactionRepositoryImpl {
+    async fn delete_reaction(
+        &self,
+        post_id: i32,
+        user_id: i32,
+        kind: &ReactionKind,
+    ) -> Result<(), ServiceError> {
+        // 他のユーザのリアクションは消さない
+        t_post_reaction::Entity::delete_many()
+            .filter(t_post_reaction::Column::PostId.eq(post_id))
+            .filter(t_post_reaction::Column::UserId.eq(user_id))
+            .filter(t_post_reaction::Column::Kind.eq(kind.value()))
+            .exec(&self.db_conn)
+            .await
+            .map_err(|err| {
+                error!("Database query failed: {:?}", err);
+                ServiceError("Internal service error".to_string())
+            })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use crate::entity::m_ng_word;
+use async_trait::async_trait;
+use domain_model::{
+    export_ng_words::{err::ServiceError, model::ExportedNgWord},
+    ng_word_policy::model::{PatternKind, Severity},
+};
+use domain_service::export_ng_words::ExportNgWordsRepository;
+use sea_orm::{entity::prelude::*, DatabaseConnection, QueryOrder, QuerySelect};
+use tracing::error;
+
+pub struct ExportNgWordsRepositoryImpl {
+    db_conn: DatabaseConnection,
+}
+impl ExportNgWordsRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection) -> Self {
+        Self { db_conn }
+    }
+}
+
+#[async_trait]
+impl ExportNgWordsRepository for ExportNgWordsRepositoryImpl {
+    async fn find_all_ng_words(&self) -> Result<Vec<ExportedNgWord>, ServiceError> {
+        let result: Result<Vec<(String, String, String)>, DbErr> = m_ng_word::Entity::find()
+            .select_only()
+            .columns([
+                m_ng_word::Column::Word,
+                m_ng_word::Column::Kind,
+                m_ng_word::Column::Severity,
+            ])
+            .order_by_asc(m_ng_word::Column::Id)
+            .into_tuple()
+            .all(&self.db_conn)
+            .await;
+
+        match result {
+            // 不明な書き方は`literal`、不明な対応は`mask`として扱う
+            Ok(ng_words) => Ok(ng_words
+                .into_iter()
+                .map(|(word, kind, severity)| ExportedNgWord {
+                    word,
+                    kind: PatternKind::parse(&kind).unwrap_or_default(),
+                    severity: Severity::parse(&severity).unwrap_or_default(),
+                })
+                .collect()),
+            Err(err) => {
+                error!("Database query failed: {:?}", err);
+                Err(ServiceError("Internal service error".to_string()))
+            }
+        }
+    }
+}
@@ -1,11 +1,17 @@
-use crate::entity::{m_user_name, t_post};
+use super::{reaction_count, reply_count};
+use crate::entity::{m_user_name, t_mute_word, t_post};
 use async_trait::async_trait;
-use domain_model::get_all_post::{
-    err::ServiceError,
-    model::{Content, Post, PostedDatetime, PostedUserName},
+use domain_model::{
+    get_all_post::{
+        err::ServiceError,
+        model::{Content, Post, PostCursor, PostedDatetime, PostedUserName},
+    },
+    post::model::PostStatus,
 };
 use domain_service::get_all_post::GetAllPostRepository;
-use sea_orm::{entity::prelude::*, DatabaseConnection};
+use sea_orm::{
+    entity::prelude::*, Condition, DatabaseConnection, QueryOrder, QuerySelect, SelectTwo,
+};
 use tracing::error;
 
 pub struct GetAllPostRepositoryImpl {
@@ -17,29 +23,81 @@ impl GetAllPostRepositoryImpl {
     }
 }
 
+/// 返信でない公開済みの投稿を新しい順に、`cursor`より後から`limit`件取得するクエリ
+///
+/// 投稿日時が同じ投稿はIDの大きい順に並べ、ページの境目で漏れや重複が出ないようにする
+fn find_published_posts_query(
+    limit: u64,
+    cursor: Option<PostCursor>,
+) -> SelectTwo<t_post::Entity, m_user_name::Entity> {
+    // モデレーション待ちの投稿は公開しない
+    let mut query = t_post::Entity::find()
+        .filter(t_post::Column::Status.eq(PostStatus::Published.as_str()))
+        .filter(t_post::Column::DeletedAt.is_null())
+        .filter(t_post::Column::ParentPostId.is_null());
+    if let Some(cursor) = cursor {
+        query = query.filter(
+            Condition::any()
+                .add(t_post::Column::CreatedAt.lt(cursor.posted_datetime))
+                .add(
+                    Condition::all()
+                        .add(t_post::Column::CreatedAt.eq(cursor.posted_datetime))
+                        .add(t_post::Column::Id.lt(cursor.id)),
+                ),
+        );
+    }
+    query
+        .inner_join(m_user_name::Entity)
+        .select_also(m_user_name::Entity)
+        .order_by_desc(t_post::Column::CreatedAt)
+        .order_by_desc(t_post::Column::Id)
+        .limit(limit)
+}
+
 #[async_trait]
 impl GetAllPostRepository for GetAllPostRepositoryImpl {
-    async fn get_all_post(&self) -> Result<Vec<Post>, ServiceError> {
-        let result = t_post::Entity::find()
-            .inner_join(m_user_name::Entity)
-            .select_also(m_user_name::Entity)
+    async fn get_all_post(
+        &self,
+        limit: u64,
+        cursor: Option<PostCursor>,
+        viewer_user_id: Option<i32>,
+    ) -> Result<Vec<Post>, ServiceError> {
+        let result = find_published_posts_query(limit, cursor)
             .all(&self.db_conn)
             .await;
 
         match result {
             Ok(posts) => {
+                let ids: Vec<i32> = posts.iter().map(|(post, _)| post.id).collect();
+                let reply_counts = reply_count::count_replies(&self.db_conn, &ids)
+                    .await
+                    .map_err(|err| {
+                        error!("Database query failed: {:?}", err);
+                        ServiceError("Internal service error".to_string())
+                    })?;
+                let mut reactions =
+                    reaction_count::count_reactions(&self.db_conn, &ids, viewer_user_id)
+                        .await
+                        .map_err(|err| {
+                            error!("Database query failed: {:?}", err);
+                            ServiceError("Internal service error".to_string())
+                        })?;
                 // Map the result to your domain model `Post`
                 let posts: Vec<Post> = posts
                     .into_iter()
                     .map(|(post, user_name)| {
                         Post {
-                            content: Content(post.content),
+                            id: post.id,
+                            content: Content(post.original_content),
                             posted_user_name: PostedUserName(if let Some(user_name) = user_name {
                                 user_name.name
                             } else {
                                 "".to_string()
                             }),
                             posted_datetime: PostedDatetime(post.created_at.naive_local()), // Map other fields as necessary
+                            muted: false,
+                            reply_count: reply_counts.get(&post.id).copied().unwrap_or_default(),
+                            reactions: reactions.remove(&post.id).unwrap_or_default(),
                         }
                     })
                     .collect();
@@ -51,4 +109,58 @@ impl GetAllPostRepository for GetAllPostRepositoryImpl {
             }
         }
     }
+    async fn find_mute_words(&self, user_id: i32) -> Result<Vec<String>, ServiceError> {
+        let result = t_mute_word::Entity::find()
+            .filter(t_mute_word::Column::UserId.eq(user_id))
+            .all(&self.db_conn)
+            .await;
+
+        match result {
+            Ok(mute_words) => Ok(mute_words
+                .into_iter()
+                .map(|mute_word| mute_word.word)
+                .collect()),
+            Err(err) => {
+                error!("Database query failed: {:?}", err);
+                Err(ServiceError("Internal service error".to_string()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDateTime;
+    use sea_orm::{DbBackend, QueryTrait};
+
+    #[test]
+    fn test_find_published_posts_query() {
+        let sql = find_published_posts_query(21, None)
+            .build(DbBackend::MySql)
+            .to_string();
+
+        assert!(sql.ends_with(
+            "WHERE `t_post`.`status` = 'published' AND `t_post`.`deleted_at` IS NULL AND `t_post`.`parent_post_id` IS NULL ORDER BY `t_post`.`created_at` DESC, `t_post`.`id` DESC LIMIT 21"
+        ));
+    }
+
+    #[test]
+    fn test_find_published_posts_query_with_cursor() {
+        let cursor = PostCursor {
+            posted_datetime: NaiveDateTime::parse_from_str(
+                "2021-01-01T00:00:00Z",
+                "%Y-%m-%dT%H:%M:%SZ",
+            )
+            .unwrap(),
+            id: 10,
+        };
+        let sql = find_published_posts_query(21, Some(cursor))
+            .build(DbBackend::MySql)
+            .to_string();
+
+        assert!(sql.ends_with(
+            "WHERE `t_post`.`status` = 'published' AND `t_post`.`deleted_at` IS NULL AND `t_post`.`parent_post_id` IS NULL AND (`t_post`.`created_at` < '2021-01-01 00:00:00' OR (`t_post`.`created_at` = '2021-01-01 00:00:00' AND `t_post`.`id` < 10)) ORDER BY `t_post`.`created_at` DESC, `t_post`.`id` DESC LIMIT 21"
+        ));
+    }
 }
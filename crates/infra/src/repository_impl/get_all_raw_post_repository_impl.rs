@@ -0,0 +1,61 @@
+use crate::entity::{m_user_name, t_post};
+use async_trait::async_trait;
+use domain_model::{
+    get_all_post::model::{Content, PostedDatetime, PostedUserName},
+    get_all_raw_post::{
+        err::ServiceError,
+        model::{PostDeletion, RawPost},
+    },
+    post::model::PostStatus,
+};
+use domain_service::get_all_raw_post::GetAllRawPostRepository;
+use sea_orm::{entity::prelude::*, DatabaseConnection, QueryOrder};
+use tracing::error;
+
+pub struct GetAllRawPostRepositoryImpl {
+    db_conn: DatabaseConnection,
+}
+impl GetAllRawPostRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection) -> Self {
+        Self { db_conn }
+    }
+}
+
+#[async_trait]
+impl GetAllRawPostRepository for GetAllRawPostRepositoryImpl {
+    async fn get_all_raw_post(&self) -> Result<Vec<RawPost>, ServiceError> {
+        let result = t_post::Entity::find()
+            .inner_join(m_user_name::Entity)
+            .select_also(m_user_name::Entity)
+            .order_by_asc(t_post::Column::Id)
+            .all(&self.db_conn)
+            .await;
+
+        match result {
+            Ok(posts) => Ok(posts
+                .into_iter()
+                .map(|(post, user_name)| RawPost {
+                    id: post.id,
+                    posted_user_name: PostedUserName(
+                        user_name
+                            .map(|user_name| user_name.name)
+                            .unwrap_or_default(),
+                    ),
+                    posted_datetime: PostedDatetime(post.created_at.naive_local()),
+                    // 不明な状態は公開しないものとして扱う
+                    status: PostStatus::parse(&post.status).unwrap_or(PostStatus::Held),
+                    content: Content(post.original_content),
+                    deletion: post.deleted_at.map(|deleted_at| PostDeletion {
+                        deleted_datetime: deleted_at.naive_local(),
+                        deleted_user_id: post.deleted_user_id,
+                        reason: post.delete_reason,
+                    }),
+                })
+                .collect()),
+            Err(err) => {
+                error!("Database query failed: {:?}", err);
+                Err(ServiceError("Internal service error".to_string()))
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use super::{reaction_count, reply_count};
+use crate::entity::{m_user_name, t_post};
+use async_trait::async_trait;
+use domain_model::{
+    get_all_post::model::{Content, Post, PostedDatetime, PostedUserName},
+    get_post::{
+        err::ServiceError,
+        model::{PostView, Tombstone},
+    },
+    post::model::PostStatus,
+};
+use domain_service::get_post::GetPostRepository;
+use sea_orm::{entity::prelude::*, DatabaseConnection};
+use tracing::error;
+
+pub struct GetPostRepositoryImpl {
+    db_conn: DatabaseConnection,
+}
+impl GetPostRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection) -> Self {
+        Self { db_conn }
+    }
+}
+
+#[async_trait]
+impl GetPostRepository for GetPostRepositoryImpl {
+    async fn find_post(&self, id: i32) -> Result<Option<PostView>, ServiceError> {
+        let result = t_post::Entity::find_by_id(id)
+            .filter(t_post::Column::Status.eq(PostStatus::Published.as_str()))
+            .inner_join(m_user_name::Entity)
+            .select_also(m_user_name::Entity)
+            .one(&self.db_conn)
+            .await;
+        let post = match result {
+            Ok(post) => post,
+            Err(err) => {
+                error!("Database query failed: {:?}", err);
+                return Err(ServiceError("Internal service error".to_string()));
+            }
+        };
+        let Some((post, user_name)) = post else {
+            return Ok(None);
+        };
+        if let Some(deleted_at) = post.deleted_at {
+            return Ok(Some(PostView::Deleted(Tombstone {
+                id: post.id,
+                deleted_datetime: deleted_at.naive_local(),
+            })));
+        }
+        let reply_counts = reply_count::count_replies(&self.db_conn, &[post.id])
+            .await
+            .map_err(|err| {
+                error!("Database query failed: {:?}", err);
+                ServiceError("Internal service error".to_string())
+            })?;
+        // ログインしていなくても取得できるため、自分のリアクションの印は付けない
+        let mut reactions = reaction_count::count_reactions(&self.db_conn, &[post.id], None)
+            .await
+            .map_err(|err| {
+                error!("Database query failed: {:?}", err);
+                ServiceError("Internal service error".to_string())
+            })?;
+
+        Ok(Some(PostView::Published(Post {
+            id: post.id,
+            posted_user_name: PostedUserName(
+                user_name
+                    .map(|user_name| user_name.name)
+                    .unwrap_or_default(),
+            ),
+            posted_datetime: PostedDatetime(post.created_at.naive_local()),
+            content: Content(post.original_content),
+            muted: false,
+            reply_count: reply_counts.get(&post.id).copied().unwrap_or_default(),
+            reactions: reactions.remove(&post.id).unwrap_or_default(),
+        })))
+    }
+}
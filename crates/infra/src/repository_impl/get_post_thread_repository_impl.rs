@@ -0,0 +1,135 @@
+use super::{reaction_count, reply_count};
+use crate::entity::{m_user_name, t_post};
+use async_trait::async_trait;
+use domain_model::{
+    get_all_post::model::{Content, Post, PostedDatetime, PostedUserName, ReactionCount},
+    get_post_thread::{
+        err::ServiceError,
+        model::{Reply, ThreadPost, ThreadTombstone},
+    },
+    post::model::PostStatus,
+};
+use domain_service::get_post_thread::GetPostThreadRepository;
+use sea_orm::{entity::prelude::*, DatabaseConnection, QueryOrder, Select};
+use std::collections::HashMap;
+use tracing::error;
+
+pub struct GetPostThreadRepositoryImpl {
+    db_conn: DatabaseConnection,
+}
+impl GetPostThreadRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection) -> Self {
+        Self { db_conn }
+    }
+}
+
+/// スレッドに含める投稿。公開していない投稿も印にするため全て取得する
+fn find_thread_posts_query() -> Select<t_post::Entity> {
+    t_post::Entity::find().inner_join(m_user_name::Entity)
+}
+
+fn is_visible(post: &t_post::Model) -> bool {
+    post.status == PostStatus::Published.as_str() && post.deleted_at.is_none()
+}
+
+/// 公開中の投稿はそのまま、削除された投稿とモデレーション待ちの投稿は印にする
+fn to_thread_post(
+    post: t_post::Model,
+    user_name: Option<m_user_name::Model>,
+    reply_counts: &HashMap<i32, u64>,
+    reactions: &mut HashMap<i32, Vec<ReactionCount>>,
+) -> ThreadPost {
+    if !is_visible(&post) {
+        return ThreadPost::Tombstone(ThreadTombstone {
+            id: post.id,
+            reply_count: reply_counts.get(&post.id).copied().unwrap_or_default(),
+        });
+    }
+    ThreadPost::Published(to_post(post, user_name, reply_counts, reactions))
+}
+
+fn to_post(
+    post: t_post::Model,
+    user_name: Option<m_user_name::Model>,
+    reply_counts: &HashMap<i32, u64>,
+    reactions: &mut HashMap<i32, Vec<ReactionCount>>,
+) -> Post {
+    Post {
+        id: post.id,
+        posted_user_name: PostedUserName(
+            user_name
+                .map(|user_name| user_name.name)
+                .unwrap_or_default(),
+        ),
+        posted_datetime: PostedDatetime(post.created_at.naive_local()),
+        content: Content(post.original_content),
+        muted: false,
+        reply_count: reply_counts.get(&post.id).copied().unwrap_or_default(),
+        reactions: reactions.remove(&post.id).unwrap_or_default(),
+    }
+}
+
+fn service_error(err: DbErr) -> ServiceError {
+    error!("Database query failed: {:?}", err);
+    ServiceError("Internal service error".to_string())
+}
+
+#[async_trait]
+impl GetPostThreadRepository for GetPostThreadRepositoryImpl {
+    async fn find_post(&self, id: i32) -> Result<Option<ThreadPost>, ServiceError> {
+        let post = find_thread_posts_query()
+            .filter(t_post::Column::Id.eq(id))
+            .select_also(m_user_name::Entity)
+            .one(&self.db_conn)
+            .await
+            .map_err(service_error)?;
+        // モデレーション待ちの投稿は、起点として指定されても存在しないものとして扱う
+        let Some((post, user_name)) = post.filter(|(post, _)| {
+            post.deleted_at.is_some() || post.status == PostStatus::Published.as_str()
+        }) else {
+            return Ok(None);
+        };
+        let reply_counts = reply_count::count_replies(&self.db_conn, &[post.id])
+            .await
+            .map_err(service_error)?;
+        // ログインしていなくても取得できるため、自分のリアクションの印は付けない
+        let mut reactions = reaction_count::count_reactions(&self.db_conn, &[post.id], None)
+            .await
+            .map_err(service_error)?;
+
+        Ok(Some(to_thread_post(
+            post,
+            user_name,
+            &reply_counts,
+            &mut reactions,
+        )))
+    }
+
+    async fn find_replies(&self, parent_post_ids: &[i32]) -> Result<Vec<Reply>, ServiceError> {
+        let replies = find_thread_posts_query()
+            .filter(t_post::Column::ParentPostId.is_in(parent_post_ids.iter().copied()))
+            .select_also(m_user_name::Entity)
+            .order_by_asc(t_post::Column::CreatedAt)
+            .order_by_asc(t_post::Column::Id)
+            .all(&self.db_conn)
+            .await
+            .map_err(service_error)?;
+        let ids: Vec<i32> = replies.iter().map(|(post, _)| post.id).collect();
+        let reply_counts = reply_count::count_replies(&self.db_conn, &ids)
+            .await
+            .map_err(service_error)?;
+        let mut reactions = reaction_count::count_reactions(&self.db_conn, &ids, None)
+            .await
+            .map_err(service_error)?;
+
+        Ok(replies
+            .into_iter()
+            .filter_map(|(post, user_name)| {
+                Some(Reply {
+                    parent_post_id: post.parent_post_id?,
+                    post: to_thread_post(post, user_name, &reply_counts, &mut reactions),
+                })
+            })
+            .collect())
+    }
+}
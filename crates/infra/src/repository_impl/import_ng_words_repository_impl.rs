@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use domain_model::{import_ng_words::err::ServiceError, register_ng_word::model::NgWord};
+use domain_service::import_ng_words::repository::ImportNgWordsRepository;
+use sea_orm::{entity::prelude::*, DatabaseConnection, QuerySelect, Set, TransactionTrait};
+use std::sync::Arc;
+use tracing::error;
+
+use super::NgWordRuleCache;
+use crate::entity::m_ng_word;
+
+/// 1回のINSERT文で登録する件数
+const INSERT_CHUNK_SIZE: usize = 100;
+
+pub struct ImportNgWordsRepositoryImpl {
+    db_conn: DatabaseConnection,
+    ng_word_rule_cache: Arc<NgWordRuleCache>,
+}
+
+impl ImportNgWordsRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection, ng_word_rule_cache: Arc<NgWordRuleCache>) -> Self {
+        Self {
+            db_conn,
+            ng_word_rule_cache,
+        }
+    }
+}
+
+fn service_error(err: DbErr) -> ServiceError {
+    error!("Database query failed: {:?}", err);
+    ServiceError("Internal service error".to_string())
+}
+
+#[async_trait]
+impl ImportNgWordsRepository for ImportNgWordsRepositoryImpl {
+    async fn find_existing_ng_words(&self, words: &[String]) -> Result<Vec<String>, ServiceError> {
+        if words.is_empty() {
+            return Ok(vec![]);
+        }
+        m_ng_word::Entity::find()
+            .select_only()
+            .column(m_ng_word::Column::Word)
+            .filter(m_ng_word::Column::Word.is_in(words.iter().cloned()))
+            .into_tuple()
+            .all(&self.db_conn)
+            .await
+            .map_err(service_error)
+    }
+
+    async fn import_ng_words(&self, ng_words: &[NgWord], user_id: i32) -> Result<(), ServiceError> {
+        let txn = self.db_conn.begin().await.map_err(service_error)?;
+        for chunk in ng_words.chunks(INSERT_CHUNK_SIZE) {
+            let models = chunk.iter().map(|ng_word| m_ng_word::ActiveModel {
+                word: Set(ng_word.value().to_string()),
+                kind: Set(ng_word.kind().as_str().to_string()),
+                severity: Set(ng_word.severity().as_str().to_string()),
+                created_user_id: Set(Some(user_id)),
+                ..Default::default()
+            });
+            // 途中で失敗した場合はトランザクションを破棄するとロールバックされる
+            m_ng_word::Entity::insert_many(models)
+                .exec(&txn)
+                .await
+                .map_err(service_error)?;
+        }
+        txn.commit().await.map_err(service_error)?;
+        self.ng_word_rule_cache.invalidate();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use crate::entity::m_allow_word;
+use async_trait::async_trait;
+use domain_model::list_allow_words::{err::ServiceError, model::AllowWord};
+use domain_service::list_allow_words::ListAllowWordsRepository;
+use sea_orm::{entity::prelude::*, DatabaseConnection, QueryOrder};
+use tracing::error;
+
+pub struct ListAllowWordsRepositoryImpl {
+    db_conn: DatabaseConnection,
+}
+impl ListAllowWordsRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection) -> Self {
+        Self { db_conn }
+    }
+}
+
+#[async_trait]
+impl ListAllowWordsRepository for ListAllowWordsRepositoryImpl {
+    async fn find_all_allow_words(&self) -> Result<Vec<AllowWord>, ServiceError> {
+        let result = m_allow_word::Entity::find()
+            .order_by_asc(m_allow_word::Column::Id)
+            .all(&self.db_conn)
+            .await;
+
+        match result {
+            Ok(allow_words) => Ok(allow_words
+                .into_iter()
+                .map(|allow_word| AllowWord {
+                    id: allow_word.id,
+                    word: allow_word.word,
+                    created_at: allow_word.created_at.naive_local(),
+                })
+                .collect()),
+            Err(err) => {
+                error!("Database query failed: {:?}", err);
+                Err(ServiceError("Internal service error".to_string()))
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::entity::t_mute_word;
+use async_trait::async_trait;
+use domain_model::list_mute_words::{err::ServiceError, model::MuteWord};
+use domain_service::list_mute_words::ListMuteWordsRepository;
+use sea_orm::{entity::prelude::*, DatabaseConnection, QueryOrder};
+use tracing::error;
+
+pub struct ListMuteWordsRepositoryImpl {
+    db_conn: DatabaseConnection,
+}
+impl ListMuteWordsRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection) -> Self {
+        Self { db_conn }
+    }
+}
+
+#[async_trait]
+impl ListMuteWordsRepository for ListMuteWordsRepositoryImpl {
+    async fn find_mute_words(&self, user_id: i32) -> Result<Vec<MuteWord>, ServiceError> {
+        let result = t_mute_word::Entity::find()
+            .filter(t_mute_word::Column::UserId.eq(user_id))
+            .order_by_asc(t_mute_word::Column::Id)
+            .all(&self.db_conn)
+            .await;
+
+        match result {
+            Ok(mute_words) => Ok(mute_words
+                .into_iter()
+                .map(|mute_word| MuteWord {
+                    id: mute_word.id,
+                    word: mute_word.word,
+                    created_at: mute_word.created_at.naive_local(),
+                })
+                .collect()),
+            Err(err) => {
+                error!("Database query failed: {:?}", err);
+                Err(ServiceError("Internal service error".to_string()))
+            }
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use crate::entity::{m_ng_word, m_user_name};
+use async_trait::async_trait;
+use domain_model::{
+    list_ng_words::{
+        err::ServiceError,
+        model::{NgWord, NgWordEditor, NgWordPage, NgWordQuery},
+    },
+    ng_word_policy::model::{PatternKind, Severity},
+};
+use domain_service::list_ng_words::ListNgWordsRepository;
+use sea_orm::{
+    entity::prelude::*,
+    sea_query::{Expr, LikeExpr},
+    DatabaseConnection, QueryOrder, QuerySelect, Select,
+};
+use std::collections::HashMap;
+use tracing::error;
+
+pub struct ListNgWordsRepositoryImpl {
+    db_conn: DatabaseConnection,
+}
+impl ListNgWordsRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection) -> Self {
+        Self { db_conn }
+    }
+}
+
+/// LIKEのパターンで特別な意味を持つ文字をエスケープする
+fn escape_like(keyword: &str) -> String {
+    let mut escaped = String::with_capacity(keyword.len());
+    for c in keyword.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// 検索条件に一致するNGワードをID順に並べるクエリ
+///
+/// キーワードは部分一致で、大文字小文字の区別はカラムの照合順序に従う
+fn find_ng_words_query(query: &NgWordQuery) -> Select<m_ng_word::Entity> {
+    let mut select = m_ng_word::Entity::find();
+    if let Some(keyword) = query.keyword() {
+        select = select.filter(
+            Expr::col((m_ng_word::Entity, m_ng_word::Column::Word))
+                .like(LikeExpr::new(format!("%{}%", escape_like(keyword))).escape('\\')),
+        );
+    }
+    select.order_by_asc(m_ng_word::Column::Id)
+}
+
+impl ListNgWordsRepositoryImpl {
+    /// NGワードを作成・変更したユーザの名前をまとめて取得する
+    async fn find_user_names(
+        &self,
+        ng_words: &[m_ng_word::Model],
+    ) -> Result<HashMap<i32, String>, DbErr> {
+        let mut user_ids: Vec<i32> = ng_words
+            .iter()
+            .flat_map(|ng_word| [ng_word.created_user_id, ng_word.updated_user_id])
+            .flatten()
+            .collect();
+        user_ids.sort_unstable();
+        user_ids.dedup();
+        if user_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let user_names: Vec<(i32, String)> = m_user_name::Entity::find()
+            .select_only()
+            .columns([m_user_name::Column::Id, m_user_name::Column::Name])
+            .filter(m_user_name::Column::Id.is_in(user_ids))
+            .into_tuple()
+            .all(&self.db_conn)
+            .await?;
+        Ok(user_names.into_iter().collect())
+    }
+}
+
+#[async_trait]
+impl ListNgWordsRepository for ListNgWordsRepositoryImpl {
+    async fn find_ng_words(&self, query: &NgWordQuery) -> Result<NgWordPage, ServiceError> {
+        let result = async {
+            let select = find_ng_words_query(query);
+            let total = select.clone().count(&self.db_conn).await?;
+            let ng_words = select
+                .offset(query.offset())
+                .limit(query.per_page())
+                .all(&self.db_conn)
+                .await?;
+            let user_names = self.find_user_names(&ng_words).await?;
+            Ok::<_, DbErr>((total, ng_words, user_names))
+        }
+        .await;
+
+        match result {
+            Ok((total, ng_words, user_names)) => {
+                // 削除済みのユーザは名前を空にする
+                let editor = |user_id: Option<i32>| {
+                    user_id.map(|id| NgWordEditor {
+                        id,
+                        name: user_names.get(&id).cloned().unwrap_or_default(),
+                    })
+                };
+                let ng_words = ng_words
+                    .into_iter()
+                    .map(|ng_word| NgWord {
+                        id: ng_word.id,
+                        kind: PatternKind::parse(&ng_word.kind).unwrap_or_default(),
+                        severity: Severity::parse(&ng_word.severity).unwrap_or_default(),
+                        created_by: editor(ng_word.created_user_id),
+                        updated_by: editor(ng_word.updated_user_id),
+                        created_at: ng_word.created_at.naive_local(),
+                        updated_at: ng_word.updated_at.naive_local(),
+                        word: ng_word.word,
+                    })
+                    .collect();
+                Ok(NgWordPage {
+                    ng_words,
+                    total,
+                    page: query.page(),
+                    per_page: query.per_page(),
+                })
+            }
+            Err(err) => {
+                error!("Database query failed: {:?}", err);
+                Err(ServiceError("Internal service error".to_string()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_model::list_ng_words::model::UnvalidatedNgWordQuery;
+    use sea_orm::{DbBackend, QueryTrait};
+
+    fn query(keyword: Option<&str>) -> NgWordQuery {
+        NgWordQuery::new(UnvalidatedNgWordQuery {
+            keyword: keyword.map(|keyword| keyword.to_string()),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_find_ng_words_query_without_keyword() {
+        let sql = find_ng_words_query(&query(None))
+            .build(DbBackend::MySql)
+            .to_string();
+
+        assert!(!sql.contains("WHERE"));
+        assert!(sql.ends_with("ORDER BY `m_ng_word`.`id` ASC"));
+    }
+
+    #[test]
+    fn test_find_ng_words_query_escapes_keyword() {
+        let sql = find_ng_words_query(&query(Some("10%_off")))
+            .build(DbBackend::MySql)
+            .to_string();
+
+        assert!(sql.contains(r"WHERE `m_ng_word`.`word` LIKE '%10\\%\\_off%' ESCAPE '\\'"));
+    }
+}
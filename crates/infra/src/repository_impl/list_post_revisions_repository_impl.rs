@@ -0,0 +1,55 @@
+use crate::entity::{t_post, t_post_revision};
+use async_trait::async_trait;
+use domain_model::{
+    get_all_post::model::{Content, PostedDatetime},
+    list_post_revisions::{err::ServiceError, model::PostRevision},
+    post::model::PostStatus,
+};
+use domain_service::list_post_revisions::ListPostRevisionsRepository;
+use sea_orm::{entity::prelude::*, DatabaseConnection, QueryOrder};
+use tracing::error;
+
+pub struct ListPostRevisionsRepositoryImpl {
+    db_conn: DatabaseConnection,
+}
+
+impl ListPostRevisionsRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection) -> Self {
+        Self { db_conn }
+    }
+}
+
+fn service_error(err: DbErr) -> ServiceError {
+    error!("Database query failed: {:?}", err);
+    ServiceError("Internal service error".to_string())
+}
+
+#[async_trait]
+impl ListPostRevisionsRepository for ListPostRevisionsRepositoryImpl {
+    async fn exists_post(&self, post_id: i32) -> Result<bool, ServiceError> {
+        let count = t_post::Entity::find_by_id(post_id)
+            .filter(t_post::Column::Status.eq(PostStatus::Published.as_str()))
+            .filter(t_post::Column::DeletedAt.is_null())
+            .count(&self.db_conn)
+            .await
+            .map_err(service_error)?;
+        Ok(count > 0)
+    }
+
+    async fn find_revisions(&self, post_id: i32) -> Result<Vec<PostRevision>, ServiceError> {
+        let models = t_post_revision::Entity::find()
+            .filter(t_post_revision::Column::PostId.eq(post_id))
+            .order_by_desc(t_post_revision::Column::Id)
+            .all(&self.db_conn)
+            .await
+            .map_err(service_error)?;
+        Ok(models
+            .into_iter()
+            .map(|model| PostRevision {
+                id: model.id,
+                posted_datetime: PostedDatetime(model.written_at.naive_local()),
+                content: Content(model.original_content),
+            })
+            .collect())
+    }
+}
@@ -1,6 +1,10 @@
+use super::user_lookup;
 use crate::entity::m_user_name;
 use async_trait::async_trait;
-use domain_model::login::{err::ServiceError, model::UncheckedUserName};
+use domain_model::login::{
+    err::ServiceError,
+    model::{HashedPassword, UncheckedUserName},
+};
 use domain_service::login::LoginRepository;
 use sea_orm::{entity::prelude::*, DatabaseConnection};
 use tracing::error;
@@ -16,13 +20,23 @@ impl LoginRepositoryImpl {
 
 #[async_trait]
 impl LoginRepository for LoginRepositoryImpl {
-    async fn user_exists(&self, user: &UncheckedUserName) -> Result<bool, ServiceError> {
-        let count = m_user_name::Entity::find()
-            .filter(m_user_name::Column::Name.contains(user.0.clone()))
-            .count(&self.db_conn)
+    async fn find_user_id(&self, user: &UncheckedUserName) -> Result<Option<i32>, ServiceError> {
+        user_lookup::find_user_id(&self.db_conn, &user.0)
+            .await
+            .map_err(|err| {
+                error!("Failed to find user: {:?}", err);
+                ServiceError("Failed to find user".to_string())
+            })
+    }
+    async fn find_password_hash(
+        &self,
+        user_id: i32,
+    ) -> Result<Option<HashedPassword>, ServiceError> {
+        let user = m_user_name::Entity::find_by_id(user_id)
+            .one(&self.db_conn)
             .await;
-        match count {
-            Ok(count) => Ok(count > 0),
+        match user {
+            Ok(user) => Ok(user.and_then(|user| user.password_hash).map(HashedPassword)),
             Err(err) => {
                 error!("Failed to find user: {:?}", err);
                 Err(ServiceError("Failed to find user".to_string()))
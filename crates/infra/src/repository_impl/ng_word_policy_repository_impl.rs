@@ -0,0 +1,33 @@
+use super::NgWordRuleCache;
+use async_trait::async_trait;
+use domain_model::ng_word_policy::{err::ServiceError, model::NgWordRuleSet};
+use domain_service::ng_word_policy::NgWordPolicyRepository;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use tracing::error;
+
+pub struct NgWordPolicyRepositoryImpl {
+    db_conn: DatabaseConnection,
+    ng_word_rule_cache: Arc<NgWordRuleCache>,
+}
+impl NgWordPolicyRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection, ng_word_rule_cache: Arc<NgWordRuleCache>) -> Self {
+        Self {
+            db_conn,
+            ng_word_rule_cache,
+        }
+    }
+}
+
+#[async_trait]
+impl NgWordPolicyRepository for NgWordPolicyRepositoryImpl {
+    async fn find_ng_word_rules(&self) -> Result<Arc<NgWordRuleSet>, ServiceError> {
+        self.ng_word_rule_cache
+            .get_or_load(&self.db_conn)
+            .await
+            .map_err(|err| {
+                error!("Failed to find ng words: {:?}", err);
+                ServiceError("Failed to find ng words".to_string())
+            })
+    }
+}
@@ -0,0 +1,311 @@
+use crate::entity::{m_allow_word, m_ng_word};
+use domain_model::ng_word_policy::model::{NgWordRule, NgWordRuleSet, PatternKind, Severity};
+use sea_orm::{entity::prelude::*, DatabaseConnection, QuerySelect};
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+    time::{Duration, Instant},
+};
+use tracing::error;
+
+/// DBから読み込んだNGワード1件分の列(ID、NGワード、書き方、対応)
+type NgWordRow = (i32, String, String, String);
+
+/// NGワードの規則と例外の語のプロセス内キャッシュ
+///
+/// NGワードの登録・取り込み・変更・削除時と、例外の語の登録・削除時に破棄する。別プロセスでの登録は検知できないため、
+/// `ttl`を過ぎたら読み込み直す。
+pub struct NgWordRuleCache {
+    ttl: Duration,
+    cached: RwLock<Option<(Instant, Arc<NgWordRuleSet>)>>,
+    /// 破棄のたびに進める世代。読み込み中に破棄された規則を保存しないために使う
+    generation: AtomicU64,
+}
+impl NgWordRuleCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            cached: RwLock::new(None),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    /// キャッシュ済みの規則を取得する。無ければDBから読み込んで照合器を構築する
+    ///
+    /// 不明な書き方は`literal`、不明な対応は`mask`として扱う。パターンを構築できない行はログに出して読み飛ばす
+    pub(crate) async fn get_or_load(
+        &self,
+        db_conn: &DatabaseConnection,
+    ) -> Result<Arc<NgWordRuleSet>, DbErr> {
+        self.get_or_load_with(|| async {
+            let ng_words = m_ng_word::Entity::find()
+                .select_only()
+                .columns([
+                    m_ng_word::Column::Id,
+                    m_ng_word::Column::Word,
+                    m_ng_word::Column::Kind,
+                    m_ng_word::Column::Severity,
+                ])
+                .into_tuple()
+                .all(db_conn)
+                .await?;
+            let allow_words = m_allow_word::Entity::find()
+                .select_only()
+                .column(m_allow_word::Column::Word)
+                .into_tuple()
+                .all(db_conn)
+                .await?;
+            Ok((ng_words, allow_words))
+        })
+        .await
+    }
+
+    async fn get_or_load_with<F, Fut>(&self, load: F) -> Result<Arc<NgWordRuleSet>, DbErr>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<(Vec<NgWordRow>, Vec<String>), DbErr>>,
+    {
+        if let Some((loaded_at, rule_set)) = self.cached.read().unwrap().as_ref() {
+            if loaded_at.elapsed() < self.ttl {
+                return Ok(rule_set.clone());
+            }
+        }
+
+        let generation = self.generation.load(Ordering::Acquire);
+        let (ng_words, allow_words) = load().await?;
+        let rules = ng_words
+            .into_iter()
+            .map(|(id, word, kind, severity)| NgWordRule {
+                id,
+                word,
+                kind: PatternKind::parse(&kind).unwrap_or_default(),
+                severity: Severity::parse(&severity).unwrap_or_default(),
+            })
+            .filter(|rule| match rule.kind.compile(&rule.word) {
+                Ok(_) => true,
+                Err(err) => {
+                    error!(
+                        "Invalid NG word: {:?} id: {} error: {}",
+                        rule.word, rule.id, err.0
+                    );
+                    false
+                }
+            })
+            .collect();
+        let rule_set = NgWordRuleSet::new(rules)
+            .and_then(|rule_set| rule_set.with_allow_words(allow_words))
+            .map_err(|err| DbErr::Custom(err.0))?;
+        let rule_set = Arc::new(rule_set);
+
+        let mut cached = self.cached.write().unwrap();
+        if self.generation.load(Ordering::Acquire) == generation {
+            *cached = Some((Instant::now(), rule_set.clone()));
+        }
+        Ok(rule_set)
+    }
+
+    /// キャッシュを破棄する
+    pub(crate) fn invalidate(&self) {
+        let mut cached = self.cached.write().unwrap();
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        *cached = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain_model::text::NormalizedText;
+    use std::sync::atomic::AtomicUsize;
+
+    fn words(words: &[&str]) -> Result<(Vec<NgWordRow>, Vec<String>), DbErr> {
+        let ng_words = words
+            .iter()
+            .zip(1..)
+            .map(|(word, id)| {
+                (
+                    id,
+                    word.to_string(),
+                    "literal".to_string(),
+                    "mask".to_string(),
+                )
+            })
+            .collect();
+        Ok((ng_words, vec![]))
+    }
+
+    fn rule(id: i32, word: &str, kind: &str, severity: &str) -> NgWordRow {
+        (id, word.to_string(), kind.to_string(), severity.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_get_or_load_is_cached() {
+        let cache = NgWordRuleCache::new(Duration::from_secs(60));
+        let loaded = AtomicUsize::new(0);
+        let load = || async {
+            loaded.fetch_add(1, Ordering::SeqCst);
+            words(&["bad"])
+        };
+
+        let first = cache.get_or_load_with(load).await.unwrap();
+        let second = cache.get_or_load_with(load).await.unwrap();
+
+        assert_eq!(loaded.load(Ordering::SeqCst), 1);
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(second.is_match(&NormalizedText::new("BAD")));
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_reloads() {
+        let cache = NgWordRuleCache::new(Duration::from_secs(60));
+
+        let first = cache
+            .get_or_load_with(|| async { words(&["bad"]) })
+            .await
+            .unwrap();
+        assert!(!first.is_match(&NormalizedText::new("evil")));
+
+        cache.invalidate();
+        let second = cache
+            .get_or_load_with(|| async { words(&["bad", "evil"]) })
+            .await
+            .unwrap();
+        assert!(second.is_match(&NormalizedText::new("evil")));
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_while_loading_is_not_cached() {
+        let cache = NgWordRuleCache::new(Duration::from_secs(60));
+
+        // 読み込み中に登録されたNGワードを含まない照合器は保存しない
+        cache
+            .get_or_load_with(|| async {
+                cache.invalidate();
+                words(&["bad"])
+            })
+            .await
+            .unwrap();
+        let second = cache
+            .get_or_load_with(|| async { words(&["bad", "evil"]) })
+            .await
+            .unwrap();
+        assert!(second.is_match(&NormalizedText::new("evil")));
+    }
+
+    #[tokio::test]
+    async fn test_expired_reloads() {
+        let cache = NgWordRuleCache::new(Duration::ZERO);
+
+        cache
+            .get_or_load_with(|| async { words(&["bad"]) })
+            .await
+            .unwrap();
+        let second = cache
+            .get_or_load_with(|| async { words(&["evil"]) })
+            .await
+            .unwrap();
+        assert!(second.is_match(&NormalizedText::new("evil")));
+    }
+
+    #[tokio::test]
+    async fn test_get_or_load_severity() {
+        let cache = NgWordRuleCache::new(Duration::from_secs(60));
+
+        let rule_set = cache
+            .get_or_load_with(|| async {
+                Ok((
+                    vec![
+                        rule(1, "bad", "literal", "reject"),
+                        rule(2, "evil", "literal", "unknown"),
+                    ],
+                    vec![],
+                ))
+            })
+            .await
+            .unwrap();
+        let found = rule_set.find_all(&NormalizedText::new("bad evil"));
+        let severities: Vec<_> = found.iter().map(|(rule, _)| rule.severity).collect();
+        // 不明な対応はmaskとして扱う
+        assert_eq!(severities, vec![Severity::Reject, Severity::Mask]);
+    }
+
+    #[tokio::test]
+    async fn test_get_or_load_kind() {
+        let cache = NgWordRuleCache::new(Duration::from_secs(60));
+
+        let rule_set = cache
+            .get_or_load_with(|| async {
+                Ok((
+                    vec![
+                        rule(1, "b*d", "glob", "mask"),
+                        rule(2, "e.il", "regex", "mask"),
+                        rule(3, "x?z", "unknown", "mask"),
+                    ],
+                    vec![],
+                ))
+            })
+            .await
+            .unwrap();
+        assert!(rule_set.is_match(&NormalizedText::new("bad")));
+        assert!(rule_set.is_match(&NormalizedText::new("evil")));
+        // 不明な書き方はliteralとして扱う
+        assert!(!rule_set.is_match(&NormalizedText::new("xyz")));
+        assert!(rule_set.is_match(&NormalizedText::new("x?z")));
+    }
+
+    #[tokio::test]
+    async fn test_get_or_load_skips_invalid_pattern() {
+        let cache = NgWordRuleCache::new(Duration::from_secs(60));
+
+        let rule_set = cache
+            .get_or_load_with(|| async {
+                Ok((
+                    vec![
+                        rule(1, "(bad", "regex", "reject"),
+                        rule(2, "evil", "literal", "mask"),
+                    ],
+                    vec![],
+                ))
+            })
+            .await
+            .unwrap();
+        // 構築できない規則だけを読み飛ばし、他の規則は使える
+        assert!(!rule_set.is_match(&NormalizedText::new("(bad")));
+        assert!(rule_set.is_match(&NormalizedText::new("evil")));
+    }
+
+    #[tokio::test]
+    async fn test_get_or_load_allow_words() {
+        let cache = NgWordRuleCache::new(Duration::from_secs(60));
+
+        let rule_set = cache
+            .get_or_load_with(|| async {
+                Ok((
+                    vec![rule(1, "ass", "literal", "mask")],
+                    vec!["class".to_string()],
+                ))
+            })
+            .await
+            .unwrap();
+        assert!(!rule_set.is_match(&NormalizedText::new("class")));
+        assert!(rule_set.is_match(&NormalizedText::new("ass")));
+    }
+
+    #[tokio::test]
+    async fn test_load_error_is_not_cached() {
+        let cache = NgWordRuleCache::new(Duration::from_secs(60));
+
+        let result = cache
+            .get_or_load_with(|| async { Err(DbErr::Custom("error".to_string())) })
+            .await;
+        assert!(result.is_err());
+        let second = cache
+            .get_or_load_with(|| async { words(&["bad"]) })
+            .await
+            .unwrap();
+        assert!(second.is_match(&NormalizedText::new("bad")));
+    }
+}
@@ -1,65 +1,78 @@
-use crate::entity::{m_ng_word, m_user_name, t_post};
+use super::{user_lookup, PostSearchIndex};
+use crate::entity::t_post;
 use async_trait::async_trait;
 use domain_model::post::{
     err::ServiceError,
-    model::{NgWord, Post, UserName},
+    model::{Post, PostStatus, UserName},
 };
 use domain_service::post::PostRepository;
-use sea_orm::{
-    entity::prelude::*, ActiveValue::NotSet, DatabaseConnection, EntityOrSelect, QuerySelect, Set,
-};
+use sea_orm::{entity::prelude::*, ActiveValue::NotSet, DatabaseConnection, Set};
+use std::sync::Arc;
 use tracing::error;
 pub struct PostRepositoryImpl {
     db_conn: DatabaseConnection,
+    search_index: Arc<PostSearchIndex>,
 }
 impl PostRepositoryImpl {
-    pub fn new(db_conn: DatabaseConnection) -> Self {
-        Self { db_conn }
+    pub fn new(db_conn: DatabaseConnection, search_index: Arc<PostSearchIndex>) -> Self {
+        Self {
+            db_conn,
+            search_index,
+        }
     }
 }
 
 #[async_trait]
 impl PostRepository for PostRepositoryImpl {
-    async fn user_exists(&self, user: &UserName) -> Result<bool, ServiceError> {
-        let count = m_user_name::Entity::find()
-            .filter(m_user_name::Column::Name.contains(user.value()))
+    async fn find_user_id(&self, user: &UserName) -> Result<Option<i32>, ServiceError> {
+        user_lookup::find_user_id(&self.db_conn, user.value())
+            .await
+            .map_err(|err| {
+                error!("Failed to find user: {:?}", err);
+                ServiceError("Failed to find user".to_string())
+            })
+    }
+    async fn exists_reply_target(&self, parent_post_id: i32) -> Result<bool, ServiceError> {
+        let result = t_post::Entity::find_by_id(parent_post_id)
+            .filter(t_post::Column::Status.eq(PostStatus::Published.as_str()))
+            .filter(t_post::Column::DeletedAt.is_null())
             .count(&self.db_conn)
             .await;
-        match count {
+        match result {
             Ok(count) => Ok(count > 0),
             Err(err) => {
-                error!("Failed to find user: {:?}", err);
-                Err(ServiceError("Failed to find user".to_string()))
-            }
-        }
-    }
-    async fn find_all_ng_word(&self) -> Result<Vec<NgWord>, ServiceError> {
-        let ng_words = m_ng_word::Entity::find().all(&self.db_conn).await;
-        match ng_words {
-            Ok(ng_words) => Ok(ng_words.into_iter().map(|ng_word| ng_word.into()).collect()),
-            Err(err) => {
-                error!("Failed to find ng words: {:?}", err);
-                Err(ServiceError("Failed to find ng words".to_string()))
+                error!("Failed to find reply target: {:?}", err);
+                Err(ServiceError("Failed to find reply target".to_string()))
             }
         }
     }
-    async fn create_post(&self, post: &Post) -> Result<(), ServiceError> {
-        let user = m_user_name::Entity::find()
-            .filter(m_user_name::Column::Name.eq(post.user_name().value()))
-            .select()
-            .column(m_user_name::Column::Id)
-            .one(&self.db_conn)
-            .await;
-        let post = t_post::ActiveModel {
+    async fn create_post(
+        &self,
+        user_id: i32,
+        parent_post_id: Option<i32>,
+        post: &Post,
+    ) -> Result<(), ServiceError> {
+        let model = t_post::ActiveModel {
             id: NotSet,
-            posted_user_id: Set(user.unwrap().unwrap().id),
-            content: Set(post.content().to_string()),
+            posted_user_id: Set(user_id),
+            parent_post_id: Set(parent_post_id),
+            original_content: Set(post.content().to_string()),
+            status: Set(post.status().as_str().to_string()),
             created_at: NotSet,
             updated_at: NotSet,
+            deleted_at: NotSet,
+            deleted_user_id: NotSet,
+            delete_reason: NotSet,
+            restored_at: NotSet,
+            restored_user_id: NotSet,
         };
-        let result = t_post::Entity::insert(post).exec(&self.db_conn).await;
+        let result = t_post::Entity::insert(model).exec(&self.db_conn).await;
         match result {
-            Ok(_) => Ok(()),
+            Ok(result) => {
+                // モデレーション待ちの投稿も、公開されたときに探せるよう索引に加える
+                self.search_index.add(result.last_insert_id, post.content());
+                Ok(())
+            }
             Err(err) => {
                 error!("Failed to create post: {:?}", err);
                 Err(ServiceError("Failed to create post".to_string()))
@@ -0,0 +1,319 @@
+use crate::entity::t_post;
+use domain_model::{search_post::model::RankedPost, text::NormalizedText};
+use sea_orm::{entity::prelude::*, DatabaseConnection, QuerySelect};
+use std::{
+    collections::{BTreeSet, HashMap},
+    future::Future,
+    sync::RwLock,
+};
+
+/// 投稿の内容のプロセス内の全文索引
+///
+/// 日本語は語の区切りが空白に現れないため、正規化した内容を1文字と2文字の断片(n-gram)に分け、
+/// 断片ごとにそれを含む投稿のIDを持つ。最初の検索時にDBの投稿を全て読み込み、以降は投稿時と編集時に追加する。
+/// 断片で絞った投稿は、正規化した内容で語を数え直して順位を付ける
+#[derive(Default)]
+pub struct PostSearchIndex {
+    indexed: RwLock<IndexedPosts>,
+}
+
+#[derive(Default)]
+struct IndexedPosts {
+    loaded: bool,
+    /// 投稿のIDと、正規化した内容
+    contents: HashMap<i32, String>,
+    /// 断片と、それを含む投稿のID
+    postings: HashMap<String, BTreeSet<i32>>,
+}
+impl IndexedPosts {
+    /// 投稿の内容を加える。既にある投稿の場合は、前の内容の断片を除いてから加える
+    fn add(&mut self, post_id: i32, content: &str) {
+        if let Some(old) = self.contents.remove(&post_id) {
+            for gram in content_grams(&old) {
+                if let Some(ids) = self.postings.get_mut(&gram) {
+                    ids.remove(&post_id);
+                    if ids.is_empty() {
+                        self.postings.remove(&gram);
+                    }
+                }
+            }
+        }
+        let normalized = NormalizedText::new(content).as_str().to_string();
+        for gram in content_grams(&normalized) {
+            self.postings.entry(gram).or_default().insert(post_id);
+        }
+        self.contents.insert(post_id, normalized);
+    }
+
+    fn find(&self, terms: &[String]) -> Vec<RankedPost> {
+        let mut found: Option<BTreeSet<i32>> = None;
+        for gram in terms.iter().flat_map(|term| query_grams(term)) {
+            let Some(ids) = self.postings.get(&gram) else {
+                return vec![];
+            };
+            found = Some(match found {
+                Some(found) => found.intersection(ids).copied().collect(),
+                None => ids.clone(),
+            });
+        }
+        // 断片が揃っていても語を含むとは限らないため、内容で数え直して含まないものを除く
+        let terms: Vec<String> = terms
+            .iter()
+            .map(|term| NormalizedText::new(term).as_str().to_string())
+            .collect();
+        let mut ranked: Vec<RankedPost> = found
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|id| {
+                let content = self.contents.get(&id)?;
+                let mut score = 0;
+                for term in &terms {
+                    let count = count_matches(content, term);
+                    if count == 0 {
+                        return None;
+                    }
+                    score += count;
+                }
+                Some(RankedPost { id, score })
+            })
+            .collect();
+        // 一致した箇所の多い順、同じならIDの大きい(新しい)順
+        ranked.sort_by(|a, b| b.score.cmp(&a.score).then(b.id.cmp(&a.id)));
+        ranked
+    }
+}
+
+/// 正規化した内容の断片。空白で区切った語ごとに、1文字ずつと隣り合う2文字ずつ
+fn content_grams(normalized: &str) -> BTreeSet<String> {
+    let mut grams = BTreeSet::new();
+    for word in normalized.split_whitespace() {
+        let chars: Vec<char> = word.chars().collect();
+        grams.extend(chars.iter().map(|c| c.to_string()));
+        grams.extend(chars.windows(2).map(|pair| pair.iter().collect()));
+    }
+    grams
+}
+
+/// 語を索引から引くための断片。1文字の語はその文字、2文字以上の語は隣り合う2文字ずつ
+fn query_grams(term: &str) -> Vec<String> {
+    let chars: Vec<char> = NormalizedText::new(term)
+        .as_str()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    if chars.len() == 1 {
+        vec![chars[0].to_string()]
+    } else {
+        chars.windows(2).map(|pair| pair.iter().collect()).collect()
+    }
+}
+
+/// 正規化した内容の中で、正規化した語が一致した箇所の数を数える。重なった一致もそれぞれ数える
+fn count_matches(normalized: &str, term: &str) -> usize {
+    if term.is_empty() {
+        return 0;
+    }
+    let mut count = 0;
+    let mut from = 0;
+    while let Some(found) = normalized[from..].find(term) {
+        count += 1;
+        let start = from + found;
+        from = start + normalized[start..].chars().next().map_or(1, char::len_utf8);
+    }
+    count
+}
+
+impl PostSearchIndex {
+    /// 投稿の内容を索引に加える
+    ///
+    /// 編集した場合も、編集後の内容で呼び出す。編集前の内容は索引から除く
+    pub(crate) fn add(&self, post_id: i32, content: &str) {
+        self.indexed.write().unwrap().add(post_id, content);
+    }
+
+    /// 語を全て含む投稿のIDを、一致した箇所の多い順、同じならIDの大きい順に取得する。
+    /// まだ読み込んでいなければDBから読み込む
+    ///
+    /// モデレーション待ちや削除された投稿も含むため、呼び出し側で除くこと
+    pub(crate) async fn find(
+        &self,
+        db_conn: &DatabaseConnection,
+        terms: &[String],
+    ) -> Result<Vec<RankedPost>, DbErr> {
+        self.find_with(
+            || async {
+                t_post::Entity::find()
+                    .select_only()
+                    .columns([t_post::Column::Id, t_post::Column::OriginalContent])
+                    .into_tuple()
+                    .all(db_conn)
+                    .await
+            },
+            terms,
+        )
+        .await
+    }
+
+    async fn find_with<F, Fut>(&self, load: F, terms: &[String]) -> Result<Vec<RankedPost>, DbErr>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Vec<(i32, String)>, DbErr>>,
+    {
+        if !self.indexed.read().unwrap().loaded {
+            let posts = load().await?;
+            // 読み込み中に投稿や編集で加えた内容の方が新しいため、読み込んだ内容で置き換えない
+            let mut indexed = self.indexed.write().unwrap();
+            for (id, content) in posts {
+                if !indexed.contents.contains_key(&id) {
+                    indexed.add(id, &content);
+                }
+            }
+            indexed.loaded = true;
+        }
+        Ok(self.indexed.read().unwrap().find(terms))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn terms(terms: &[&str]) -> Vec<String> {
+        terms.iter().map(|term| term.to_string()).collect()
+    }
+
+    async fn index(posts: &[(i32, &str)]) -> PostSearchIndex {
+        let index = PostSearchIndex::default();
+        let posts: Vec<_> = posts
+            .iter()
+            .map(|(id, content)| (*id, content.to_string()))
+            .collect();
+        index
+            .find_with(|| async { Ok(posts) }, &terms(&["a"]))
+            .await
+            .unwrap();
+        index
+    }
+
+    fn find(index: &PostSearchIndex, query: &[&str]) -> Vec<i32> {
+        index
+            .indexed
+            .read()
+            .unwrap()
+            .find(&terms(query))
+            .iter()
+            .map(|ranked| ranked.id)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_find_is_loaded_once() {
+        let index = PostSearchIndex::default();
+        let loaded = AtomicUsize::new(0);
+        let load = || async {
+            loaded.fetch_add(1, Ordering::SeqCst);
+            Ok(vec![(1, "掲示板".to_string())])
+        };
+
+        assert_eq!(
+            index.find_with(load, &terms(&["掲示板"])).await,
+            Ok(vec![RankedPost { id: 1, score: 1 }])
+        );
+        assert_eq!(
+            index.find_with(load, &terms(&["掲示板"])).await,
+            Ok(vec![RankedPost { id: 1, score: 1 }])
+        );
+        assert_eq!(loaded.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_find_japanese() {
+        let index = index(&[(1, "Rustの掲示板を作った"), (2, "掲示を見た"), (3, "板")]).await;
+
+        assert_eq!(find(&index, &["掲示板"]), vec![1]);
+        assert_eq!(find(&index, &["掲示"]), vec![2, 1]);
+        // 1文字の語も探せる
+        assert_eq!(find(&index, &["板"]), vec![3, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_find_all_terms() {
+        let index = index(&[(1, "rust 掲示板"), (2, "rust"), (3, "掲示板")]).await;
+
+        assert_eq!(find(&index, &["rust", "掲示板"]), vec![1]);
+        assert_eq!(find(&index, &["go", "掲示板"]), Vec::<i32>::new());
+    }
+
+    #[tokio::test]
+    async fn test_find_normalized() {
+        let index = index(&[(1, "ＲＵＳＴのケイジバン")]).await;
+
+        assert_eq!(find(&index, &["rust", "けいじばん"]), vec![1]);
+    }
+
+    #[tokio::test]
+    async fn test_find_ranked() {
+        let index = index(&[
+            (1, "rust"),
+            (2, "rust rust rust"),
+            (3, "rustrust"),
+            (4, "rust"),
+        ])
+        .await;
+
+        // 一致した箇所の多い順、同じならIDの大きい順
+        assert_eq!(
+            index.indexed.read().unwrap().find(&terms(&["rust"])),
+            vec![
+                RankedPost { id: 2, score: 3 },
+                RankedPost { id: 3, score: 2 },
+                RankedPost { id: 4, score: 1 },
+                RankedPost { id: 1, score: 1 },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_excludes_split_words() {
+        let index = index(&[(1, "掲示 示板"), (2, "掲示板")]).await;
+
+        // 断片が揃っていても、語を含まない投稿は返さない
+        assert_eq!(find(&index, &["掲示板"]), vec![2]);
+    }
+
+    #[tokio::test]
+    async fn test_add_after_load() {
+        let index = index(&[(1, "rust")]).await;
+        index.add(2, "rust 掲示板");
+
+        assert_eq!(find(&index, &["rust"]), vec![2, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_add_edited() {
+        let index = index(&[(1, "rust 掲示板")]).await;
+        index.add(1, "go");
+
+        // 編集前の内容では見つからず、断片も残らない
+        assert_eq!(find(&index, &["rust"]), Vec::<i32>::new());
+        assert_eq!(find(&index, &["go"]), vec![1]);
+        assert!(!index.indexed.read().unwrap().postings.contains_key("掲示"));
+    }
+
+    #[tokio::test]
+    async fn test_load_keeps_added() {
+        let index = PostSearchIndex::default();
+        index.add(1, "go");
+
+        // 読み込む前に編集した内容を、読み込んだ内容で置き換えない
+        let found = index
+            .find_with(
+                || async { Ok(vec![(1, "rust".to_string())]) },
+                &terms(&["rust"]),
+            )
+            .await;
+        assert_eq!(found, Ok(vec![]));
+        assert_eq!(find(&index, &["go"]), vec![1]);
+    }
+}
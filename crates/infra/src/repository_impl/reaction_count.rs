@@ -0,0 +1,97 @@
+use crate::entity::t_post_reaction;
+use domain_model::get_all_post::model::ReactionCount;
+use sea_orm::{entity::prelude::*, ConnectionTrait, QuerySelect, Select};
+use std::collections::{HashMap, HashSet};
+
+/// 投稿ごと、種類ごとのリアクションの数を数えるクエリ
+fn count_reactions_query(post_ids: &[i32]) -> Select<t_post_reaction::Entity> {
+    t_post_reaction::Entity::find()
+        .select_only()
+        .column(t_post_reaction::Column::PostId)
+        .column(t_post_reaction::Column::Kind)
+        .column_as(t_post_reaction::Column::Id.count(), "reaction_count")
+        .filter(t_post_reaction::Column::PostId.is_in(post_ids.iter().copied()))
+        .group_by(t_post_reaction::Column::PostId)
+        .group_by(t_post_reaction::Column::Kind)
+}
+
+/// ユーザ自身が付けたリアクションを取得するクエリ
+fn find_own_reactions_query(post_ids: &[i32], user_id: i32) -> Select<t_post_reaction::Entity> {
+    t_post_reaction::Entity::find()
+        .select_only()
+        .column(t_post_reaction::Column::PostId)
+        .column(t_post_reaction::Column::Kind)
+        .filter(t_post_reaction::Column::PostId.is_in(post_ids.iter().copied()))
+        .filter(t_post_reaction::Column::UserId.eq(user_id))
+}
+
+/// 投稿ごとのリアクションの集計を取得する
+///
+/// 数の多い順、同じ数なら種類の名前順に並べる。リアクションの無い投稿は含まない。
+/// `viewer_user_id`を渡した場合は、そのユーザが付けた種類に印を付ける
+pub(crate) async fn count_reactions<C: ConnectionTrait>(
+    db_conn: &C,
+    post_ids: &[i32],
+    viewer_user_id: Option<i32>,
+) -> Result<HashMap<i32, Vec<ReactionCount>>, DbErr> {
+    if post_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let counts = count_reactions_query(post_ids)
+        .into_tuple::<(i32, String, i64)>()
+        .all(db_conn)
+        .await?;
+    let own: HashSet<(i32, String)> = match viewer_user_id {
+        Some(user_id) => find_own_reactions_query(post_ids, user_id)
+            .into_tuple::<(i32, String)>()
+            .all(db_conn)
+            .await?
+            .into_iter()
+            .collect(),
+        None => HashSet::new(),
+    };
+
+    let mut reactions: HashMap<i32, Vec<ReactionCount>> = HashMap::new();
+    for (post_id, kind, count) in counts {
+        let reacted_by_me = own.contains(&(post_id, kind.clone()));
+        reactions.entry(post_id).or_default().push(ReactionCount {
+            kind,
+            count: count.unsigned_abs(),
+            reacted_by_me,
+        });
+    }
+    for counts in reactions.values_mut() {
+        counts.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.kind.cmp(&b.kind)));
+    }
+    Ok(reactions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DbBackend, QueryTrait};
+
+    #[test]
+    fn test_count_reactions_query() {
+        let sql = count_reactions_query(&[1, 2])
+            .build(DbBackend::MySql)
+            .to_string();
+
+        assert_eq!(
+            sql,
+            "SELECT `t_post_reaction`.`post_id`, `t_post_reaction`.`kind`, COUNT(`t_post_reaction`.`id`) AS `reaction_count` FROM `t_post_reaction` WHERE `t_post_reaction`.`post_id` IN (1, 2) GROUP BY `t_post_reaction`.`post_id`, `t_post_reaction`.`kind`"
+        );
+    }
+
+    #[test]
+    fn test_find_own_reactions_query() {
+        let sql = find_own_reactions_query(&[1, 2], 3)
+            .build(DbBackend::MySql)
+            .to_string();
+
+        assert_eq!(
+            sql,
+            "SELECT `t_post_reaction`.`post_id`, `t_post_reaction`.`kind` FROM `t_post_reaction` WHERE `t_post_reaction`.`post_id` IN (1, 2) AND `t_post_reaction`.`user_id` = 3"
+        );
+    }
+}
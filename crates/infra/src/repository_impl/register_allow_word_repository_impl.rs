@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use domain_model::register_allow_word::{err::ServiceError, model::AllowWord};
+use domain_service::register_allow_word::repository::RegisterAllowWordRepository;
+use sea_orm::{ActiveModelTrait, DatabaseConnection, Set};
+use std::sync::Arc;
+use tracing::error;
+
+use super::NgWordRuleCache;
+use crate::entity::m_allow_word;
+
+pub struct RegisterAllowWordRepositoryImpl {
+    db_conn: DatabaseConnection,
+    ng_word_rule_cache: Arc<NgWordRuleCache>,
+}
+
+impl RegisterAllowWordRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection, ng_word_rule_cache: Arc<NgWordRuleCache>) -> Self {
+        Self {
+            db_conn,
+            ng_word_rule_cache,
+        }
+    }
+}
+
+#[async_trait]
+impl RegisterAllowWordRepository for RegisterAllowWordRepositoryImpl {
+    async fn register_allow_word(
+        &self,
+        allow_word: &AllowWord,
+        user_id: i32,
+    ) -> Result<(), ServiceError> {
+        let model = m_allow_word::ActiveModel {
+            word: Set(allow_word.value().to_string()),
+            created_user_id: Set(Some(user_id)),
+            ..Default::default()
+        };
+
+        model.insert(&self.db_conn).await.map_err(|err| {
+            error!("Database query failed: {:?}", err);
+            ServiceError("Internal service error".to_string())
+        })?;
+        self.ng_word_rule_cache.invalidate();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use domain_model::register_mute_word::{err::ServiceError, model::MuteWord};
+use domain_service::register_mute_word::repository::RegisterMuteWordRepository;
+use sea_orm::{entity::prelude::*, ActiveModelTrait, DatabaseConnection, Set};
+use tracing::error;
+
+use crate::entity::t_mute_word;
+
+pub struct RegisterMuteWordRepositoryImpl {
+    db_conn: DatabaseConnection,
+}
+
+impl RegisterMuteWordRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection) -> Self {
+        Self { db_conn }
+    }
+}
+
+fn service_error(err: DbErr) -> ServiceError {
+    error!("Database query failed: {:?}", err);
+    ServiceError("Internal service error".to_string())
+}
+
+#[async_trait]
+impl RegisterMuteWordRepository for RegisterMuteWordRepositoryImpl {
+    async fn exists_mute_word(
+        &self,
+        mute_word: &MuteWord,
+        user_id: i32,
+    ) -> Result<bool, ServiceError> {
+        let count = t_mute_word::Entity::find()
+            .filter(t_mute_word::Column::UserId.eq(user_id))
+            .filter(t_mute_word::Column::Word.eq(mute_word.value()))
+            .count(&self.db_conn)
+            .await
+            .map_err(service_error)?;
+
+        Ok(count > 0)
+    }
+
+    async fn register_mute_word(
+        &self,
+        mute_word: &MuteWord,
+        user_id: i32,
+    ) -> Result<(), ServiceError> {
+        let model = t_mute_word::ActiveModel {
+            user_id: Set(user_id),
+            word: Set(mute_word.value().to_string()),
+            ..Default::default()
+        };
+
+        model.insert(&self.db_conn).await.map_err(service_error)?;
+
+        Ok(())
+    }
+}
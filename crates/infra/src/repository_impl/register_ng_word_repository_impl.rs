@@ -2,16 +2,22 @@ use async_trait::async_trait;
 use domain_model::register_ng_word::{err::ServiceError, model::NgWord};
 use domain_service::register_ng_word::repository::RegisterNgWordRepository;
 use sea_orm::{ActiveModelTrait, DatabaseConnection, Set};
+use std::sync::Arc;
 
+use super::NgWordRuleCache;
 use crate::entity::m_ng_word;
 
 pub struct RegisterNgWordRepositoryImpl {
     conn: DatabaseConnection,
+    ng_word_rule_cache: Arc<NgWordRuleCache>,
 }
 
 impl RegisterNgWordRepositoryImpl {
-    pub fn new(conn: DatabaseConnection) -> Self {
-        Self { conn }
+    pub fn new(conn: DatabaseConnection, ng_word_rule_cache: Arc<NgWordRuleCache>) -> Self {
+        Self {
+            conn,
+            ng_word_rule_cache,
+        }
     }
 }
 
@@ -20,6 +26,8 @@ impl RegisterNgWordRepository for RegisterNgWordRepositoryImpl {
     async fn register_ng_word(&self, ng_word: &NgWord, user_id: i32) -> Result<(), ServiceError> {
         let model = m_ng_word::ActiveModel {
             word: Set(ng_word.value().to_string()),
+            kind: Set(ng_word.kind().as_str().to_string()),
+            severity: Set(ng_word.severity().as_str().to_string()),
             created_user_id: Set(Some(user_id)),
             ..Default::default()
         };
@@ -28,6 +36,7 @@ impl RegisterNgWordRepository for RegisterNgWordRepositoryImpl {
             .insert(&self.conn)
             .await
             .map_err(|e| ServiceError(e.to_string()))?;
+        self.ng_word_rule_cache.invalidate();
 
         Ok(())
     }
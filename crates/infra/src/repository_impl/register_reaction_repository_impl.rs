@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use domain_model::{
+    post::model::PostStatus, reaction::model::ReactionKind, register_reaction::err::ServiceError,
+};
+use domain_service::register_reaction::repository::RegisterReactionRepository;
+use sea_orm::{entity::prelude::*, sea_query::OnConflict, DatabaseConnection, Insert, Set};
+use tracing::error;
+
+use crate::entity::{t_post, t_post_reaction};
+
+pub struct RegisterReactionRepositoryImpl {
+    db_conn: DatabaseConnection,
+}
+
+impl RegisterReactionRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection) -> Self {
+        Self { db_conn }
+    }
+}
+
+/// リアクションを保存するクエリ
+///
+/// 同じユーザが同じ投稿に同じ種類を付けている場合は何もしない
+fn insert_reaction_query(
+    post_id: i32,
+    user_id: i32,
+    kind: &ReactionKind,
+) -> Insert<t_post_reaction::ActiveModel> {
+    let model = t_post_reaction::ActiveModel {
+        post_id: Set(post_id),
+        user_id: Set(user_id),
+        kind: Set(kind.value().to_string()),
+        ..Default::default()
+    };
+    // MySQLでは`id = id`に更新することで、何もしないことを表す
+    t_post_reaction::Entity::insert(model).on_conflict(
+        OnConflict::columns([
+            t_post_reaction::Column::PostId,
+            t_post_reaction::Column::UserId,
+            t_post_reaction::Column::Kind,
+        ])
+        .do_nothing_on([t_post_reaction::Column::Id])
+        .to_owned(),
+    )
+}
+
+fn service_error(err: DbErr) -> ServiceError {
+    error!("Database query failed: {:?}", err);
+    ServiceError("Internal service error".to_string())
+}
+
+#[async_trait]
+impl RegisterReactionRepository for RegisterReactionRepositoryImpl {
+    async fn exists_post(&self, post_id: i32) -> Result<bool, ServiceError> {
+        let count = t_post::Entity::find_by_id(post_id)
+            .filter(t_post::Column::Status.eq(PostStatus::Published.as_str()))
+            .filter(t_post::Column::DeletedAt.is_null())
+            .count(&self.db_conn)
+            .await
+            .map_err(service_error)?;
+
+        Ok(count > 0)
+    }
+
+    async fn register_reaction(
+        &self,
+        post_id: i32,
+        user_id: i32,
+        kind: &ReactionKind,
+    ) -> Result<(), ServiceError> {
+        // 同時に同じリアクションを付けても、一意制約で1件だけ残る
+        insert_reaction_query(post_id, user_id, kind)
+            .exec_without_returning(&self.db_conn)
+            .await
+            .map_err(service_error)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DbBackend, QueryTrait};
+
+    #[test]
+    fn test_insert_reaction_query() {
+        let sql = insert_reaction_query(1, 2, &ReactionKind::new("like").unwrap())
+            .build(DbBackend::MySql)
+            .to_string();
+
+        assert_eq!(
+            sql,
+            "INSERT INTO `t_post_reaction` (`post_id`, `user_id`, `kind`) VALUES (1, 2, 'like') ON DUPLICATE KEY UPDATE `id` = `id`"
+        );
+    }
+}
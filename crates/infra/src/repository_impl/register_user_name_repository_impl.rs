@@ -1,13 +1,12 @@
+use super::user_lookup;
 use crate::entity::m_user_name;
 use async_trait::async_trait;
 use domain_model::register_user_name::{
-    err::{RegisterUserNameError, ServiceError},
-    model::UserName,
+    err::ServiceError,
+    model::{HashedPassword, UserName},
 };
 use domain_service::register_user_name::RegisterUserNameRepository;
-use sea_orm::{
-    entity::prelude::*, ActiveValue::NotSet, DatabaseConnection, DbBackend, Set, Statement,
-};
+use sea_orm::{entity::prelude::*, ActiveValue::NotSet, DatabaseConnection, Set};
 use tracing::{error, info};
 
 pub struct RegisterUserNameRepositoryImpl {
@@ -20,50 +19,39 @@ impl RegisterUserNameRepositoryImpl {
 }
 #[async_trait]
 impl RegisterUserNameRepository for RegisterUserNameRepositoryImpl {
-    async fn find_ng_word(&self, user_name: &UserName) -> Result<bool, RegisterUserNameError> {
-        let query = r#"
-            SELECT count(*) cnt
-            FROM m_ng_word mnw
-            WHERE ? LIKE CONCAT('%', mnw.word, '%');
-        "#;
-
-        let statement =
-            Statement::from_sql_and_values(DbBackend::MySql, query, vec![user_name.value().into()]);
-
-        let result = self.db_conn.query_one(statement).await;
-
-        match result {
-            Ok(Some(row)) => match row.try_get::<i64>("", "cnt") {
-                Ok(count) => Ok(count > 0),
-                Err(err) => {
-                    error!("Failed to find ng word: {:?}", err);
-                    Err(RegisterUserNameError::from(ServiceError(
-                        "Failed to find ng word".to_string(),
-                    )))
-                }
-            },
-            Ok(None) => Ok(false),
-            Err(err) => {
-                error!("Failed to find ng word: {:?}", err);
-                Err(RegisterUserNameError::from(ServiceError(
-                    "Failed to find ng word".to_string(),
-                )))
-            }
-        }
+    async fn find_user_id(&self, user_name: &UserName) -> Result<Option<i32>, ServiceError> {
+        user_lookup::find_user_id(&self.db_conn, user_name.value())
+            .await
+            .map_err(|err| {
+                error!("Failed to find user: {:?}", err);
+                ServiceError("Failed to find user".to_string())
+            })
     }
-    async fn create_user_name(&self, user_name: &UserName) -> Result<(), ServiceError> {
+    async fn create_user_name(
+        &self,
+        user_name: &UserName,
+        password: &HashedPassword,
+    ) -> Result<(), ServiceError> {
         let user_name = m_user_name::ActiveModel {
             id: NotSet,
             name: Set(user_name.value().to_string()),
+            password_hash: Set(Some(password.value().to_string())),
+            role: NotSet,
             created_at: NotSet,
             updated_at: NotSet,
         };
 
         let result = user_name.insert(&self.db_conn).await;
-        info!("Insert result: {:?}", result);
 
         match result {
-            Ok(_) => Ok(()),
+            // パスワードのハッシュをログに出さないよう、IDと名前だけを出す
+            Ok(user_name) => {
+                info!(
+                    "Created user name: id={}, name={}",
+                    user_name.id, user_name.name
+                );
+                Ok(())
+            }
             Err(err) => {
                 error!("Failed to create user name: {:?}", err);
                 Err(ServiceError("Failed to create user name".to_string()))
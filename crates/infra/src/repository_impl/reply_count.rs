@@ -0,0 +1,56 @@
+use crate::entity::t_post;
+use domain_model::post::model::PostStatus;
+use sea_orm::{entity::prelude::*, ConnectionTrait, QuerySelect, Select};
+use std::collections::HashMap;
+
+/// 投稿ごとの公開中の直接の返信の数を数えるクエリ
+///
+/// モデレーション待ちや削除された返信は数えない
+fn count_replies_query(parent_post_ids: &[i32]) -> Select<t_post::Entity> {
+    t_post::Entity::find()
+        .select_only()
+        .column(t_post::Column::ParentPostId)
+        .column_as(t_post::Column::Id.count(), "reply_count")
+        .filter(t_post::Column::ParentPostId.is_in(parent_post_ids.iter().copied()))
+        .filter(t_post::Column::Status.eq(PostStatus::Published.as_str()))
+        .filter(t_post::Column::DeletedAt.is_null())
+        .group_by(t_post::Column::ParentPostId)
+}
+
+/// 投稿ごとの公開中の直接の返信の数を取得する
+///
+/// 返信が無い投稿は含まない
+pub(crate) async fn count_replies<C: ConnectionTrait>(
+    db_conn: &C,
+    parent_post_ids: &[i32],
+) -> Result<HashMap<i32, u64>, DbErr> {
+    if parent_post_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let counts = count_replies_query(parent_post_ids)
+        .into_tuple::<(i32, i64)>()
+        .all(db_conn)
+        .await?;
+    Ok(counts
+        .into_iter()
+        .map(|(id, count)| (id, count.unsigned_abs()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DbBackend, QueryTrait};
+
+    #[test]
+    fn test_count_replies_query() {
+        let sql = count_replies_query(&[1, 2])
+            .build(DbBackend::MySql)
+            .to_string();
+
+        assert_eq!(
+            sql,
+            "SELECT `t_post`.`parent_post_id`, COUNT(`t_post`.`id`) AS `reply_count` FROM `t_post` WHERE `t_post`.`parent_post_id` IN (1, 2) AND `t_post`.`status` = 'published' AND `t_post`.`deleted_at` IS NULL GROUP BY `t_post`.`parent_post_id`"
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use crate::entity::t_post;
+use async_trait::async_trait;
+use domain_model::restore_post::err::ServiceError;
+use domain_service::restore_post::RestorePostRepository;
+use sea_orm::{entity::prelude::*, sea_query::Expr, DatabaseConnection};
+use tracing::error;
+
+pub struct RestorePostRepositoryImpl {
+    db_conn: DatabaseConnection,
+}
+
+impl RestorePostRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection) -> Self {
+        Self { db_conn }
+    }
+}
+
+#[async_trait]
+impl RestorePostRepository for RestorePostRepositoryImpl {
+    async fn restore_post(&self, id: i32, user_id: i32) -> Result<bool, ServiceError> {
+        // 読み込みは削除日時で削除済みかを判断するため、削除したユーザと理由は残す
+        let result = t_post::Entity::update_many()
+            .col_expr(
+                t_post::Column::DeletedAt,
+                Expr::value(Option::<DateTimeWithTimeZone>::None),
+            )
+            .col_expr(t_post::Column::RestoredAt, Expr::current_timestamp().into())
+            .col_expr(t_post::Column::RestoredUserId, Expr::value(user_id))
+            // 削除と同じく、更新日時は変えない
+            .col_expr(
+                t_post::Column::UpdatedAt,
+                Expr::col(t_post::Column::UpdatedAt).into(),
+            )
+            .filter(t_post::Column::Id.eq(id))
+            .filter(t_post::Column::DeletedAt.is_not_null())
+            .exec(&self.db_conn)
+            .await
+            .map_err(|err| {
+                error!("Database query failed: {:?}", err);
+                ServiceError("Internal service error".to_string())
+            })?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,171 @@
+use super::{reaction_count, reply_count, PostSearchIndex};
+use crate::entity::{m_user_name, t_mute_word, t_post};
+use async_trait::async_trait;
+use domain_model::{
+    get_all_post::model::{Content, Post, PostedDatetime, PostedUserName},
+    post::model::PostStatus,
+    search_post::{
+        err::ServiceError,
+        model::{RankedPost, SearchCandidate, SearchFilter, SearchQuery},
+    },
+};
+use domain_service::search_post::SearchPostRepository;
+use sea_orm::{entity::prelude::*, DatabaseConnection, SelectTwo};
+use std::{collections::HashMap, sync::Arc};
+use tracing::error;
+
+pub struct SearchPostRepositoryImpl {
+    db_conn: DatabaseConnection,
+    search_index: Arc<PostSearchIndex>,
+}
+impl SearchPostRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection, search_index: Arc<PostSearchIndex>) -> Self {
+        Self {
+            db_conn,
+            search_index,
+        }
+    }
+}
+
+/// 索引で見つかった投稿のうち、公開済みで絞り込みを満たすものを取得するクエリ
+fn find_candidates_query(
+    ids: &[i32],
+    filter: &SearchFilter,
+) -> SelectTwo<t_post::Entity, m_user_name::Entity> {
+    let mut query = t_post::Entity::find()
+        .filter(t_post::Column::Id.is_in(ids.iter().copied()))
+        .filter(t_post::Column::Status.eq(PostStatus::Published.as_str()))
+        .filter(t_post::Column::DeletedAt.is_null());
+    if let Some(author) = &filter.author {
+        query = query.filter(m_user_name::Column::Name.eq(author.as_str()));
+    }
+    if let Some(since) = filter.since {
+        query = query.filter(t_post::Column::CreatedAt.gte(since));
+    }
+    if let Some(until) = filter.until {
+        query = query.filter(t_post::Column::CreatedAt.lte(until));
+    }
+    query
+        .inner_join(m_user_name::Entity)
+        .select_also(m_user_name::Entity)
+}
+
+#[async_trait]
+impl SearchPostRepository for SearchPostRepositoryImpl {
+    async fn rank_posts(&self, query: &SearchQuery) -> Result<Vec<RankedPost>, ServiceError> {
+        self.search_index
+            .find(&self.db_conn, query.terms())
+            .await
+            .map_err(|err| {
+                error!("Failed to load search index: {:?}", err);
+                ServiceError("Internal service error".to_string())
+            })
+    }
+
+    async fn find_posts(
+        &self,
+        ids: &[i32],
+        filter: &SearchFilter,
+    ) -> Result<Vec<SearchCandidate>, ServiceError> {
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut posts = find_candidates_query(ids, filter)
+            .all(&self.db_conn)
+            .await
+            .map_err(|err| {
+                error!("Database query failed: {:?}", err);
+                ServiceError("Internal service error".to_string())
+            })?;
+        // 索引で付けた順位の順に並べ直す
+        let ranks: HashMap<i32, usize> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+        posts.sort_by_key(|(post, _)| ranks.get(&post.id).copied());
+        let ids: Vec<i32> = posts.iter().map(|(post, _)| post.id).collect();
+        let reply_counts = reply_count::count_replies(&self.db_conn, &ids)
+            .await
+            .map_err(|err| {
+                error!("Database query failed: {:?}", err);
+                ServiceError("Internal service error".to_string())
+            })?;
+        // ログインしていなくても検索できるため、自分のリアクションの印は付けない
+        let mut reactions = reaction_count::count_reactions(&self.db_conn, &ids, None)
+            .await
+            .map_err(|err| {
+                error!("Database query failed: {:?}", err);
+                ServiceError("Internal service error".to_string())
+            })?;
+        Ok(posts
+            .into_iter()
+            .map(|(post, user_name)| SearchCandidate {
+                parent_post_id: post.parent_post_id,
+                post: Post {
+                    id: post.id,
+                    content: Content(post.original_content),
+                    posted_user_name: PostedUserName(
+                        user_name
+                            .map(|user_name| user_name.name)
+                            .unwrap_or_default(),
+                    ),
+                    posted_datetime: PostedDatetime(post.created_at.naive_local()),
+                    muted: false,
+                    reply_count: reply_counts.get(&post.id).copied().unwrap_or_default(),
+                    reactions: reactions.remove(&post.id).unwrap_or_default(),
+                },
+            })
+            .collect())
+    }
+
+    async fn find_mute_words(&self, user_id: i32) -> Result<Vec<String>, ServiceError> {
+        let result = t_mute_word::Entity::find()
+            .filter(t_mute_word::Column::UserId.eq(user_id))
+            .all(&self.db_conn)
+            .await;
+
+        match result {
+            Ok(mute_words) => Ok(mute_words
+                .into_iter()
+                .map(|mute_word| mute_word.word)
+                .collect()),
+            Err(err) => {
+                error!("Database query failed: {:?}", err);
+                Err(ServiceError("Internal service error".to_string()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDateTime;
+    use sea_orm::{DbBackend, QueryTrait};
+
+    #[test]
+    fn test_find_candidates_query() {
+        let sql = find_candidates_query(&[3, 1], &SearchFilter::default())
+            .build(DbBackend::MySql)
+            .to_string();
+
+        assert!(sql.ends_with(
+            "WHERE `t_post`.`id` IN (3, 1) AND `t_post`.`status` = 'published' AND `t_post`.`deleted_at` IS NULL"
+        ));
+    }
+
+    #[test]
+    fn test_find_candidates_query_with_filter() {
+        let datetime = |value| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%SZ").unwrap();
+        let filter = SearchFilter {
+            author: Some("test".to_string()),
+            since: Some(datetime("2021-01-01T00:00:00Z")),
+            until: Some(datetime("2021-01-31T00:00:00Z")),
+        };
+        let sql = find_candidates_query(&[1], &filter)
+            .build(DbBackend::MySql)
+            .to_string();
+
+        assert!(sql.ends_with(
+            "WHERE `t_post`.`id` IN (1) AND `t_post`.`status` = 'published' AND `t_post`.`deleted_at` IS NULL AND `m_user_name`.`name` = 'test' AND `t_post`.`created_at` >= '2021-01-01 00:00:00' AND `t_post`.`created_at` <= '2021-01-31 00:00:00'"
+        ));
+    }
+}
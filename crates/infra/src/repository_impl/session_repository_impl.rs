@@ -0,0 +1,132 @@
+use crate::entity::{m_user_name, t_session};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain_model::login::{
+    err::ServiceError,
+    model::{LoggedInUser, Role, Session, SessionToken},
+};
+use domain_service::login::SessionRepository;
+use sea_orm::{entity::prelude::*, ActiveValue::NotSet, DatabaseConnection, Set};
+use sha2::{Digest, Sha256};
+use tracing::{error, warn};
+
+pub struct SessionRepositoryImpl {
+    db_conn: DatabaseConnection,
+}
+impl SessionRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection) -> Self {
+        Self { db_conn }
+    }
+}
+
+/// 保存・照合に使うトークンのハッシュ
+///
+/// DBやバックアップが漏れても有効なセッションを使われないよう、トークンはSHA-256だけを保存する。
+/// トークンは十分に長い乱数なので、ソルトや遅いハッシュは使わない
+fn hash_token(token: &SessionToken) -> String {
+    format!("{:x}", Sha256::digest(token.0.as_bytes()))
+}
+
+#[async_trait]
+impl SessionRepository for SessionRepositoryImpl {
+    async fn create_session(&self, user_id: i32, session: &Session) -> Result<(), ServiceError> {
+        let session = t_session::ActiveModel {
+            id: NotSet,
+            token_hash: Set(hash_token(&session.token)),
+            user_id: Set(user_id),
+            expires_at: Set(session.expires_at.fixed_offset()),
+            created_at: NotSet,
+            updated_at: NotSet,
+        };
+        let result = t_session::Entity::insert(session).exec(&self.db_conn).await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                error!("Failed to create session: {:?}", err);
+                Err(ServiceError("Failed to create session".to_string()))
+            }
+        }
+    }
+    async fn find_session_user(
+        &self,
+        token: &SessionToken,
+        now: DateTime<Utc>,
+    ) -> Result<Option<LoggedInUser>, ServiceError> {
+        let result = t_session::Entity::find()
+            .filter(t_session::Column::TokenHash.eq(hash_token(token)))
+            .filter(t_session::Column::ExpiresAt.gt(now))
+            .find_also_related(m_user_name::Entity)
+            .one(&self.db_conn)
+            .await;
+        match result {
+            Ok(Some((_, Some(user)))) => Ok(Some(LoggedInUser {
+                id: user.id,
+                name: user.name,
+                // 不明な値は記録したうえで、最も権限の弱い`Member`として扱う
+                role: Role::parse(&user.role).unwrap_or_else(|| {
+                    warn!("Unknown role: {:?} user_id: {}", user.role, user.id);
+                    Role::Member
+                }),
+            })),
+            Ok(_) => Ok(None),
+            Err(err) => {
+                error!("Failed to find session: {:?}", err);
+                Err(ServiceError("Failed to find session".to_string()))
+            }
+        }
+    }
+    async fn revoke_session(&self, token: &SessionToken) -> Result<(), ServiceError> {
+        let result = t_session::Entity::delete_many()
+            .filter(t_session::Column::TokenHash.eq(hash_token(token)))
+            .exec(&self.db_conn)
+            .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                error!("Failed to revoke session: {:?}", err);
+                Err(ServiceError("Failed to revoke session".to_string()))
+            }
+        }
+    }
+    async fn revoke_all_sessions(&self, user_id: i32) -> Result<(), ServiceError> {
+        let result = t_session::Entity::delete_many()
+            .filter(t_session::Column::UserId.eq(user_id))
+            .exec(&self.db_conn)
+            .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                error!("Failed to revoke sessions: {:?}", err);
+                Err(ServiceError("Failed to revoke sessions".to_string()))
+            }
+        }
+    }
+    async fn delete_expired_sessions(&self, now: DateTime<Utc>) -> Result<u64, ServiceError> {
+        let result = t_session::Entity::delete_many()
+            .filter(t_session::Column::ExpiresAt.lte(now))
+            .exec(&self.db_conn)
+            .await;
+        match result {
+            Ok(result) => Ok(result.rows_affected),
+            Err(err) => {
+                error!("Failed to delete expired sessions: {:?}", err);
+                Err(ServiceError(
+                    "Failed to delete expired sessions".to_string(),
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_token() {
+        assert_eq!(
+            hash_token(&SessionToken("token".to_string())),
+            "3c469e9d6c5875d37a43f353d4f88e61fcf812c66eee3457465a40b0da4153e0"
+        );
+    }
+}
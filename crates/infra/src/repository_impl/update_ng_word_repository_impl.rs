@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use domain_model::{
+    ng_word_policy::model::{PatternKind, Severity},
+    register_ng_word::model::NgWord,
+    update_ng_word::{err::ServiceError, model::StoredNgWord},
+};
+use domain_service::update_ng_word::repository::UpdateNgWordRepository;
+use sea_orm::{entity::prelude::*, sea_query::Expr, DatabaseConnection, UpdateMany};
+use std::sync::Arc;
+use tracing::error;
+
+use super::NgWordRuleCache;
+use crate::entity::m_ng_word;
+
+pub struct UpdateNgWordRepositoryImpl {
+    db_conn: DatabaseConnection,
+    ng_word_rule_cache: Arc<NgWordRuleCache>,
+}
+
+impl UpdateNgWordRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection, ng_word_rule_cache: Arc<NgWordRuleCache>) -> Self {
+        Self {
+            db_conn,
+            ng_word_rule_cache,
+        }
+    }
+}
+
+/// NGワードを変更し、変更したユーザと日時を記録するクエリ
+fn update_ng_word_query(id: i32, ng_word: &NgWord, user_id: i32) -> UpdateMany<m_ng_word::Entity> {
+    m_ng_word::Entity::update_many()
+        .col_expr(m_ng_word::Column::Word, Expr::value(ng_word.value()))
+        .col_expr(
+            m_ng_word::Column::Kind,
+            Expr::value(ng_word.kind().as_str()),
+        )
+        .col_expr(
+            m_ng_word::Column::Severity,
+            Expr::value(ng_word.severity().as_str()),
+        )
+        .col_expr(m_ng_word::Column::UpdatedUserId, Expr::value(user_id))
+        .col_expr(
+            m_ng_word::Column::UpdatedAt,
+            Expr::current_timestamp().into(),
+        )
+        .filter(m_ng_word::Column::Id.eq(id))
+}
+
+fn service_error(err: DbErr) -> ServiceError {
+    error!("Database query failed: {:?}", err);
+    ServiceError("Internal service error".to_string())
+}
+
+#[async_trait]
+impl UpdateNgWordRepository for UpdateNgWordRepositoryImpl {
+    async fn find_ng_word(&self, id: i32) -> Result<Option<StoredNgWord>, ServiceError> {
+        let model = m_ng_word::Entity::find_by_id(id)
+            .one(&self.db_conn)
+            .await
+            .map_err(service_error)?;
+
+        // 不明な書き方は`literal`、不明な対応は`mask`として扱う
+        Ok(model.map(|model| StoredNgWord {
+            word: model.word,
+            kind: PatternKind::parse(&model.kind).unwrap_or_default(),
+            severity: Severity::parse(&model.severity).unwrap_or_default(),
+        }))
+    }
+
+    async fn update_ng_word(
+        &self,
+        id: i32,
+        ng_word: &NgWord,
+        user_id: i32,
+    ) -> Result<bool, ServiceError> {
+        // sqlxはFOUND_ROWSで接続するため、値が変わらなくても一致した行は件数に数えられる
+        let result = update_ng_word_query(id, ng_word, user_id)
+            .exec(&self.db_conn)
+            .await
+            .map_err(service_error)?;
+        if result.rows_affected == 0 {
+            return Ok(false);
+        }
+        self.ng_word_rule_cache.invalidate();
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DbBackend, QueryTrait};
+
+    #[test]
+    fn test_update_ng_word_query() {
+        let ng_word = NgWord::with_kind("b*d", PatternKind::Glob)
+            .unwrap()
+            .with_severity(Severity::Reject);
+        let sql = update_ng_word_query(10, &ng_word, 1)
+            .build(DbBackend::MySql)
+            .to_string();
+
+        assert_eq!(
+            sql,
+            "UPDATE `m_ng_word` SET `word` = 'b*d', `kind` = 'glob', `severity` = 'reject', `updated_user_id` = 1, `updated_at` = CURRENT_TIMESTAMP WHERE `m_ng_word`.`id` = 10"
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use super::PostSearchIndex;
+use crate::entity::{t_post, t_post_revision};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use domain_model::{
+    post::model::Post,
+    update_post::{
+        err::ServiceError,
+        model::{StoredPost, UpdateOutcome},
+    },
+};
+use domain_service::update_post::UpdatePostRepository;
+use sea_orm::{
+    entity::prelude::*, sea_query::Expr, DatabaseConnection, QueryOrder, QuerySelect, Set,
+    TransactionTrait,
+};
+use std::sync::Arc;
+use tracing::error;
+
+pub struct UpdatePostRepositoryImpl {
+    db_conn: DatabaseConnection,
+    search_index: Arc<PostSearchIndex>,
+}
+
+impl UpdatePostRepositoryImpl {
+    pub fn new(db_conn: DatabaseConnection, search_index: Arc<PostSearchIndex>) -> Self {
+        Self {
+            db_conn,
+            search_index,
+        }
+    }
+}
+
+fn service_error(err: DbErr) -> ServiceError {
+    error!("Database query failed: {:?}", err);
+    ServiceError("Internal service error".to_string())
+}
+
+#[async_trait]
+impl UpdatePostRepository for UpdatePostRepositoryImpl {
+    async fn find_post(&self, id: i32) -> Result<Option<StoredPost>, ServiceError> {
+        let model = t_post::Entity::find_by_id(id)
+            .filter(t_post::Column::DeletedAt.is_null())
+            .one(&self.db_conn)
+            .await
+            .map_err(service_error)?;
+        Ok(model.map(|model| StoredPost {
+            id: model.id,
+            posted_user_id: model.posted_user_id,
+            created_at: model.created_at.to_utc(),
+        }))
+    }
+
+    async fn update_post(
+        &self,
+        id: i32,
+        post: &Post,
+        user_id: i32,
+        window: Duration,
+    ) -> Result<UpdateOutcome, ServiceError> {
+        let txn = self.db_conn.begin().await.map_err(service_error)?;
+        // 同時に編集された時に版が抜けないように、更新するまで行をロックする
+        let Some(current) = t_post::Entity::find_by_id(id)
+            .filter(t_post::Column::DeletedAt.is_null())
+            .lock_exclusive()
+            .one(&txn)
+            .await
+            .map_err(service_error)?
+        else {
+            return Ok(UpdateOutcome::NotFound);
+        };
+        // 確かめてからロックするまでの間に期間を過ぎていないか、ロックした行で確かめ直す
+        let stored = StoredPost {
+            id: current.id,
+            posted_user_id: current.posted_user_id,
+            created_at: current.created_at.to_utc(),
+        };
+        if let Err(err) = stored.check_editable(user_id, Utc::now(), window) {
+            return Ok(UpdateOutcome::NotEditable(err));
+        }
+        // 版は編集と同じトランザクションで残すため、直前の版を残した日時が今の内容を書いた日時になる。
+        // 初めての編集なら投稿した日時になる
+        let written_at = t_post_revision::Entity::find()
+            .filter(t_post_revision::Column::PostId.eq(current.id))
+            .order_by_desc(t_post_revision::Column::Id)
+            .one(&txn)
+            .await
+            .map_err(service_error)?
+            .map_or(current.created_at, |revision| revision.created_at);
+        t_post_revision::ActiveModel {
+            post_id: Set(current.id),
+            original_content: Set(current.original_content),
+            written_at: Set(written_at),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await
+        .map_err(service_error)?;
+        t_post::Entity::update_many()
+            .col_expr(t_post::Column::OriginalContent, Expr::value(post.content()))
+            .col_expr(t_post::Column::Status, Expr::value(post.status().as_str()))
+            .filter(t_post::Column::Id.eq(id))
+            .exec(&txn)
+            .await
+            .map_err(service_error)?;
+        txn.commit().await.map_err(service_error)?;
+        self.search_index.add(id, post.content());
+
+        Ok(UpdateOutcome::Updated)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::entity::m_user_name;
+use sea_orm::{entity::prelude::*, ConnectionTrait, QuerySelect, Select};
+
+/// ユーザ名に完全一致するユーザのIDを検索するクエリ
+///
+/// `=`で比較するため一致判定はカラムの照合順序に従う
+fn find_user_id_query(name: &str) -> Select<m_user_name::Entity> {
+    m_user_name::Entity::find()
+        .select_only()
+        .column(m_user_name::Column::Id)
+        .filter(m_user_name::Column::Name.eq(name))
+}
+
+/// ユーザ名に完全一致するユーザのIDを取得する
+pub(crate) async fn find_user_id<C: ConnectionTrait>(
+    db_conn: &C,
+    name: &str,
+) -> Result<Option<i32>, DbErr> {
+    find_user_id_query(name)
+        .into_tuple::<i32>()
+        .one(db_conn)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{DbBackend, QueryTrait};
+
+    #[test]
+    fn test_find_user_id_query_is_exact_match() {
+        let sql = find_user_id_query("bob")
+            .build(DbBackend::MySql)
+            .to_string();
+
+        assert_eq!(
+            sql,
+            "SELECT `m_user_name`.`id` FROM `m_user_name` WHERE `m_user_name`.`name` = 'bob'"
+        );
+        assert!(!sql.contains("LIKE"));
+    }
+}
@@ -0,0 +1,2 @@
+mod interface;
+pub use interface::check_ng_words;
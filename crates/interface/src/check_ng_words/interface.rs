@@ -0,0 +1,284 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{
+    self, post,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::check_ng_words::AppService as CheckNgWordsAppService;
+use domain_model::{
+    check_ng_words::{err::CheckNgWordsError, model::UnvalidatedCheckText},
+    ng_word_policy::model::{ContentVerdict, NgWordCheck, NgWordMatch},
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Deserialize, Serialize)]
+struct CheckNgWordsDto {
+    text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NgWordMatchDto {
+    pub id: i32,
+    pub ng_word: String,
+    pub severity: String,
+    pub matched: String,
+    /// 一致した箇所の先頭。コードポイント単位
+    pub start: usize,
+    /// 一致した箇所の末尾の次。コードポイント単位
+    pub end: usize,
+}
+impl From<NgWordMatch> for NgWordMatchDto {
+    fn from(found: NgWordMatch) -> Self {
+        Self {
+            id: found.rule.id,
+            ng_word: found.rule.word,
+            severity: found.rule.severity.as_str().to_string(),
+            matched: found.matched,
+            start: found.range.start,
+            end: found.range.end,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NgWordCheckDto {
+    /// 投稿した時の扱い(accept, hold, reject)
+    pub verdict: String,
+    /// 投稿した時に公開される内容。拒否される場合は`None`
+    pub masked: Option<String>,
+    pub matches: Vec<NgWordMatchDto>,
+}
+impl From<NgWordCheck> for NgWordCheckDto {
+    fn from(check: NgWordCheck) -> Self {
+        let (verdict, masked) = match check.verdict {
+            ContentVerdict::Accept(content) => ("accept", Some(content)),
+            ContentVerdict::Hold { content, .. } => ("hold", Some(content)),
+            ContentVerdict::Reject(_) => ("reject", None),
+        };
+        Self {
+            verdict: verdict.to_string(),
+            masked,
+            matches: check
+                .matches
+                .into_iter()
+                .map(NgWordMatchDto::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CheckNgWordsErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<CheckNgWordsError> for CheckNgWordsErrorDto {
+    fn from(err: CheckNgWordsError) -> Self {
+        match err {
+            CheckNgWordsError::ValidationError(err) => CheckNgWordsErrorDto {
+                error_code: 400,
+                error_message: err.0,
+            },
+            CheckNgWordsError::ServiceError(_) => CheckNgWordsErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+        }
+    }
+}
+
+#[post("/ng-words/check")]
+pub async fn check_ng_words(
+    user: AuthenticatedUser,
+    check: web::Json<CheckNgWordsDto>,
+    service: Data<Arc<dyn CheckNgWordsAppService>>,
+) -> impl Responder {
+    let text = UnvalidatedCheckText(check.into_inner().text);
+    match service.check_ng_words(text, &user.0).await {
+        Ok(check) => HttpResponse::Ok().json(NgWordCheckDto::from(check)),
+        Err(err) => match err {
+            CheckNgWordsError::ValidationError(_) => {
+                HttpResponse::BadRequest().json(CheckNgWordsErrorDto::from(err))
+            }
+            CheckNgWordsError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(CheckNgWordsErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use domain_model::{
+        check_ng_words::err::{ServiceError, ValidationError},
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+        ng_word_policy::model::{NgWordRule, PatternKind, Severity},
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn member() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role: Role::Member,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(member()));
+        Arc::new(service)
+    }
+
+    mock! {
+        pub CheckNgWordsAppService {}
+
+        #[async_trait]
+        impl CheckNgWordsAppService for CheckNgWordsAppService {
+            async fn check_ng_words(&self, text: UnvalidatedCheckText, user: &LoggedInUser) -> Result<NgWordCheck, CheckNgWordsError>;
+        }
+    }
+
+    async fn call(service: MockCheckNgWordsAppService) -> actix_web::dev::ServiceResponse {
+        let arc_service: Arc<dyn CheckNgWordsAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(check_ng_words)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/ng-words/check")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .set_json(CheckNgWordsDto {
+                text: "この馬鹿め".to_string(),
+            })
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_check_ng_words() {
+        let mut service = MockCheckNgWordsAppService::new();
+        service
+            .expect_check_ng_words()
+            .with(
+                eq(UnvalidatedCheckText("この馬鹿め".to_string())),
+                eq(member()),
+            )
+            .times(1)
+            .returning(|_, _| {
+                Ok(NgWordCheck {
+                    matches: vec![NgWordMatch {
+                        rule: NgWordRule {
+                            id: 1,
+                            word: "馬鹿".to_string(),
+                            kind: PatternKind::Literal,
+                            severity: Severity::Mask,
+                        },
+                        matched: "馬鹿".to_string(),
+                        range: 2..4,
+                    }],
+                    verdict: ContentVerdict::Accept("この**め".to_string()),
+                })
+            });
+
+        let resp = call(service).await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from(
+                "{\"verdict\":\"accept\",\"masked\":\"この**め\",\"matches\":[{\"id\":1,\"ng_word\":\"馬鹿\",\"severity\":\"mask\",\"matched\":\"馬鹿\",\"start\":2,\"end\":4}]}"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_check_ng_words_reject() {
+        let mut service = MockCheckNgWordsAppService::new();
+        service.expect_check_ng_words().times(1).returning(|_, _| {
+            let rule = NgWordRule {
+                id: 1,
+                word: "馬鹿".to_string(),
+                kind: PatternKind::Literal,
+                severity: Severity::Reject,
+            };
+            Ok(NgWordCheck {
+                matches: vec![NgWordMatch {
+                    rule: rule.clone(),
+                    matched: "馬鹿".to_string(),
+                    range: 2..4,
+                }],
+                verdict: ContentVerdict::Reject(rule),
+            })
+        });
+
+        let resp = call(service).await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from(
+                "{\"verdict\":\"reject\",\"masked\":null,\"matches\":[{\"id\":1,\"ng_word\":\"馬鹿\",\"severity\":\"reject\",\"matched\":\"馬鹿\",\"start\":2,\"end\":4}]}"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_check_ng_words_validation_error() {
+        let mut service = MockCheckNgWordsAppService::new();
+        service.expect_check_ng_words().times(1).returning(|_, _| {
+            Err(CheckNgWordsError::ValidationError(ValidationError(
+                "Text must not be empty".to_string(),
+            )))
+        });
+
+        assert_eq!(call(service).await.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_check_ng_words_service_error() {
+        let mut service = MockCheckNgWordsAppService::new();
+        service.expect_check_ng_words().times(1).returning(|_, _| {
+            Err(CheckNgWordsError::ServiceError(ServiceError(
+                "Service Error".to_string(),
+            )))
+        });
+
+        assert_eq!(
+            call(service).await.status(),
+            http::StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{
+    self, delete,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::delete_allow_word::AppService as DeleteAllowWordAppService;
+use domain_model::delete_allow_word::err::DeleteAllowWordError;
+use serde::Serialize;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteAllowWordErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<DeleteAllowWordError> for DeleteAllowWordErrorDto {
+    fn from(err: DeleteAllowWordError) -> Self {
+        match err {
+            DeleteAllowWordError::ServiceError(_) => DeleteAllowWordErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+            DeleteAllowWordError::PermissionError(err) => DeleteAllowWordErrorDto {
+                error_code: 403,
+                error_message: err.0,
+            },
+            DeleteAllowWordError::NotFoundError(err) => DeleteAllowWordErrorDto {
+                error_code: 404,
+                error_message: err.0,
+            },
+        }
+    }
+}
+
+#[delete("/allow-words/{id}")]
+pub async fn delete_allow_word(
+    user: AuthenticatedUser,
+    id: web::Path<i32>,
+    service: Data<Arc<dyn DeleteAllowWordAppService>>,
+) -> impl Responder {
+    let result = service.delete_allow_word(id.into_inner(), &user.0).await;
+    match result {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(err) => match err {
+            DeleteAllowWordError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(DeleteAllowWordErrorDto::from(err))
+            }
+            DeleteAllowWordError::PermissionError(_) => {
+                HttpResponse::Forbidden().json(DeleteAllowWordErrorDto::from(err))
+            }
+            DeleteAllowWordError::NotFoundError(_) => {
+                HttpResponse::NotFound().json(DeleteAllowWordErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use domain_model::{
+        delete_allow_word::err::{NotFoundError, PermissionError, ServiceError},
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(moderator()));
+        Arc::new(service)
+    }
+
+    mock! {
+        pub DeleteAllowWordAppService {}
+
+        #[async_trait]
+        impl DeleteAllowWordAppService for DeleteAllowWordAppService {
+            async fn delete_allow_word(&self, id: i32, user: &LoggedInUser) -> Result<(), DeleteAllowWordError>;
+        }
+    }
+
+    async fn call(service: MockDeleteAllowWordAppService, uri: &str) -> http::StatusCode {
+        let arc_service: Arc<dyn DeleteAllowWordAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(delete_allow_word)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::delete()
+            .uri(uri)
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+
+        test::call_service(&app, req).await.status()
+    }
+
+    #[actix_web::test]
+    async fn test_delete_allow_word_ok() {
+        let mut service = MockDeleteAllowWordAppService::new();
+        service
+            .expect_delete_allow_word()
+            .with(eq(10), eq(moderator()))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        assert_eq!(
+            call(service, "/allow-words/10").await,
+            http::StatusCode::NO_CONTENT
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_delete_allow_word_not_found() {
+        let mut service = MockDeleteAllowWordAppService::new();
+        service
+            .expect_delete_allow_word()
+            .with(eq(99), eq(moderator()))
+            .times(1)
+            .returning(|_, _| {
+                Err(DeleteAllowWordError::NotFoundError(NotFoundError(
+                    "Allow word not found".to_string(),
+                )))
+            });
+
+        assert_eq!(
+            call(service, "/allow-words/99").await,
+            http::StatusCode::NOT_FOUND
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_delete_allow_word_forbidden() {
+        let mut service = MockDeleteAllowWordAppService::new();
+        service
+            .expect_delete_allow_word()
+            .with(eq(10), eq(moderator()))
+            .times(1)
+            .returning(|_, _| {
+                Err(DeleteAllowWordError::PermissionError(PermissionError(
+                    "Only moderators can delete Allow words".to_string(),
+                )))
+            });
+
+        assert_eq!(
+            call(service, "/allow-words/10").await,
+            http::StatusCode::FORBIDDEN
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_delete_allow_word_service_error() {
+        let mut service = MockDeleteAllowWordAppService::new();
+        service
+            .expect_delete_allow_word()
+            .times(1)
+            .returning(|_, _| {
+                Err(DeleteAllowWordError::ServiceError(ServiceError(
+                    "Service Error".to_string(),
+                )))
+            });
+
+        assert_eq!(
+            call(service, "/allow-words/10").await,
+            http::StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
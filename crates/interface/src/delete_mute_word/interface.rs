@@ -0,0 +1,178 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{
+    self, delete,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::delete_mute_word::AppService as DeleteMuteWordAppService;
+use domain_model::delete_mute_word::err::DeleteMuteWordError;
+use serde::Serialize;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteMuteWordErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<DeleteMuteWordError> for DeleteMuteWordErrorDto {
+    fn from(err: DeleteMuteWordError) -> Self {
+        match err {
+            DeleteMuteWordError::ServiceError(_) => DeleteMuteWordErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+            DeleteMuteWordError::NotFoundError(err) => DeleteMuteWordErrorDto {
+                error_code: 404,
+                error_message: err.0,
+            },
+        }
+    }
+}
+
+#[delete("/mute-words/{id}")]
+pub async fn delete_mute_word(
+    user: AuthenticatedUser,
+    id: web::Path<i32>,
+    service: Data<Arc<dyn DeleteMuteWordAppService>>,
+) -> impl Responder {
+    let result = service.delete_mute_word(id.into_inner(), &user.0).await;
+    match result {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(err) => match err {
+            DeleteMuteWordError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(DeleteMuteWordErrorDto::from(err))
+            }
+            DeleteMuteWordError::NotFoundError(_) => {
+                HttpResponse::NotFound().json(DeleteMuteWordErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use domain_model::{
+        delete_mute_word::err::{NotFoundError, ServiceError},
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn member() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role: Role::Member,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(member()));
+        Arc::new(service)
+    }
+
+    mock! {
+        pub DeleteMuteWordAppService {}
+
+        #[async_trait]
+        impl DeleteMuteWordAppService for DeleteMuteWordAppService {
+            async fn delete_mute_word(&self, id: i32, user: &LoggedInUser) -> Result<(), DeleteMuteWordError>;
+        }
+    }
+
+    async fn call(service: MockDeleteMuteWordAppService, uri: &str) -> http::StatusCode {
+        let arc_service: Arc<dyn DeleteMuteWordAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(delete_mute_word)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::delete()
+            .uri(uri)
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+
+        test::call_service(&app, req).await.status()
+    }
+
+    #[actix_web::test]
+    async fn test_delete_mute_word_ok() {
+        let mut service = MockDeleteMuteWordAppService::new();
+        service
+            .expect_delete_mute_word()
+            .with(eq(10), eq(member()))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        assert_eq!(
+            call(service, "/mute-words/10").await,
+            http::StatusCode::NO_CONTENT
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_delete_mute_word_not_found() {
+        let mut service = MockDeleteMuteWordAppService::new();
+        service
+            .expect_delete_mute_word()
+            .with(eq(99), eq(member()))
+            .times(1)
+            .returning(|_, _| {
+                Err(DeleteMuteWordError::NotFoundError(NotFoundError(
+                    "Mute word not found".to_string(),
+                )))
+            });
+
+        assert_eq!(
+            call(service, "/mute-words/99").await,
+            http::StatusCode::NOT_FOUND
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_delete_mute_word_service_error() {
+        let mut service = MockDeleteMuteWordAppService::new();
+        service
+            .expect_delete_mute_word()
+            .times(1)
+            .returning(|_, _| {
+                Err(DeleteMuteWordError::ServiceError(ServiceError(
+                    "Service Error".to_string(),
+                )))
+            });
+
+        assert_eq!(
+            call(service, "/mute-words/10").await,
+            http::StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
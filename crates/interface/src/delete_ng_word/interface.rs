@@ -0,0 +1,201 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{
+    self, delete,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::delete_ng_word::AppService as DeleteNgWordAppService;
+use domain_model::delete_ng_word::err::DeleteNgWordError;
+use serde::Serialize;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteNgWordErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<DeleteNgWordError> for DeleteNgWordErrorDto {
+    fn from(err: DeleteNgWordError) -> Self {
+        match err {
+            DeleteNgWordError::ServiceError(_) => DeleteNgWordErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+            DeleteNgWordError::PermissionError(err) => DeleteNgWordErrorDto {
+                error_code: 403,
+                error_message: err.0,
+            },
+            DeleteNgWordError::NotFoundError(err) => DeleteNgWordErrorDto {
+                error_code: 404,
+                error_message: err.0,
+            },
+        }
+    }
+}
+
+#[delete("/ng-words/{id}")]
+pub async fn delete_ng_word(
+    user: AuthenticatedUser,
+    id: web::Path<i32>,
+    service: Data<Arc<dyn DeleteNgWordAppService>>,
+) -> impl Responder {
+    let result = service.delete_ng_word(id.into_inner(), &user.0).await;
+    match result {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(err) => match err {
+            DeleteNgWordError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(DeleteNgWordErrorDto::from(err))
+            }
+            DeleteNgWordError::PermissionError(_) => {
+                HttpResponse::Forbidden().json(DeleteNgWordErrorDto::from(err))
+            }
+            DeleteNgWordError::NotFoundError(_) => {
+                HttpResponse::NotFound().json(DeleteNgWordErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use domain_model::{
+        delete_ng_word::err::{NotFoundError, PermissionError, ServiceError},
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(moderator()));
+        Arc::new(service)
+    }
+
+    mock! {
+        pub DeleteNgWordAppService {}
+
+        #[async_trait]
+        impl DeleteNgWordAppService for DeleteNgWordAppService {
+            async fn delete_ng_word(&self, id: i32, user: &LoggedInUser) -> Result<(), DeleteNgWordError>;
+        }
+    }
+
+    async fn call(service: MockDeleteNgWordAppService, uri: &str) -> http::StatusCode {
+        let arc_service: Arc<dyn DeleteNgWordAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(delete_ng_word)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::delete()
+            .uri(uri)
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+
+        test::call_service(&app, req).await.status()
+    }
+
+    #[actix_web::test]
+    async fn test_delete_ng_word_ok() {
+        let mut service = MockDeleteNgWordAppService::new();
+        service
+            .expect_delete_ng_word()
+            .with(eq(10), eq(moderator()))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        assert_eq!(
+            call(service, "/ng-words/10").await,
+            http::StatusCode::NO_CONTENT
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_delete_ng_word_not_found() {
+        let mut service = MockDeleteNgWordAppService::new();
+        service
+            .expect_delete_ng_word()
+            .with(eq(99), eq(moderator()))
+            .times(1)
+            .returning(|_, _| {
+                Err(DeleteNgWordError::NotFoundError(NotFoundError(
+                    "NG word not found".to_string(),
+                )))
+            });
+
+        assert_eq!(
+            call(service, "/ng-words/99").await,
+            http::StatusCode::NOT_FOUND
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_delete_ng_word_forbidden() {
+        let mut service = MockDeleteNgWordAppService::new();
+        service
+            .expect_delete_ng_word()
+            .with(eq(10), eq(moderator()))
+            .times(1)
+            .returning(|_, _| {
+                Err(DeleteNgWordError::PermissionError(PermissionError(
+                    "Only moderators can delete NG words".to_string(),
+                )))
+            });
+
+        assert_eq!(
+            call(service, "/ng-words/10").await,
+            http::StatusCode::FORBIDDEN
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_delete_ng_word_service_error() {
+        let mut service = MockDeleteNgWordAppService::new();
+        service.expect_delete_ng_word().times(1).returning(|_, _| {
+            Err(DeleteNgWordError::ServiceError(ServiceError(
+                "Service Error".to_string(),
+            )))
+        });
+
+        assert_eq!(
+            call(service, "/ng-words/10").await,
+            http::StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
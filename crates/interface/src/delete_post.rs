@@ -0,0 +1,2 @@
+mod interface;
+pub use interface::delete_post;
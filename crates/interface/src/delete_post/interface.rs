@@ -0,0 +1,214 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{
+    self, delete,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::delete_post::AppService as DeletePostAppService;
+use domain_model::delete_post::{err::DeletePostError, model::UnvalidatedDeleteReason};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// 本文は省略できる
+#[derive(Deserialize, Serialize)]
+struct DeletePostDto {
+    #[serde(default)]
+    reason: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeletePostErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<DeletePostError> for DeletePostErrorDto {
+    fn from(err: DeletePostError) -> Self {
+        match err {
+            DeletePostError::ValidationError(err) => DeletePostErrorDto {
+                error_code: 400,
+                error_message: err.0,
+            },
+            DeletePostError::ServiceError(_) => DeletePostErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+            DeletePostError::PermissionError(err) => DeletePostErrorDto {
+                error_code: 403,
+                error_message: err.0,
+            },
+            DeletePostError::NotFoundError(err) => DeletePostErrorDto {
+                error_code: 404,
+                error_message: err.0,
+            },
+        }
+    }
+}
+
+#[delete("/post/{id}")]
+pub async fn delete_post(
+    user: AuthenticatedUser,
+    id: web::Path<i32>,
+    body: Option<web::Json<DeletePostDto>>,
+    service: Data<Arc<dyn DeletePostAppService>>,
+) -> impl Responder {
+    let reason = UnvalidatedDeleteReason(body.and_then(|body| body.into_inner().reason));
+    let result = service.delete_post(id.into_inner(), reason, &user.0).await;
+    match result {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(err) => match err {
+            DeletePostError::ValidationError(_) => {
+                HttpResponse::BadRequest().json(DeletePostErrorDto::from(err))
+            }
+            DeletePostError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(DeletePostErrorDto::from(err))
+            }
+            DeletePostError::PermissionError(_) => {
+                HttpResponse::Forbidden().json(DeletePostErrorDto::from(err))
+            }
+            DeletePostError::NotFoundError(_) => {
+                HttpResponse::NotFound().json(DeletePostErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use domain_model::{
+        delete_post::err::{NotFoundError, PermissionError},
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn member() -> LoggedInUser {
+        LoggedInUser {
+            id: 2,
+            name: "user_name".to_string(),
+            role: Role::Member,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(member()));
+        Arc::new(service)
+    }
+
+    mock! {
+        pub DeletePostAppService {}
+
+        #[async_trait]
+        impl DeletePostAppService for DeletePostAppService {
+            async fn delete_post(&self, id: i32, reason: UnvalidatedDeleteReason, user: &LoggedInUser) -> Result<(), DeletePostError>;
+        }
+    }
+
+    async fn call(
+        service: MockDeletePostAppService,
+        req: test::TestRequest,
+    ) -> actix_web::dev::ServiceResponse {
+        let arc_service: Arc<dyn DeletePostAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(delete_post)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = req
+            .uri("/post/1")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_delete_post_without_reason() {
+        let mut service = MockDeletePostAppService::new();
+        service
+            .expect_delete_post()
+            .with(eq(1), eq(UnvalidatedDeleteReason(None)), eq(member()))
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let resp = call(service, test::TestRequest::delete()).await;
+
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_post_with_reason() {
+        let mut service = MockDeletePostAppService::new();
+        service
+            .expect_delete_post()
+            .with(
+                eq(1),
+                eq(UnvalidatedDeleteReason(Some("spam".to_string()))),
+                eq(member()),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let req = test::TestRequest::delete().set_json(DeletePostDto {
+            reason: Some("spam".to_string()),
+        });
+        let resp = call(service, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::NO_CONTENT);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_post_forbidden() {
+        let mut service = MockDeletePostAppService::new();
+        service.expect_delete_post().times(1).returning(|_, _, _| {
+            Err(DeletePostError::PermissionError(PermissionError(
+                "Only the author or moderators can delete the post".to_string(),
+            )))
+        });
+
+        let resp = call(service, test::TestRequest::delete()).await;
+
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_post_not_found() {
+        let mut service = MockDeletePostAppService::new();
+        service.expect_delete_post().times(1).returning(|_, _, _| {
+            Err(DeletePostError::NotFoundError(NotFoundError(
+                "Post not found".to_string(),
+            )))
+        });
+
+        let resp = call(service, test::TestRequest::delete()).await;
+
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{
+    self, delete,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::delete_reaction::AppService as DeleteReactionAppService;
+use domain_model::delete_reaction::err::DeleteReactionError;
+use serde::Serialize;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteReactionErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<DeleteReactionError> for DeleteReactionErrorDto {
+    fn from(err: DeleteReactionError) -> Self {
+        match err {
+            DeleteReactionError::ValidationError(err) => DeleteReactionErrorDto {
+                error_code: 400,
+                error_message: err.0,
+            },
+            DeleteReactionError::ServiceError(_) => DeleteReactionErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+        }
+    }
+}
+
+#[delete("/post/{id}/reactions/{kind}")]
+pub async fn delete_reaction(
+    user: AuthenticatedUser,
+    path: web::Path<(i32, String)>,
+    service: Data<Arc<dyn DeleteReactionAppService>>,
+) -> impl Responder {
+    let (id, kind) = path.into_inner();
+    let result = service.delete_reaction(id, kind, &user.0).await;
+    match result {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(err) => match err {
+            DeleteReactionError::ValidationError(_) => {
+                HttpResponse::BadRequest().json(DeleteReactionErrorDto::from(err))
+            }
+            DeleteReactionError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(DeleteReactionErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use domain_model::{
+        delete_reaction::err::{ServiceError, ValidationError},
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn member() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role: Role::Member,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(member()));
+        Arc::new(service)
+    }
+
+    mock! {
+        pub DeleteReactionAppService {}
+
+        #[async_trait]
+        impl DeleteReactionAppService for DeleteReactionAppService {
+            async fn delete_reaction(&self, post_id: i32, kind: String, user: &LoggedInUser) -> Result<(), DeleteReactionError>;
+        }
+    }
+
+    async fn call(service: MockDeleteReactionAppService, uri: &str) -> http::StatusCode {
+        let arc_service: Arc<dyn DeleteReactionAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(delete_reaction)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::delete()
+            .uri(uri)
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+
+        test::call_service(&app, req).await.status()
+    }
+
+    #[actix_web::test]
+    async fn test_delete_reaction_ok() {
+        let mut service = MockDeleteReactionAppService::new();
+        service
+            .expect_delete_reaction()
+            .with(eq(10), eq("like".to_string()), eq(member()))
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        assert_eq!(
+            call(service, "/post/10/reactions/like").await,
+            http::StatusCode::NO_CONTENT
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_delete_reaction_validation_error() {
+        let mut service = MockDeleteReactionAppService::new();
+        service
+            .expect_delete_reaction()
+            .times(1)
+            .returning(|_, _, _| {
+                Err(DeleteReactionError::ValidationError(ValidationError(
+                    "Reaction kind must consist of lowercase letters, digits and underscores, or be a single emoji"
+                        .to_string(),
+                )))
+            });
+
+        assert_eq!(
+            call(service, "/post/10/reactions/Like").await,
+            http::StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_delete_reaction_service_error() {
+        let mut service = MockDeleteReactionAppService::new();
+        service
+            .expect_delete_reaction()
+            .times(1)
+            .returning(|_, _, _| {
+                Err(DeleteReactionError::ServiceError(ServiceError(
+                    "Service Error".to_string(),
+                )))
+            });
+
+        assert_eq!(
+            call(service, "/post/10/reactions/like").await,
+            http::StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
@@ -0,0 +1,2 @@
+mod interface;
+pub use interface::export_ng_words;
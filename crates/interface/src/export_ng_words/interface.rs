@@ -0,0 +1,294 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{
+    self, get,
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::export_ng_words::AppService as ExportNgWordsAppService;
+use domain_model::export_ng_words::{err::ExportNgWordsError, model::ExportedNgWord};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Deserialize, Serialize)]
+struct ExportNgWordsQueryDto {
+    /// csvかjson。省略時はjson
+    #[serde(default)]
+    format: Option<String>,
+}
+
+/// 書き出す1行。取り込みと同じ項目名にして、そのまま取り込めるようにする
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExportedNgWordDto {
+    pub ng_word: String,
+    pub kind: String,
+    pub severity: String,
+}
+impl From<ExportedNgWord> for ExportedNgWordDto {
+    fn from(ng_word: ExportedNgWord) -> Self {
+        Self {
+            ng_word: ng_word.word,
+            kind: ng_word.kind.as_str().to_string(),
+            severity: ng_word.severity.as_str().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExportNgWordsErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<ExportNgWordsError> for ExportNgWordsErrorDto {
+    fn from(err: ExportNgWordsError) -> Self {
+        match err {
+            ExportNgWordsError::ServiceError(_) => ExportNgWordsErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+            ExportNgWordsError::PermissionError(err) => ExportNgWordsErrorDto {
+                error_code: 403,
+                error_message: err.0,
+            },
+        }
+    }
+}
+
+/// 見出し行付きのCSVに書き出す
+fn to_csv(ng_words: &[ExportedNgWordDto]) -> Result<Vec<u8>, csv::Error> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+    if ng_words.is_empty() {
+        writer.write_record(["ng_word", "kind", "severity"])?;
+    }
+    for ng_word in ng_words {
+        writer.serialize(ng_word)?;
+    }
+    writer
+        .into_inner()
+        .map_err(|err| csv::Error::from(err.into_error()))
+}
+
+#[get("/ng-words/export")]
+pub async fn export_ng_words(
+    user: AuthenticatedUser,
+    query: web::Query<ExportNgWordsQueryDto>,
+    service: Data<Arc<dyn ExportNgWordsAppService>>,
+) -> impl Responder {
+    let format = query.format.as_deref().unwrap_or("json");
+    if format != "csv" && format != "json" {
+        return HttpResponse::BadRequest().json(ExportNgWordsErrorDto {
+            error_code: 400,
+            error_message: "Format must be one of csv, json".to_string(),
+        });
+    }
+
+    let ng_words: Vec<ExportedNgWordDto> = match service.export_ng_words(&user.0).await {
+        Ok(ng_words) => ng_words.into_iter().map(ExportedNgWordDto::from).collect(),
+        Err(err) => {
+            return match err {
+                ExportNgWordsError::ServiceError(_) => {
+                    HttpResponse::InternalServerError().json(ExportNgWordsErrorDto::from(err))
+                }
+                ExportNgWordsError::PermissionError(_) => {
+                    HttpResponse::Forbidden().json(ExportNgWordsErrorDto::from(err))
+                }
+            }
+        }
+    };
+
+    if format == "json" {
+        return HttpResponse::Ok().json(ng_words);
+    }
+    match to_csv(&ng_words) {
+        Ok(body) => HttpResponse::Ok()
+            .content_type("text/csv; charset=utf-8")
+            .insert_header(ContentDisposition {
+                disposition: DispositionType::Attachment,
+                parameters: vec![DispositionParam::Filename("ng_words.csv".to_string())],
+            })
+            .body(body),
+        Err(_) => HttpResponse::InternalServerError().json(ExportNgWordsErrorDto {
+            error_code: 500,
+            error_message: "Internal Server Error".to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use domain_model::{
+        export_ng_words::err::PermissionError,
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+        ng_word_policy::model::{PatternKind, Severity},
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(moderator()));
+        Arc::new(service)
+    }
+
+    fn ng_words() -> Vec<ExportedNgWord> {
+        vec![
+            ExportedNgWord {
+                word: "bad".to_string(),
+                kind: PatternKind::Literal,
+                severity: Severity::Mask,
+            },
+            ExportedNgWord {
+                word: "b,d".to_string(),
+                kind: PatternKind::Glob,
+                severity: Severity::Reject,
+            },
+        ]
+    }
+
+    mock! {
+        pub ExportNgWordsAppService {}
+
+        #[async_trait]
+        impl ExportNgWordsAppService for ExportNgWordsAppService {
+            async fn export_ng_words(&self, user: &LoggedInUser) -> Result<Vec<ExportedNgWord>, ExportNgWordsError>;
+        }
+    }
+
+    async fn call(
+        service: MockExportNgWordsAppService,
+        uri: &str,
+    ) -> actix_web::dev::ServiceResponse {
+        let arc_service: Arc<dyn ExportNgWordsAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(export_ng_words)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri(uri)
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_export_ng_words_json() {
+        let mut service = MockExportNgWordsAppService::new();
+        service
+            .expect_export_ng_words()
+            .with(eq(moderator()))
+            .times(1)
+            .returning(|_| Ok(ng_words()));
+
+        let resp = call(service, "/ng-words/export").await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"[{\"ng_word\":\"bad\",\"kind\":\"literal\",\"severity\":\"mask\"},{\"ng_word\":\"b,d\",\"kind\":\"glob\",\"severity\":\"reject\"}]"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_export_ng_words_csv() {
+        let mut service = MockExportNgWordsAppService::new();
+        service
+            .expect_export_ng_words()
+            .times(1)
+            .returning(|_| Ok(ng_words()));
+
+        let resp = call(service, "/ng-words/export?format=csv").await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            resp.headers().get(header::CONTENT_TYPE).unwrap(),
+            "text/csv; charset=utf-8"
+        );
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"ng_word,kind,severity\nbad,literal,mask\n\"b,d\",glob,reject\n"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_export_ng_words_csv_empty() {
+        let mut service = MockExportNgWordsAppService::new();
+        service
+            .expect_export_ng_words()
+            .times(1)
+            .returning(|_| Ok(vec![]));
+
+        let resp = call(service, "/ng-words/export?format=csv").await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(b"ng_word,kind,severity\n")
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_export_ng_words_unknown_format() {
+        let mut service = MockExportNgWordsAppService::new();
+        service.expect_export_ng_words().times(0);
+
+        let resp = call(service, "/ng-words/export?format=xml").await;
+
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_export_ng_words_forbidden() {
+        let mut service = MockExportNgWordsAppService::new();
+        service.expect_export_ng_words().times(1).returning(|_| {
+            Err(ExportNgWordsError::PermissionError(PermissionError(
+                "Only moderators can export NG words".to_string(),
+            )))
+        });
+
+        let resp = call(service, "/ng-words/export").await;
+
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
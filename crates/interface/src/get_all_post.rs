@@ -1,2 +1,2 @@
 mod interface;
-pub use interface::get_all_post;
+pub use interface::{get_all_post, PostDto};
@@ -1,18 +1,44 @@
-use actix_web::{self, get, web::Data, HttpResponse, Responder};
+use crate::login::OptionalAuthenticatedUser;
+use actix_web::{
+    self, get,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
 use app_service::get_all_post::AppService as PostAppService;
-use domain_model::get_all_post::model::Post;
-use serde::Serialize;
+use domain_model::get_all_post::{
+    err::GetAllPostError,
+    model::{MuteMode, Post, PostPage, ReactionCount, UnvalidatedPageRequest, Viewer},
+};
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+#[derive(Deserialize, Serialize)]
+struct GetAllPostQueryDto {
+    /// ミュートした語を含む投稿の扱い(hide, collapse)。省略時はhide
+    #[serde(default)]
+    muted: Option<String>,
+    /// 1ページの件数。省略時は20
+    #[serde(default)]
+    limit: Option<usize>,
+    /// 前のページの`next_cursor`。省略時は最新の投稿から
+    #[serde(default)]
+    cursor: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PostDto {
+    pub id: i32,
     pub posted_user_name: String,
     pub posted_datetime: String,
     pub content: String,
+    pub muted: bool,
+    pub reply_count: u64,
+    pub reactions: Vec<ReactionCountDto>,
 }
 impl From<Post> for PostDto {
     fn from(post: Post) -> Self {
         Self {
+            id: post.id,
             posted_user_name: post.posted_user_name.0,
             posted_datetime: post
                 .posted_datetime
@@ -20,85 +46,401 @@ impl From<Post> for PostDto {
                 .format("%Y-%m-%dT%H:%M:%SZ")
                 .to_string(),
             content: post.content.0,
+            muted: post.muted,
+            reply_count: post.reply_count,
+            reactions: post
+                .reactions
+                .into_iter()
+                .map(ReactionCountDto::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReactionCountDto {
+    pub kind: String,
+    pub count: u64,
+    pub reacted_by_me: bool,
+}
+impl From<ReactionCount> for ReactionCountDto {
+    fn from(reaction: ReactionCount) -> Self {
+        Self {
+            kind: reaction.kind,
+            count: reaction.count,
+            reacted_by_me: reaction.reacted_by_me,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PostPageDto {
+    pub posts: Vec<PostDto>,
+    /// 続きがある場合、次のページを取得するための位置
+    pub next_cursor: Option<String>,
+}
+impl From<PostPage> for PostPageDto {
+    fn from(page: PostPage) -> Self {
+        Self {
+            posts: page.posts.into_iter().map(PostDto::from).collect(),
+            next_cursor: page.next_cursor.map(|cursor| cursor.encode()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GetAllPostErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<GetAllPostError> for GetAllPostErrorDto {
+    fn from(err: GetAllPostError) -> Self {
+        match err {
+            GetAllPostError::ValidationError(err) => GetAllPostErrorDto {
+                error_code: 400,
+                error_message: err.0,
+            },
+            GetAllPostError::ServiceError(_) => GetAllPostErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
         }
     }
 }
 
+/// 公開済みの投稿を新しい順に1ページ分返す
+///
+/// ログイン中であれば、そのユーザがミュートした語を含む投稿を隠すか折りたたむ
+///
+/// `Authorization`ヘッダが無い場合はログインしていないものとして扱う。セッションが無効な場合は401
 #[get("/post")]
-pub async fn get_all_post(service: Data<Arc<dyn PostAppService>>) -> impl Responder {
-    let result = service.get_all_post().await;
+pub async fn get_all_post(
+    user: OptionalAuthenticatedUser,
+    query: web::Query<GetAllPostQueryDto>,
+    service: Data<Arc<dyn PostAppService>>,
+) -> impl Responder {
+    let mute_mode = match query.muted.as_deref() {
+        None => MuteMode::default(),
+        Some(mode) => match MuteMode::parse(mode) {
+            Some(mute_mode) => mute_mode,
+            None => {
+                return HttpResponse::BadRequest().json(GetAllPostErrorDto {
+                    error_code: 400,
+                    error_message: "Muted must be one of hide, collapse".to_string(),
+                });
+            }
+        },
+    };
+    let viewer = user.0.map(|user| Viewer {
+        user_id: user.0.id,
+        mute_mode,
+    });
+    let query = query.into_inner();
+    let page = UnvalidatedPageRequest {
+        limit: query.limit,
+        cursor: query.cursor,
+    };
+    let result = service.get_all_post(page, viewer).await;
     match result {
-        Ok(posts) => {
-            let posts: Vec<PostDto> = posts.into_iter().map(PostDto::from).collect();
-            HttpResponse::Ok().json(posts)
-        }
-        Err(_) => HttpResponse::InternalServerError().finish(),
+        Ok(page) => HttpResponse::Ok().json(PostPageDto::from(page)),
+        Err(err) => match err {
+            GetAllPostError::ValidationError(_) => {
+                HttpResponse::BadRequest().json(GetAllPostErrorDto::from(err))
+            }
+            GetAllPostError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(GetAllPostErrorDto::from(err))
+            }
+        },
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use actix_web::{http, test, App};
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
     use async_trait::async_trait;
     use chrono::NaiveDateTime;
-    use domain_model::get_all_post::{
-        err::ServiceError,
-        model::{Content, Post, PostedDatetime, PostedUserName},
+    use domain_model::{
+        get_all_post::{
+            err::{ServiceError, ValidationError},
+            model::{Content, PostCursor, PostedDatetime, PostedUserName},
+        },
+        login::{
+            self,
+            err::{LoginError, SessionError},
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
     };
     use mockall::{predicate::*, *};
 
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| {
+                Ok(LoggedInUser {
+                    id: 2,
+                    name: "user_name".to_string(),
+                    role: Role::Member,
+                })
+            });
+        Arc::new(service)
+    }
+
     mock! {
         pub PostAppService {}
         #[async_trait]
         impl PostAppService for PostAppService {
-            async fn get_all_post(&self) -> Result<Vec<Post>, ServiceError>;
+            async fn get_all_post(&self, page: UnvalidatedPageRequest, viewer: Option<Viewer>) -> Result<PostPage, GetAllPostError>;
         }
     }
 
-    #[actix_web::test]
-    async fn test_get_all_post() {
-        let mut mock = MockPostAppService::new();
-        mock.expect_get_all_post().times(1).returning(|| {
-            Ok(vec![Post {
-                posted_user_name: PostedUserName("test".to_string()),
-                posted_datetime: PostedDatetime(
-                    NaiveDateTime::parse_from_str("2021-01-01T00:00:00Z", "%Y-%m-%dT%H:%M:%SZ")
-                        .unwrap(),
-                ),
-                content: Content("test".to_string()),
-            }])
-        });
+    fn post(muted: bool) -> Post {
+        Post {
+            id: 1,
+            posted_user_name: PostedUserName("test".to_string()),
+            posted_datetime: PostedDatetime(
+                NaiveDateTime::parse_from_str("2021-01-01T00:00:00Z", "%Y-%m-%dT%H:%M:%SZ")
+                    .unwrap(),
+            ),
+            content: Content("test".to_string()),
+            muted,
+            reply_count: 2,
+            reactions: vec![ReactionCount {
+                kind: "like".to_string(),
+                count: 3,
+                reacted_by_me: true,
+            }],
+        }
+    }
+
+    fn page(posts: Vec<Post>) -> PostPage {
+        PostPage {
+            posts,
+            next_cursor: None,
+        }
+    }
+
+    async fn call(
+        mock: MockPostAppService,
+        req: test::TestRequest,
+    ) -> actix_web::dev::ServiceResponse {
+        call_with_login(mock, login_service(), req).await
+    }
+
+    async fn call_with_login(
+        mock: MockPostAppService,
+        login_service: Arc<dyn LoginAppService>,
+        req: test::TestRequest,
+    ) -> actix_web::dev::ServiceResponse {
         let arc_service: Arc<dyn PostAppService> = Arc::new(mock);
 
-        let mut app = test::init_service(
+        let app = test::init_service(
             App::new()
                 .app_data(Data::new(arc_service))
+                .app_data(Data::new(login_service))
                 .service(get_all_post),
         )
         .await;
-        let req = test::TestRequest::get().uri("/post").to_request();
-        let resp = test::call_service(&mut app, req).await;
-        assert_eq!(resp.status(), http::StatusCode::OK);
+        test::call_service(&app, req.to_request()).await
     }
 
     #[actix_web::test]
-    async fn test_get_all_post_internal_server_error() {
+    async fn test_get_all_post() {
         let mut mock = MockPostAppService::new();
         mock.expect_get_all_post()
+            .with(eq(UnvalidatedPageRequest::default()), eq(None))
             .times(1)
-            .returning(|| Err(ServiceError("error".to_string())));
+            .returning(|_, _| Ok(page(vec![post(false)])));
 
-        let arc_service: Arc<dyn PostAppService> = Arc::new(mock);
+        let resp = call(mock, test::TestRequest::get().uri("/post")).await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+    }
 
-        let mut app = test::init_service(
-            App::new()
-                .app_data(Data::new(arc_service))
-                .service(get_all_post),
+    #[actix_web::test]
+    async fn test_get_all_post_next_cursor() {
+        let cursor = PostCursor {
+            posted_datetime: post(false).posted_datetime.0,
+            id: 1,
+        };
+        let encoded = cursor.encode();
+        let mut mock = MockPostAppService::new();
+        mock.expect_get_all_post()
+            .with(
+                eq(UnvalidatedPageRequest {
+                    limit: Some(1),
+                    cursor: Some("abc".to_string()),
+                }),
+                eq(None),
+            )
+            .times(1)
+            .returning(move |_, _| {
+                Ok(PostPage {
+                    posts: vec![post(false)],
+                    next_cursor: Some(cursor.clone()),
+                })
+            });
+
+        let resp = call(
+            mock,
+            test::TestRequest::get().uri("/post?limit=1&cursor=abc"),
         )
         .await;
-        let req = test::TestRequest::get().uri("/post").to_request();
-        let resp = test::call_service(&mut app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from(format!(
+                "{{\"posts\":[{{\"id\":1,\"posted_user_name\":\"test\",\"posted_datetime\":\"2021-01-01T00:00:00Z\",\"content\":\"test\",\"muted\":false,\"reply_count\":2,\"reactions\":[{{\"kind\":\"like\",\"count\":3,\"reacted_by_me\":true}}]}}],\"next_cursor\":\"{}\"}}",
+                encoded
+            ))
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_get_all_post_validation_error() {
+        let mut mock = MockPostAppService::new();
+        mock.expect_get_all_post().times(1).returning(|_, _| {
+            Err(GetAllPostError::ValidationError(ValidationError(
+                "Cursor is invalid".to_string(),
+            )))
+        });
+
+        let resp = call(mock, test::TestRequest::get().uri("/post?cursor=zz")).await;
+
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"{\"error_code\":400,\"error_message\":\"Cursor is invalid\"}"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_get_all_post_muted_collapse() {
+        let mut mock = MockPostAppService::new();
+        mock.expect_get_all_post()
+            .with(
+                always(),
+                eq(Some(Viewer {
+                    user_id: 2,
+                    mute_mode: MuteMode::Collapse,
+                })),
+            )
+            .times(1)
+            .returning(|_, _| Ok(page(vec![post(true)])));
+
+        let req = test::TestRequest::get()
+            .uri("/post?muted=collapse")
+            .insert_header((header::AUTHORIZATION, "Bearer token"));
+        let resp = call(mock, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"{\"posts\":[{\"id\":1,\"posted_user_name\":\"test\",\"posted_datetime\":\"2021-01-01T00:00:00Z\",\"content\":\"test\",\"muted\":true,\"reply_count\":2,\"reactions\":[{\"kind\":\"like\",\"count\":3,\"reacted_by_me\":true}]}],\"next_cursor\":null}"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_get_all_post_muted_default_hide() {
+        let mut mock = MockPostAppService::new();
+        mock.expect_get_all_post()
+            .with(
+                always(),
+                eq(Some(Viewer {
+                    user_id: 2,
+                    mute_mode: MuteMode::Hide,
+                })),
+            )
+            .times(1)
+            .returning(|_, _| Ok(page(vec![])));
+
+        let req = test::TestRequest::get()
+            .uri("/post")
+            .insert_header((header::AUTHORIZATION, "Bearer token"));
+        assert_eq!(call(mock, req).await.status(), http::StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_all_post_invalid_session() {
+        let mut mock = MockPostAppService::new();
+        mock.expect_get_all_post().times(0);
+        let mut login_service = MockLoginAppService::new();
+        login_service.expect_authenticate().times(1).returning(|_| {
+            Err(LoginError::from(SessionError(
+                "Invalid or expired session".to_string(),
+            )))
+        });
+
+        // 無効なセッションはログインしていないものとして扱わない
+        let req = test::TestRequest::get()
+            .uri("/post")
+            .insert_header((header::AUTHORIZATION, "Bearer expired"));
+        let resp = call_with_login(mock, Arc::new(login_service), req).await;
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn test_get_all_post_session_service_error() {
+        let mut mock = MockPostAppService::new();
+        mock.expect_get_all_post().times(0);
+        let mut login_service = MockLoginAppService::new();
+        login_service.expect_authenticate().times(1).returning(|_| {
+            Err(LoginError::from(login::err::ServiceError(
+                "error".to_string(),
+            )))
+        });
+
+        let req = test::TestRequest::get()
+            .uri("/post")
+            .insert_header((header::AUTHORIZATION, "Bearer token"));
+        let resp = call_with_login(mock, Arc::new(login_service), req).await;
+        assert_eq!(resp.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[actix_web::test]
+    async fn test_get_all_post_muted_unknown() {
+        let mut mock = MockPostAppService::new();
+        mock.expect_get_all_post().times(0);
+
+        let req = test::TestRequest::get().uri("/post?muted=blur");
+        assert_eq!(
+            call(mock, req).await.status(),
+            http::StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_get_all_post_internal_server_error() {
+        let mut mock = MockPostAppService::new();
+        mock.expect_get_all_post().times(1).returning(|_, _| {
+            Err(GetAllPostError::ServiceError(ServiceError(
+                "error".to_string(),
+            )))
+        });
+
+        let resp = call(mock, test::TestRequest::get().uri("/post")).await;
         assert_eq!(resp.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
     }
 }
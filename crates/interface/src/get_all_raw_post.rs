@@ -0,0 +1,2 @@
+mod interface;
+pub use interface::get_all_raw_post;
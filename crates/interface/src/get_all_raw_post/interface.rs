@@ -0,0 +1,242 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{self, get, web::Data, HttpResponse, Responder};
+use app_service::get_all_raw_post::AppService as GetAllRawPostAppService;
+use domain_model::get_all_raw_post::{
+    err::GetAllRawPostError,
+    model::{PostDeletion, RawPost},
+};
+use serde::Serialize;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RawPostDto {
+    pub id: i32,
+    pub posted_user_name: String,
+    pub posted_datetime: String,
+    pub status: String,
+    pub content: String,
+    pub deletion: Option<PostDeletionDto>,
+}
+impl From<RawPost> for RawPostDto {
+    fn from(post: RawPost) -> Self {
+        Self {
+            id: post.id,
+            posted_user_name: post.posted_user_name.0,
+            posted_datetime: post
+                .posted_datetime
+                .0
+                .format("%Y-%m-%dT%H:%M:%SZ")
+                .to_string(),
+            status: post.status.as_str().to_string(),
+            content: post.content.0,
+            deletion: post.deletion.map(PostDeletionDto::from),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PostDeletionDto {
+    pub deleted_datetime: String,
+    pub deleted_user_id: Option<i32>,
+    pub reason: Option<String>,
+}
+impl From<PostDeletion> for PostDeletionDto {
+    fn from(deletion: PostDeletion) -> Self {
+        Self {
+            deleted_datetime: deletion
+                .deleted_datetime
+                .format("%Y-%m-%dT%H:%M:%SZ")
+                .to_string(),
+            deleted_user_id: deletion.deleted_user_id,
+            reason: deletion.reason,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GetAllRawPostErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<GetAllRawPostError> for GetAllRawPostErrorDto {
+    fn from(err: GetAllRawPostError) -> Self {
+        match err {
+            GetAllRawPostError::ServiceError(_) => GetAllRawPostErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+            GetAllRawPostError::PermissionError(err) => GetAllRawPostErrorDto {
+                error_code: 403,
+                error_message: err.0,
+            },
+        }
+    }
+}
+
+#[get("/post/raw")]
+pub async fn get_all_raw_post(
+    user: AuthenticatedUser,
+    service: Data<Arc<dyn GetAllRawPostAppService>>,
+) -> impl Responder {
+    match service.get_all_raw_post(&user.0).await {
+        Ok(posts) => {
+            let posts: Vec<RawPostDto> = posts.into_iter().map(RawPostDto::from).collect();
+            HttpResponse::Ok().json(posts)
+        }
+        Err(err) => match err {
+            GetAllRawPostError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(GetAllRawPostErrorDto::from(err))
+            }
+            GetAllRawPostError::PermissionError(_) => {
+                HttpResponse::Forbidden().json(GetAllRawPostErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, web, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use chrono::NaiveDateTime;
+    use domain_model::{
+        get_all_post::model::{Content, PostedDatetime, PostedUserName},
+        get_all_raw_post::err::{PermissionError, ServiceError},
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+        post::model::PostStatus,
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(moderator()));
+        Arc::new(service)
+    }
+
+    mock! {
+        pub GetAllRawPostAppService {}
+
+        #[async_trait]
+        impl GetAllRawPostAppService for GetAllRawPostAppService {
+            async fn get_all_raw_post(&self, user: &LoggedInUser) -> Result<Vec<RawPost>, GetAllRawPostError>;
+        }
+    }
+
+    async fn call(service: MockGetAllRawPostAppService) -> actix_web::dev::ServiceResponse {
+        let arc_service: Arc<dyn GetAllRawPostAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(get_all_raw_post)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/post/raw")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_get_all_raw_post() {
+        let mut service = MockGetAllRawPostAppService::new();
+        service
+            .expect_get_all_raw_post()
+            .with(eq(moderator()))
+            .times(1)
+            .returning(|_| {
+                Ok(vec![RawPost {
+                    id: 1,
+                    posted_user_name: PostedUserName("test".to_string()),
+                    posted_datetime: PostedDatetime(
+                        NaiveDateTime::parse_from_str("2021-01-01T00:00:00Z", "%Y-%m-%dT%H:%M:%SZ")
+                            .unwrap(),
+                    ),
+                    status: PostStatus::Held,
+                    content: Content("so bad".to_string()),
+                    deletion: Some(PostDeletion {
+                        deleted_datetime: NaiveDateTime::parse_from_str(
+                            "2021-01-02T00:00:00Z",
+                            "%Y-%m-%dT%H:%M:%SZ",
+                        )
+                        .unwrap(),
+                        deleted_user_id: Some(1),
+                        reason: Some("spam".to_string()),
+                    }),
+                }])
+            });
+
+        let resp = call(service).await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"[{\"id\":1,\"posted_user_name\":\"test\",\"posted_datetime\":\"2021-01-01T00:00:00Z\",\"status\":\"held\",\"content\":\"so bad\",\"deletion\":{\"deleted_datetime\":\"2021-01-02T00:00:00Z\",\"deleted_user_id\":1,\"reason\":\"spam\"}}]"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_get_all_raw_post_forbidden() {
+        let mut service = MockGetAllRawPostAppService::new();
+        service.expect_get_all_raw_post().times(1).returning(|_| {
+            Err(GetAllRawPostError::PermissionError(PermissionError(
+                "Only moderators can view raw posts".to_string(),
+            )))
+        });
+
+        let resp = call(service).await;
+
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_get_all_raw_post_internal_server_error() {
+        let mut service = MockGetAllRawPostAppService::new();
+        service.expect_get_all_raw_post().times(1).returning(|_| {
+            Err(GetAllRawPostError::ServiceError(ServiceError(
+                "error".to_string(),
+            )))
+        });
+
+        let resp = call(service).await;
+
+        assert_eq!(resp.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::get_all_post::PostDto;
+use actix_web::{
+    self, get,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::get_post::AppService as GetPostAppService;
+use domain_model::get_post::{
+    err::GetPostError,
+    model::{PostView, Tombstone},
+};
+use serde::Serialize;
+use std::sync::Arc;
+
+/// 削除された投稿の代わりに返す印
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TombstoneDto {
+    pub id: i32,
+    pub deleted: bool,
+    pub deleted_datetime: String,
+}
+impl From<Tombstone> for TombstoneDto {
+    fn from(tombstone: Tombstone) -> Self {
+        Self {
+            id: tombstone.id,
+            deleted: true,
+            deleted_datetime: tombstone
+                .deleted_datetime
+                .format("%Y-%m-%dT%H:%M:%SZ")
+                .to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GetPostErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<GetPostError> for GetPostErrorDto {
+    fn from(err: GetPostError) -> Self {
+        match err {
+            GetPostError::ServiceError(_) => GetPostErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+            GetPostError::NotFoundError(err) => GetPostErrorDto {
+                error_code: 404,
+                error_message: err.0,
+            },
+        }
+    }
+}
+
+/// 公開済みの投稿を1件返す
+///
+/// 削除された投稿は、内容の代わりに削除の印を410で返す
+///
+/// `/post/raw`と重なるため、それより後に登録すること
+#[get("/post/{id}")]
+pub async fn get_post(
+    path: web::Path<i32>,
+    service: Data<Arc<dyn GetPostAppService>>,
+) -> impl Responder {
+    match service.get_post(path.into_inner()).await {
+        Ok(PostView::Published(post)) => HttpResponse::Ok().json(PostDto::from(post)),
+        Ok(PostView::Deleted(tombstone)) => {
+            HttpResponse::Gone().json(TombstoneDto::from(tombstone))
+        }
+        Err(err) => match err {
+            GetPostError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(GetPostErrorDto::from(err))
+            }
+            GetPostError::NotFoundError(_) => {
+                HttpResponse::NotFound().json(GetPostErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, test, App};
+    use async_trait::async_trait;
+    use chrono::NaiveDateTime;
+    use domain_model::{
+        get_all_post::model::{Content, Post, PostedDatetime, PostedUserName},
+        get_post::err::{NotFoundError, ServiceError},
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub GetPostAppService {}
+        #[async_trait]
+        impl GetPostAppService for GetPostAppService {
+            async fn get_post(&self, id: i32) -> Result<PostView, GetPostError>;
+        }
+    }
+
+    async fn call(mock: MockGetPostAppService, uri: &str) -> actix_web::dev::ServiceResponse {
+        let arc_service: Arc<dyn GetPostAppService> = Arc::new(mock);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(arc_service))
+                .service(get_post),
+        )
+        .await;
+        test::call_service(&app, test::TestRequest::get().uri(uri).to_request()).await
+    }
+
+    #[actix_web::test]
+    async fn test_get_post() {
+        let mut mock = MockGetPostAppService::new();
+        mock.expect_get_post().with(eq(1)).times(1).returning(|_| {
+            Ok(PostView::Published(Post {
+                id: 1,
+                posted_user_name: PostedUserName("test".to_string()),
+                posted_datetime: PostedDatetime(
+                    NaiveDateTime::parse_from_str("2021-01-01T00:00:00Z", "%Y-%m-%dT%H:%M:%SZ")
+                        .unwrap(),
+                ),
+                content: Content("test".to_string()),
+                muted: false,
+                reply_count: 0,
+                reactions: vec![],
+            }))
+        });
+
+        let resp = call(mock, "/post/1").await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"{\"id\":1,\"posted_user_name\":\"test\",\"posted_datetime\":\"2021-01-01T00:00:00Z\",\"content\":\"test\",\"muted\":false,\"reply_count\":0,\"reactions\":[]}"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_get_post_deleted() {
+        let mut mock = MockGetPostAppService::new();
+        mock.expect_get_post().with(eq(1)).times(1).returning(|_| {
+            Ok(PostView::Deleted(Tombstone {
+                id: 1,
+                deleted_datetime: NaiveDateTime::parse_from_str(
+                    "2021-01-02T00:00:00Z",
+                    "%Y-%m-%dT%H:%M:%SZ",
+                )
+                .unwrap(),
+            }))
+        });
+
+        let resp = call(mock, "/post/1").await;
+
+        assert_eq!(resp.status(), http::StatusCode::GONE);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"{\"id\":1,\"deleted\":true,\"deleted_datetime\":\"2021-01-02T00:00:00Z\"}"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_get_post_not_found() {
+        let mut mock = MockGetPostAppService::new();
+        mock.expect_get_post().with(eq(2)).times(1).returning(|_| {
+            Err(GetPostError::NotFoundError(NotFoundError(
+                "Post not found".to_string(),
+            )))
+        });
+
+        let resp = call(mock, "/post/2").await;
+
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(b"{\"error_code\":404,\"error_message\":\"Post not found\"}")
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_get_post_invalid_id() {
+        let mut mock = MockGetPostAppService::new();
+        mock.expect_get_post().times(0);
+
+        let resp = call(mock, "/post/abc").await;
+
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_get_post_internal_server_error() {
+        let mut mock = MockGetPostAppService::new();
+        mock.expect_get_post().times(1).returning(|_| {
+            Err(GetPostError::ServiceError(ServiceError(
+                "error".to_string(),
+            )))
+        });
+
+        let resp = call(mock, "/post/1").await;
+
+        assert_eq!(resp.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
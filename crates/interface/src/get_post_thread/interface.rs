@@ -0,0 +1,257 @@
+use crate::get_all_post::PostDto;
+use actix_web::{
+    self, get,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::get_post_thread::AppService as GetPostThreadAppService;
+use domain_model::get_post_thread::{
+    err::GetPostThreadError,
+    model::{ThreadNode, ThreadPost, ThreadTombstone},
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+struct GetPostThreadQuery {
+    depth: Option<usize>,
+}
+
+/// 公開していない投稿の代わりに返す印
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ThreadTombstoneDto {
+    pub id: i32,
+    pub deleted: bool,
+    pub reply_count: u64,
+}
+impl From<ThreadTombstone> for ThreadTombstoneDto {
+    fn from(tombstone: ThreadTombstone) -> Self {
+        Self {
+            id: tombstone.id,
+            deleted: true,
+            reply_count: tombstone.reply_count,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ThreadPostDto {
+    Published(PostDto),
+    Tombstone(ThreadTombstoneDto),
+}
+impl From<ThreadPost> for ThreadPostDto {
+    fn from(post: ThreadPost) -> Self {
+        match post {
+            ThreadPost::Published(post) => ThreadPostDto::Published(PostDto::from(post)),
+            ThreadPost::Tombstone(tombstone) => {
+                ThreadPostDto::Tombstone(ThreadTombstoneDto::from(tombstone))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ThreadNodeDto {
+    #[serde(flatten)]
+    pub post: ThreadPostDto,
+    pub replies: Vec<ThreadNodeDto>,
+}
+impl From<ThreadNode> for ThreadNodeDto {
+    fn from(node: ThreadNode) -> Self {
+        Self {
+            post: ThreadPostDto::from(node.post),
+            replies: node.replies.into_iter().map(ThreadNodeDto::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GetPostThreadErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<GetPostThreadError> for GetPostThreadErrorDto {
+    fn from(err: GetPostThreadError) -> Self {
+        match err {
+            GetPostThreadError::ValidationError(err) => GetPostThreadErrorDto {
+                error_code: 400,
+                error_message: err.0,
+            },
+            GetPostThreadError::ServiceError(_) => GetPostThreadErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+            GetPostThreadError::NotFoundError(err) => GetPostThreadErrorDto {
+                error_code: 404,
+                error_message: err.0,
+            },
+        }
+    }
+}
+
+/// 投稿と、その返信を木にして返す
+#[get("/post/{id}/thread")]
+pub async fn get_post_thread(
+    path: web::Path<i32>,
+    query: web::Query<GetPostThreadQuery>,
+    service: Data<Arc<dyn GetPostThreadAppService>>,
+) -> impl Responder {
+    match service
+        .get_post_thread(path.into_inner(), query.depth)
+        .await
+    {
+        Ok(node) => HttpResponse::Ok().json(ThreadNodeDto::from(node)),
+        Err(err) => match err {
+            GetPostThreadError::ValidationError(_) => {
+                HttpResponse::BadRequest().json(GetPostThreadErrorDto::from(err))
+            }
+            GetPostThreadError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(GetPostThreadErrorDto::from(err))
+            }
+            GetPostThreadError::NotFoundError(_) => {
+                HttpResponse::NotFound().json(GetPostThreadErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, test, App};
+    use async_trait::async_trait;
+    use chrono::NaiveDateTime;
+    use domain_model::{
+        get_all_post::model::{Content, Post, PostedDatetime, PostedUserName},
+        get_post_thread::err::{NotFoundError, ValidationError},
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub GetPostThreadAppService {}
+        #[async_trait]
+        impl GetPostThreadAppService for GetPostThreadAppService {
+            async fn get_post_thread(&self, id: i32, depth: Option<usize>) -> Result<ThreadNode, GetPostThreadError>;
+        }
+    }
+
+    async fn call(mock: MockGetPostThreadAppService, uri: &str) -> actix_web::dev::ServiceResponse {
+        let arc_service: Arc<dyn GetPostThreadAppService> = Arc::new(mock);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(arc_service))
+                .service(get_post_thread),
+        )
+        .await;
+        test::call_service(&app, test::TestRequest::get().uri(uri).to_request()).await
+    }
+
+    fn post(id: i32, reply_count: u64) -> ThreadPost {
+        ThreadPost::Published(Post {
+            id,
+            posted_user_name: PostedUserName("test".to_string()),
+            posted_datetime: PostedDatetime(
+                NaiveDateTime::parse_from_str("2021-01-01T00:00:00Z", "%Y-%m-%dT%H:%M:%SZ")
+                    .unwrap(),
+            ),
+            content: Content("test".to_string()),
+            muted: false,
+            reply_count,
+            reactions: vec![],
+        })
+    }
+
+    #[actix_web::test]
+    async fn test_get_post_thread() {
+        let mut mock = MockGetPostThreadAppService::new();
+        mock.expect_get_post_thread()
+            .with(eq(1), eq(Some(1)))
+            .times(1)
+            .returning(|_, _| {
+                Ok(ThreadNode {
+                    post: post(1, 1),
+                    replies: vec![ThreadNode {
+                        post: post(2, 0),
+                        replies: vec![],
+                    }],
+                })
+            });
+
+        let resp = call(mock, "/post/1/thread?depth=1").await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"{\"id\":1,\"posted_user_name\":\"test\",\"posted_datetime\":\"2021-01-01T00:00:00Z\",\"content\":\"test\",\"muted\":false,\"reply_count\":1,\"reactions\":[],\"replies\":[{\"id\":2,\"posted_user_name\":\"test\",\"posted_datetime\":\"2021-01-01T00:00:00Z\",\"content\":\"test\",\"muted\":false,\"reply_count\":0,\"reactions\":[],\"replies\":[]}]}"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_get_post_thread_with_tombstone() {
+        let mut mock = MockGetPostThreadAppService::new();
+        mock.expect_get_post_thread()
+            .with(eq(1), eq(None))
+            .times(1)
+            .returning(|_, _| {
+                Ok(ThreadNode {
+                    post: ThreadPost::Tombstone(ThreadTombstone {
+                        id: 1,
+                        reply_count: 1,
+                    }),
+                    replies: vec![ThreadNode {
+                        post: post(2, 0),
+                        replies: vec![],
+                    }],
+                })
+            });
+
+        let resp = call(mock, "/post/1/thread").await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"{\"id\":1,\"deleted\":true,\"reply_count\":1,\"replies\":[{\"id\":2,\"posted_user_name\":\"test\",\"posted_datetime\":\"2021-01-01T00:00:00Z\",\"content\":\"test\",\"muted\":false,\"reply_count\":0,\"reactions\":[],\"replies\":[]}]}"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_get_post_thread_invalid_depth() {
+        let mut mock = MockGetPostThreadAppService::new();
+        mock.expect_get_post_thread()
+            .with(eq(1), eq(Some(11)))
+            .times(1)
+            .returning(|_, _| {
+                Err(GetPostThreadError::ValidationError(ValidationError(
+                    "Depth must be between 1 and 10".to_string(),
+                )))
+            });
+
+        let resp = call(mock, "/post/1/thread?depth=11").await;
+
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_get_post_thread_not_found() {
+        let mut mock = MockGetPostThreadAppService::new();
+        mock.expect_get_post_thread()
+            .with(eq(1), eq(None))
+            .times(1)
+            .returning(|_, _| {
+                Err(GetPostThreadError::NotFoundError(NotFoundError(
+                    "Post not found".to_string(),
+                )))
+            });
+
+        let resp = call(mock, "/post/1/thread").await;
+
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,2 @@
+mod interface;
+pub use interface::import_ng_words;
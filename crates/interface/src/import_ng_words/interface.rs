@@ -0,0 +1,503 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{
+    self, post,
+    web::{self, Data},
+    HttpMessage, HttpRequest, HttpResponse, Responder,
+};
+use app_service::import_ng_words::AppService as ImportNgWordsAppService;
+use domain_model::{
+    import_ng_words::{
+        err::ImportNgWordsError,
+        model::{ImportReport, ImportRow, ImportRowResult},
+    },
+    ng_word_policy::model::{PatternKind, Severity},
+    register_ng_word::model::UnvalidatedNgWord,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Deserialize, Serialize)]
+struct ImportNgWordsQueryDto {
+    /// 検証だけして登録しない
+    #[serde(default)]
+    dry_run: bool,
+}
+
+/// 取り込む1行。CSVの場合は見出し行の列名で対応付ける
+#[derive(Deserialize, Serialize)]
+struct ImportNgWordDto {
+    ng_word: String,
+    /// NGワードの書き方(literal, glob, regex)。省略時や空欄はliteral
+    #[serde(default)]
+    kind: Option<String>,
+    /// 一致した時の対応(mask, hold, reject)。省略時や空欄はmask
+    #[serde(default)]
+    severity: Option<String>,
+}
+impl From<ImportNgWordDto> for UnvalidatedNgWord {
+    fn from(dto: ImportNgWordDto) -> Self {
+        UnvalidatedNgWord {
+            ng_word: dto.ng_word,
+            kind: dto
+                .kind
+                .filter(|kind| !kind.is_empty())
+                .unwrap_or_else(|| PatternKind::default().as_str().to_string()),
+            severity: dto
+                .severity
+                .filter(|severity| !severity.is_empty())
+                .unwrap_or_else(|| Severity::default().as_str().to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ImportRowResultDto {
+    pub row: usize,
+    pub ng_word: String,
+    pub error: Option<String>,
+}
+impl From<ImportRowResult> for ImportRowResultDto {
+    fn from(row: ImportRowResult) -> Self {
+        Self {
+            row: row.row,
+            ng_word: row.ng_word,
+            error: row.error,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ImportReportDto {
+    pub dry_run: bool,
+    pub imported: bool,
+    pub total: usize,
+    pub invalid: usize,
+    pub rows: Vec<ImportRowResultDto>,
+}
+impl From<ImportReport> for ImportReportDto {
+    fn from(report: ImportReport) -> Self {
+        Self {
+            dry_run: report.dry_run,
+            imported: report.imported,
+            total: report.rows.len(),
+            invalid: report.rows.iter().filter(|row| row.error.is_some()).count(),
+            rows: report
+                .rows
+                .into_iter()
+                .map(ImportRowResultDto::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ImportNgWordsErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<ImportNgWordsError> for ImportNgWordsErrorDto {
+    fn from(err: ImportNgWordsError) -> Self {
+        match err {
+            ImportNgWordsError::ValidationError(err) => ImportNgWordsErrorDto {
+                error_code: 400,
+                error_message: err.0,
+            },
+            ImportNgWordsError::ServiceError(_) => ImportNgWordsErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+            ImportNgWordsError::PermissionError(err) => ImportNgWordsErrorDto {
+                error_code: 403,
+                error_message: err.0,
+            },
+        }
+    }
+}
+
+/// 見出し行付きのCSVを読み込む
+///
+/// 読み込めない行はその行だけを誤りにして、残りの行を読み込む
+fn parse_csv(body: &[u8]) -> Result<Vec<ImportRow>, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::Headers)
+        .from_reader(body);
+    // 見出し行が読めなければ列を対応付けられないため、全体を誤りにする
+    reader
+        .headers()
+        .map_err(|err| format!("Invalid CSV: {}", err))?;
+    Ok(reader
+        .deserialize::<ImportNgWordDto>()
+        .map(|row| match row {
+            Ok(dto) => ImportRow::Parsed(dto.into()),
+            Err(err) => ImportRow::Unreadable(format!("Invalid CSV: {}", err)),
+        })
+        .collect())
+}
+
+/// 行の配列のJSONを読み込む
+///
+/// 配列として読めなければ全体を誤りにする。項目の足りない行などはその行だけを誤りにする
+fn parse_json(body: &[u8]) -> Result<Vec<ImportRow>, String> {
+    let values: Vec<serde_json::Value> =
+        serde_json::from_slice(body).map_err(|err| format!("Invalid JSON: {}", err))?;
+    Ok(values
+        .into_iter()
+        .map(
+            |value| match serde_json::from_value::<ImportNgWordDto>(value) {
+                Ok(dto) => ImportRow::Parsed(dto.into()),
+                Err(err) => ImportRow::Unreadable(format!("Invalid JSON: {}", err)),
+            },
+        )
+        .collect())
+}
+
+#[post("/ng-words/import")]
+pub async fn import_ng_words(
+    user: AuthenticatedUser,
+    req: HttpRequest,
+    query: web::Query<ImportNgWordsQueryDto>,
+    body: web::Bytes,
+    service: Data<Arc<dyn ImportNgWordsAppService>>,
+) -> impl Responder {
+    let parsed = match req.content_type() {
+        "text/csv" => parse_csv(&body),
+        "application/json" => parse_json(&body),
+        _ => {
+            return HttpResponse::UnsupportedMediaType().json(ImportNgWordsErrorDto {
+                error_code: 415,
+                error_message: "Content-Type must be text/csv or application/json".to_string(),
+            })
+        }
+    };
+    let rows = match parsed {
+        Ok(rows) => rows,
+        Err(error_message) => {
+            return HttpResponse::BadRequest().json(ImportNgWordsErrorDto {
+                error_code: 400,
+                error_message,
+            })
+        }
+    };
+
+    let result = service.import_ng_words(rows, query.dry_run, &user.0).await;
+    match result {
+        // 誤りのある行があれば何も登録せず、行ごとの結果を返す
+        Ok(report) if report.has_errors() => {
+            HttpResponse::UnprocessableEntity().json(ImportReportDto::from(report))
+        }
+        Ok(report) => HttpResponse::Ok().json(ImportReportDto::from(report)),
+        Err(err) => match err {
+            ImportNgWordsError::ValidationError(_) => {
+                HttpResponse::BadRequest().json(ImportNgWordsErrorDto::from(err))
+            }
+            ImportNgWordsError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(ImportNgWordsErrorDto::from(err))
+            }
+            ImportNgWordsError::PermissionError(_) => {
+                HttpResponse::Forbidden().json(ImportNgWordsErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use domain_model::{
+        import_ng_words::err::PermissionError,
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn parsed(ng_word: &str, kind: &str, severity: &str) -> ImportRow {
+        ImportRow::Parsed(UnvalidatedNgWord {
+            ng_word: ng_word.to_string(),
+            kind: kind.to_string(),
+            severity: severity.to_string(),
+        })
+    }
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(moderator()));
+        Arc::new(service)
+    }
+
+    fn report(rows: Vec<ImportRowResult>, dry_run: bool, imported: bool) -> ImportReport {
+        ImportReport {
+            rows,
+            dry_run,
+            imported,
+        }
+    }
+
+    fn valid_row(row: usize, ng_word: &str) -> ImportRowResult {
+        ImportRowResult {
+            row,
+            ng_word: ng_word.to_string(),
+            error: None,
+        }
+    }
+
+    mock! {
+        pub ImportNgWordsAppService {}
+
+        #[async_trait]
+        impl ImportNgWordsAppService for ImportNgWordsAppService {
+            async fn import_ng_words(&self, rows: Vec<ImportRow>, dry_run: bool, user: &LoggedInUser) -> Result<ImportReport, ImportNgWordsError>;
+        }
+    }
+
+    async fn call(
+        service: MockImportNgWordsAppService,
+        uri: &str,
+        content_type: &str,
+        body: &'static str,
+    ) -> (http::StatusCode, web::Bytes) {
+        let arc_service: Arc<dyn ImportNgWordsAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(import_ng_words)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri(uri)
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .insert_header((header::CONTENT_TYPE, content_type))
+            .set_payload(body)
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        (resp.status(), test::read_body(resp).await)
+    }
+
+    #[actix_web::test]
+    async fn test_import_ng_words_csv() {
+        let mut service = MockImportNgWordsAppService::new();
+        service
+            .expect_import_ng_words()
+            .with(
+                eq(vec![
+                    parsed("bad", "literal", "mask"),
+                    parsed("b*d", "glob", "reject"),
+                ]),
+                eq(false),
+                eq(moderator()),
+            )
+            .times(1)
+            .returning(|_, _, _| {
+                Ok(report(
+                    vec![valid_row(1, "bad"), valid_row(2, "b*d")],
+                    false,
+                    true,
+                ))
+            });
+
+        let (status, body) = call(
+            service,
+            "/ng-words/import",
+            "text/csv; charset=utf-8",
+            "\u{feff}ng_word,kind,severity\nbad,,\nb*d,glob,reject\n",
+        )
+        .await;
+
+        assert_eq!(status, http::StatusCode::OK);
+        assert_eq!(
+            body,
+            web::Bytes::from_static(
+                b"{\"dry_run\":false,\"imported\":true,\"total\":2,\"invalid\":0,\"rows\":[{\"row\":1,\"ng_word\":\"bad\",\"error\":null},{\"row\":2,\"ng_word\":\"b*d\",\"error\":null}]}"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_import_ng_words_json_dry_run() {
+        let mut service = MockImportNgWordsAppService::new();
+        service
+            .expect_import_ng_words()
+            .with(
+                eq(vec![parsed("bad", "literal", "hold")]),
+                eq(true),
+                eq(moderator()),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(report(vec![valid_row(1, "bad")], true, false)));
+
+        let (status, _) = call(
+            service,
+            "/ng-words/import?dry_run=true",
+            "application/json",
+            r#"[{"ng_word":"bad","severity":"hold"}]"#,
+        )
+        .await;
+
+        assert_eq!(status, http::StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_import_ng_words_invalid_rows() {
+        let mut service = MockImportNgWordsAppService::new();
+        service
+            .expect_import_ng_words()
+            .with(
+                eq(vec![parsed("ba", "literal", "mask")]),
+                eq(false),
+                eq(moderator()),
+            )
+            .times(1)
+            .returning(|_, _, _| {
+                Ok(report(
+                    vec![ImportRowResult {
+                        row: 1,
+                        ng_word: "ba".to_string(),
+                        error: Some("NgWord must be at least 3 characters long".to_string()),
+                    }],
+                    false,
+                    false,
+                ))
+            });
+
+        let (status, body) = call(
+            service,
+            "/ng-words/import",
+            "application/json",
+            r#"[{"ng_word":"ba"}]"#,
+        )
+        .await;
+
+        assert_eq!(status, http::StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            body,
+            web::Bytes::from_static(
+                b"{\"dry_run\":false,\"imported\":false,\"total\":1,\"invalid\":1,\"rows\":[{\"row\":1,\"ng_word\":\"ba\",\"error\":\"NgWord must be at least 3 characters long\"}]}"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_import_ng_words_unreadable_csv_row() {
+        let mut service = MockImportNgWordsAppService::new();
+        service
+            .expect_import_ng_words()
+            .withf(|rows, dry_run, _| {
+                matches!(
+                    rows.as_slice(),
+                    [first, ImportRow::Unreadable(_), third]
+                        if *first == parsed("bad", "literal", "mask")
+                            && *third == parsed("evil", "literal", "mask")
+                ) && !dry_run
+            })
+            .times(1)
+            .returning(|_, _, _| {
+                Ok(report(
+                    vec![
+                        valid_row(1, "bad"),
+                        ImportRowResult {
+                            row: 2,
+                            ng_word: "".to_string(),
+                            error: Some("Invalid CSV".to_string()),
+                        },
+                        valid_row(3, "evil"),
+                    ],
+                    false,
+                    false,
+                ))
+            });
+
+        // 2行目は列が足りない
+        let (status, _) = call(
+            service,
+            "/ng-words/import",
+            "text/csv",
+            "ng_word,kind,severity\nbad,,\nworse\nevil,,\n",
+        )
+        .await;
+
+        assert_eq!(status, http::StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[actix_web::test]
+    async fn test_import_ng_words_malformed_json() {
+        let mut service = MockImportNgWordsAppService::new();
+        service.expect_import_ng_words().times(0);
+
+        let (status, _) = call(
+            service,
+            "/ng-words/import",
+            "application/json",
+            r#"{"ng_word":"bad"}"#,
+        )
+        .await;
+
+        assert_eq!(status, http::StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_import_ng_words_unsupported_media_type() {
+        let mut service = MockImportNgWordsAppService::new();
+        service.expect_import_ng_words().times(0);
+
+        let (status, _) = call(service, "/ng-words/import", "text/plain", "bad").await;
+
+        assert_eq!(status, http::StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    #[actix_web::test]
+    async fn test_import_ng_words_forbidden() {
+        let mut service = MockImportNgWordsAppService::new();
+        service
+            .expect_import_ng_words()
+            .times(1)
+            .returning(|_, _, _| {
+                Err(ImportNgWordsError::PermissionError(PermissionError(
+                    "Only moderators can import NG words".to_string(),
+                )))
+            });
+
+        let (status, _) = call(
+            service,
+            "/ng-words/import",
+            "application/json",
+            r#"[{"ng_word":"bad"}]"#,
+        )
+        .await;
+
+        assert_eq!(status, http::StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,201 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{self, get, web::Data, HttpResponse, Responder};
+use app_service::list_allow_words::AppService as ListAllowWordsAppService;
+use domain_model::list_allow_words::{err::ListAllowWordsError, model::AllowWord};
+use serde::Serialize;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AllowWordDto {
+    pub id: i32,
+    pub word: String,
+    pub created_at: String,
+}
+impl From<AllowWord> for AllowWordDto {
+    fn from(allow_word: AllowWord) -> Self {
+        Self {
+            id: allow_word.id,
+            word: allow_word.word,
+            created_at: allow_word
+                .created_at
+                .format("%Y-%m-%dT%H:%M:%SZ")
+                .to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ListAllowWordsErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<ListAllowWordsError> for ListAllowWordsErrorDto {
+    fn from(err: ListAllowWordsError) -> Self {
+        match err {
+            ListAllowWordsError::ServiceError(_) => ListAllowWordsErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+            ListAllowWordsError::PermissionError(err) => ListAllowWordsErrorDto {
+                error_code: 403,
+                error_message: err.0,
+            },
+        }
+    }
+}
+
+#[get("/allow-words")]
+pub async fn list_allow_words(
+    user: AuthenticatedUser,
+    service: Data<Arc<dyn ListAllowWordsAppService>>,
+) -> impl Responder {
+    match service.list_allow_words(&user.0).await {
+        Ok(allow_words) => {
+            let allow_words: Vec<AllowWordDto> =
+                allow_words.into_iter().map(AllowWordDto::from).collect();
+            HttpResponse::Ok().json(allow_words)
+        }
+        Err(err) => match err {
+            ListAllowWordsError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(ListAllowWordsErrorDto::from(err))
+            }
+            ListAllowWordsError::PermissionError(_) => {
+                HttpResponse::Forbidden().json(ListAllowWordsErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, web, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use chrono::NaiveDateTime;
+    use domain_model::{
+        list_allow_words::err::{PermissionError, ServiceError},
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(moderator()));
+        Arc::new(service)
+    }
+
+    mock! {
+        pub ListAllowWordsAppService {}
+
+        #[async_trait]
+        impl ListAllowWordsAppService for ListAllowWordsAppService {
+            async fn list_allow_words(&self, user: &LoggedInUser) -> Result<Vec<AllowWord>, ListAllowWordsError>;
+        }
+    }
+
+    async fn call(service: MockListAllowWordsAppService) -> actix_web::dev::ServiceResponse {
+        let arc_service: Arc<dyn ListAllowWordsAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(list_allow_words)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/allow-words")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_list_allow_words() {
+        let mut service = MockListAllowWordsAppService::new();
+        service
+            .expect_list_allow_words()
+            .with(eq(moderator()))
+            .times(1)
+            .returning(|_| {
+                Ok(vec![AllowWord {
+                    id: 1,
+                    word: "class".to_string(),
+                    created_at: NaiveDateTime::parse_from_str(
+                        "2021-01-01T00:00:00Z",
+                        "%Y-%m-%dT%H:%M:%SZ",
+                    )
+                    .unwrap(),
+                }])
+            });
+
+        let resp = call(service).await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"[{\"id\":1,\"word\":\"class\",\"created_at\":\"2021-01-01T00:00:00Z\"}]"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_list_allow_words_forbidden() {
+        let mut service = MockListAllowWordsAppService::new();
+        service.expect_list_allow_words().times(1).returning(|_| {
+            Err(ListAllowWordsError::PermissionError(PermissionError(
+                "Only moderators can list allow words".to_string(),
+            )))
+        });
+
+        assert_eq!(call(service).await.status(), http::StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_list_allow_words_service_error() {
+        let mut service = MockListAllowWordsAppService::new();
+        service.expect_list_allow_words().times(1).returning(|_| {
+            Err(ListAllowWordsError::ServiceError(ServiceError(
+                "Service Error".to_string(),
+            )))
+        });
+
+        assert_eq!(
+            call(service).await.status(),
+            http::StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{self, get, web::Data, HttpResponse, Responder};
+use app_service::list_mute_words::AppService as ListMuteWordsAppService;
+use domain_model::list_mute_words::{err::ListMuteWordsError, model::MuteWord};
+use serde::Serialize;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MuteWordDto {
+    pub id: i32,
+    pub word: String,
+    pub created_at: String,
+}
+impl From<MuteWord> for MuteWordDto {
+    fn from(mute_word: MuteWord) -> Self {
+        Self {
+            id: mute_word.id,
+            word: mute_word.word,
+            created_at: mute_word
+                .created_at
+                .format("%Y-%m-%dT%H:%M:%SZ")
+                .to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ListMuteWordsErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<ListMuteWordsError> for ListMuteWordsErrorDto {
+    fn from(err: ListMuteWordsError) -> Self {
+        match err {
+            ListMuteWordsError::ServiceError(_) => ListMuteWordsErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+        }
+    }
+}
+
+#[get("/mute-words")]
+pub async fn list_mute_words(
+    user: AuthenticatedUser,
+    service: Data<Arc<dyn ListMuteWordsAppService>>,
+) -> impl Responder {
+    match service.list_mute_words(&user.0).await {
+        Ok(mute_words) => {
+            let mute_words: Vec<MuteWordDto> =
+                mute_words.into_iter().map(MuteWordDto::from).collect();
+            HttpResponse::Ok().json(mute_words)
+        }
+        Err(err) => match err {
+            ListMuteWordsError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(ListMuteWordsErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, web, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use chrono::NaiveDateTime;
+    use domain_model::{
+        list_mute_words::err::ServiceError,
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn member() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role: Role::Member,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(member()));
+        Arc::new(service)
+    }
+
+    mock! {
+        pub ListMuteWordsAppService {}
+
+        #[async_trait]
+        impl ListMuteWordsAppService for ListMuteWordsAppService {
+            async fn list_mute_words(&self, user: &LoggedInUser) -> Result<Vec<MuteWord>, ListMuteWordsError>;
+        }
+    }
+
+    async fn call(service: MockListMuteWordsAppService) -> actix_web::dev::ServiceResponse {
+        let arc_service: Arc<dyn ListMuteWordsAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(list_mute_words)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/mute-words")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_list_mute_words() {
+        let mut service = MockListMuteWordsAppService::new();
+        service
+            .expect_list_mute_words()
+            .with(eq(member()))
+            .times(1)
+            .returning(|_| {
+                Ok(vec![MuteWord {
+                    id: 1,
+                    word: "spoiler".to_string(),
+                    created_at: NaiveDateTime::parse_from_str(
+                        "2021-01-01T00:00:00Z",
+                        "%Y-%m-%dT%H:%M:%SZ",
+                    )
+                    .unwrap(),
+                }])
+            });
+
+        let resp = call(service).await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"[{\"id\":1,\"word\":\"spoiler\",\"created_at\":\"2021-01-01T00:00:00Z\"}]"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_list_mute_words_service_error() {
+        let mut service = MockListMuteWordsAppService::new();
+        service.expect_list_mute_words().times(1).returning(|_| {
+            Err(ListMuteWordsError::ServiceError(ServiceError(
+                "Service Error".to_string(),
+            )))
+        });
+
+        assert_eq!(
+            call(service).await.status(),
+            http::StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
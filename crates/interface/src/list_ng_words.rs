@@ -0,0 +1,2 @@
+mod interface;
+pub use interface::list_ng_words;
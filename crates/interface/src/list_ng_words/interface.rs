@@ -0,0 +1,360 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{
+    self, get,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::list_ng_words::AppService as ListNgWordsAppService;
+use domain_model::list_ng_words::{
+    err::ListNgWordsError,
+    model::{NgWord, NgWordEditor, NgWordPage, UnvalidatedNgWordQuery},
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Deserialize, Serialize)]
+struct ListNgWordsQueryDto {
+    /// NGワードに含まれる文字列で絞り込む
+    #[serde(default)]
+    keyword: Option<String>,
+    /// 1始まりのページ番号。省略時は1
+    #[serde(default)]
+    page: Option<u64>,
+    /// 1ページの件数。省略時は20
+    #[serde(default)]
+    per_page: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NgWordEditorDto {
+    pub id: i32,
+    pub name: String,
+}
+impl From<NgWordEditor> for NgWordEditorDto {
+    fn from(editor: NgWordEditor) -> Self {
+        Self {
+            id: editor.id,
+            name: editor.name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NgWordDto {
+    pub id: i32,
+    pub word: String,
+    pub kind: String,
+    pub severity: String,
+    pub created_by: Option<NgWordEditorDto>,
+    pub updated_by: Option<NgWordEditorDto>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+impl From<NgWord> for NgWordDto {
+    fn from(ng_word: NgWord) -> Self {
+        Self {
+            id: ng_word.id,
+            word: ng_word.word,
+            kind: ng_word.kind.as_str().to_string(),
+            severity: ng_word.severity.as_str().to_string(),
+            created_by: ng_word.created_by.map(NgWordEditorDto::from),
+            updated_by: ng_word.updated_by.map(NgWordEditorDto::from),
+            created_at: ng_word.created_at.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            updated_at: ng_word.updated_at.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NgWordPageDto {
+    pub ng_words: Vec<NgWordDto>,
+    pub total: u64,
+    pub page: u64,
+    pub per_page: u64,
+}
+impl From<NgWordPage> for NgWordPageDto {
+    fn from(page: NgWordPage) -> Self {
+        Self {
+            ng_words: page.ng_words.into_iter().map(NgWordDto::from).collect(),
+            total: page.total,
+            page: page.page,
+            per_page: page.per_page,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ListNgWordsErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<ListNgWordsError> for ListNgWordsErrorDto {
+    fn from(err: ListNgWordsError) -> Self {
+        match err {
+            ListNgWordsError::ValidationError(err) => ListNgWordsErrorDto {
+                error_code: 400,
+                error_message: err.0,
+            },
+            ListNgWordsError::ServiceError(_) => ListNgWordsErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+            ListNgWordsError::PermissionError(err) => ListNgWordsErrorDto {
+                error_code: 403,
+                error_message: err.0,
+            },
+        }
+    }
+}
+
+#[get("/ng-words")]
+pub async fn list_ng_words(
+    user: AuthenticatedUser,
+    query: web::Query<ListNgWordsQueryDto>,
+    service: Data<Arc<dyn ListNgWordsAppService>>,
+) -> impl Responder {
+    let query = query.into_inner();
+    let unvalidated_query = UnvalidatedNgWordQuery {
+        keyword: query.keyword,
+        page: query.page,
+        per_page: query.per_page,
+    };
+    let result = service.list_ng_words(unvalidated_query, &user.0).await;
+    match result {
+        Ok(page) => HttpResponse::Ok().json(NgWordPageDto::from(page)),
+        Err(err) => match err {
+            ListNgWordsError::ValidationError(_) => {
+                HttpResponse::BadRequest().json(ListNgWordsErrorDto::from(err))
+            }
+            ListNgWordsError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(ListNgWordsErrorDto::from(err))
+            }
+            ListNgWordsError::PermissionError(_) => {
+                HttpResponse::Forbidden().json(ListNgWordsErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use chrono::NaiveDateTime;
+    use domain_model::{
+        list_ng_words::err::{PermissionError, ValidationError},
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+        ng_word_policy::model::{PatternKind, Severity},
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(moderator()));
+        Arc::new(service)
+    }
+
+    mock! {
+        pub ListNgWordsAppService {}
+
+        #[async_trait]
+        impl ListNgWordsAppService for ListNgWordsAppService {
+            async fn list_ng_words(&self, query: UnvalidatedNgWordQuery, user: &LoggedInUser) -> Result<NgWordPage, ListNgWordsError>;
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_list_ng_words_ok() {
+        let mut service = MockListNgWordsAppService::new();
+        service
+            .expect_list_ng_words()
+            .with(
+                eq(UnvalidatedNgWordQuery {
+                    keyword: Some("bad".to_string()),
+                    page: Some(2),
+                    per_page: Some(10),
+                }),
+                eq(moderator()),
+            )
+            .times(1)
+            .returning(|_, _| {
+                let datetime =
+                    NaiveDateTime::parse_from_str("2024-01-01 00:00:00", "%Y-%m-%d %H:%M:%S")
+                        .unwrap();
+                Ok(NgWordPage {
+                    ng_words: vec![NgWord {
+                        id: 3,
+                        word: "bad".to_string(),
+                        kind: PatternKind::Literal,
+                        severity: Severity::Reject,
+                        created_by: Some(NgWordEditor {
+                            id: 1,
+                            name: "user_name".to_string(),
+                        }),
+                        updated_by: None,
+                        created_at: datetime,
+                        updated_at: datetime,
+                    }],
+                    total: 11,
+                    page: 2,
+                    per_page: 10,
+                })
+            });
+
+        let arc_service: Arc<dyn ListNgWordsAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(list_ng_words)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/ng-words?keyword=bad&page=2&per_page=10")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"{\"ng_words\":[{\"id\":3,\"word\":\"bad\",\"kind\":\"literal\",\"severity\":\"reject\",\"created_by\":{\"id\":1,\"name\":\"user_name\"},\"updated_by\":null,\"created_at\":\"2024-01-01T00:00:00Z\",\"updated_at\":\"2024-01-01T00:00:00Z\"}],\"total\":11,\"page\":2,\"per_page\":10}"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_list_ng_words_validation_error() {
+        let mut service = MockListNgWordsAppService::new();
+        service
+            .expect_list_ng_words()
+            .with(
+                eq(UnvalidatedNgWordQuery {
+                    page: Some(0),
+                    ..Default::default()
+                }),
+                eq(moderator()),
+            )
+            .times(1)
+            .returning(|_, _| {
+                Err(ListNgWordsError::ValidationError(ValidationError(
+                    "Page must be at least 1".to_string(),
+                )))
+            });
+
+        let arc_service: Arc<dyn ListNgWordsAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(list_ng_words)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/ng-words?page=0")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_list_ng_words_unauthorized() {
+        let mut service = MockListNgWordsAppService::new();
+        service.expect_list_ng_words().times(0);
+
+        let arc_service: Arc<dyn ListNgWordsAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(list_ng_words)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/ng-words").to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn test_list_ng_words_forbidden() {
+        let mut service = MockListNgWordsAppService::new();
+        service
+            .expect_list_ng_words()
+            .with(eq(UnvalidatedNgWordQuery::default()), eq(moderator()))
+            .times(1)
+            .returning(|_, _| {
+                Err(ListNgWordsError::PermissionError(PermissionError(
+                    "Only moderators can list NG words".to_string(),
+                )))
+            });
+
+        let arc_service: Arc<dyn ListNgWordsAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(list_ng_words)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/ng-words")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"{\"error_code\":403,\"error_message\":\"Only moderators can list NG words\"}"
+            )
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use actix_web::{
+    self, get,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::list_post_revisions::AppService as ListPostRevisionsAppService;
+use domain_model::list_post_revisions::{err::ListPostRevisionsError, model::PostRevision};
+use serde::Serialize;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PostRevisionDto {
+    pub id: i32,
+    pub posted_datetime: String,
+    pub content: String,
+}
+impl From<PostRevision> for PostRevisionDto {
+    fn from(revision: PostRevision) -> Self {
+        Self {
+            id: revision.id,
+            posted_datetime: revision
+                .posted_datetime
+                .0
+                .format("%Y-%m-%dT%H:%M:%SZ")
+                .to_string(),
+            content: revision.content.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ListPostRevisionsErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<ListPostRevisionsError> for ListPostRevisionsErrorDto {
+    fn from(err: ListPostRevisionsError) -> Self {
+        match err {
+            ListPostRevisionsError::ServiceError(_) => ListPostRevisionsErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+            ListPostRevisionsError::NotFoundError(err) => ListPostRevisionsErrorDto {
+                error_code: 404,
+                error_message: err.0,
+            },
+        }
+    }
+}
+
+/// 公開済みの投稿の編集前の版を新しい順に返す
+#[get("/post/{id}/revisions")]
+pub async fn list_post_revisions(
+    id: web::Path<i32>,
+    service: Data<Arc<dyn ListPostRevisionsAppService>>,
+) -> impl Responder {
+    match service.list_post_revisions(id.into_inner()).await {
+        Ok(revisions) => {
+            let revisions: Vec<PostRevisionDto> =
+                revisions.into_iter().map(PostRevisionDto::from).collect();
+            HttpResponse::Ok().json(revisions)
+        }
+        Err(err) => match err {
+            ListPostRevisionsError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(ListPostRevisionsErrorDto::from(err))
+            }
+            ListPostRevisionsError::NotFoundError(_) => {
+                HttpResponse::NotFound().json(ListPostRevisionsErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, test, App};
+    use async_trait::async_trait;
+    use chrono::NaiveDateTime;
+    use domain_model::{
+        get_all_post::model::{Content, PostedDatetime},
+        list_post_revisions::err::{NotFoundError, ServiceError},
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub ListPostRevisionsAppService {}
+
+        #[async_trait]
+        impl ListPostRevisionsAppService for ListPostRevisionsAppService {
+            async fn list_post_revisions(&self, post_id: i32) -> Result<Vec<PostRevision>, ListPostRevisionsError>;
+        }
+    }
+
+    async fn call(service: MockListPostRevisionsAppService) -> actix_web::dev::ServiceResponse {
+        let arc_service: Arc<dyn ListPostRevisionsAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(list_post_revisions)
+                .app_data(web::Data::new(arc_service)),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/post/1/revisions")
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_list_post_revisions() {
+        let mut service = MockListPostRevisionsAppService::new();
+        service
+            .expect_list_post_revisions()
+            .with(eq(1))
+            .times(1)
+            .returning(|_| {
+                Ok(vec![PostRevision {
+                    id: 3,
+                    posted_datetime: PostedDatetime(
+                        NaiveDateTime::parse_from_str("2021-01-01T00:00:00Z", "%Y-%m-%dT%H:%M:%SZ")
+                            .unwrap(),
+                    ),
+                    content: Content("first".to_string()),
+                }])
+            });
+
+        let resp = call(service).await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"[{\"id\":3,\"posted_datetime\":\"2021-01-01T00:00:00Z\",\"content\":\"first\"}]"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_list_post_revisions_not_found() {
+        let mut service = MockListPostRevisionsAppService::new();
+        service
+            .expect_list_post_revisions()
+            .times(1)
+            .returning(|_| {
+                Err(ListPostRevisionsError::NotFoundError(NotFoundError(
+                    "Post not found".to_string(),
+                )))
+            });
+
+        assert_eq!(call(service).await.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_list_post_revisions_internal_server_error() {
+        let mut service = MockListPostRevisionsAppService::new();
+        service
+            .expect_list_post_revisions()
+            .times(1)
+            .returning(|_| {
+                Err(ListPostRevisionsError::ServiceError(ServiceError(
+                    "error".to_string(),
+                )))
+            });
+
+        assert_eq!(
+            call(service).await.status(),
+            http::StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
@@ -1,2 +1,4 @@
+mod extractor;
 mod interface;
-pub use interface::post_login;
+pub use extractor::{AuthenticatedUser, OptionalAuthenticatedUser};
+pub use interface::{post_login, post_logout, post_logout_all, LoginErrorDto};
@@ -0,0 +1,259 @@
+use super::interface::LoginErrorDto;
+use actix_web::{dev::Payload, http::header, web::Data, FromRequest, HttpRequest};
+use app_service::login::AppService as LoginAppService;
+use domain_model::login::{
+    err::{LoginError, ServiceError, SessionError},
+    model::{LoggedInUser, SessionToken},
+};
+use std::{future::Future, pin::Pin, sync::Arc};
+use tracing::error;
+
+const BEARER_PREFIX: &str = "Bearer ";
+
+/// `Authorization: Bearer <token>`ヘッダのセッションから解決したログイン中のユーザとそのトークン
+///
+/// ハンドラの引数に置くと、セッションが無効な場合は401を返しハンドラは呼ばれない
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthenticatedUser(pub LoggedInUser, pub SessionToken);
+
+impl FromRequest for AuthenticatedUser {
+    type Error = LoginErrorDto;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let service = req.app_data::<Data<Arc<dyn LoginAppService>>>().cloned();
+        let token = req
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix(BEARER_PREFIX))
+            .map(|token| SessionToken(token.trim().to_string()));
+
+        Box::pin(async move {
+            let Some(service) = service else {
+                error!("LoginAppService is not registered as app data");
+                return Err(LoginErrorDto::from(LoginError::from(ServiceError(
+                    "LoginAppService is not registered".to_string(),
+                ))));
+            };
+            let Some(token) = token else {
+                return Err(LoginErrorDto::from(LoginError::from(SessionError(
+                    "Missing session token".to_string(),
+                ))));
+            };
+            service
+                .authenticate(token.clone())
+                .await
+                .map(|user| AuthenticatedUser(user, token))
+                .map_err(LoginErrorDto::from)
+        })
+    }
+}
+
+/// ログインしていなくてもよいハンドラのための`AuthenticatedUser`
+///
+/// `Authorization`ヘッダが無い場合だけ`None`になる。ヘッダがあってセッションが無効な場合は401、
+/// セッションを確認できない場合は500を返しハンドラは呼ばれない
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionalAuthenticatedUser(pub Option<AuthenticatedUser>);
+
+impl FromRequest for OptionalAuthenticatedUser {
+    type Error = LoginErrorDto;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        if !req.headers().contains_key(header::AUTHORIZATION) {
+            return Box::pin(async { Ok(OptionalAuthenticatedUser(None)) });
+        }
+        let user = AuthenticatedUser::from_request(req, payload);
+        Box::pin(async move { user.await.map(|user| OptionalAuthenticatedUser(Some(user))) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{get, http, test, App, HttpResponse, Responder};
+    use async_trait::async_trait;
+    use domain_model::login::model::{Role, Session, UncheckedPassword, UncheckedUserName};
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    #[get("/me")]
+    async fn me(user: AuthenticatedUser) -> impl Responder {
+        HttpResponse::Ok().body(user.0.name)
+    }
+
+    #[get("/maybe")]
+    async fn maybe(user: OptionalAuthenticatedUser) -> impl Responder {
+        HttpResponse::Ok().body(user.0.map_or("anonymous".to_string(), |user| user.0.name))
+    }
+
+    #[actix_web::test]
+    async fn test_authenticated_user() {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .times(1)
+            .returning(|_| {
+                Ok(LoggedInUser {
+                    id: 1,
+                    name: "user_name".to_string(),
+                    role: Role::Member,
+                })
+            });
+        let arc_service: Arc<dyn LoginAppService> = Arc::new(service);
+
+        let app = test::init_service(App::new().service(me).app_data(Data::new(arc_service))).await;
+
+        let req = test::TestRequest::get()
+            .uri("/me")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            actix_web::web::Bytes::from_static(b"user_name")
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_authenticated_user_missing_token() {
+        let mut service = MockLoginAppService::new();
+        service.expect_authenticate().times(0);
+        let arc_service: Arc<dyn LoginAppService> = Arc::new(service);
+
+        let app = test::init_service(App::new().service(me).app_data(Data::new(arc_service))).await;
+
+        let req = test::TestRequest::get().uri("/me").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn test_authenticated_user_invalid_session() {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .times(1)
+            .returning(|_| {
+                Err(LoginError::from(SessionError(
+                    "Invalid or expired session".to_string(),
+                )))
+            });
+        let arc_service: Arc<dyn LoginAppService> = Arc::new(service);
+
+        let app = test::init_service(App::new().service(me).app_data(Data::new(arc_service))).await;
+
+        let req = test::TestRequest::get()
+            .uri("/me")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            test::read_body(resp).await,
+            actix_web::web::Bytes::from_static(
+                b"{\"error_code\":401,\"error_message\":\"Invalid or expired session\"}"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_optional_authenticated_user_missing_header() {
+        let mut service = MockLoginAppService::new();
+        service.expect_authenticate().times(0);
+        let arc_service: Arc<dyn LoginAppService> = Arc::new(service);
+
+        let app =
+            test::init_service(App::new().service(maybe).app_data(Data::new(arc_service))).await;
+
+        let req = test::TestRequest::get().uri("/maybe").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            actix_web::web::Bytes::from_static(b"anonymous")
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_optional_authenticated_user_invalid_session() {
+        let mut service = MockLoginAppService::new();
+        service.expect_authenticate().times(1).returning(|_| {
+            Err(LoginError::from(SessionError(
+                "Invalid or expired session".to_string(),
+            )))
+        });
+        let arc_service: Arc<dyn LoginAppService> = Arc::new(service);
+
+        let app =
+            test::init_service(App::new().service(maybe).app_data(Data::new(arc_service))).await;
+
+        let req = test::TestRequest::get()
+            .uri("/maybe")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn test_optional_authenticated_user_not_bearer() {
+        let mut service = MockLoginAppService::new();
+        service.expect_authenticate().times(0);
+        let arc_service: Arc<dyn LoginAppService> = Arc::new(service);
+
+        let app =
+            test::init_service(App::new().service(maybe).app_data(Data::new(arc_service))).await;
+
+        let req = test::TestRequest::get()
+            .uri("/maybe")
+            .insert_header((header::AUTHORIZATION, "Basic dXNlcjpwYXNz"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn test_optional_authenticated_user_service_error() {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .times(1)
+            .returning(|_| Err(LoginError::from(ServiceError("error".to_string()))));
+        let arc_service: Arc<dyn LoginAppService> = Arc::new(service);
+
+        let app =
+            test::init_service(App::new().service(maybe).app_data(Data::new(arc_service))).await;
+
+        let req = test::TestRequest::get()
+            .uri("/maybe")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -1,16 +1,37 @@
+use super::extractor::AuthenticatedUser;
 use actix_web::{
-    self, post,
+    self,
+    http::StatusCode,
+    post,
     web::{self, Data},
-    HttpResponse, Responder,
+    HttpResponse, Responder, ResponseError,
 };
 use app_service::login::AppService as LoginAppService;
-use domain_model::login::{err::LoginError, model::UncheckedUserName};
+use domain_model::login::{
+    err::LoginError,
+    model::{Session, UncheckedPassword, UncheckedUserName},
+};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::{fmt, sync::Arc};
 
 #[derive(Deserialize, Serialize)]
 struct LoginDto {
     user_name: String,
+    password: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SessionDto {
+    pub token: String,
+    pub expires_at: String,
+}
+impl From<Session> for SessionDto {
+    fn from(session: Session) -> Self {
+        Self {
+            token: session.token.0,
+            expires_at: session.expires_at.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        }
+    }
 }
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct LoginErrorDto {
@@ -28,9 +49,30 @@ impl From<LoginError> for LoginErrorDto {
                 error_code: 500,
                 error_message: "Internal Server Error".to_string(),
             },
+            LoginError::SessionError(err) => LoginErrorDto {
+                error_code: 401,
+                error_message: err.0,
+            },
+            LoginError::CredentialError(err) => LoginErrorDto {
+                error_code: 401,
+                error_message: err.0,
+            },
         }
     }
 }
+impl fmt::Display for LoginErrorDto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.error_code, self.error_message)
+    }
+}
+impl ResponseError for LoginErrorDto {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.error_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(self)
+    }
+}
 
 #[post("/login")]
 pub async fn post_login(
@@ -38,26 +80,47 @@ pub async fn post_login(
     service: Data<Arc<dyn LoginAppService>>,
 ) -> impl Responder {
     let unchecked_user_name = UncheckedUserName(user_name.user_name.clone());
-    let result = service.login(unchecked_user_name);
+    let unchecked_password = UncheckedPassword(user_name.password.clone());
+    let result = service.login(unchecked_user_name, unchecked_password);
     match result.await {
+        Ok(session) => HttpResponse::Ok().json(SessionDto::from(session)),
+        Err(err) => LoginErrorDto::from(err).error_response(),
+    }
+}
+
+#[post("/logout")]
+pub async fn post_logout(
+    user: AuthenticatedUser,
+    service: Data<Arc<dyn LoginAppService>>,
+) -> impl Responder {
+    match service.logout(user.1).await {
         Ok(_) => HttpResponse::Ok().finish(),
-        Err(err) => match err {
-            LoginError::ValidationError(_) => {
-                HttpResponse::BadRequest().json(LoginErrorDto::from(err))
-            }
-            LoginError::ServiceError(_) => {
-                HttpResponse::InternalServerError().json(LoginErrorDto::from(err))
-            }
-        },
+        Err(err) => LoginErrorDto::from(err).error_response(),
+    }
+}
+
+/// ログイン中のユーザの全セッションを失効させる(パスワード変更後など)
+#[post("/logout/all")]
+pub async fn post_logout_all(
+    user: AuthenticatedUser,
+    service: Data<Arc<dyn LoginAppService>>,
+) -> impl Responder {
+    match service.revoke_all_sessions(&user.0).await {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(err) => LoginErrorDto::from(err).error_response(),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use actix_web::{http, test, App};
+    use actix_web::{http, http::header, test, App};
     use async_trait::async_trait;
-    use domain_model::login::err::{LoginError, ServiceError, ValidationError};
+    use chrono::{TimeZone, Utc};
+    use domain_model::login::{
+        err::{CredentialError, LoginError, ServiceError, ValidationError},
+        model::{LoggedInUser, Role, SessionToken},
+    };
     use mockall::{predicate::*, *};
 
     mock! {
@@ -65,7 +128,11 @@ mod tests {
 
         #[async_trait]
         impl LoginAppService for LoginAppService {
-            async fn login(&self, user_name: UncheckedUserName) -> Result<(), LoginError>;
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
         }
     }
 
@@ -74,13 +141,21 @@ mod tests {
         let mut service = MockLoginAppService::new();
         service
             .expect_login()
-            .with(eq(UncheckedUserName("user_name".to_string())))
+            .with(
+                eq(UncheckedUserName("user_name".to_string())),
+                eq(UncheckedPassword("password1".to_string())),
+            )
             .times(1)
-            .returning(|_| Ok(()));
+            .returning(|_, _| {
+                Ok(Session {
+                    token: SessionToken("token".to_string()),
+                    expires_at: Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap(),
+                })
+            });
 
         let arc_service: Arc<dyn LoginAppService> = Arc::new(service);
 
-        let mut app = test::init_service(
+        let app = test::init_service(
             App::new()
                 .service(post_login)
                 .app_data(web::Data::new(arc_service)),
@@ -91,12 +166,19 @@ mod tests {
             .uri("/login")
             .set_json(&LoginDto {
                 user_name: "user_name".to_string(),
+                password: "password1".to_string(),
             })
             .to_request();
 
-        let resp = test::call_service(&mut app, req).await;
+        let resp = test::call_service(&app, req).await;
 
         assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"{\"token\":\"token\",\"expires_at\":\"2021-01-01T00:00:00Z\"}"
+            )
+        );
     }
 
     #[actix_web::test]
@@ -104,9 +186,12 @@ mod tests {
         let mut service = MockLoginAppService::new();
         service
             .expect_login()
-            .with(eq(UncheckedUserName("user_name".to_string())))
+            .with(
+                eq(UncheckedUserName("user_name".to_string())),
+                eq(UncheckedPassword("password1".to_string())),
+            )
             .times(1)
-            .returning(|_| {
+            .returning(|_, _| {
                 Err(LoginError::ValidationError(ValidationError(
                     "Validation Error".to_string(),
                 )))
@@ -114,7 +199,7 @@ mod tests {
 
         let arc_service: Arc<dyn LoginAppService> = Arc::new(service);
 
-        let mut app = test::init_service(
+        let app = test::init_service(
             App::new()
                 .service(post_login)
                 .app_data(web::Data::new(arc_service)),
@@ -125,10 +210,11 @@ mod tests {
             .uri("/login")
             .set_json(&LoginDto {
                 user_name: "user_name".to_string(),
+                password: "password1".to_string(),
             })
             .to_request();
 
-        let resp = test::call_service(&mut app, req).await;
+        let resp = test::call_service(&app, req).await;
 
         assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
     }
@@ -138,9 +224,12 @@ mod tests {
         let mut service = MockLoginAppService::new();
         service
             .expect_login()
-            .with(eq(UncheckedUserName("user_name".to_string())))
+            .with(
+                eq(UncheckedUserName("user_name".to_string())),
+                eq(UncheckedPassword("password1".to_string())),
+            )
             .times(1)
-            .returning(|_| {
+            .returning(|_, _| {
                 Err(LoginError::ServiceError(ServiceError(
                     "Service Error".to_string(),
                 )))
@@ -148,7 +237,7 @@ mod tests {
 
         let arc_service: Arc<dyn LoginAppService> = Arc::new(service);
 
-        let mut app = test::init_service(
+        let app = test::init_service(
             App::new()
                 .service(post_login)
                 .app_data(web::Data::new(arc_service)),
@@ -159,10 +248,182 @@ mod tests {
             .uri("/login")
             .set_json(&LoginDto {
                 user_name: "user_name".to_string(),
+                password: "password1".to_string(),
             })
             .to_request();
 
-        let resp = test::call_service(&mut app, req).await;
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[actix_web::test]
+    async fn test_post_login_credential_error() {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_login()
+            .with(
+                eq(UncheckedUserName("user_name".to_string())),
+                eq(UncheckedPassword("password1".to_string())),
+            )
+            .times(1)
+            .returning(|_, _| {
+                Err(LoginError::CredentialError(CredentialError(
+                    "Invalid user name or password".to_string(),
+                )))
+            });
+
+        let arc_service: Arc<dyn LoginAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(post_login)
+                .app_data(web::Data::new(arc_service)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/login")
+            .set_json(&LoginDto {
+                user_name: "user_name".to_string(),
+                password: "password1".to_string(),
+            })
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"{\"error_code\":401,\"error_message\":\"Invalid user name or password\"}"
+            )
+        );
+    }
+
+    fn authenticated_service() -> MockLoginAppService {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| {
+                Ok(LoggedInUser {
+                    id: 1,
+                    name: "user_name".to_string(),
+                    role: Role::Member,
+                })
+            });
+        service
+    }
+
+    #[actix_web::test]
+    async fn test_post_logout_ok() {
+        let mut service = authenticated_service();
+        service
+            .expect_logout()
+            .with(eq(SessionToken("token".to_string())))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let arc_service: Arc<dyn LoginAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(post_logout)
+                .app_data(web::Data::new(arc_service)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/logout")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_post_logout_unauthorized() {
+        let mut service = MockLoginAppService::new();
+        service.expect_logout().times(0);
+
+        let arc_service: Arc<dyn LoginAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(post_logout)
+                .app_data(web::Data::new(arc_service)),
+        )
+        .await;
+
+        let req = test::TestRequest::post().uri("/logout").to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn test_post_logout_all_ok() {
+        let mut service = authenticated_service();
+        service
+            .expect_revoke_all_sessions()
+            .with(eq(LoggedInUser {
+                id: 1,
+                name: "user_name".to_string(),
+                role: Role::Member,
+            }))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let arc_service: Arc<dyn LoginAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(post_logout_all)
+                .app_data(web::Data::new(arc_service)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/logout/all")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_post_logout_all_service_error() {
+        let mut service = authenticated_service();
+        service
+            .expect_revoke_all_sessions()
+            .times(1)
+            .returning(|_| {
+                Err(LoginError::ServiceError(ServiceError(
+                    "Service Error".to_string(),
+                )))
+            });
+
+        let arc_service: Arc<dyn LoginAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(post_logout_all)
+                .app_data(web::Data::new(arc_service)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/logout/all")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
 
         assert_eq!(resp.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
     }
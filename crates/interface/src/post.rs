@@ -1,2 +1,2 @@
 mod interface;
-pub use interface::post_post;
+pub use interface::{post_post, post_reply};
@@ -1,3 +1,4 @@
+use crate::login::AuthenticatedUser;
 use actix_web::{
     self, post,
     web::{self, Data},
@@ -6,14 +7,13 @@ use actix_web::{
 use app_service::post::AppService as PostAppService;
 use domain_model::post::{
     err::PostError,
-    model::{UnvalidatedPost, UnvalidatedUserName},
+    model::{PostStatus, UnvalidatedPost, UnvalidatedUserName},
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 #[derive(Deserialize, Serialize)]
 struct PostDto {
-    user_name: String,
     content: String,
 }
 
@@ -33,30 +33,61 @@ impl From<PostError> for PostErrorDto {
                 error_code: 500,
                 error_message: "Internal Server Error".to_string(),
             },
+            PostError::NgWordError(err) => PostErrorDto {
+                error_code: 400,
+                error_message: err.0,
+            },
+            PostError::NotFoundError(err) => PostErrorDto {
+                error_code: 404,
+                error_message: err.0,
+            },
         }
     }
 }
 
 #[post("/post")]
 pub async fn post_post(
+    user: AuthenticatedUser,
+    post: web::Json<PostDto>,
+    service: Data<Arc<dyn PostAppService>>,
+) -> impl Responder {
+    let user_name = UnvalidatedUserName(user.0.name);
+    let unvalidated_post = UnvalidatedPost {
+        user_name,
+        content: post.content.clone(),
+    };
+    post_response(service.post(unvalidated_post).await)
+}
+
+#[post("/post/{id}/replies")]
+pub async fn post_reply(
+    user: AuthenticatedUser,
+    id: web::Path<i32>,
     post: web::Json<PostDto>,
     service: Data<Arc<dyn PostAppService>>,
 ) -> impl Responder {
-    let user_name = UnvalidatedUserName(post.user_name.clone());
+    let user_name = UnvalidatedUserName(user.0.name);
     let unvalidated_post = UnvalidatedPost {
         user_name,
         content: post.content.clone(),
     };
-    let result = service.post(unvalidated_post);
-    match result.await {
-        Ok(_) => HttpResponse::Ok().finish(),
+    post_response(service.reply(id.into_inner(), unvalidated_post).await)
+}
+
+/// 投稿と返信で同じ応答を返す
+fn post_response(result: Result<PostStatus, PostError>) -> HttpResponse {
+    match result {
+        Ok(PostStatus::Published) => HttpResponse::Ok().finish(),
+        // 保存はしたがモデレーション待ちで公開されていない
+        Ok(PostStatus::Held) => HttpResponse::Accepted().finish(),
         Err(err) => match err {
-            PostError::ValidationError(_) => {
+            PostError::ValidationError(_) | PostError::NgWordError(_) => {
                 HttpResponse::BadRequest().json(PostErrorDto::from(err))
             }
             PostError::ServiceError(_) => {
                 HttpResponse::InternalServerError().json(PostErrorDto::from(err))
             }
+            PostError::NotFoundError(_) => HttpResponse::NotFound().json(PostErrorDto::from(err)),
         },
     }
 }
@@ -64,11 +95,47 @@ pub async fn post_post(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use actix_web::{http, test, App};
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
     use async_trait::async_trait;
-    use domain_model::post::err::{PostError, ServiceError, ValidationError};
+    use domain_model::{
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+        post::err::{NgWordError, NotFoundError, PostError, ServiceError, ValidationError},
+    };
     use mockall::{predicate::*, *};
 
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| {
+                Ok(LoggedInUser {
+                    id: 1,
+                    name: "user_name".to_string(),
+                    role: Role::Member,
+                })
+            });
+        Arc::new(service)
+    }
+
     mock! {
         pub PostAppService {}
         #[async_trait]
@@ -76,7 +143,12 @@ mod tests {
             async fn post(
                 &self,
                 post: UnvalidatedPost,
-            ) -> Result<(), PostError>;
+            ) -> Result<PostStatus, PostError>;
+            async fn reply(
+                &self,
+                parent_post_id: i32,
+                post: UnvalidatedPost,
+            ) -> Result<PostStatus, PostError>;
         }
     }
 
@@ -90,24 +162,25 @@ mod tests {
                 content: "content".to_string(),
             }))
             .times(1)
-            .returning(|_| Ok(()));
+            .returning(|_| Ok(PostStatus::Published));
         let arc_service: Arc<dyn PostAppService> = Arc::new(mock_service);
 
-        let mut app = test::init_service(
+        let app = test::init_service(
             App::new()
                 .service(post_post)
-                .app_data(web::Data::new(arc_service)),
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
         )
         .await;
 
         let req = test::TestRequest::post()
             .uri("/post")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
             .set_json(&PostDto {
-                user_name: "user_name".to_string(),
                 content: "content".to_string(),
             })
             .to_request();
-        let resp = test::call_service(&mut app, req).await;
+        let resp = test::call_service(&app, req).await;
 
         assert_eq!(resp.status(), http::StatusCode::OK);
     }
@@ -130,25 +203,102 @@ mod tests {
 
         let arc_service: Arc<dyn PostAppService> = Arc::new(mock_service);
 
-        let mut app = test::init_service(
+        let app = test::init_service(
             App::new()
                 .service(post_post)
-                .app_data(web::Data::new(arc_service)),
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
         )
         .await;
 
         let req = test::TestRequest::post()
             .uri("/post")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
             .set_json(&PostDto {
-                user_name: "user_name".to_string(),
                 content: "content".to_string(),
             })
             .to_request();
-        let resp = test::call_service(&mut app, req).await;
+        let resp = test::call_service(&app, req).await;
 
         assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
     }
 
+    #[actix_web::test]
+    async fn test_post_post_held() {
+        let mut mock_service = MockPostAppService::new();
+        mock_service
+            .expect_post()
+            .with(eq(UnvalidatedPost {
+                user_name: UnvalidatedUserName("user_name".to_string()),
+                content: "content".to_string(),
+            }))
+            .times(1)
+            .returning(|_| Ok(PostStatus::Held));
+        let arc_service: Arc<dyn PostAppService> = Arc::new(mock_service);
+
+        let app = test::init_service(
+            App::new()
+                .service(post_post)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/post")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .set_json(&PostDto {
+                content: "content".to_string(),
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::ACCEPTED);
+    }
+
+    #[actix_web::test]
+    async fn test_post_post_ng_word_error() {
+        let mut mock_service = MockPostAppService::new();
+        mock_service
+            .expect_post()
+            .with(eq(UnvalidatedPost {
+                user_name: UnvalidatedUserName("user_name".to_string()),
+                content: "content".to_string(),
+            }))
+            .times(1)
+            .returning(|_| {
+                Err(PostError::NgWordError(NgWordError(
+                    "Content contains a rejected NG word (rule 1): content".to_string(),
+                )))
+            });
+        let arc_service: Arc<dyn PostAppService> = Arc::new(mock_service);
+
+        let app = test::init_service(
+            App::new()
+                .service(post_post)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/post")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .set_json(&PostDto {
+                content: "content".to_string(),
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"{\"error_code\":400,\"error_message\":\"Content contains a rejected NG word (rule 1): content\"}"
+            )
+        );
+    }
+
     #[actix_web::test]
     async fn test_post_post_service_error() {
         let mut mock_service = MockPostAppService::new();
@@ -166,22 +316,114 @@ mod tests {
             });
         let arc_service: Arc<dyn PostAppService> = Arc::new(mock_service);
 
-        let mut app = test::init_service(
+        let app = test::init_service(
             App::new()
                 .service(post_post)
-                .app_data(web::Data::new(arc_service)),
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
         )
         .await;
 
         let req = test::TestRequest::post()
             .uri("/post")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
             .set_json(&PostDto {
-                user_name: "user_name".to_string(),
                 content: "content".to_string(),
             })
             .to_request();
-        let resp = test::call_service(&mut app, req).await;
+        let resp = test::call_service(&app, req).await;
 
         assert_eq!(resp.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
     }
+
+    #[actix_web::test]
+    async fn test_post_post_unauthorized() {
+        let mut mock_service = MockPostAppService::new();
+        mock_service.expect_post().times(0);
+        let arc_service: Arc<dyn PostAppService> = Arc::new(mock_service);
+
+        let app = test::init_service(
+            App::new()
+                .service(post_post)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/post")
+            .set_json(&PostDto {
+                content: "content".to_string(),
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn test_post_reply_ok() {
+        let mut mock_service = MockPostAppService::new();
+        mock_service
+            .expect_reply()
+            .with(
+                eq(10),
+                eq(UnvalidatedPost {
+                    user_name: UnvalidatedUserName("user_name".to_string()),
+                    content: "content".to_string(),
+                }),
+            )
+            .times(1)
+            .returning(|_, _| Ok(PostStatus::Published));
+        let arc_service: Arc<dyn PostAppService> = Arc::new(mock_service);
+
+        let app = test::init_service(
+            App::new()
+                .service(post_reply)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/post/10/replies")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .set_json(&PostDto {
+                content: "content".to_string(),
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_post_reply_not_found() {
+        let mut mock_service = MockPostAppService::new();
+        mock_service.expect_reply().times(1).returning(|_, _| {
+            Err(PostError::NotFoundError(NotFoundError(
+                "Post not found".to_string(),
+            )))
+        });
+        let arc_service: Arc<dyn PostAppService> = Arc::new(mock_service);
+
+        let app = test::init_service(
+            App::new()
+                .service(post_reply)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/post/10/replies")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .set_json(&PostDto {
+                content: "content".to_string(),
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+    }
 }
@@ -0,0 +1,195 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{
+    self, post,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::register_allow_word::AppService as RegisterAllowWordAppService;
+use domain_model::register_allow_word::{err::RegisterAllowWordError, model::UnvalidatedAllowWord};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Deserialize, Serialize)]
+struct RegisterAllowWordDto {
+    allow_word: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RegisterAllowWordErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<RegisterAllowWordError> for RegisterAllowWordErrorDto {
+    fn from(err: RegisterAllowWordError) -> Self {
+        match err {
+            RegisterAllowWordError::ValidationError(err) => RegisterAllowWordErrorDto {
+                error_code: 400,
+                error_message: err.0,
+            },
+            RegisterAllowWordError::ServiceError(_) => RegisterAllowWordErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+            RegisterAllowWordError::PermissionError(err) => RegisterAllowWordErrorDto {
+                error_code: 403,
+                error_message: err.0,
+            },
+        }
+    }
+}
+
+#[post("/allow-words")]
+pub async fn register_allow_word(
+    user: AuthenticatedUser,
+    allow_word: web::Json<RegisterAllowWordDto>,
+    service: Data<Arc<dyn RegisterAllowWordAppService>>,
+) -> impl Responder {
+    let unvalidated_allow_word = UnvalidatedAllowWord {
+        allow_word: allow_word.into_inner().allow_word,
+    };
+    let result = service
+        .register_allow_word(unvalidated_allow_word, &user.0)
+        .await;
+    match result {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(err) => match err {
+            RegisterAllowWordError::ValidationError(_) => {
+                HttpResponse::BadRequest().json(RegisterAllowWordErrorDto::from(err))
+            }
+            RegisterAllowWordError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(RegisterAllowWordErrorDto::from(err))
+            }
+            RegisterAllowWordError::PermissionError(_) => {
+                HttpResponse::Forbidden().json(RegisterAllowWordErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use domain_model::{
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+        register_allow_word::err::{PermissionError, ValidationError},
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn unvalidated(allow_word: &str) -> UnvalidatedAllowWord {
+        UnvalidatedAllowWord {
+            allow_word: allow_word.to_string(),
+        }
+    }
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(moderator()));
+        Arc::new(service)
+    }
+
+    mock! {
+        pub RegisterAllowWordAppService {}
+
+        #[async_trait]
+        impl RegisterAllowWordAppService for RegisterAllowWordAppService {
+            async fn register_allow_word(&self, allow_word: UnvalidatedAllowWord, user: &LoggedInUser) -> Result<(), RegisterAllowWordError>;
+        }
+    }
+
+    async fn call(service: MockRegisterAllowWordAppService, allow_word: &str) -> http::StatusCode {
+        let arc_service: Arc<dyn RegisterAllowWordAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(register_allow_word)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/allow-words")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .set_json(RegisterAllowWordDto {
+                allow_word: allow_word.to_string(),
+            })
+            .to_request();
+
+        test::call_service(&app, req).await.status()
+    }
+
+    #[actix_web::test]
+    async fn test_register_allow_word_ok() {
+        let mut service = MockRegisterAllowWordAppService::new();
+        service
+            .expect_register_allow_word()
+            .with(eq(unvalidated("class")), eq(moderator()))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        assert_eq!(call(service, "class").await, http::StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_register_allow_word_validation_error() {
+        let mut service = MockRegisterAllowWordAppService::new();
+        service
+            .expect_register_allow_word()
+            .with(eq(unvalidated("cl")), eq(moderator()))
+            .times(1)
+            .returning(|_, _| {
+                Err(RegisterAllowWordError::ValidationError(ValidationError(
+                    "AllowWord must be at least 3 characters long".to_string(),
+                )))
+            });
+
+        assert_eq!(call(service, "cl").await, http::StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_register_allow_word_forbidden() {
+        let mut service = MockRegisterAllowWordAppService::new();
+        service
+            .expect_register_allow_word()
+            .times(1)
+            .returning(|_, _| {
+                Err(RegisterAllowWordError::PermissionError(PermissionError(
+                    "Only moderators can register allow words".to_string(),
+                )))
+            });
+
+        assert_eq!(call(service, "class").await, http::StatusCode::FORBIDDEN);
+    }
+}
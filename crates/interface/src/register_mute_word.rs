@@ -0,0 +1,2 @@
+mod interface;
+pub use interface::register_mute_word;
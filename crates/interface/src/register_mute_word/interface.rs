@@ -0,0 +1,191 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{
+    self, post,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::register_mute_word::AppService as RegisterMuteWordAppService;
+use domain_model::register_mute_word::{err::RegisterMuteWordError, model::UnvalidatedMuteWord};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Deserialize, Serialize)]
+struct RegisterMuteWordDto {
+    mute_word: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RegisterMuteWordErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<RegisterMuteWordError> for RegisterMuteWordErrorDto {
+    fn from(err: RegisterMuteWordError) -> Self {
+        match err {
+            RegisterMuteWordError::ValidationError(err) => RegisterMuteWordErrorDto {
+                error_code: 400,
+                error_message: err.0,
+            },
+            RegisterMuteWordError::ServiceError(_) => RegisterMuteWordErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+        }
+    }
+}
+
+#[post("/mute-words")]
+pub async fn register_mute_word(
+    user: AuthenticatedUser,
+    mute_word: web::Json<RegisterMuteWordDto>,
+    service: Data<Arc<dyn RegisterMuteWordAppService>>,
+) -> impl Responder {
+    let unvalidated_mute_word = UnvalidatedMuteWord {
+        mute_word: mute_word.into_inner().mute_word,
+    };
+    let result = service
+        .register_mute_word(unvalidated_mute_word, &user.0)
+        .await;
+    match result {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(err) => match err {
+            RegisterMuteWordError::ValidationError(_) => {
+                HttpResponse::BadRequest().json(RegisterMuteWordErrorDto::from(err))
+            }
+            RegisterMuteWordError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(RegisterMuteWordErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use domain_model::{
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+        register_mute_word::err::{ServiceError, ValidationError},
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn unvalidated(mute_word: &str) -> UnvalidatedMuteWord {
+        UnvalidatedMuteWord {
+            mute_word: mute_word.to_string(),
+        }
+    }
+
+    fn member() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role: Role::Member,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(member()));
+        Arc::new(service)
+    }
+
+    mock! {
+        pub RegisterMuteWordAppService {}
+
+        #[async_trait]
+        impl RegisterMuteWordAppService for RegisterMuteWordAppService {
+            async fn register_mute_word(&self, mute_word: UnvalidatedMuteWord, user: &LoggedInUser) -> Result<(), RegisterMuteWordError>;
+        }
+    }
+
+    async fn call(service: MockRegisterMuteWordAppService, mute_word: &str) -> http::StatusCode {
+        let arc_service: Arc<dyn RegisterMuteWordAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(register_mute_word)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/mute-words")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .set_json(RegisterMuteWordDto {
+                mute_word: mute_word.to_string(),
+            })
+            .to_request();
+
+        test::call_service(&app, req).await.status()
+    }
+
+    #[actix_web::test]
+    async fn test_register_mute_word_ok() {
+        let mut service = MockRegisterMuteWordAppService::new();
+        service
+            .expect_register_mute_word()
+            .with(eq(unvalidated("spoiler")), eq(member()))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        assert_eq!(call(service, "spoiler").await, http::StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_register_mute_word_validation_error() {
+        let mut service = MockRegisterMuteWordAppService::new();
+        service
+            .expect_register_mute_word()
+            .with(eq(unvalidated("")), eq(member()))
+            .times(1)
+            .returning(|_, _| {
+                Err(RegisterMuteWordError::ValidationError(ValidationError(
+                    "MuteWord must be at least 1 characters long".to_string(),
+                )))
+            });
+
+        assert_eq!(call(service, "").await, http::StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_register_mute_word_service_error() {
+        let mut service = MockRegisterMuteWordAppService::new();
+        service
+            .expect_register_mute_word()
+            .times(1)
+            .returning(|_, _| {
+                Err(RegisterMuteWordError::ServiceError(ServiceError(
+                    "Service Error".to_string(),
+                )))
+            });
+
+        assert_eq!(
+            call(service, "spoiler").await,
+            http::StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
@@ -1,17 +1,26 @@
+use crate::login::AuthenticatedUser;
 use actix_web::{
     self, post,
     web::{self, Data},
     HttpResponse, Responder,
 };
 use app_service::register_ng_word::AppService as RegisterNgWordAppService;
-use domain_model::register_ng_word::err::RegisterNgWordError;
+use domain_model::{
+    ng_word_policy::model::{PatternKind, Severity},
+    register_ng_word::{err::RegisterNgWordError, model::UnvalidatedNgWord},
+};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 #[derive(Deserialize, Serialize)]
 struct RegisterNgWordDto {
     ng_word: String,
-    user_id: i32,
+    /// NGワードの書き方(literal, glob, regex)。省略時はliteral
+    #[serde(default)]
+    kind: Option<String>,
+    /// 一致した時の対応(mask, hold, reject)。省略時はmask
+    #[serde(default)]
+    severity: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -31,18 +40,31 @@ impl From<RegisterNgWordError> for RegisterNgWordErrorDto {
                 error_code: 500,
                 error_message: "Internal Server Error".to_string(),
             },
+            RegisterNgWordError::PermissionError(err) => RegisterNgWordErrorDto {
+                error_code: 403,
+                error_message: err.0,
+            },
         }
     }
 }
 
 #[post("/register-ng-word")]
 pub async fn register_ng_word(
+    user: AuthenticatedUser,
     ng_word: web::Json<RegisterNgWordDto>,
     service: Data<Arc<dyn RegisterNgWordAppService>>,
 ) -> impl Responder {
-    let result = service
-        .register_ng_word(&ng_word.ng_word, ng_word.user_id)
-        .await;
+    let ng_word = ng_word.into_inner();
+    let unvalidated_ng_word = UnvalidatedNgWord {
+        ng_word: ng_word.ng_word,
+        kind: ng_word
+            .kind
+            .unwrap_or_else(|| PatternKind::default().as_str().to_string()),
+        severity: ng_word
+            .severity
+            .unwrap_or_else(|| Severity::default().as_str().to_string()),
+    };
+    let result = service.register_ng_word(unvalidated_ng_word, &user.0).await;
     match result {
         Ok(_) => HttpResponse::Ok().finish(),
         Err(err) => match err {
@@ -52,6 +74,9 @@ pub async fn register_ng_word(
             RegisterNgWordError::ServiceError(_) => {
                 HttpResponse::InternalServerError().json(RegisterNgWordErrorDto::from(err))
             }
+            RegisterNgWordError::PermissionError(_) => {
+                HttpResponse::Forbidden().json(RegisterNgWordErrorDto::from(err))
+            }
         },
     }
 }
@@ -59,17 +84,63 @@ pub async fn register_ng_word(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use actix_web::{http, test, App};
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
     use async_trait::async_trait;
-    use domain_model::register_ng_word::err::{ServiceError, ValidationError};
+    use domain_model::{
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+        register_ng_word::err::{PermissionError, ServiceError, ValidationError},
+    };
     use mockall::{predicate::*, *};
 
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn unvalidated(ng_word: &str, kind: &str, severity: &str) -> UnvalidatedNgWord {
+        UnvalidatedNgWord {
+            ng_word: ng_word.to_string(),
+            kind: kind.to_string(),
+            severity: severity.to_string(),
+        }
+    }
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(moderator()));
+        Arc::new(service)
+    }
+
     mock! {
         pub RegisterNgWordAppService {}
 
         #[async_trait]
         impl RegisterNgWordAppService for RegisterNgWordAppService {
-            async fn register_ng_word(&self, ng_word: &str, user_id: i32) -> Result<(), RegisterNgWordError>;
+            async fn register_ng_word(&self, ng_word: UnvalidatedNgWord, user: &LoggedInUser) -> Result<(), RegisterNgWordError>;
         }
     }
 
@@ -78,28 +149,102 @@ mod tests {
         let mut service = MockRegisterNgWordAppService::new();
         service
             .expect_register_ng_word()
-            .with(eq("test"), eq(1))
+            .with(eq(unvalidated("test", "literal", "mask")), eq(moderator()))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let arc_service: Arc<dyn RegisterNgWordAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(register_ng_word)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/register-ng-word")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .set_json(&RegisterNgWordDto {
+                ng_word: "test".to_string(),
+                kind: None,
+                severity: None,
+            })
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_register_ng_word_severity() {
+        let mut service = MockRegisterNgWordAppService::new();
+        service
+            .expect_register_ng_word()
+            .with(
+                eq(unvalidated("test", "literal", "reject")),
+                eq(moderator()),
+            )
             .times(1)
             .returning(|_, _| Ok(()));
 
         let arc_service: Arc<dyn RegisterNgWordAppService> = Arc::new(service);
 
-        let mut app = test::init_service(
+        let app = test::init_service(
             App::new()
                 .service(register_ng_word)
-                .app_data(web::Data::new(arc_service)),
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
         )
         .await;
 
         let req = test::TestRequest::post()
             .uri("/register-ng-word")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
             .set_json(&RegisterNgWordDto {
                 ng_word: "test".to_string(),
-                user_id: 1,
+                kind: None,
+                severity: Some("reject".to_string()),
+            })
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_register_ng_word_kind() {
+        let mut service = MockRegisterNgWordAppService::new();
+        service
+            .expect_register_ng_word()
+            .with(eq(unvalidated("b*a*d", "glob", "mask")), eq(moderator()))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let arc_service: Arc<dyn RegisterNgWordAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(register_ng_word)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/register-ng-word")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .set_json(&RegisterNgWordDto {
+                ng_word: "b*a*d".to_string(),
+                kind: Some("glob".to_string()),
+                severity: None,
             })
             .to_request();
 
-        let resp = test::call_service(&mut app, req).await;
+        let resp = test::call_service(&app, req).await;
 
         assert_eq!(resp.status(), http::StatusCode::OK);
     }
@@ -109,7 +254,7 @@ mod tests {
         let mut service = MockRegisterNgWordAppService::new();
         service
             .expect_register_ng_word()
-            .with(eq("te"), eq(1))
+            .with(eq(unvalidated("te", "literal", "mask")), eq(moderator()))
             .times(1)
             .returning(|_, _| {
                 Err(RegisterNgWordError::ValidationError(ValidationError(
@@ -119,22 +264,25 @@ mod tests {
 
         let arc_service: Arc<dyn RegisterNgWordAppService> = Arc::new(service);
 
-        let mut app = test::init_service(
+        let app = test::init_service(
             App::new()
                 .service(register_ng_word)
-                .app_data(web::Data::new(arc_service)),
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
         )
         .await;
 
         let req = test::TestRequest::post()
             .uri("/register-ng-word")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
             .set_json(&RegisterNgWordDto {
                 ng_word: "te".to_string(),
-                user_id: 1,
+                kind: None,
+                severity: None,
             })
             .to_request();
 
-        let resp = test::call_service(&mut app, req).await;
+        let resp = test::call_service(&app, req).await;
 
         assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
     }
@@ -144,7 +292,7 @@ mod tests {
         let mut service = MockRegisterNgWordAppService::new();
         service
             .expect_register_ng_word()
-            .with(eq("test"), eq(1))
+            .with(eq(unvalidated("test", "literal", "mask")), eq(moderator()))
             .times(1)
             .returning(|_, _| {
                 Err(RegisterNgWordError::ServiceError(ServiceError(
@@ -154,23 +302,99 @@ mod tests {
 
         let arc_service: Arc<dyn RegisterNgWordAppService> = Arc::new(service);
 
-        let mut app = test::init_service(
+        let app = test::init_service(
             App::new()
                 .service(register_ng_word)
-                .app_data(web::Data::new(arc_service)),
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
         )
         .await;
 
         let req = test::TestRequest::post()
             .uri("/register-ng-word")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
             .set_json(&RegisterNgWordDto {
                 ng_word: "test".to_string(),
-                user_id: 1,
+                kind: None,
+                severity: None,
             })
             .to_request();
 
-        let resp = test::call_service(&mut app, req).await;
+        let resp = test::call_service(&app, req).await;
 
         assert_eq!(resp.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
     }
+
+    #[actix_web::test]
+    async fn test_register_ng_word_unauthorized() {
+        let mut service = MockRegisterNgWordAppService::new();
+        service.expect_register_ng_word().times(0);
+
+        let arc_service: Arc<dyn RegisterNgWordAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(register_ng_word)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/register-ng-word")
+            .set_json(&RegisterNgWordDto {
+                ng_word: "test".to_string(),
+                kind: None,
+                severity: None,
+            })
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn test_register_ng_word_forbidden() {
+        let mut service = MockRegisterNgWordAppService::new();
+        service
+            .expect_register_ng_word()
+            .with(eq(unvalidated("test", "literal", "mask")), eq(moderator()))
+            .times(1)
+            .returning(|_, _| {
+                Err(RegisterNgWordError::PermissionError(PermissionError(
+                    "Only moderators can register NG words".to_string(),
+                )))
+            });
+
+        let arc_service: Arc<dyn RegisterNgWordAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(register_ng_word)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/register-ng-word")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .set_json(&RegisterNgWordDto {
+                ng_word: "test".to_string(),
+                kind: None,
+                severity: None,
+            })
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"{\"error_code\":403,\"error_message\":\"Only moderators can register NG words\"}"
+            )
+        );
+    }
 }
@@ -0,0 +1,218 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{
+    self, put,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::register_reaction::AppService as RegisterReactionAppService;
+use domain_model::register_reaction::err::RegisterReactionError;
+use serde::Serialize;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RegisterReactionErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<RegisterReactionError> for RegisterReactionErrorDto {
+    fn from(err: RegisterReactionError) -> Self {
+        match err {
+            RegisterReactionError::ValidationError(err) => RegisterReactionErrorDto {
+                error_code: 400,
+                error_message: err.0,
+            },
+            RegisterReactionError::NotFoundError(err) => RegisterReactionErrorDto {
+                error_code: 404,
+                error_message: err.0,
+            },
+            RegisterReactionError::ServiceError(_) => RegisterReactionErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+        }
+    }
+}
+
+#[put("/post/{id}/reactions/{kind}")]
+pub async fn register_reaction(
+    user: AuthenticatedUser,
+    path: web::Path<(i32, String)>,
+    service: Data<Arc<dyn RegisterReactionAppService>>,
+) -> impl Responder {
+    let (id, kind) = path.into_inner();
+    let result = service.register_reaction(id, kind, &user.0).await;
+    match result {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(err) => match err {
+            RegisterReactionError::ValidationError(_) => {
+                HttpResponse::BadRequest().json(RegisterReactionErrorDto::from(err))
+            }
+            RegisterReactionError::NotFoundError(_) => {
+                HttpResponse::NotFound().json(RegisterReactionErrorDto::from(err))
+            }
+            RegisterReactionError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(RegisterReactionErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use domain_model::{
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+        register_reaction::err::{NotFoundError, ServiceError, ValidationError},
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn member() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role: Role::Member,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(member()));
+        Arc::new(service)
+    }
+
+    mock! {
+        pub RegisterReactionAppService {}
+
+        #[async_trait]
+        impl RegisterReactionAppService for RegisterReactionAppService {
+            async fn register_reaction(&self, post_id: i32, kind: String, user: &LoggedInUser) -> Result<(), RegisterReactionError>;
+        }
+    }
+
+    async fn call(service: MockRegisterReactionAppService, uri: &str) -> http::StatusCode {
+        let arc_service: Arc<dyn RegisterReactionAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(register_reaction)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::put()
+            .uri(uri)
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+
+        test::call_service(&app, req).await.status()
+    }
+
+    #[actix_web::test]
+    async fn test_register_reaction_ok() {
+        let mut service = MockRegisterReactionAppService::new();
+        service
+            .expect_register_reaction()
+            .with(eq(10), eq("like".to_string()), eq(member()))
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        assert_eq!(
+            call(service, "/post/10/reactions/like").await,
+            http::StatusCode::NO_CONTENT
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_register_reaction_emoji() {
+        let mut service = MockRegisterReactionAppService::new();
+        service
+            .expect_register_reaction()
+            .with(eq(10), eq("👍".to_string()), eq(member()))
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        assert_eq!(
+            call(service, "/post/10/reactions/%F0%9F%91%8D").await,
+            http::StatusCode::NO_CONTENT
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_register_reaction_validation_error() {
+        let mut service = MockRegisterReactionAppService::new();
+        service
+            .expect_register_reaction()
+            .times(1)
+            .returning(|_, _, _| {
+                Err(RegisterReactionError::ValidationError(ValidationError(
+                    "Reaction kind must be one of like, laugh".to_string(),
+                )))
+            });
+
+        assert_eq!(
+            call(service, "/post/10/reactions/angry").await,
+            http::StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_register_reaction_not_found() {
+        let mut service = MockRegisterReactionAppService::new();
+        service
+            .expect_register_reaction()
+            .times(1)
+            .returning(|_, _, _| {
+                Err(RegisterReactionError::NotFoundError(NotFoundError(
+                    "Post not found".to_string(),
+                )))
+            });
+
+        assert_eq!(
+            call(service, "/post/99/reactions/like").await,
+            http::StatusCode::NOT_FOUND
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_register_reaction_service_error() {
+        let mut service = MockRegisterReactionAppService::new();
+        service
+            .expect_register_reaction()
+            .times(1)
+            .returning(|_, _, _| {
+                Err(RegisterReactionError::ServiceError(ServiceError(
+                    "Service Error".to_string(),
+                )))
+            });
+
+        assert_eq!(
+            call(service, "/post/10/reactions/like").await,
+            http::StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
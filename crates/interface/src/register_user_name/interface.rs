@@ -4,13 +4,17 @@ use actix_web::{
     HttpResponse, Responder,
 };
 use app_service::register_user_name::AppService as RegisterUserNameAppService;
-use domain_model::register_user_name::{err::RegisterUserNameError, model::UnvalidatedUserName};
+use domain_model::register_user_name::{
+    err::RegisterUserNameError,
+    model::{UnvalidatedPassword, UnvalidatedUserName},
+};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 #[derive(Deserialize, Serialize)]
 struct UserNameDto {
     user_name: String,
+    password: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -39,7 +43,8 @@ pub async fn post_user(
     service: Data<Arc<dyn RegisterUserNameAppService>>,
 ) -> impl Responder {
     let user_name_dto = UnvalidatedUserName(user_name.user_name.clone());
-    let result = service.register_user_name(user_name_dto);
+    let password = UnvalidatedPassword(user_name.password.clone());
+    let result = service.register_user_name(user_name_dto, password);
     match result.await {
         Ok(_) => HttpResponse::Ok().finish(),
         Err(err) => match err {
@@ -70,6 +75,7 @@ mod tests {
             async fn register_user_name(
                 &self,
                 user_name: UnvalidatedUserName,
+                password: UnvalidatedPassword,
             ) -> Result<(), RegisterUserNameError>;
         }
     }
@@ -79,17 +85,21 @@ mod tests {
         let user_name = UnvalidatedUserName("user_name".to_string());
         let user_name_dto = UserNameDto {
             user_name: user_name.0.clone(),
+            password: "password1".to_string(),
         };
 
         let mut service = MockRegisterUserNameAppService::new();
         service
             .expect_register_user_name()
-            .with(eq(user_name))
+            .with(
+                eq(user_name),
+                eq(UnvalidatedPassword("password1".to_string())),
+            )
             .times(1)
-            .returning(|_| Ok(()));
+            .returning(|_, _| Ok(()));
         let arc_service: Arc<dyn RegisterUserNameAppService> = Arc::new(service);
 
-        let mut app = test::init_service(
+        let app = test::init_service(
             App::new()
                 .app_data(web::Data::new(arc_service))
                 .service(post_user),
@@ -100,7 +110,7 @@ mod tests {
             .uri("/user")
             .set_json(&user_name_dto)
             .to_request();
-        let resp = test::call_service(&mut app, req).await;
+        let resp = test::call_service(&app, req).await;
 
         assert_eq!(resp.status(), http::StatusCode::OK);
         assert_eq!(test::read_body(resp).await, web::Bytes::from_static(b""));
@@ -111,21 +121,25 @@ mod tests {
         let user_name = UnvalidatedUserName("user_name".to_string());
         let user_name_dto = UserNameDto {
             user_name: user_name.0.clone(),
+            password: "password1".to_string(),
         };
 
         let mut service = MockRegisterUserNameAppService::new();
         service
             .expect_register_user_name()
-            .with(eq(user_name))
+            .with(
+                eq(user_name),
+                eq(UnvalidatedPassword("password1".to_string())),
+            )
             .times(1)
-            .returning(|_| {
+            .returning(|_, _| {
                 Err(RegisterUserNameError::from(ValidationError(
                     "Name must not contain NG words".to_string(),
                 )))
             });
         let arc_service: Arc<dyn RegisterUserNameAppService> = Arc::new(service);
 
-        let mut app = test::init_service(
+        let app = test::init_service(
             App::new()
                 .app_data(web::Data::new(arc_service))
                 .service(post_user),
@@ -136,7 +150,7 @@ mod tests {
             .uri("/user")
             .set_json(&user_name_dto)
             .to_request();
-        let resp = test::call_service(&mut app, req).await;
+        let resp = test::call_service(&app, req).await;
 
         assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
         assert_eq!(
@@ -152,21 +166,25 @@ mod tests {
         let user_name = UnvalidatedUserName("user_name".to_string());
         let user_name_dto = UserNameDto {
             user_name: user_name.0.clone(),
+            password: "password1".to_string(),
         };
 
         let mut service = MockRegisterUserNameAppService::new();
         service
             .expect_register_user_name()
-            .with(eq(user_name))
+            .with(
+                eq(user_name),
+                eq(UnvalidatedPassword("password1".to_string())),
+            )
             .times(1)
-            .returning(|_| {
+            .returning(|_, _| {
                 Err(RegisterUserNameError::from(ServiceError(
                     "ServiceError".to_string(),
                 )))
             });
         let arc_service: Arc<dyn RegisterUserNameAppService> = Arc::new(service);
 
-        let mut app = test::init_service(
+        let app = test::init_service(
             App::new()
                 .app_data(web::Data::new(arc_service))
                 .service(post_user),
@@ -177,7 +195,7 @@ mod tests {
             .uri("/user")
             .set_json(&user_name_dto)
             .to_request();
-        let resp = test::call_service(&mut app, req).await;
+        let resp = test::call_service(&app, req).await;
 
         assert_eq!(resp.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
         assert_eq!(
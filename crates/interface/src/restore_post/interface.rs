@@ -0,0 +1,167 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{
+    self, post,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::restore_post::AppService as RestorePostAppService;
+use domain_model::restore_post::err::RestorePostError;
+use serde::Serialize;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RestorePostErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<RestorePostError> for RestorePostErrorDto {
+    fn from(err: RestorePostError) -> Self {
+        match err {
+            RestorePostError::ServiceError(_) => RestorePostErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+            RestorePostError::PermissionError(err) => RestorePostErrorDto {
+                error_code: 403,
+                error_message: err.0,
+            },
+            RestorePostError::NotFoundError(err) => RestorePostErrorDto {
+                error_code: 404,
+                error_message: err.0,
+            },
+        }
+    }
+}
+
+#[post("/post/{id}/restore")]
+pub async fn restore_post(
+    user: AuthenticatedUser,
+    id: web::Path<i32>,
+    service: Data<Arc<dyn RestorePostAppService>>,
+) -> impl Responder {
+    match service.restore_post(id.into_inner(), &user.0).await {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(err) => match err {
+            RestorePostError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(RestorePostErrorDto::from(err))
+            }
+            RestorePostError::PermissionError(_) => {
+                HttpResponse::Forbidden().json(RestorePostErrorDto::from(err))
+            }
+            RestorePostError::NotFoundError(_) => {
+                HttpResponse::NotFound().json(RestorePostErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use domain_model::{
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+        restore_post::err::{NotFoundError, PermissionError},
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(moderator()));
+        Arc::new(service)
+    }
+
+    mock! {
+        pub RestorePostAppService {}
+
+        #[async_trait]
+        impl RestorePostAppService for RestorePostAppService {
+            async fn restore_post(&self, id: i32, user: &LoggedInUser) -> Result<(), RestorePostError>;
+        }
+    }
+
+    async fn call(service: MockRestorePostAppService) -> actix_web::dev::ServiceResponse {
+        let arc_service: Arc<dyn RestorePostAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(restore_post)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/post/1/restore")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .to_request();
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_restore_post() {
+        let mut service = MockRestorePostAppService::new();
+        service
+            .expect_restore_post()
+            .with(eq(1), eq(moderator()))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        assert_eq!(call(service).await.status(), http::StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_restore_post_forbidden() {
+        let mut service = MockRestorePostAppService::new();
+        service.expect_restore_post().times(1).returning(|_, _| {
+            Err(RestorePostError::PermissionError(PermissionError(
+                "Only moderators can restore posts".to_string(),
+            )))
+        });
+
+        assert_eq!(call(service).await.status(), http::StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_restore_post_not_found() {
+        let mut service = MockRestorePostAppService::new();
+        service.expect_restore_post().times(1).returning(|_, _| {
+            Err(RestorePostError::NotFoundError(NotFoundError(
+                "Deleted post not found".to_string(),
+            )))
+        });
+
+        assert_eq!(call(service).await.status(), http::StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,351 @@
+use crate::{get_all_post::PostDto, login::OptionalAuthenticatedUser};
+use actix_web::{
+    self, get,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::search_post::AppService as SearchPostAppService;
+use domain_model::{
+    get_all_post::model::{MuteMode, Viewer},
+    search_post::{
+        err::SearchPostError,
+        model::{SearchHit, UnvalidatedSearchRequest},
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+struct SearchPostQuery {
+    /// 検索語。空白で区切った語を全て含む投稿を探す
+    q: Option<String>,
+    /// 投稿したユーザの名前
+    author: Option<String>,
+    /// この日時以降の投稿に絞る(`2021-01-01T00:00:00Z`の形)
+    since: Option<String>,
+    /// この日時以前の投稿に絞る(`2021-01-01T00:00:00Z`の形)
+    until: Option<String>,
+    /// 件数。省略時は20
+    limit: Option<usize>,
+    /// ミュートした語を含む投稿の扱い(`hide`か`collapse`)。省略時は`hide`
+    muted: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HighlightDto {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SearchHitDto {
+    #[serde(flatten)]
+    pub post: PostDto,
+    pub parent_post_id: Option<i32>,
+    /// `content`の中で検索語に一致した箇所(コードポイント単位、`end`は含まない)
+    pub highlights: Vec<HighlightDto>,
+}
+impl From<SearchHit> for SearchHitDto {
+    fn from(hit: SearchHit) -> Self {
+        Self {
+            post: PostDto::from(hit.post),
+            parent_post_id: hit.parent_post_id,
+            highlights: hit
+                .highlights
+                .into_iter()
+                .map(|range| HighlightDto {
+                    start: range.start,
+                    end: range.end,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SearchResultDto {
+    pub posts: Vec<SearchHitDto>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SearchPostErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<SearchPostError> for SearchPostErrorDto {
+    fn from(err: SearchPostError) -> Self {
+        match err {
+            SearchPostError::ValidationError(err) => SearchPostErrorDto {
+                error_code: 400,
+                error_message: err.0,
+            },
+            SearchPostError::ServiceError(_) => SearchPostErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+        }
+    }
+}
+
+/// 公開済みの投稿を検索語で探し、一致した箇所の多い順に返す
+///
+/// ログイン中であれば、投稿取得と同じくミュートした語を含む投稿を隠すか折りたたむ
+#[get("/post/search")]
+pub async fn search_post(
+    user: OptionalAuthenticatedUser,
+    query: web::Query<SearchPostQuery>,
+    service: Data<Arc<dyn SearchPostAppService>>,
+) -> impl Responder {
+    let mute_mode = match query.muted.as_deref() {
+        None => MuteMode::default(),
+        Some(mode) => match MuteMode::parse(mode) {
+            Some(mute_mode) => mute_mode,
+            None => {
+                return HttpResponse::BadRequest().json(SearchPostErrorDto {
+                    error_code: 400,
+                    error_message: "Muted must be one of hide, collapse".to_string(),
+                });
+            }
+        },
+    };
+    let viewer = user.0.map(|user| Viewer {
+        user_id: user.0.id,
+        mute_mode,
+    });
+    let query = query.into_inner();
+    let request = UnvalidatedSearchRequest {
+        query: query.q,
+        author: query.author,
+        since: query.since,
+        until: query.until,
+        limit: query.limit,
+    };
+    match service.search_post(request, viewer).await {
+        Ok(hits) => HttpResponse::Ok().json(SearchResultDto {
+            posts: hits.into_iter().map(SearchHitDto::from).collect(),
+        }),
+        Err(err) => match err {
+            SearchPostError::ValidationError(_) => {
+                HttpResponse::BadRequest().json(SearchPostErrorDto::from(err))
+            }
+            SearchPostError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(SearchPostErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use chrono::NaiveDateTime;
+    use domain_model::{
+        get_all_post::model::{Content, Post, PostedDatetime, PostedUserName},
+        login::{
+            err::{LoginError, SessionError},
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+        search_post::err::{ServiceError, ValidationError},
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    mock! {
+        pub SearchPostAppService {}
+        #[async_trait]
+        impl SearchPostAppService for SearchPostAppService {
+            async fn search_post(&self, request: UnvalidatedSearchRequest, viewer: Option<Viewer>) -> Result<Vec<SearchHit>, SearchPostError>;
+        }
+    }
+
+    async fn call(mock: MockSearchPostAppService, uri: &str) -> actix_web::dev::ServiceResponse {
+        let arc_service: Arc<dyn SearchPostAppService> = Arc::new(mock);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(arc_service))
+                .service(search_post),
+        )
+        .await;
+        test::call_service(&app, test::TestRequest::get().uri(uri).to_request()).await
+    }
+
+    async fn call_with_token(
+        mock: MockSearchPostAppService,
+        login_service: MockLoginAppService,
+        uri: &str,
+    ) -> actix_web::dev::ServiceResponse {
+        let arc_service: Arc<dyn SearchPostAppService> = Arc::new(mock);
+        let arc_login_service: Arc<dyn LoginAppService> = Arc::new(login_service);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(arc_service))
+                .app_data(Data::new(arc_login_service))
+                .service(search_post),
+        )
+        .await;
+        let req = test::TestRequest::get()
+            .uri(uri)
+            .insert_header((header::AUTHORIZATION, "Bearer token"));
+        test::call_service(&app, req.to_request()).await
+    }
+
+    fn post(id: i32) -> Post {
+        Post {
+            id,
+            posted_user_name: PostedUserName("test".to_string()),
+            posted_datetime: PostedDatetime(
+                NaiveDateTime::parse_from_str("2021-01-01T00:00:00Z", "%Y-%m-%dT%H:%M:%SZ")
+                    .unwrap(),
+            ),
+            content: Content("rust and rust".to_string()),
+            muted: false,
+            reply_count: 0,
+            reactions: vec![],
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_search_post() {
+        let mut mock = MockSearchPostAppService::new();
+        mock.expect_search_post()
+            .with(
+                eq(UnvalidatedSearchRequest {
+                    query: Some("rust".to_string()),
+                    author: Some("test".to_string()),
+                    since: Some("2021-01-01T00:00:00Z".to_string()),
+                    until: None,
+                    limit: Some(10),
+                }),
+                eq(None),
+            )
+            .times(1)
+            .returning(|_, _| {
+                Ok(vec![SearchHit {
+                    post: post(2),
+                    parent_post_id: Some(1),
+                    highlights: vec![0..4, 9..13],
+                }])
+            });
+
+        let resp = call(
+            mock,
+            "/post/search?q=rust&author=test&since=2021-01-01T00:00:00Z&limit=10",
+        )
+        .await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"{\"posts\":[{\"id\":2,\"posted_user_name\":\"test\",\"posted_datetime\":\"2021-01-01T00:00:00Z\",\"content\":\"rust and rust\",\"muted\":false,\"reply_count\":0,\"reactions\":[],\"parent_post_id\":1,\"highlights\":[{\"start\":0,\"end\":4},{\"start\":9,\"end\":13}]}]}"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_search_post_validation_error() {
+        let mut mock = MockSearchPostAppService::new();
+        mock.expect_search_post()
+            .with(eq(UnvalidatedSearchRequest::default()), eq(None))
+            .times(1)
+            .returning(|_, _| {
+                Err(SearchPostError::ValidationError(ValidationError(
+                    "Query is required".to_string(),
+                )))
+            });
+
+        let resp = call(mock, "/post/search").await;
+
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_search_post_service_error() {
+        let mut mock = MockSearchPostAppService::new();
+        mock.expect_search_post().times(1).returning(|_, _| {
+            Err(SearchPostError::ServiceError(ServiceError(
+                "Internal service error".to_string(),
+            )))
+        });
+
+        let resp = call(mock, "/post/search?q=rust").await;
+
+        assert_eq!(resp.status(), http::StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[actix_web::test]
+    async fn test_search_post_muted_collapse() {
+        let mut login_service = MockLoginAppService::new();
+        login_service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .times(1)
+            .returning(|_| {
+                Ok(LoggedInUser {
+                    id: 2,
+                    name: "user_name".to_string(),
+                    role: Role::Member,
+                })
+            });
+        let mut mock = MockSearchPostAppService::new();
+        mock.expect_search_post()
+            .with(
+                always(),
+                eq(Some(Viewer {
+                    user_id: 2,
+                    mute_mode: MuteMode::Collapse,
+                })),
+            )
+            .times(1)
+            .returning(|_, _| Ok(vec![]));
+
+        let resp = call_with_token(mock, login_service, "/post/search?q=rust&muted=collapse").await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_search_post_invalid_session() {
+        let mut login_service = MockLoginAppService::new();
+        login_service.expect_authenticate().times(1).returning(|_| {
+            Err(LoginError::from(SessionError(
+                "Invalid or expired session".to_string(),
+            )))
+        });
+        let mut mock = MockSearchPostAppService::new();
+        mock.expect_search_post().times(0);
+
+        let resp = call_with_token(mock, login_service, "/post/search?q=rust").await;
+
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn test_search_post_muted_unknown() {
+        let mut mock = MockSearchPostAppService::new();
+        mock.expect_search_post().times(0);
+
+        let resp = call(mock, "/post/search?q=rust&muted=blur").await;
+
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,2 @@
+mod interface;
+pub use interface::update_ng_word;
@@ -0,0 +1,304 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{
+    self, patch,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::update_ng_word::AppService as UpdateNgWordAppService;
+use domain_model::update_ng_word::{err::UpdateNgWordError, model::UnvalidatedNgWordPatch};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// 省略した項目は変更しない
+#[derive(Deserialize, Serialize)]
+struct UpdateNgWordDto {
+    #[serde(default)]
+    ng_word: Option<String>,
+    /// NGワードの書き方(literal, glob, regex)
+    #[serde(default)]
+    kind: Option<String>,
+    /// 一致した時の対応(mask, hold, reject)
+    #[serde(default)]
+    severity: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateNgWordErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<UpdateNgWordError> for UpdateNgWordErrorDto {
+    fn from(err: UpdateNgWordError) -> Self {
+        match err {
+            UpdateNgWordError::ValidationError(err) => UpdateNgWordErrorDto {
+                error_code: 400,
+                error_message: err.0,
+            },
+            UpdateNgWordError::ServiceError(_) => UpdateNgWordErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+            UpdateNgWordError::PermissionError(err) => UpdateNgWordErrorDto {
+                error_code: 403,
+                error_message: err.0,
+            },
+            UpdateNgWordError::NotFoundError(err) => UpdateNgWordErrorDto {
+                error_code: 404,
+                error_message: err.0,
+            },
+        }
+    }
+}
+
+#[patch("/ng-words/{id}")]
+pub async fn update_ng_word(
+    user: AuthenticatedUser,
+    id: web::Path<i32>,
+    ng_word: web::Json<UpdateNgWordDto>,
+    service: Data<Arc<dyn UpdateNgWordAppService>>,
+) -> impl Responder {
+    let ng_word = ng_word.into_inner();
+    let patch = UnvalidatedNgWordPatch {
+        ng_word: ng_word.ng_word,
+        kind: ng_word.kind,
+        severity: ng_word.severity,
+    };
+    let result = service
+        .update_ng_word(id.into_inner(), patch, &user.0)
+        .await;
+    match result {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(err) => match err {
+            UpdateNgWordError::ValidationError(_) => {
+                HttpResponse::BadRequest().json(UpdateNgWordErrorDto::from(err))
+            }
+            UpdateNgWordError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(UpdateNgWordErrorDto::from(err))
+            }
+            UpdateNgWordError::PermissionError(_) => {
+                HttpResponse::Forbidden().json(UpdateNgWordErrorDto::from(err))
+            }
+            UpdateNgWordError::NotFoundError(_) => {
+                HttpResponse::NotFound().json(UpdateNgWordErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use domain_model::{
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+        update_ng_word::err::{NotFoundError, PermissionError, ValidationError},
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn moderator() -> LoggedInUser {
+        LoggedInUser {
+            id: 1,
+            name: "user_name".to_string(),
+            role: Role::Moderator,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(moderator()));
+        Arc::new(service)
+    }
+
+    fn severity_patch() -> UnvalidatedNgWordPatch {
+        UnvalidatedNgWordPatch {
+            severity: Some("reject".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn severity_dto() -> UpdateNgWordDto {
+        UpdateNgWordDto {
+            ng_word: None,
+            kind: None,
+            severity: Some("reject".to_string()),
+        }
+    }
+
+    mock! {
+        pub UpdateNgWordAppService {}
+
+        #[async_trait]
+        impl UpdateNgWordAppService for UpdateNgWordAppService {
+            async fn update_ng_word(&self, id: i32, patch: UnvalidatedNgWordPatch, user: &LoggedInUser) -> Result<(), UpdateNgWordError>;
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_update_ng_word_ok() {
+        let mut service = MockUpdateNgWordAppService::new();
+        service
+            .expect_update_ng_word()
+            .with(eq(10), eq(severity_patch()), eq(moderator()))
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let arc_service: Arc<dyn UpdateNgWordAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(update_ng_word)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::patch()
+            .uri("/ng-words/10")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .set_json(severity_dto())
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_update_ng_word_validation_error() {
+        let mut service = MockUpdateNgWordAppService::new();
+        service
+            .expect_update_ng_word()
+            .with(
+                eq(10),
+                eq(UnvalidatedNgWordPatch::default()),
+                eq(moderator()),
+            )
+            .times(1)
+            .returning(|_, _, _| {
+                Err(UpdateNgWordError::ValidationError(ValidationError(
+                    "At least one of ng_word, kind or severity is required".to_string(),
+                )))
+            });
+
+        let arc_service: Arc<dyn UpdateNgWordAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(update_ng_word)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::patch()
+            .uri("/ng-words/10")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .set_json(&UpdateNgWordDto {
+                ng_word: None,
+                kind: None,
+                severity: None,
+            })
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_update_ng_word_not_found() {
+        let mut service = MockUpdateNgWordAppService::new();
+        service
+            .expect_update_ng_word()
+            .with(eq(99), eq(severity_patch()), eq(moderator()))
+            .times(1)
+            .returning(|_, _, _| {
+                Err(UpdateNgWordError::NotFoundError(NotFoundError(
+                    "NG word not found".to_string(),
+                )))
+            });
+
+        let arc_service: Arc<dyn UpdateNgWordAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(update_ng_word)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::patch()
+            .uri("/ng-words/99")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .set_json(severity_dto())
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"{\"error_code\":404,\"error_message\":\"NG word not found\"}"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_update_ng_word_forbidden() {
+        let mut service = MockUpdateNgWordAppService::new();
+        service
+            .expect_update_ng_word()
+            .with(eq(10), eq(severity_patch()), eq(moderator()))
+            .times(1)
+            .returning(|_, _, _| {
+                Err(UpdateNgWordError::PermissionError(PermissionError(
+                    "Only moderators can update NG words".to_string(),
+                )))
+            });
+
+        let arc_service: Arc<dyn UpdateNgWordAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(update_ng_word)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::patch()
+            .uri("/ng-words/10")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .set_json(severity_dto())
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+    }
+}
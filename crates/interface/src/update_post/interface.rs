@@ -0,0 +1,249 @@
+use crate::login::AuthenticatedUser;
+use actix_web::{
+    self, patch,
+    web::{self, Data},
+    HttpResponse, Responder,
+};
+use app_service::update_post::AppService as UpdatePostAppService;
+use domain_model::{post::model::PostStatus, update_post::err::UpdatePostError};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Deserialize, Serialize)]
+struct UpdatePostDto {
+    content: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UpdatePostErrorDto {
+    pub error_code: u16,
+    pub error_message: String,
+}
+
+impl From<UpdatePostError> for UpdatePostErrorDto {
+    fn from(err: UpdatePostError) -> Self {
+        match err {
+            UpdatePostError::ValidationError(err) => UpdatePostErrorDto {
+                error_code: 400,
+                error_message: err.0,
+            },
+            UpdatePostError::ServiceError(_) => UpdatePostErrorDto {
+                error_code: 500,
+                error_message: "Internal Server Error".to_string(),
+            },
+            UpdatePostError::NgWordError(err) => UpdatePostErrorDto {
+                error_code: 400,
+                error_message: err.0,
+            },
+            UpdatePostError::PermissionError(err) => UpdatePostErrorDto {
+                error_code: 403,
+                error_message: err.0,
+            },
+            UpdatePostError::NotFoundError(err) => UpdatePostErrorDto {
+                error_code: 404,
+                error_message: err.0,
+            },
+        }
+    }
+}
+
+#[patch("/post/{id}")]
+pub async fn update_post(
+    user: AuthenticatedUser,
+    id: web::Path<i32>,
+    post: web::Json<UpdatePostDto>,
+    service: Data<Arc<dyn UpdatePostAppService>>,
+) -> impl Responder {
+    let result = service
+        .update_post(id.into_inner(), post.into_inner().content, &user.0)
+        .await;
+    match result {
+        Ok(PostStatus::Published) => HttpResponse::Ok().finish(),
+        // 変更はしたがモデレーション待ちで公開されていない
+        Ok(PostStatus::Held) => HttpResponse::Accepted().finish(),
+        Err(err) => match err {
+            UpdatePostError::ValidationError(_) | UpdatePostError::NgWordError(_) => {
+                HttpResponse::BadRequest().json(UpdatePostErrorDto::from(err))
+            }
+            UpdatePostError::ServiceError(_) => {
+                HttpResponse::InternalServerError().json(UpdatePostErrorDto::from(err))
+            }
+            UpdatePostError::PermissionError(_) => {
+                HttpResponse::Forbidden().json(UpdatePostErrorDto::from(err))
+            }
+            UpdatePostError::NotFoundError(_) => {
+                HttpResponse::NotFound().json(UpdatePostErrorDto::from(err))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http, http::header, test, App};
+    use app_service::login::AppService as LoginAppService;
+    use async_trait::async_trait;
+    use domain_model::{
+        login::{
+            err::LoginError,
+            model::{
+                LoggedInUser, Role, Session, SessionToken, UncheckedPassword, UncheckedUserName,
+            },
+        },
+        update_post::err::{NgWordError, NotFoundError, PermissionError},
+    };
+    use mockall::{predicate::*, *};
+
+    mock! {
+        pub LoginAppService {}
+        #[async_trait]
+        impl LoginAppService for LoginAppService {
+            async fn login(&self, user_name: UncheckedUserName, password: UncheckedPassword) -> Result<Session, LoginError>;
+            async fn authenticate(&self, token: SessionToken) -> Result<LoggedInUser, LoginError>;
+            async fn logout(&self, token: SessionToken) -> Result<(), LoginError>;
+            async fn revoke_all_sessions(&self, user: &LoggedInUser) -> Result<(), LoginError>;
+            async fn purge_expired_sessions(&self) -> Result<u64, LoginError>;
+        }
+    }
+
+    fn author() -> LoggedInUser {
+        LoggedInUser {
+            id: 2,
+            name: "user_name".to_string(),
+            role: Role::Member,
+        }
+    }
+
+    fn login_service() -> Arc<dyn LoginAppService> {
+        let mut service = MockLoginAppService::new();
+        service
+            .expect_authenticate()
+            .with(eq(SessionToken("token".to_string())))
+            .returning(|_| Ok(author()));
+        Arc::new(service)
+    }
+
+    mock! {
+        pub UpdatePostAppService {}
+
+        #[async_trait]
+        impl UpdatePostAppService for UpdatePostAppService {
+            async fn update_post(&self, id: i32, content: String, user: &LoggedInUser) -> Result<PostStatus, UpdatePostError>;
+        }
+    }
+
+    async fn call(service: MockUpdatePostAppService) -> actix_web::dev::ServiceResponse {
+        let arc_service: Arc<dyn UpdatePostAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(update_post)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::patch()
+            .uri("/post/1")
+            .insert_header((header::AUTHORIZATION, "Bearer token"))
+            .set_json(UpdatePostDto {
+                content: "edited".to_string(),
+            })
+            .to_request();
+
+        test::call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_update_post_ok() {
+        let mut service = MockUpdatePostAppService::new();
+        service
+            .expect_update_post()
+            .with(eq(1), eq("edited".to_string()), eq(author()))
+            .times(1)
+            .returning(|_, _, _| Ok(PostStatus::Published));
+
+        assert_eq!(call(service).await.status(), http::StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_update_post_held() {
+        let mut service = MockUpdatePostAppService::new();
+        service
+            .expect_update_post()
+            .times(1)
+            .returning(|_, _, _| Ok(PostStatus::Held));
+
+        assert_eq!(call(service).await.status(), http::StatusCode::ACCEPTED);
+    }
+
+    #[actix_web::test]
+    async fn test_update_post_ng_word() {
+        let mut service = MockUpdatePostAppService::new();
+        service.expect_update_post().times(1).returning(|_, _, _| {
+            Err(UpdatePostError::NgWordError(NgWordError(
+                "Content contains a rejected NG word (rule 1): bad".to_string(),
+            )))
+        });
+
+        assert_eq!(call(service).await.status(), http::StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_update_post_forbidden() {
+        let mut service = MockUpdatePostAppService::new();
+        service.expect_update_post().times(1).returning(|_, _, _| {
+            Err(UpdatePostError::PermissionError(PermissionError(
+                "Post can only be edited within 15 minutes".to_string(),
+            )))
+        });
+
+        let resp = call(service).await;
+
+        assert_eq!(resp.status(), http::StatusCode::FORBIDDEN);
+        assert_eq!(
+            test::read_body(resp).await,
+            web::Bytes::from_static(
+                b"{\"error_code\":403,\"error_message\":\"Post can only be edited within 15 minutes\"}"
+            )
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_update_post_not_found() {
+        let mut service = MockUpdatePostAppService::new();
+        service.expect_update_post().times(1).returning(|_, _, _| {
+            Err(UpdatePostError::NotFoundError(NotFoundError(
+                "Post not found".to_string(),
+            )))
+        });
+
+        assert_eq!(call(service).await.status(), http::StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_update_post_unauthorized() {
+        let mut service = MockUpdatePostAppService::new();
+        service.expect_update_post().times(0);
+        let arc_service: Arc<dyn UpdatePostAppService> = Arc::new(service);
+
+        let app = test::init_service(
+            App::new()
+                .service(update_post)
+                .app_data(web::Data::new(arc_service))
+                .app_data(web::Data::new(login_service())),
+        )
+        .await;
+
+        let req = test::TestRequest::patch()
+            .uri("/post/1")
+            .set_json(UpdatePostDto {
+                content: "edited".to_string(),
+            })
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), http::StatusCode::UNAUTHORIZED);
+    }
+}
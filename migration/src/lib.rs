@@ -3,6 +3,24 @@ pub use sea_orm_migration::prelude::*;
 mod m20220101_000001_create_user_ng_word;
 mod m20241231_061508_post;
 mod m20250123_074710_modify_ng_word_created_user_id_type;
+mod m20261018_090000_create_session;
+mod m20261018_100000_add_user_password_hash;
+mod m20261018_110000_add_session_expires_at_index;
+mod m20261018_120000_add_user_role;
+mod m20261018_130000_add_ng_word_severity;
+mod m20261018_140000_add_post_status;
+mod m20261018_150000_add_ng_word_kind;
+mod m20261018_160000_add_ng_word_updated_user_id;
+mod m20261018_170000_add_post_original_content;
+mod m20261018_180000_create_allow_word;
+mod m20261018_190000_create_mute_word;
+mod m20261018_200000_add_post_created_at_index;
+mod m20261018_210000_create_post_revision;
+mod m20261018_220000_add_post_deletion;
+mod m20261018_230000_add_post_parent_post_id;
+mod m20261018_240000_create_post_reaction;
+mod m20261018_250000_drop_post_content;
+mod m20261018_260000_add_post_restoration;
 
 pub struct Migrator;
 
@@ -13,6 +31,24 @@ impl MigratorTrait for Migrator {
             Box::new(m20220101_000001_create_user_ng_word::Migration),
             Box::new(m20241231_061508_post::Migration),
             Box::new(m20250123_074710_modify_ng_word_created_user_id_type::Migration),
+            Box::new(m20261018_090000_create_session::Migration),
+            Box::new(m20261018_100000_add_user_password_hash::Migration),
+            Box::new(m20261018_110000_add_session_expires_at_index::Migration),
+            Box::new(m20261018_120000_add_user_role::Migration),
+            Box::new(m20261018_130000_add_ng_word_severity::Migration),
+            Box::new(m20261018_140000_add_post_status::Migration),
+            Box::new(m20261018_150000_add_ng_word_kind::Migration),
+            Box::new(m20261018_160000_add_ng_word_updated_user_id::Migration),
+            Box::new(m20261018_170000_add_post_original_content::Migration),
+            Box::new(m20261018_180000_create_allow_word::Migration),
+            Box::new(m20261018_190000_create_mute_word::Migration),
+            Box::new(m20261018_200000_add_post_created_at_index::Migration),
+            Box::new(m20261018_210000_create_post_revision::Migration),
+            Box::new(m20261018_220000_add_post_deletion::Migration),
+            Box::new(m20261018_230000_add_post_parent_post_id::Migration),
+            Box::new(m20261018_240000_create_post_reaction::Migration),
+            Box::new(m20261018_250000_drop_post_content::Migration),
+            Box::new(m20261018_260000_add_post_restoration::Migration),
         ]
     }
 }
@@ -24,7 +24,7 @@ impl MigrationTrait for Migration {
                             .integer()
                             .not_null(),
                     )
-                    .col(ColumnDef::new(t_post::Column::Content).string().not_null())
+                    .col(ColumnDef::new(Alias::new("content")).string().not_null())
                     .col(
                         ColumnDef::new(t_post::Column::CreatedAt)
                             .timestamp_with_time_zone()
@@ -0,0 +1,35 @@
+use infra::entity::m_user_name;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 既存ユーザはパスワード未設定のためNULLを許容する
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(m_user_name::Entity)
+                    .add_column(
+                        ColumnDef::new(m_user_name::Column::PasswordHash)
+                            .string()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(m_user_name::Entity)
+                    .drop_column(m_user_name::Column::PasswordHash)
+                    .to_owned(),
+            )
+            .await
+    }
+}
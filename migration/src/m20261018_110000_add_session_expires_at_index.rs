@@ -0,0 +1,33 @@
+use infra::entity::t_session;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 期限切れセッションの定期削除用
+        // user_idは外部キーのインデックスがあるので不要
+        manager
+            .create_index(
+                sea_query::Index::create()
+                    .name("idx_session_expires_at")
+                    .table(t_session::Entity)
+                    .col(t_session::Column::ExpiresAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                sea_query::Index::drop()
+                    .name("idx_session_expires_at")
+                    .table(t_session::Entity)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,36 @@
+use infra::entity::m_user_name;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // member, moderator, adminのいずれか
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(m_user_name::Entity)
+                    .add_column(
+                        ColumnDef::new(m_user_name::Column::Role)
+                            .string_len(16)
+                            .not_null()
+                            .default("member"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(m_user_name::Entity)
+                    .drop_column(m_user_name::Column::Role)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,36 @@
+use infra::entity::t_post;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // published, heldのいずれか。既存の投稿は公開済みとする
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(t_post::Entity)
+                    .add_column(
+                        ColumnDef::new(t_post::Column::Status)
+                            .string_len(16)
+                            .not_null()
+                            .default("published"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(t_post::Entity)
+                    .drop_column(t_post::Column::Status)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,36 @@
+use infra::entity::m_ng_word;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // literal, glob, regexのいずれか
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(m_ng_word::Entity)
+                    .add_column(
+                        ColumnDef::new(m_ng_word::Column::Kind)
+                            .string_len(16)
+                            .not_null()
+                            .default("literal"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(m_ng_word::Entity)
+                    .drop_column(m_ng_word::Column::Kind)
+                    .to_owned(),
+            )
+            .await
+    }
+}
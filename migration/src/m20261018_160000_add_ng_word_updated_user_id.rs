@@ -0,0 +1,35 @@
+use infra::entity::m_ng_word;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 最後に変更したユーザ。未変更の場合はNULL
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(m_ng_word::Entity)
+                    .add_column(
+                        ColumnDef::new(m_ng_word::Column::UpdatedUserId)
+                            .integer()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(m_ng_word::Entity)
+                    .drop_column(m_ng_word::Column::UpdatedUserId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,63 @@
+use infra::entity::t_post;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // NGワードで塗りつぶす前の投稿内容。既存の投稿は保存済みの内容で埋める
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(t_post::Entity)
+                    .add_column(
+                        ColumnDef::new(t_post::Column::OriginalContent)
+                            .string()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(t_post::Entity)
+                    .value(
+                        t_post::Column::OriginalContent,
+                        Expr::col(Alias::new("content")),
+                    )
+                    // 埋めるだけなので更新日時は変えない
+                    .value(
+                        t_post::Column::UpdatedAt,
+                        Expr::col(t_post::Column::UpdatedAt),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(t_post::Entity)
+                    .modify_column(
+                        ColumnDef::new(t_post::Column::OriginalContent)
+                            .string()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(t_post::Entity)
+                    .drop_column(t_post::Column::OriginalContent)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,56 @@
+use infra::entity::m_allow_word;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // NGワードの例外にする語
+        manager
+            .create_table(
+                sea_query::Table::create()
+                    .table(m_allow_word::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(m_allow_word::Column::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(m_allow_word::Column::Word)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(m_allow_word::Column::CreatedUserId)
+                            .integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(m_allow_word::Column::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(m_allow_word::Column::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp())
+                            .extra("ON UPDATE CURRENT_TIMESTAMP".to_owned()),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(m_allow_word::Entity).to_owned())
+            .await
+    }
+}
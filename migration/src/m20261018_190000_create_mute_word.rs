@@ -0,0 +1,78 @@
+use infra::entity::{m_user_name, t_mute_word};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ユーザごとにミュートする語
+        manager
+            .create_table(
+                sea_query::Table::create()
+                    .table(t_mute_word::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(t_mute_word::Column::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(t_mute_word::Column::UserId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(t_mute_word::Column::Word)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(t_mute_word::Column::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(t_mute_word::Column::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp())
+                            .extra("ON UPDATE CURRENT_TIMESTAMP".to_owned()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk_mute_word_user_id")
+                    .from(t_mute_word::Entity, t_mute_word::Column::UserId)
+                    .to(m_user_name::Entity, m_user_name::Column::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+        // 同じユーザが同じ語を二重に登録しないように
+        manager
+            .create_index(
+                sea_query::Index::create()
+                    .name("idx_mute_word_user_id_word")
+                    .table(t_mute_word::Entity)
+                    .col(t_mute_word::Column::UserId)
+                    .col(t_mute_word::Column::Word)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(t_mute_word::Entity).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,33 @@
+use infra::entity::t_post;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 投稿一覧を新しい順にページングするため
+        manager
+            .create_index(
+                sea_query::Index::create()
+                    .name("idx_post_created_at_id")
+                    .table(t_post::Entity)
+                    .col(t_post::Column::CreatedAt)
+                    .col(t_post::Column::Id)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                sea_query::Index::drop()
+                    .name("idx_post_created_at_id")
+                    .table(t_post::Entity)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,71 @@
+use infra::entity::{t_post, t_post_revision};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 編集される前の投稿の版
+        manager
+            .create_table(
+                sea_query::Table::create()
+                    .table(t_post_revision::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(t_post_revision::Column::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(t_post_revision::Column::PostId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(t_post_revision::Column::OriginalContent)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(t_post_revision::Column::WrittenAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(t_post_revision::Column::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(t_post_revision::Column::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp())
+                            .extra("ON UPDATE CURRENT_TIMESTAMP".to_owned()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk_post_revision_post_id")
+                    .from(t_post_revision::Entity, t_post_revision::Column::PostId)
+                    .to(t_post::Entity, t_post::Column::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(t_post_revision::Entity).to_owned())
+            .await
+    }
+}
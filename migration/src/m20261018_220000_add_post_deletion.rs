@@ -0,0 +1,43 @@
+use infra::entity::t_post;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 削除した日時、削除したユーザ、削除の理由。削除されていない場合はNULL
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(t_post::Entity)
+                    .add_column(
+                        ColumnDef::new(t_post::Column::DeletedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(t_post::Column::DeletedUserId)
+                            .integer()
+                            .null(),
+                    )
+                    .add_column(ColumnDef::new(t_post::Column::DeleteReason).string().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(t_post::Entity)
+                    .drop_column(t_post::Column::DeletedAt)
+                    .drop_column(t_post::Column::DeletedUserId)
+                    .drop_column(t_post::Column::DeleteReason)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,54 @@
+use infra::entity::t_post;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 返信先の投稿。返信でない場合はNULL
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(t_post::Entity)
+                    .add_column(
+                        ColumnDef::new(t_post::Column::ParentPostId)
+                            .integer()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        // 返信の取得と返信数の集計に使うインデックスも外部キーで作られる
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk_post_parent_post_id")
+                    .from(t_post::Entity, t_post::Column::ParentPostId)
+                    .to(t_post::Entity, t_post::Column::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                sea_query::ForeignKey::drop()
+                    .name("fk_post_parent_post_id")
+                    .table(t_post::Entity)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(t_post::Entity)
+                    .drop_column(t_post::Column::ParentPostId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
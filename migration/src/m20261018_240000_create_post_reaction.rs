@@ -0,0 +1,87 @@
+use infra::entity::{m_user_name, t_post, t_post_reaction};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // ユーザが投稿に付けたリアクション
+        manager
+            .create_table(
+                sea_query::Table::create()
+                    .table(t_post_reaction::Entity)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(t_post_reaction::Column::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(t_post_reaction::Column::PostId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(t_post_reaction::Column::UserId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(t_post_reaction::Column::Kind)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(t_post_reaction::Column::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk_post_reaction_post_id")
+                    .from(t_post_reaction::Entity, t_post_reaction::Column::PostId)
+                    .to(t_post::Entity, t_post::Column::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_foreign_key(
+                sea_query::ForeignKey::create()
+                    .name("fk_post_reaction_user_id")
+                    .from(t_post_reaction::Entity, t_post_reaction::Column::UserId)
+                    .to(m_user_name::Entity, m_user_name::Column::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+        // 同じユーザが同じ投稿に同じ種類のリアクションを二重に付けないように。集計にも使う
+        manager
+            .create_index(
+                sea_query::Index::create()
+                    .name("idx_post_reaction_post_id_user_id_kind")
+                    .table(t_post_reaction::Entity)
+                    .col(t_post_reaction::Column::PostId)
+                    .col(t_post_reaction::Column::UserId)
+                    .col(t_post_reaction::Column::Kind)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(t_post_reaction::Entity).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,56 @@
+use infra::entity::t_post;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 投稿は取得時に塗りつぶすため、書いた時点で塗りつぶした内容は使わない
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(t_post::Entity)
+                    .drop_column(Alias::new("content"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 塗りつぶした内容は戻せないため、書かれたままの内容で埋める
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(t_post::Entity)
+                    .add_column(ColumnDef::new(Alias::new("content")).string().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(t_post::Entity)
+                    .value(
+                        Alias::new("content"),
+                        Expr::col(t_post::Column::OriginalContent),
+                    )
+                    // 埋めるだけなので更新日時は変えない
+                    .value(
+                        t_post::Column::UpdatedAt,
+                        Expr::col(t_post::Column::UpdatedAt),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                sea_query::Table::alter()
+                    .table(t_post::Entity)
+                    .modify_column(ColumnDef::new(Alias::new("content")).string().not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -1,36 +1,163 @@
 use actix_web::{self, web::Data, App, HttpServer};
 use app_service::{
+    check_ng_words::{
+        AppService as CheckNgWordsAppService, AppServiceImpl as CheckNgWordsAppServiceImpl,
+    },
+    delete_allow_word::{
+        AppService as DeleteAllowWordAppService, AppServiceImpl as DeleteAllowWordAppServiceImpl,
+    },
+    delete_mute_word::{
+        AppService as DeleteMuteWordAppService, AppServiceImpl as DeleteMuteWordAppServiceImpl,
+    },
+    delete_ng_word::{
+        AppService as DeleteNgWordAppService, AppServiceImpl as DeleteNgWordAppServiceImpl,
+    },
+    delete_post::{AppService as DeletePostAppService, AppServiceImpl as DeletePostAppServiceImpl},
+    delete_reaction::{
+        AppService as DeleteReactionAppService, AppServiceImpl as DeleteReactionAppServiceImpl,
+    },
+    export_ng_words::{
+        AppService as ExportNgWordsAppService, AppServiceImpl as ExportNgWordsAppServiceImpl,
+    },
     get_all_post::{
         AppService as GetAllPostAppService, AppServiceImpl as GetAllPostAppServiceImpl,
     },
+    get_all_raw_post::{
+        AppService as GetAllRawPostAppService, AppServiceImpl as GetAllRawPostAppServiceImpl,
+    },
+    get_post::{AppService as GetPostAppService, AppServiceImpl as GetPostAppServiceImpl},
+    get_post_thread::{
+        AppService as GetPostThreadAppService, AppServiceImpl as GetPostThreadAppServiceImpl,
+    },
+    import_ng_words::{
+        AppService as ImportNgWordsAppService, AppServiceImpl as ImportNgWordsAppServiceImpl,
+    },
+    list_allow_words::{
+        AppService as ListAllowWordsAppService, AppServiceImpl as ListAllowWordsAppServiceImpl,
+    },
+    list_mute_words::{
+        AppService as ListMuteWordsAppService, AppServiceImpl as ListMuteWordsAppServiceImpl,
+    },
+    list_ng_words::{
+        AppService as ListNgWordsAppService, AppServiceImpl as ListNgWordsAppServiceImpl,
+    },
+    list_post_revisions::{
+        AppService as ListPostRevisionsAppService,
+        AppServiceImpl as ListPostRevisionsAppServiceImpl,
+    },
     login::{AppService as LoginAppService, AppServiceImpl as LoginAppServiceImpl},
     post::{AppService as PostAppService, AppServiceImpl as PostAppServiceImpl},
+    register_allow_word::{
+        AppService as RegisterAllowWordAppService,
+        AppServiceImpl as RegisterAllowWordAppServiceImpl,
+    },
+    register_mute_word::{
+        AppService as RegisterMuteWordAppService, AppServiceImpl as RegisterMuteWordAppServiceImpl,
+    },
     register_ng_word::{
         AppService as RegisterNgWordAppService, AppServiceImpl as RegisterNgWordAppServiceImpl,
     },
+    register_reaction::{
+        AppService as RegisterReactionAppService, AppServiceImpl as RegisterReactionAppServiceImpl,
+    },
     register_user_name::{
         AppService as RegisterUserNameAppService, AppServiceImpl as RegisterUserNameAppServiceImpl,
     },
+    restore_post::{
+        AppService as RestorePostAppService, AppServiceImpl as RestorePostAppServiceImpl,
+    },
+    search_post::{AppService as SearchPostAppService, AppServiceImpl as SearchPostAppServiceImpl},
+    update_ng_word::{
+        AppService as UpdateNgWordAppService, AppServiceImpl as UpdateNgWordAppServiceImpl,
+    },
+    update_post::{AppService as UpdatePostAppService, AppServiceImpl as UpdatePostAppServiceImpl},
 };
+use domain_model::reaction::model::ReactionKindSet;
 use domain_service::{
+    check_ng_words::DomainServiceImpl as CheckNgWordsDomainService,
+    delete_allow_word::DomainServiceImpl as DeleteAllowWordDomainService,
+    delete_mute_word::DomainServiceImpl as DeleteMuteWordDomainService,
+    delete_ng_word::DomainServiceImpl as DeleteNgWordDomainService,
+    delete_post::DomainServiceImpl as DeletePostDomainService,
+    delete_reaction::DomainServiceImpl as DeleteReactionDomainService,
+    export_ng_words::DomainServiceImpl as ExportNgWordsDomainService,
     get_all_post::DomainServiceImpl as GetAllPostDomainService,
-    login::DomainServiceImpl as LoginDomainService, post::DomainServiceImpl as PostDomainService,
+    get_all_raw_post::DomainServiceImpl as GetAllRawPostDomainService,
+    get_post::DomainServiceImpl as GetPostDomainService,
+    get_post_thread::DomainServiceImpl as GetPostThreadDomainService,
+    import_ng_words::DomainServiceImpl as ImportNgWordsDomainService,
+    list_allow_words::DomainServiceImpl as ListAllowWordsDomainService,
+    list_mute_words::DomainServiceImpl as ListMuteWordsDomainService,
+    list_ng_words::DomainServiceImpl as ListNgWordsDomainService,
+    list_post_revisions::DomainServiceImpl as ListPostRevisionsDomainService,
+    login::DomainServiceImpl as LoginDomainService,
+    ng_word_policy::{NgWordPolicy, NgWordPolicyImpl},
+    post::DomainServiceImpl as PostDomainService,
+    register_allow_word::DomainServiceImpl as RegisterAllowWordDomainService,
+    register_mute_word::DomainServiceImpl as RegisterMuteWordDomainService,
     register_ng_word::DomainServiceImpl as RegisterNgWordDomainService,
+    register_reaction::DomainServiceImpl as RegisterReactionDomainService,
     register_user_name::DomainServiceImpl as RegisterUserNameDomainService,
+    restore_post::DomainServiceImpl as RestorePostDomainService,
+    search_post::DomainServiceImpl as SearchPostDomainService,
+    update_ng_word::DomainServiceImpl as UpdateNgWordDomainService,
+    update_post::DomainServiceImpl as UpdatePostDomainService,
 };
 use dotenv::dotenv;
 use infra::repository_impl::{
-    GetAllPostRepositoryImpl, LoginRepositoryImpl, PostRepositoryImpl,
-    RegisterNgWordRepositoryImpl, RegisterUserNameRepositoryImpl,
+    DeleteAllowWordRepositoryImpl, DeleteMuteWordRepositoryImpl, DeleteNgWordRepositoryImpl,
+    DeletePostRepositoryImpl, DeleteReactionRepositoryImpl, ExportNgWordsRepositoryImpl,
+    GetAllPostRepositoryImpl, GetAllRawPostRepositoryImpl, GetPostRepositoryImpl,
+    GetPostThreadRepositoryImpl, ImportNgWordsRepositoryImpl, ListAllowWordsRepositoryImpl,
+    ListMuteWordsRepositoryImpl, ListNgWordsRepositoryImpl, ListPostRevisionsRepositoryImpl,
+    LoginRepositoryImpl, NgWordPolicyRepositoryImpl, NgWordRuleCache, PostRepositoryImpl,
+    PostSearchIndex, RegisterAllowWordRepositoryImpl, RegisterMuteWordRepositoryImpl,
+    RegisterNgWordRepositoryImpl, RegisterReactionRepositoryImpl, RegisterUserNameRepositoryImpl,
+    RestorePostRepositoryImpl, SearchPostRepositoryImpl, SessionRepositoryImpl,
+    UpdateNgWordRepositoryImpl, UpdatePostRepositoryImpl,
 };
 use interface::{
-    get_all_post::get_all_post, login::post_login, post::post_post,
-    register_ng_word::register_ng_word, register_user_name::post_user,
+    check_ng_words::check_ng_words,
+    delete_allow_word::delete_allow_word,
+    delete_mute_word::delete_mute_word,
+    delete_ng_word::delete_ng_word,
+    delete_post::delete_post,
+    delete_reaction::delete_reaction,
+    export_ng_words::export_ng_words,
+    get_all_post::get_all_post,
+    get_all_raw_post::get_all_raw_post,
+    get_post::get_post,
+    get_post_thread::get_post_thread,
+    import_ng_words::import_ng_words,
+    list_allow_words::list_allow_words,
+    list_mute_words::list_mute_words,
+    list_ng_words::list_ng_words,
+    list_post_revisions::list_post_revisions,
+    login::{post_login, post_logout, post_logout_all},
+    post::{post_post, post_reply},
+    register_allow_word::register_allow_word,
+    register_mute_word::register_mute_word,
+    register_ng_word::register_ng_word,
+    register_reaction::register_reaction,
+    register_user_name::post_user,
+    restore_post::restore_post,
+    search_post::search_post,
+    update_ng_word::update_ng_word,
+    update_post::update_post,
 };
 use sea_orm::*;
-use std::{env, sync::Arc};
+use std::{env, sync::Arc, time::Duration};
 use tracing::*;
 
+// 期限切れセッションを削除する間隔
+const SESSION_PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+// NGワード照合器のキャッシュ期間(別プロセスでのNGワード登録を反映するまでの最大時間)
+const NG_WORD_RULE_CACHE_TTL: Duration = Duration::from_secs(60);
+// 投稿してから編集できる期間(分)。POST_EDIT_WINDOW_MINUTESで変更できる
+const DEFAULT_POST_EDIT_WINDOW_MINUTES: u64 = 15;
+// 投稿に付けられるリアクションの種類。REACTION_KINDSにカンマ区切りで指定して変更できる
+const DEFAULT_REACTION_KINDS: &str = "like,love,laugh,wow,sad";
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     // dotenv適用
@@ -51,26 +178,189 @@ async fn main() -> std::io::Result<()> {
         .await
         .expect("Fail to Connect Database");
 
+    let ng_word_rule_cache = Arc::new(NgWordRuleCache::new(NG_WORD_RULE_CACHE_TTL));
+    let ng_word_policy: Arc<dyn NgWordPolicy> = Arc::new(NgWordPolicyImpl::new(Arc::new(
+        NgWordPolicyRepositoryImpl::new(db_conn.clone(), ng_word_rule_cache.clone()),
+    )));
+
+    let post_search_index = Arc::new(PostSearchIndex::default());
+
     let register_user_name_app_service: Arc<dyn RegisterUserNameAppService> = Arc::new(
         RegisterUserNameAppServiceImpl::new(Arc::new(RegisterUserNameDomainService::new(
             Arc::new(RegisterUserNameRepositoryImpl::new(db_conn.clone())),
+            ng_word_policy.clone(),
         ))),
     );
-    let post_app_service: Arc<dyn PostAppService> = Arc::new(PostAppServiceImpl::new(Arc::new(
-        PostDomainService::new(Arc::new(PostRepositoryImpl::new(db_conn.clone()))),
-    )));
-    let login_app_service: Arc<dyn LoginAppService> = Arc::new(LoginAppServiceImpl::new(Arc::new(
-        LoginDomainService::new(Arc::new(LoginRepositoryImpl::new(db_conn.clone()))),
-    )));
-
-    let get_all_post_service: Arc<dyn GetAllPostAppService> =
-        Arc::new(GetAllPostAppServiceImpl::new(Arc::new(
-            GetAllPostDomainService::new(Arc::new(GetAllPostRepositoryImpl::new(db_conn.clone()))),
+    let post_app_service: Arc<dyn PostAppService> =
+        Arc::new(PostAppServiceImpl::new(Arc::new(PostDomainService::new(
+            Arc::new(PostRepositoryImpl::new(
+                db_conn.clone(),
+                post_search_index.clone(),
+            )),
+            ng_word_policy.clone(),
+        ))));
+    let post_edit_window_minutes: u64 = env::var("POST_EDIT_WINDOW_MINUTES")
+        .map(|minutes| {
+            minutes
+                .parse()
+                .expect("POST_EDIT_WINDOW_MINUTES is invalid")
+        })
+        .unwrap_or(DEFAULT_POST_EDIT_WINDOW_MINUTES);
+    let update_post_service: Arc<dyn UpdatePostAppService> = Arc::new(
+        UpdatePostAppServiceImpl::new(Arc::new(UpdatePostDomainService::new(
+            Arc::new(UpdatePostRepositoryImpl::new(
+                db_conn.clone(),
+                post_search_index.clone(),
+            )),
+            ng_word_policy.clone(),
+            Duration::from_secs(post_edit_window_minutes.saturating_mul(60)),
+        ))),
+    );
+    let list_post_revisions_service: Arc<dyn ListPostRevisionsAppService> = Arc::new(
+        ListPostRevisionsAppServiceImpl::new(Arc::new(ListPostRevisionsDomainService::new(
+            Arc::new(ListPostRevisionsRepositoryImpl::new(db_conn.clone())),
+            ng_word_policy.clone(),
+        ))),
+    );
+    let delete_post_service: Arc<dyn DeletePostAppService> =
+        Arc::new(DeletePostAppServiceImpl::new(Arc::new(
+            DeletePostDomainService::new(Arc::new(DeletePostRepositoryImpl::new(db_conn.clone()))),
         )));
+    let restore_post_service: Arc<dyn RestorePostAppService> = Arc::new(
+        RestorePostAppServiceImpl::new(Arc::new(RestorePostDomainService::new(Arc::new(
+            RestorePostRepositoryImpl::new(db_conn.clone()),
+        )))),
+    );
+    let login_app_service: Arc<dyn LoginAppService> =
+        Arc::new(LoginAppServiceImpl::new(Arc::new(LoginDomainService::new(
+            Arc::new(LoginRepositoryImpl::new(db_conn.clone())),
+            Arc::new(SessionRepositoryImpl::new(db_conn.clone())),
+        ))));
+
+    // 期限切れセッションの定期削除
+    let purge_login_app_service = login_app_service.clone();
+    actix_web::rt::spawn(async move {
+        let mut interval = actix_web::rt::time::interval(SESSION_PURGE_INTERVAL);
+        loop {
+            interval.tick().await;
+            // エラーはアプリケーションサービスでログ出力済みなので次の周期で再試行する
+            let _ = purge_login_app_service.purge_expired_sessions().await;
+        }
+    });
+
+    let get_all_post_service: Arc<dyn GetAllPostAppService> = Arc::new(
+        GetAllPostAppServiceImpl::new(Arc::new(GetAllPostDomainService::new(
+            Arc::new(GetAllPostRepositoryImpl::new(db_conn.clone())),
+            ng_word_policy.clone(),
+        ))),
+    );
+
+    let get_all_raw_post_service: Arc<dyn GetAllRawPostAppService> = Arc::new(
+        GetAllRawPostAppServiceImpl::new(Arc::new(GetAllRawPostDomainService::new(Arc::new(
+            GetAllRawPostRepositoryImpl::new(db_conn.clone()),
+        )))),
+    );
 
-    let register_ng_word_service: Arc<dyn RegisterNgWordAppService> =
-        Arc::new(RegisterNgWordAppServiceImpl::new(Arc::new(
-            RegisterNgWordDomainService::new(Arc::new(RegisterNgWordRepositoryImpl::new(db_conn))),
+    let get_post_service: Arc<dyn GetPostAppService> = Arc::new(GetPostAppServiceImpl::new(
+        Arc::new(GetPostDomainService::new(
+            Arc::new(GetPostRepositoryImpl::new(db_conn.clone())),
+            ng_word_policy.clone(),
+        )),
+    ));
+    let get_post_thread_service: Arc<dyn GetPostThreadAppService> = Arc::new(
+        GetPostThreadAppServiceImpl::new(Arc::new(GetPostThreadDomainService::new(
+            Arc::new(GetPostThreadRepositoryImpl::new(db_conn.clone())),
+            ng_word_policy.clone(),
+        ))),
+    );
+    let search_post_service: Arc<dyn SearchPostAppService> = Arc::new(
+        SearchPostAppServiceImpl::new(Arc::new(SearchPostDomainService::new(
+            Arc::new(SearchPostRepositoryImpl::new(
+                db_conn.clone(),
+                post_search_index.clone(),
+            )),
+            ng_word_policy.clone(),
+        ))),
+    );
+
+    let register_ng_word_service: Arc<dyn RegisterNgWordAppService> = Arc::new(
+        RegisterNgWordAppServiceImpl::new(Arc::new(RegisterNgWordDomainService::new(Arc::new(
+            RegisterNgWordRepositoryImpl::new(db_conn.clone(), ng_word_rule_cache.clone()),
+        )))),
+    );
+    let import_ng_words_service: Arc<dyn ImportNgWordsAppService> = Arc::new(
+        ImportNgWordsAppServiceImpl::new(Arc::new(ImportNgWordsDomainService::new(Arc::new(
+            ImportNgWordsRepositoryImpl::new(db_conn.clone(), ng_word_rule_cache.clone()),
+        )))),
+    );
+    let export_ng_words_service: Arc<dyn ExportNgWordsAppService> = Arc::new(
+        ExportNgWordsAppServiceImpl::new(Arc::new(ExportNgWordsDomainService::new(Arc::new(
+            ExportNgWordsRepositoryImpl::new(db_conn.clone()),
+        )))),
+    );
+    let check_ng_words_service: Arc<dyn CheckNgWordsAppService> = Arc::new(
+        CheckNgWordsAppServiceImpl::new(Arc::new(CheckNgWordsDomainService::new(ng_word_policy))),
+    );
+    let list_ng_words_service: Arc<dyn ListNgWordsAppService> = Arc::new(
+        ListNgWordsAppServiceImpl::new(Arc::new(ListNgWordsDomainService::new(Arc::new(
+            ListNgWordsRepositoryImpl::new(db_conn.clone()),
+        )))),
+    );
+    let update_ng_word_service: Arc<dyn UpdateNgWordAppService> = Arc::new(
+        UpdateNgWordAppServiceImpl::new(Arc::new(UpdateNgWordDomainService::new(Arc::new(
+            UpdateNgWordRepositoryImpl::new(db_conn.clone(), ng_word_rule_cache.clone()),
+        )))),
+    );
+    let delete_ng_word_service: Arc<dyn DeleteNgWordAppService> = Arc::new(
+        DeleteNgWordAppServiceImpl::new(Arc::new(DeleteNgWordDomainService::new(Arc::new(
+            DeleteNgWordRepositoryImpl::new(db_conn.clone(), ng_word_rule_cache.clone()),
+        )))),
+    );
+    let register_allow_word_service: Arc<dyn RegisterAllowWordAppService> =
+        Arc::new(RegisterAllowWordAppServiceImpl::new(Arc::new(
+            RegisterAllowWordDomainService::new(Arc::new(RegisterAllowWordRepositoryImpl::new(
+                db_conn.clone(),
+                ng_word_rule_cache.clone(),
+            ))),
+        )));
+    let list_allow_words_service: Arc<dyn ListAllowWordsAppService> = Arc::new(
+        ListAllowWordsAppServiceImpl::new(Arc::new(ListAllowWordsDomainService::new(Arc::new(
+            ListAllowWordsRepositoryImpl::new(db_conn.clone()),
+        )))),
+    );
+    let delete_allow_word_service: Arc<dyn DeleteAllowWordAppService> = Arc::new(
+        DeleteAllowWordAppServiceImpl::new(Arc::new(DeleteAllowWordDomainService::new(Arc::new(
+            DeleteAllowWordRepositoryImpl::new(db_conn.clone(), ng_word_rule_cache),
+        )))),
+    );
+    let reaction_kinds = ReactionKindSet::parse(
+        &env::var("REACTION_KINDS").unwrap_or_else(|_| DEFAULT_REACTION_KINDS.to_string()),
+    )
+    .expect("REACTION_KINDS is invalid");
+    let register_reaction_service: Arc<dyn RegisterReactionAppService> = Arc::new(
+        RegisterReactionAppServiceImpl::new(Arc::new(RegisterReactionDomainService::new(
+            Arc::new(RegisterReactionRepositoryImpl::new(db_conn.clone())),
+            reaction_kinds,
+        ))),
+    );
+    let delete_reaction_service: Arc<dyn DeleteReactionAppService> = Arc::new(
+        DeleteReactionAppServiceImpl::new(Arc::new(DeleteReactionDomainService::new(Arc::new(
+            DeleteReactionRepositoryImpl::new(db_conn.clone()),
+        )))),
+    );
+    let register_mute_word_service: Arc<dyn RegisterMuteWordAppService> = Arc::new(
+        RegisterMuteWordAppServiceImpl::new(Arc::new(RegisterMuteWordDomainService::new(
+            Arc::new(RegisterMuteWordRepositoryImpl::new(db_conn.clone())),
+        ))),
+    );
+    let list_mute_words_service: Arc<dyn ListMuteWordsAppService> = Arc::new(
+        ListMuteWordsAppServiceImpl::new(Arc::new(ListMuteWordsDomainService::new(Arc::new(
+            ListMuteWordsRepositoryImpl::new(db_conn.clone()),
+        )))),
+    );
+    let delete_mute_word_service: Arc<dyn DeleteMuteWordAppService> =
+        Arc::new(DeleteMuteWordAppServiceImpl::new(Arc::new(
+            DeleteMuteWordDomainService::new(Arc::new(DeleteMuteWordRepositoryImpl::new(db_conn))),
         )));
 
     println!("Playground: http://localhost:8000");
@@ -80,13 +370,61 @@ async fn main() -> std::io::Result<()> {
             .service(post_user)
             .app_data(Data::new(register_user_name_app_service.clone()))
             .service(post_post)
+            .service(post_reply)
             .app_data(Data::new(post_app_service.clone()))
+            .service(update_post)
+            .app_data(Data::new(update_post_service.clone()))
+            .service(list_post_revisions)
+            .app_data(Data::new(list_post_revisions_service.clone()))
+            .service(delete_post)
+            .app_data(Data::new(delete_post_service.clone()))
+            .service(restore_post)
+            .app_data(Data::new(restore_post_service.clone()))
             .service(post_login)
+            .service(post_logout)
+            .service(post_logout_all)
             .app_data(Data::new(login_app_service.clone()))
             .service(get_all_post)
             .app_data(Data::new(get_all_post_service.clone()))
+            .service(get_all_raw_post)
+            .app_data(Data::new(get_all_raw_post_service.clone()))
+            .service(search_post)
+            .app_data(Data::new(search_post_service.clone()))
+            // `/post/raw`と`/post/search`より後に登録する
+            .service(get_post)
+            .app_data(Data::new(get_post_service.clone()))
+            .service(get_post_thread)
+            .app_data(Data::new(get_post_thread_service.clone()))
+            .service(register_reaction)
+            .app_data(Data::new(register_reaction_service.clone()))
+            .service(delete_reaction)
+            .app_data(Data::new(delete_reaction_service.clone()))
             .service(register_ng_word)
             .app_data(Data::new(register_ng_word_service.clone()))
+            .service(import_ng_words)
+            .app_data(Data::new(import_ng_words_service.clone()))
+            .service(export_ng_words)
+            .app_data(Data::new(export_ng_words_service.clone()))
+            .service(check_ng_words)
+            .app_data(Data::new(check_ng_words_service.clone()))
+            .service(list_ng_words)
+            .app_data(Data::new(list_ng_words_service.clone()))
+            .service(update_ng_word)
+            .app_data(Data::new(update_ng_word_service.clone()))
+            .service(delete_ng_word)
+            .app_data(Data::new(delete_ng_word_service.clone()))
+            .service(register_allow_word)
+            .app_data(Data::new(register_allow_word_service.clone()))
+            .service(list_allow_words)
+            .app_data(Data::new(list_allow_words_service.clone()))
+            .service(delete_allow_word)
+            .app_data(Data::new(delete_allow_word_service.clone()))
+            .service(register_mute_word)
+            .app_data(Data::new(register_mute_word_service.clone()))
+            .service(list_mute_words)
+            .app_data(Data::new(list_mute_words_service.clone()))
+            .service(delete_mute_word)
+            .app_data(Data::new(delete_mute_word_service.clone()))
     };
     // ローカルサーバー
     HttpServer::new(factory)